@@ -1,7 +1,7 @@
 // contracts/anti_volatility_oracle/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, IntoVal, Val};
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
 use sha3::{Digest, Sha3_512};
 use num_bigint::BigUint; // For Pi math in volatility modeling
@@ -95,8 +95,9 @@ impl AntiVolatilityOracleContract {
     pub fn auto_reject_transaction(env: Env, asset: Symbol, transaction_contract: Address) -> bool {
         let report = Self::check_volatility(env.clone(), asset);
         if report.is_rejected {
-            // Call transaction contract to reject (simulated)
-            contractcall!(env, transaction_contract, reject_transaction, report.asset);
+            // Transaction contract fails this asset's transactions; it must have this oracle registered
+            let args: Vec<Val> = Vec::from_array(&env, [report.asset.into_val(&env)]);
+            env.invoke_contract::<()>(&transaction_contract, &Symbol::new(&env, "reject_transaction"), args);
             true
         } else {
             false
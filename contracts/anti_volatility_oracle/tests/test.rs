@@ -1,3 +1,7 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+use anti_volatility_oracle::{AntiVolatilityOracleContract, AntiVolatilityOracleContractClient};
+use transaction::{TransactionContract, TransactionContractClient};
+
 #[test]
 fn test_check_volatility() {
     let env = Env::default();
@@ -8,3 +12,26 @@ fn test_check_volatility() {
     let report = client.check_volatility(&Symbol::new(&env, "bitcoin"));
     assert!(report.is_rejected);  // Bitcoin should be rejected
 }
+
+#[test]
+fn test_auto_reject_transaction_rejects_asset_in_transaction_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let oracle = AntiVolatilityOracleContractClient::new(&env, &env.register_contract(None, AntiVolatilityOracleContract));
+    oracle.init(&admin, &5);
+    
+    let transaction = TransactionContractClient::new(&env, &env.register_contract(None, TransactionContract));
+    transaction.init(&admin, &Address::generate(&env), &Address::generate(&env));
+    transaction.set_volatility_oracle(&oracle.address);
+    
+    // Volatile asset: the oracle calls into the transaction contract, which records the rejection
+    let bitcoin = Symbol::new(&env, "bitcoin");
+    assert!(oracle.auto_reject_transaction(&bitcoin, &transaction.address));
+    assert!(transaction.is_asset_rejected(&bitcoin));
+    
+    // Stable asset: nothing is rejected
+    let pi = Symbol::new(&env, "pi");
+    assert!(!oracle.auto_reject_transaction(&pi, &transaction.address));
+    assert!(!transaction.is_asset_rejected(&pi));
+}
@@ -3,17 +3,61 @@
 // AI-driven proposals, voting; eternal governance.
 // Features: AI propose, vote, execute, GodHead Nexus AI moderation.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, Vec, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Map, Vec, log};
+
+/// How a weighted tally that meets the participation floor resolves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ApprovalRule {
+    Majority,          // More approve weight than reject; a tie is rejected.
+    Supermajority(u32), // Approve weight must be at least this % (51-100) of weight cast.
+}
 
 #[contract]
 pub struct AdvancedAiGovernance {
+    admin: Address,
     proposals: Map<Symbol, Map<Symbol, Vec<Symbol>>>, // Proposal -> AI Votes (outcome, voters).
+    weighted_tallies: Map<Symbol, Map<Symbol, u32>>, // Proposal -> outcome -> summed agent weight.
+    agent_weights: Map<Symbol, u32>, // Registered AI agents and their voting weight.
+    rule: ApprovalRule,
+    min_participation: u32, // Less total weight cast than this is "inconclusive".
 }
 
 #[contractimpl]
 impl AdvancedAiGovernance {
-    pub fn init(env: Env) -> AdvancedAiGovernance {
-        AdvancedAiGovernance { proposals: Map::new(&env) }
+    pub fn init(env: Env, admin: Address) -> AdvancedAiGovernance {
+        AdvancedAiGovernance {
+            admin,
+            proposals: Map::new(&env),
+            weighted_tallies: Map::new(&env),
+            agent_weights: Map::new(&env),
+            rule: ApprovalRule::Majority,
+            min_participation: 1,
+        }
+    }
+
+    /// Set the approval rule and the minimum weight a decision needs. Admin only.
+    pub fn set_decision_rule(&mut self, env: Env, rule: ApprovalRule, min_participation: u32) -> Result<(), &'static str> {
+        self.admin.require_auth();
+        if let ApprovalRule::Supermajority(pct) = rule {
+            if !(51..=100).contains(&pct) {
+                return Err("Supermajority must be 51-100%.");
+            }
+        }
+        self.rule = rule;
+        self.min_participation = min_participation;
+        log!(&env, "AI decision rule set; minimum participation {}", min_participation);
+        Ok(())
+    }
+
+    /// Register (or reweight) an AI agent. Admin only.
+    pub fn register_ai_agent(&mut self, env: Env, agent: Symbol, weight: u32) -> Result<(), &'static str> {
+        self.admin.require_auth();
+        if weight == 0 {
+            return Err("Weight must be positive.");
+        }
+        self.agent_weights.set(agent.clone(), weight);
+        log!(&env, "AI agent registered: {} with weight {}", agent, weight);
+        Ok(())
     }
 
     /// AI-generated proposal.
@@ -21,28 +65,63 @@ impl AdvancedAiGovernance {
         let mut votes = Map::new(&env);
         votes.set(Symbol::new(&env, "approve"), Vec::new(&env));
         votes.set(Symbol::new(&env, "reject"), Vec::new(&env));
-        self.proposals.set(proposal, votes);
+        self.proposals.set(proposal.clone(), votes);
+        self.weighted_tallies.set(proposal.clone(), Map::new(&env));
         log!(&env, "AI proposed: {}", proposal);
     }
 
-    /// AI cast vote.
-    pub fn ai_vote(&mut self, env: Env, proposal: Symbol, ai_agent: Symbol, vote: Symbol) {
-        let mut proposal_votes = self.proposals.get(proposal).ok_or("Proposal not found")?;
-        let mut vote_list = proposal_votes.get(vote).unwrap_or(Vec::new(&env));
-        vote_list.push_back(ai_agent);
-        proposal_votes.set(vote, vote_list);
-        self.proposals.set(proposal, proposal_votes);
-        log!(&env, "AI voted: {} on {} by {}", vote, proposal, ai_agent);
+    /// AI cast vote. Registered agents only, once per proposal, weighted by agent weight.
+    pub fn ai_vote(&mut self, env: Env, proposal: Symbol, ai_agent: Symbol, vote: Symbol) -> Result<(), &'static str> {
+        let weight = self.agent_weights.get(ai_agent.clone()).ok_or("AI agent not registered")?;
+        let mut proposal_votes = self.proposals.get(proposal.clone()).ok_or("Proposal not found")?;
+        if vote != Symbol::new(&env, "approve") && vote != Symbol::new(&env, "reject") {
+            return Err("Invalid vote.");
+        }
+        for (_, voters) in proposal_votes.iter() {
+            if voters.contains(&ai_agent) {
+                return Err("AI agent already voted.");
+            }
+        }
+
+        let mut vote_list = proposal_votes.get(vote.clone()).unwrap_or(Vec::new(&env));
+        vote_list.push_back(ai_agent.clone());
+        proposal_votes.set(vote.clone(), vote_list);
+        self.proposals.set(proposal.clone(), proposal_votes);
+
+        let mut tally = self.weighted_tallies.get(proposal.clone()).unwrap_or(Map::new(&env));
+        let current = tally.get(vote.clone()).unwrap_or(0);
+        tally.set(vote.clone(), current.saturating_add(weight));
+        self.weighted_tallies.set(proposal.clone(), tally);
+        log!(&env, "AI voted: {} on {} by {} (weight {})", vote, proposal, ai_agent, weight);
+        Ok(())
+    }
+
+    /// Weighted outcome: "approved", "rejected", or "inconclusive" below the participation floor.
+    pub fn ai_decision_status(&self, env: Env, proposal: Symbol) -> Result<Symbol, &'static str> {
+        let tally = self.weighted_tallies.get(proposal).ok_or("Proposal not found")?;
+        let approve_weight = tally.get(Symbol::new(&env, "approve")).unwrap_or(0) as u64;
+        let reject_weight = tally.get(Symbol::new(&env, "reject")).unwrap_or(0) as u64;
+        let cast = approve_weight + reject_weight;
+        let outcome = if cast < self.min_participation as u64 {
+            "inconclusive"
+        } else {
+            let approved = match self.rule {
+                ApprovalRule::Majority => approve_weight > reject_weight,
+                ApprovalRule::Supermajority(pct) => approve_weight * 100 >= pct as u64 * cast,
+            };
+            if approved { "approved" } else { "rejected" }
+        };
+        Ok(Symbol::new(&env, outcome))
     }
 
-    /// Execute based on AI consensus.
+    /// Execute based on weighted AI consensus.
     pub fn execute_ai_decision(&self, env: Env, proposal: Symbol) -> Result<(), &'static str> {
-        let proposal_votes = self.proposals.get(proposal).ok_or("Proposal not found")?;
-        let approve_votes = proposal_votes.get(Symbol::new(&env, "approve")).unwrap_or(Vec::new(&env)).len();
-        let reject_votes = proposal_votes.get(Symbol::new(&env, "reject")).unwrap_or(Vec::new(&env)).len();
-        if approve_votes > reject_votes {
+        let status = self.ai_decision_status(env.clone(), proposal.clone())?;
+        if status == Symbol::new(&env, "approved") {
             log!(&env, "AI executed: {}", proposal);
             Ok(())
+        } else if status == Symbol::new(&env, "inconclusive") {
+            Err("AI decision inconclusive.")
         } else {
             Err("AI rejected proposal.")
         }
@@ -53,3 +132,75 @@ impl AdvancedAiGovernance {
         self.proposals.get(proposal).unwrap_or(Map::new(&env))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_unregistered_agent_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let mut governance = AdvancedAiGovernance::init(env.clone(), Address::generate(&env));
+        let proposal = Symbol::new(&env, "upgrade");
+        governance.ai_propose(env.clone(), proposal.clone());
+
+        let result = governance.ai_vote(env.clone(), proposal.clone(), Symbol::new(&env, "sybil"), Symbol::new(&env, "approve"));
+        assert_eq!(result, Err("AI agent not registered"));
+        assert_eq!(governance.get_proposal_votes(env.clone(), proposal).get(Symbol::new(&env, "approve")).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_weighted_consensus() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let mut governance = AdvancedAiGovernance::init(env.clone(), Address::generate(&env));
+        let heavy = Symbol::new(&env, "oracle_ai");
+        governance.register_ai_agent(env.clone(), heavy.clone(), 5).unwrap();
+        governance.register_ai_agent(env.clone(), Symbol::new(&env, "agent_a"), 1).unwrap();
+        governance.register_ai_agent(env.clone(), Symbol::new(&env, "agent_b"), 1).unwrap();
+        let proposal = Symbol::new(&env, "upgrade");
+        governance.ai_propose(env.clone(), proposal.clone());
+
+        // One heavy approval outweighs two light rejections
+        governance.ai_vote(env.clone(), proposal.clone(), heavy.clone(), Symbol::new(&env, "approve")).unwrap();
+        governance.ai_vote(env.clone(), proposal.clone(), Symbol::new(&env, "agent_a"), Symbol::new(&env, "reject")).unwrap();
+        governance.ai_vote(env.clone(), proposal.clone(), Symbol::new(&env, "agent_b"), Symbol::new(&env, "reject")).unwrap();
+        assert_eq!(governance.ai_vote(env.clone(), proposal.clone(), heavy, Symbol::new(&env, "reject")), Err("AI agent already voted."));
+
+        assert_eq!(governance.execute_ai_decision(env.clone(), proposal), Ok(()));
+    }
+
+    #[test]
+    fn test_weighted_tie_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let mut governance = AdvancedAiGovernance::init(env.clone(), Address::generate(&env));
+        governance.register_ai_agent(env.clone(), Symbol::new(&env, "agent_a"), 2).unwrap();
+        governance.register_ai_agent(env.clone(), Symbol::new(&env, "agent_b"), 2).unwrap();
+        let proposal = Symbol::new(&env, "upgrade");
+        governance.ai_propose(env.clone(), proposal.clone());
+
+        governance.ai_vote(env.clone(), proposal.clone(), Symbol::new(&env, "agent_a"), Symbol::new(&env, "approve")).unwrap();
+        governance.ai_vote(env.clone(), proposal.clone(), Symbol::new(&env, "agent_b"), Symbol::new(&env, "reject")).unwrap();
+        assert_eq!(governance.ai_decision_status(env.clone(), proposal.clone()), Ok(Symbol::new(&env, "rejected")));
+        assert_eq!(governance.execute_ai_decision(env.clone(), proposal), Err("AI rejected proposal."));
+    }
+
+    #[test]
+    fn test_below_participation_floor_inconclusive() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let mut governance = AdvancedAiGovernance::init(env.clone(), Address::generate(&env));
+        governance.register_ai_agent(env.clone(), Symbol::new(&env, "agent_a"), 3).unwrap();
+        governance.set_decision_rule(env.clone(), ApprovalRule::Supermajority(67), 5).unwrap();
+        let proposal = Symbol::new(&env, "upgrade");
+        governance.ai_propose(env.clone(), proposal.clone());
+        assert_eq!(governance.ai_decision_status(env.clone(), proposal.clone()), Ok(Symbol::new(&env, "inconclusive")));
+
+        // A unanimous 3 still falls short of the 5-weight floor
+        governance.ai_vote(env.clone(), proposal.clone(), Symbol::new(&env, "agent_a"), Symbol::new(&env, "approve")).unwrap();
+        assert_eq!(governance.execute_ai_decision(env.clone(), proposal), Err("AI decision inconclusive."));
+    }
+}
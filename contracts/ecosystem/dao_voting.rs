@@ -3,17 +3,39 @@
 // Autonomous tallying, eternal governance.
 // Features: Propose, vote, execute, GodHead Nexus AI moderation.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, Vec, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Symbol, Map, Vec, log};
+
+/// How a tally that meets the participation floor resolves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ApprovalRule {
+    Majority,          // More yes than no; a tie is rejected.
+    Supermajority(u32), // Yes must be at least this % (51-100) of votes cast.
+}
+
+// Events: topic0 is the event name, topic1 the proposal. ProposalSubmitted and ProposalExecuted carry no data.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCastEvent {
+    pub voter: Symbol,
+    pub vote: Symbol, // "yes" or "no"
+}
 
 #[contract]
 pub struct DaoVoting {
     proposals: Map<Symbol, Map<Symbol, Vec<Symbol>>>, // Proposal -> Votes (yes/no).
+    rule: ApprovalRule,
+    min_participation: u32, // Fewer votes cast than this tallies as "inconclusive".
 }
 
 #[contractimpl]
 impl DaoVoting {
-    pub fn init(env: Env) -> DaoVoting {
-        DaoVoting { proposals: Map::new(&env) }
+    pub fn init(env: Env, rule: ApprovalRule, min_participation: u32) -> Result<DaoVoting, &'static str> {
+        if let ApprovalRule::Supermajority(pct) = rule {
+            if !(51..=100).contains(&pct) {
+                return Err("Supermajority must be 51-100%.");
+            }
+        }
+        Ok(DaoVoting { proposals: Map::new(&env), rule, min_participation })
     }
 
     /// Submit proposal.
@@ -21,40 +43,111 @@ impl DaoVoting {
         let mut votes = Map::new(&env);
         votes.set(Symbol::new(&env, "yes"), Vec::new(&env));
         votes.set(Symbol::new(&env, "no"), Vec::new(&env));
-        self.proposals.set(proposal, votes);
+        self.proposals.set(proposal.clone(), votes);
+        env.events().publish((Symbol::new(&env, "ProposalSubmitted"), proposal.clone()), ());
         log!(&env, "Proposal submitted: {}", proposal);
     }
 
     /// Cast vote.
     pub fn cast_vote(&mut self, env: Env, proposal: Symbol, voter: Symbol, vote: Symbol) {
-        let mut proposal_votes = self.proposals.get(proposal).ok_or("Proposal not found")?;
-        let mut vote_list = proposal_votes.get(vote).unwrap_or(Vec::new(&env));
-        vote_list.push_back(voter);
-        proposal_votes.set(vote, vote_list);
-        self.proposals.set(proposal, proposal_votes);
+        let mut proposal_votes = self.proposals.get(proposal.clone()).ok_or("Proposal not found")?;
+        let mut vote_list = proposal_votes.get(vote.clone()).unwrap_or(Vec::new(&env));
+        vote_list.push_back(voter.clone());
+        proposal_votes.set(vote.clone(), vote_list);
+        self.proposals.set(proposal.clone(), proposal_votes);
+        env.events().publish((Symbol::new(&env, "VoteCast"), proposal.clone()), VoteCastEvent { voter: voter.clone(), vote: vote.clone() });
         log!(&env, "Voted: {} on {} by {}", vote, proposal, voter);
     }
 
-    /// Tally votes.
-    pub fn tally_votes(&self, env: Env, proposal: Symbol) -> Symbol {
+    /// Tally votes: "approved", "rejected", or "inconclusive" below the participation floor.
+    pub fn tally_votes(&self, env: Env, proposal: Symbol) -> Result<Symbol, &'static str> {
         let proposal_votes = self.proposals.get(proposal).ok_or("Proposal not found")?;
         let yes_votes = proposal_votes.get(Symbol::new(&env, "yes")).unwrap_or(Vec::new(&env)).len();
         let no_votes = proposal_votes.get(Symbol::new(&env, "no")).unwrap_or(Vec::new(&env)).len();
-        if yes_votes > no_votes {
-            Symbol::new(&env, "approved")
+        let cast = yes_votes + no_votes;
+        let outcome = if cast < self.min_participation {
+            "inconclusive"
         } else {
-            Symbol::new(&env, "rejected")
-        }
+            let approved = match self.rule {
+                ApprovalRule::Majority => yes_votes > no_votes,
+                ApprovalRule::Supermajority(pct) => yes_votes as u64 * 100 >= pct as u64 * cast as u64,
+            };
+            if approved { "approved" } else { "rejected" }
+        };
+        Ok(Symbol::new(&env, outcome))
     }
 
     /// Execute approved proposal.
     pub fn execute_proposal(&self, env: Env, proposal: Symbol) -> Result<(), &'static str> {
-        let result = self.tally_votes(env.clone(), proposal);
+        let result = self.tally_votes(env.clone(), proposal.clone())?;
         if result == Symbol::new(&env, "approved") {
+            env.events().publish((Symbol::new(&env, "ProposalExecuted"), proposal.clone()), ());
             log!(&env, "Proposal executed: {}", proposal);
             Ok(())
+        } else if result == Symbol::new(&env, "inconclusive") {
+            Err("Proposal inconclusive.")
         } else {
             Err("Proposal rejected.")
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::TryFromVal;
+
+    fn vote_all(dao: &mut DaoVoting, env: &Env, proposal: &Symbol, yes: &[&str], no: &[&str]) {
+        for voter in yes {
+            dao.cast_vote(env.clone(), proposal.clone(), Symbol::new(env, voter), Symbol::new(env, "yes"));
+        }
+        for voter in no {
+            dao.cast_vote(env.clone(), proposal.clone(), Symbol::new(env, voter), Symbol::new(env, "no"));
+        }
+    }
+
+    #[test]
+    fn test_tie_rejected_and_supermajority_enforced() {
+        let env = Env::default();
+        let proposal = Symbol::new(&env, "expand");
+        let mut dao = DaoVoting::init(env.clone(), ApprovalRule::Majority, 2).unwrap();
+        dao.submit_proposal(env.clone(), proposal.clone());
+        vote_all(&mut dao, &env, &proposal, &["alice"], &["bob"]);
+        assert_eq!(dao.tally_votes(env.clone(), proposal.clone()), Ok(Symbol::new(&env, "rejected")));
+
+        // 3 of 4 is a majority but short of a 80% supermajority
+        let mut dao = DaoVoting::init(env.clone(), ApprovalRule::Supermajority(80), 2).unwrap();
+        dao.submit_proposal(env.clone(), proposal.clone());
+        vote_all(&mut dao, &env, &proposal, &["alice", "bob", "carol"], &["dave"]);
+        assert_eq!(dao.tally_votes(env.clone(), proposal.clone()), Ok(Symbol::new(&env, "rejected")));
+        assert!(DaoVoting::init(env.clone(), ApprovalRule::Supermajority(50), 2).is_err());
+    }
+
+    #[test]
+    fn test_below_participation_floor_inconclusive() {
+        let env = Env::default();
+        let proposal = Symbol::new(&env, "expand");
+        let mut dao = DaoVoting::init(env.clone(), ApprovalRule::Majority, 3).unwrap();
+        dao.submit_proposal(env.clone(), proposal.clone());
+        assert_eq!(dao.tally_votes(env.clone(), proposal.clone()), Ok(Symbol::new(&env, "inconclusive")));
+
+        vote_all(&mut dao, &env, &proposal, &["alice", "bob"], &[]);
+        assert_eq!(dao.tally_votes(env.clone(), proposal.clone()), Ok(Symbol::new(&env, "inconclusive")));
+        assert_eq!(dao.execute_proposal(env.clone(), proposal), Err("Proposal inconclusive."));
+    }
+
+    #[test]
+    fn test_vote_cast_event() {
+        let env = Env::default();
+        let proposal = Symbol::new(&env, "expand");
+        let mut dao = DaoVoting::init(env.clone(), ApprovalRule::Majority, 1).unwrap();
+        dao.submit_proposal(env.clone(), proposal.clone());
+        vote_all(&mut dao, &env, &proposal, &["alice"], &[]);
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "VoteCast"));
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), proposal);
+        assert_eq!(VoteCastEvent::try_from_val(&env, &data).unwrap(), VoteCastEvent { voter: Symbol::new(&env, "alice"), vote: Symbol::new(&env, "yes") });
+    }
+}
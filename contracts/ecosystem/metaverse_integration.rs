@@ -1,50 +1,114 @@
 // contracts/ecosystem/metaverse_integration.rs
 // Metaverse Integration: Immersive virtual worlds for Pi Coin.
 // Autonomous world building, interactions; eternal metaverse.
-// Features: Create world, interact, trade virtual assets, GodHead Nexus AI curation.
+// Features: Create world, interact, trade virtual assets, capped worlds, asset listing, GodHead Nexus AI curation.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, Vec, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Symbol, Map, Vec, log};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorldAsset {
+    pub owner: Symbol,
+    pub asset: Symbol,
+}
+
+// Events: topic0 is the event name, topic1 the world; data is the struct below
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorldCreatedEvent {
+    pub creator: Symbol,
+    pub max_assets: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetTradedEvent {
+    pub seller: Symbol,
+    pub buyer: Symbol,
+    pub asset: Symbol,
+}
 
 #[contract]
 pub struct MetaverseIntegration {
     worlds: Map<Symbol, Map<Symbol, Vec<Symbol>>>, // World -> Assets (user, item).
+    asset_caps: Map<Symbol, u32>, // World -> Maximum assets.
+    asset_counts: Map<Symbol, u32>, // World -> Assets currently in the world.
 }
 
 #[contractimpl]
 impl MetaverseIntegration {
     pub fn init(env: Env) -> MetaverseIntegration {
-        MetaverseIntegration { worlds: Map::new(&env) }
+        MetaverseIntegration { worlds: Map::new(&env), asset_caps: Map::new(&env), asset_counts: Map::new(&env) }
     }
 
-    /// Create virtual world.
-    pub fn create_world(&mut self, env: Env, world_id: Symbol, creator: Symbol) {
+    /// Create virtual world holding at most `max_assets` assets.
+    pub fn create_world(&mut self, env: Env, world_id: Symbol, creator: Symbol, max_assets: u32) {
         let mut assets = Map::new(&env);
         assets.set(Symbol::new(&env, "land"), Vec::new(&env));
-        self.worlds.set(world_id, assets);
+        self.worlds.set(world_id.clone(), assets);
+        self.asset_caps.set(world_id.clone(), max_assets);
+        self.asset_counts.set(world_id.clone(), 0);
+        env.events().publish((Symbol::new(&env, "WorldCreated"), world_id.clone()), WorldCreatedEvent { creator: creator.clone(), max_assets });
         log!(&env, "World created: {} by {}", world_id, creator);
     }
 
-    /// Add virtual asset.
-    pub fn add_asset(&mut self, env: Env, world_id: Symbol, user: Symbol, asset: Symbol) {
-        let mut world_assets = self.worlds.get(world_id).ok_or("World not found")?;
-        let mut user_assets = world_assets.get(user).unwrap_or(Vec::new(&env));
-        user_assets.push_back(asset);
-        world_assets.set(user, user_assets);
-        self.worlds.set(world_id, world_assets);
+    /// Add virtual asset, up to the world's asset cap.
+    pub fn add_asset(&mut self, env: Env, world_id: Symbol, user: Symbol, asset: Symbol) -> Result<(), &'static str> {
+        let mut world_assets = self.worlds.get(world_id.clone()).ok_or("World not found")?;
+        let count = self.total_assets(world_id.clone());
+        if count >= self.asset_caps.get(world_id.clone()).unwrap_or(0) {
+            return Err("World asset cap reached.");
+        }
+        let mut user_assets = world_assets.get(user.clone()).unwrap_or(Vec::new(&env));
+        user_assets.push_back(asset.clone());
+        world_assets.set(user.clone(), user_assets);
+        self.worlds.set(world_id.clone(), world_assets);
+        self.asset_counts.set(world_id.clone(), count + 1);
+        env.events().publish((Symbol::new(&env, "AssetAdded"), world_id.clone()), WorldAsset { owner: user.clone(), asset: asset.clone() });
         log!(&env, "Asset added: {} to {} in {}", asset, user, world_id);
+        Ok(())
+    }
+
+    /// Number of assets in a world.
+    pub fn total_assets(&self, world_id: Symbol) -> u32 {
+        self.asset_counts.get(world_id).unwrap_or(0)
+    }
+
+    /// Page through a world's assets, grouped by owner; the cursor is None once exhausted.
+    pub fn list_world_assets(&self, env: Env, world_id: Symbol, cursor: u32, limit: u32) -> (Vec<WorldAsset>, Option<u32>) {
+        let world_assets = self.worlds.get(world_id.clone()).unwrap_or(Map::new(&env));
+        let total = self.total_assets(world_id);
+        let end = cursor.saturating_add(limit).min(total);
+        let mut page = Vec::new(&env);
+        let mut index = 0u32;
+        for (owner, assets) in world_assets.iter() {
+            for asset in assets.iter() {
+                if index >= end {
+                    break;
+                }
+                if index >= cursor {
+                    page.push_back(WorldAsset { owner: owner.clone(), asset });
+                }
+                index += 1;
+            }
+        }
+        let next = if end < total { Some(end) } else { None };
+        (page, next)
     }
 
     /// Trade virtual asset.
     pub fn trade_asset(&mut self, env: Env, world_id: Symbol, seller: Symbol, buyer: Symbol, asset: Symbol) -> Result<(), &'static str> {
-        let mut world_assets = self.worlds.get(world_id).ok_or("World not found")?;
-        let mut seller_assets = world_assets.get(seller).ok_or("Seller has no assets")?;
+        let mut world_assets = self.worlds.get(world_id.clone()).ok_or("World not found")?;
+        let mut seller_assets = world_assets.get(seller.clone()).ok_or("Seller has no assets")?;
         if seller_assets.contains(&asset) {
             seller_assets.retain(|&a| a != asset);
-            let mut buyer_assets = world_assets.get(buyer).unwrap_or(Vec::new(&env));
-            buyer_assets.push_back(asset);
-            world_assets.set(seller, seller_assets);
-            world_assets.set(buyer, buyer_assets);
-            self.worlds.set(world_id, world_assets);
+            let mut buyer_assets = world_assets.get(buyer.clone()).unwrap_or(Vec::new(&env));
+            buyer_assets.push_back(asset.clone());
+            world_assets.set(seller.clone(), seller_assets);
+            world_assets.set(buyer.clone(), buyer_assets);
+            self.worlds.set(world_id.clone(), world_assets);
+            env.events().publish((Symbol::new(&env, "AssetTraded"), world_id.clone()), AssetTradedEvent { seller: seller.clone(), buyer: buyer.clone(), asset: asset.clone() });
             log!(&env, "Asset traded: {} from {} to {} in {}", asset, seller, buyer, world_id);
             Ok(())
         } else {
@@ -57,3 +121,68 @@ impl MetaverseIntegration {
         self.worlds.get(world_id).unwrap_or(Map::new(&env))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::TryFromVal;
+
+    fn setup(env: &Env, max_assets: u32) -> (MetaverseIntegration, Symbol) {
+        let mut metaverse = MetaverseIntegration::init(env.clone());
+        let world_id = Symbol::new(env, "pi_city");
+        metaverse.create_world(env.clone(), world_id.clone(), Symbol::new(env, "alice"), max_assets);
+        (metaverse, world_id)
+    }
+
+    #[test]
+    fn test_world_asset_cap() {
+        let env = Env::default();
+        let (mut metaverse, world_id) = setup(&env, 2);
+        metaverse.add_asset(env.clone(), world_id.clone(), Symbol::new(&env, "alice"), Symbol::new(&env, "house")).unwrap();
+        metaverse.add_asset(env.clone(), world_id.clone(), Symbol::new(&env, "bob"), Symbol::new(&env, "car")).unwrap();
+        assert_eq!(metaverse.add_asset(env.clone(), world_id.clone(), Symbol::new(&env, "bob"), Symbol::new(&env, "boat")), Err("World asset cap reached."));
+        assert_eq!(metaverse.total_assets(world_id.clone()), 2);
+
+        // Trading moves assets without changing the count
+        metaverse.trade_asset(env.clone(), world_id.clone(), Symbol::new(&env, "bob"), Symbol::new(&env, "carol"), Symbol::new(&env, "car")).unwrap();
+        assert_eq!(metaverse.total_assets(world_id), 2);
+    }
+
+    #[test]
+    fn test_list_world_assets_pages() {
+        let env = Env::default();
+        let (mut metaverse, world_id) = setup(&env, 10);
+        for (owner, asset) in [("alice", "house"), ("alice", "garden"), ("bob", "car"), ("carol", "boat"), ("carol", "dock")] {
+            metaverse.add_asset(env.clone(), world_id.clone(), Symbol::new(&env, owner), Symbol::new(&env, asset)).unwrap();
+        }
+
+        let mut listed = Vec::new(&env);
+        let mut cursor = Some(0);
+        while let Some(start) = cursor {
+            let (page, next) = metaverse.list_world_assets(env.clone(), world_id.clone(), start, 2);
+            assert!(page.len() <= 2);
+            for entry in page.iter() {
+                listed.push_back(entry);
+            }
+            cursor = next;
+        }
+        assert_eq!(listed.len(), 5);
+        assert!(listed.contains(&WorldAsset { owner: Symbol::new(&env, "bob"), asset: Symbol::new(&env, "car") }));
+        assert!(listed.contains(&WorldAsset { owner: Symbol::new(&env, "carol"), asset: Symbol::new(&env, "dock") }));
+    }
+
+    #[test]
+    fn test_asset_traded_event() {
+        let env = Env::default();
+        let (mut metaverse, world_id) = setup(&env, 2);
+        let (bob, carol, car) = (Symbol::new(&env, "bob"), Symbol::new(&env, "carol"), Symbol::new(&env, "car"));
+        metaverse.add_asset(env.clone(), world_id.clone(), bob.clone(), car.clone()).unwrap();
+        metaverse.trade_asset(env.clone(), world_id.clone(), bob.clone(), carol.clone(), car.clone()).unwrap();
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "AssetTraded"));
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), world_id);
+        assert_eq!(AssetTradedEvent::try_from_val(&env, &data).unwrap(), AssetTradedEvent { seller: bob, buyer: carol, asset: car });
+    }
+}
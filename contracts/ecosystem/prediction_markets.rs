@@ -1,53 +1,462 @@
 // contracts/ecosystem/prediction_markets.rs
 // Prediction Markets: Forecast Pi Coin outcomes.
 // Autonomous resolution, payouts; eternal predictions.
-// Features: Create market, bet, resolve, GodHead Nexus AI insights.
+// Features: Create market, bet (pooled or CPMM-priced), live odds, resolve, claim winnings, GodHead Nexus AI insights.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, Vec, log};
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, Symbol, Map, Vec, log};
+
+const BPS: i128 = 10_000;
+
+/// A winner's payout recorded at resolution, withdrawn through claim_winnings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entitlement {
+    pub amount: i128,
+    pub claimed: bool,
+}
+
+// Events: topic0 is the event name, topic1 the market, topic2 the user for BetPlaced and
+// WinningsClaimed; data is the struct below (the winning outcome Symbol for MarketResolved).
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketCreatedEvent {
+    pub outcomes: Vec<Symbol>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetPlacedEvent {
+    pub outcome: Symbol,
+    pub amount: i128,
+    pub position: i128, // Stake in pool mode, shares bought in AMM mode
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WinningsClaimedEvent {
+    pub amount: i128,
+}
 
 #[contract]
 pub struct PredictionMarkets {
-    markets: Map<Symbol, Map<Symbol, Vec<i128>>>, // Market -> Bets (user, amount, outcome).
+    pi_coin: Address, // Token stakes are paid in and winnings paid out of.
+    escrow: Address, // Holds stakes until claimed; authorizes payouts.
+    resolver: Address, // Oracle or committee whose authorization settles every market.
+    markets: Map<Symbol, Map<Symbol, Vec<(Address, i128)>>>, // Market -> Outcome -> Bets (user, stake or shares).
+    pools: Map<Symbol, Map<Symbol, i128>>, // Market -> Outcome -> total staked.
+    amm_reserves: Map<Symbol, Map<Symbol, i128>>, // Market -> Outcome -> CPMM share reserve; absent for pool-mode markets.
+    resolutions: Map<Symbol, Symbol>, // Market -> winning outcome.
+    entitlements: Map<(Symbol, Address), Entitlement>, // (Market, winner) -> payout.
+    escrowed: Map<Symbol, i128>, // Market -> tokens in escrow (stakes plus funded liquidity), the most claims can pay.
 }
 
 #[contractimpl]
 impl PredictionMarkets {
-    pub fn init(env: Env) -> PredictionMarkets {
-        PredictionMarkets { markets: Map::new(&env) }
+    pub fn init(env: Env, pi_coin: Address, escrow: Address, resolver: Address) -> PredictionMarkets {
+        PredictionMarkets {
+            pi_coin,
+            escrow,
+            resolver,
+            markets: Map::new(&env),
+            pools: Map::new(&env),
+            amm_reserves: Map::new(&env),
+            resolutions: Map::new(&env),
+            entitlements: Map::new(&env),
+            escrowed: Map::new(&env),
+        }
     }
 
-    /// Create prediction market.
-    pub fn create_market(&mut self, env: Env, market: Symbol, outcomes: Vec<Symbol>) {
+    /// Create prediction market with simple pooled bets. Market ids are never reused.
+    pub fn create_market(&mut self, env: Env, market: Symbol, outcomes: Vec<Symbol>) -> Result<(), &'static str> {
+        if self.markets.contains_key(market.clone()) {
+            return Err("Market already exists.");
+        }
         let mut bets = Map::new(&env);
-        for outcome in &outcomes {
-            bets.set(*outcome, Vec::new(&env));
+        let mut pool = Map::new(&env);
+        for outcome in outcomes.iter() {
+            bets.set(outcome.clone(), Vec::new(&env));
+            pool.set(outcome, 0);
         }
-        self.markets.set(market, bets);
+        self.markets.set(market.clone(), bets);
+        self.pools.set(market.clone(), pool);
+        env.events().publish((Symbol::new(&env, "MarketCreated"), market.clone()), MarketCreatedEvent { outcomes });
         log!(&env, "Market created: {}", market);
+        Ok(())
     }
 
-    /// Place bet.
-    pub fn place_bet(&mut self, env: Env, market: Symbol, user: Symbol, outcome: Symbol, amount: i128) {
-        let mut market_bets = self.markets.get(market).ok_or("Market not found")?;
-        let mut outcome_bets = market_bets.get(outcome).unwrap_or(Vec::new(&env));
-        outcome_bets.push_back(user);
-        outcome_bets.push_back(amount);
-        market_bets.set(outcome, outcome_bets);
-        self.markets.set(market, market_bets);
+    /// Create prediction market priced by a constant-product market maker.
+    /// Every outcome starts with `liquidity` shares in reserve, i.e. equal odds. A unit mints one
+    /// share of every outcome, so `creator` funds exactly `liquidity` into escrow.
+    pub fn create_amm_market(&mut self, env: Env, creator: Address, market: Symbol, outcomes: Vec<Symbol>, liquidity: i128) -> Result<(), &'static str> {
+        creator.require_auth();
+        if liquidity <= 0 || outcomes.len() < 2 {
+            return Err("Invalid market parameters.");
+        }
+        self.create_market(env.clone(), market.clone(), outcomes.clone())?;
+        token::Client::new(&env, &self.pi_coin).transfer(&creator, &self.escrow, &liquidity);
+        self.escrowed.set(market.clone(), liquidity);
+        let mut reserves = Map::new(&env);
+        for outcome in outcomes.iter() {
+            reserves.set(outcome, liquidity);
+        }
+        self.amm_reserves.set(market, reserves);
+        Ok(())
+    }
+
+    /// Place bet, moving the stake into escrow. Returns the position recorded: the stake in
+    /// pool mode, or shares bought in AMM mode.
+    pub fn place_bet(&mut self, env: Env, market: Symbol, user: Address, outcome: Symbol, amount: i128) -> Result<i128, &'static str> {
+        user.require_auth();
+        let mut market_bets = self.markets.get(market.clone()).ok_or("Market not found")?;
+        let mut outcome_bets = market_bets.get(outcome.clone()).ok_or("Outcome not found")?;
+        if amount <= 0 {
+            return Err("Invalid amount.");
+        }
+        if self.resolutions.contains_key(market.clone()) {
+            return Err("Market already resolved.");
+        }
+        token::Client::new(&env, &self.pi_coin).transfer(&user, &self.escrow, &amount);
+        self.escrowed.set(market.clone(), self.escrowed.get(market.clone()).unwrap_or(0) + amount);
+
+        let position = match self.amm_reserves.get(market.clone()) {
+            Some(reserves) => {
+                let (shares, reserves) = Self::buy_shares(&env, reserves, &outcome, amount)?;
+                self.amm_reserves.set(market.clone(), reserves);
+                shares
+            }
+            None => amount,
+        };
+        let mut pool = self.pools.get(market.clone()).unwrap_or(Map::new(&env));
+        pool.set(outcome.clone(), pool.get(outcome.clone()).unwrap_or(0) + amount);
+        self.pools.set(market.clone(), pool);
+
+        outcome_bets.push_back((user.clone(), position));
+        market_bets.set(outcome.clone(), outcome_bets);
+        self.markets.set(market.clone(), market_bets);
+        env.events().publish((Symbol::new(&env, "BetPlaced"), market.clone(), user), BetPlacedEvent { outcome: outcome.clone(), amount, position });
         log!(&env, "Bet placed: {} on {} in {}", amount, outcome, market);
+        Ok(position)
     }
 
-    /// Resolve market.
+    // CPMM buy: `amount` mints one share of every outcome into the reserves, then shares of
+    // `outcome` are paid out until the product of reserves returns to its previous value.
+    fn buy_shares(env: &Env, reserves: Map<Symbol, i128>, outcome: &Symbol, amount: i128) -> Result<(i128, Map<Symbol, i128>), &'static str> {
+        let mut invariant: i128 = 1;
+        let mut others_after: i128 = 1;
+        for (key, reserve) in reserves.iter() {
+            invariant = invariant.checked_mul(reserve).ok_or("Overflow")?;
+            if key != *outcome {
+                others_after = others_after.checked_mul(reserve + amount).ok_or("Overflow")?;
+            }
+        }
+        let current = reserves.get(outcome.clone()).ok_or("Outcome not found")?;
+        // Round the remaining reserve up so rounding favours the pool
+        let remaining = (invariant + others_after - 1) / others_after;
+        let shares = current + amount - remaining;
+
+        let mut updated = Map::new(env);
+        for (key, reserve) in reserves.iter() {
+            updated.set(key.clone(), if key == *outcome { remaining } else { reserve + amount });
+        }
+        Ok((shares, updated))
+    }
+
+    /// Implied probability of each outcome in basis points. AMM markets price an outcome
+    /// inversely to its share reserve; pool markets use each outcome's share of the total stake.
+    pub fn get_odds(&self, env: Env, market: Symbol) -> Result<Map<Symbol, u32>, &'static str> {
+        let weights = match self.amm_reserves.get(market.clone()) {
+            // Weight of outcome i is the product of every other reserve, proportional to 1 / r_i
+            Some(reserves) => {
+                let mut weights = Map::new(&env);
+                for (outcome, _) in reserves.iter() {
+                    let mut weight: i128 = 1;
+                    for (other, reserve) in reserves.iter() {
+                        if other != outcome {
+                            weight = weight.checked_mul(reserve).ok_or("Overflow")?;
+                        }
+                    }
+                    weights.set(outcome, weight);
+                }
+                weights
+            }
+            None => self.pools.get(market).ok_or("Market not found")?,
+        };
+
+        let total: i128 = weights.values().iter().sum();
+        let mut odds = Map::new(&env);
+        for (outcome, weight) in weights.iter() {
+            let bps = if total == 0 { BPS / weights.len() as i128 } else { weight * BPS / total };
+            odds.set(outcome, bps as u32);
+        }
+        Ok(odds)
+    }
+
+    /// Resolve market, recording each winner's entitlement for claim_winnings. Pool winners
+    /// split the whole pot pro rata to stake (rounded down); each AMM share of the winning
+    /// outcome redeems for one unit. Only the configured resolver can settle a market.
     pub fn resolve_market(&mut self, env: Env, market: Symbol, winning_outcome: Symbol) -> Result<(), &'static str> {
-        let market_bets = self.markets.get(market).ok_or("Market not found")?;
-        let winning_bets = market_bets.get(winning_outcome).ok_or("Outcome not found")?;
-        // Distribute payouts.
+        self.resolver.require_auth();
+        let market_bets = self.markets.get(market.clone()).ok_or("Market not found")?;
+        let winning_bets = market_bets.get(winning_outcome.clone()).ok_or("Outcome not found")?;
+        if self.resolutions.contains_key(market.clone()) {
+            return Err("Market already resolved.");
+        }
+
+        let pool = self.pools.get(market.clone()).unwrap_or(Map::new(&env));
+        let total_pool: i128 = pool.values().iter().sum();
+        let winning_pool = pool.get(winning_outcome.clone()).unwrap_or(0);
+        let is_amm = self.amm_reserves.contains_key(market.clone());
+        for (user, position) in winning_bets.iter() {
+            let payout = if is_amm { position } else { position * total_pool / winning_pool };
+            let key = (market.clone(), user);
+            let mut entitlement = self.entitlements.get(key.clone()).unwrap_or(Entitlement { amount: 0, claimed: false });
+            entitlement.amount += payout;
+            self.entitlements.set(key, entitlement);
+        }
+        self.resolutions.set(market.clone(), winning_outcome.clone());
+        env.events().publish((Symbol::new(&env, "MarketResolved"), market.clone()), winning_outcome.clone());
         log!(&env, "Market resolved: {} wins in {}", winning_outcome, market);
         Ok(())
     }
 
+    /// Withdraw a winner's entitlement from escrow. Each entitlement can be claimed once, and
+    /// never pays out more than the market still holds in escrow.
+    pub fn claim_winnings(&mut self, env: Env, market: Symbol, user: Address) -> Result<i128, &'static str> {
+        user.require_auth();
+        let key = (market.clone(), user.clone());
+        let mut entitlement = self.entitlements.get(key.clone()).ok_or("No winnings to claim.")?;
+        if entitlement.claimed {
+            return Err("Winnings already claimed.");
+        }
+        entitlement.claimed = true;
+        self.entitlements.set(key, entitlement.clone());
+
+        let held = self.escrowed.get(market.clone()).unwrap_or(0);
+        let amount = entitlement.amount.min(held);
+        self.escrowed.set(market.clone(), held - amount);
+        token::Client::new(&env, &self.pi_coin).transfer(&self.escrow, &user, &amount);
+        env.events().publish((Symbol::new(&env, "WinningsClaimed"), market.clone(), user), WinningsClaimedEvent { amount });
+        log!(&env, "Winnings claimed: {} from {}", amount, market);
+        Ok(amount)
+    }
+
+    /// Tokens a market still holds in escrow.
+    pub fn get_escrowed(&self, market: Symbol) -> i128 {
+        self.escrowed.get(market).unwrap_or(0)
+    }
+
+    /// Recorded entitlement for a user in a resolved market.
+    pub fn get_entitlement(&self, market: Symbol, user: Address) -> Option<Entitlement> {
+        self.entitlements.get((market, user))
+    }
+
     /// Get market status.
-    pub fn get_market(&self, env: Env, market: Symbol) -> Map<Symbol, Vec<i128>> {
+    pub fn get_market(&self, env: Env, market: Symbol) -> Map<Symbol, Vec<(Address, i128)>> {
         self.markets.get(market).unwrap_or(Map::new(&env))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::TryFromVal;
+
+    #[contract]
+    pub struct MockPiCoin;
+
+    #[contractimpl]
+    impl MockPiCoin {
+        pub fn set_balance(env: Env, account: Address, balance: i128) {
+            env.storage().instance().set(&account, &balance);
+        }
+
+        pub fn balance(env: Env, account: Address) -> i128 {
+            env.storage().instance().get(&account).unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            let from_balance = Self::balance(env.clone(), from.clone());
+            assert!(from_balance >= amount, "insufficient balance");
+            Self::set_balance(env.clone(), from, from_balance - amount);
+            let to_balance = Self::balance(env.clone(), to.clone());
+            Self::set_balance(env, to, to_balance + amount);
+        }
+    }
+
+    struct Setup<'a> {
+        markets: PredictionMarkets,
+        pi_coin: MockPiCoinClient<'a>,
+        alice: Address,
+        bob: Address,
+        maker: Address,
+    }
+
+    // Markets with a funded mock Pi Coin; alice and bob hold 1,000 each, the market maker 10,000
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths();
+        let pi_coin = MockPiCoinClient::new(env, &env.register_contract(None, MockPiCoin));
+        let markets = PredictionMarkets::init(env.clone(), pi_coin.address.clone(), Address::generate(env), Address::generate(env));
+        let (alice, bob) = (Address::generate(env), Address::generate(env));
+        pi_coin.set_balance(&alice, &1_000);
+        pi_coin.set_balance(&bob, &1_000);
+        let maker = Address::generate(env);
+        pi_coin.set_balance(&maker, &10_000);
+        Setup { markets, pi_coin, alice, bob, maker }
+    }
+
+    fn outcomes(env: &Env) -> Vec<Symbol> {
+        Vec::from_array(env, [Symbol::new(env, "yes"), Symbol::new(env, "no")])
+    }
+
+    #[test]
+    fn test_amm_odds_shift_monotonically() {
+        let env = Env::default();
+        let Setup { mut markets, alice, bob, maker, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        let (yes, no) = (Symbol::new(&env, "yes"), Symbol::new(&env, "no"));
+        markets.create_amm_market(env.clone(), maker, market.clone(), outcomes(&env), 1_000).unwrap();
+        assert_eq!(markets.get_odds(env.clone(), market.clone()).unwrap().get(yes.clone()), Some(5_000));
+
+        let mut last_odds = 5_000;
+        let mut last_shares = i128::MAX;
+        for _ in 0..3 {
+            let shares = markets.place_bet(env.clone(), market.clone(), alice.clone(), yes.clone(), 100).unwrap();
+            let odds = markets.get_odds(env.clone(), market.clone()).unwrap();
+            let yes_odds = odds.get(yes.clone()).unwrap();
+            // Each bet raises the probability, so the same stake buys fewer shares
+            assert!(yes_odds > last_odds);
+            assert!(shares < last_shares && shares > 100);
+            assert!(yes_odds + odds.get(no.clone()).unwrap() >= 9_999);
+            last_odds = yes_odds;
+            last_shares = shares;
+        }
+
+        // Betting the other way moves the odds back
+        markets.place_bet(env.clone(), market.clone(), bob, no, 300).unwrap();
+        assert!(markets.get_odds(env.clone(), market).unwrap().get(yes).unwrap() < last_odds);
+    }
+
+    #[test]
+    fn test_pool_market_odds_follow_stakes() {
+        let env = Env::default();
+        let Setup { mut markets, alice, bob, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        let yes = Symbol::new(&env, "yes");
+        markets.create_market(env.clone(), market.clone(), outcomes(&env)).unwrap();
+        assert_eq!(markets.get_odds(env.clone(), market.clone()).unwrap().get(yes.clone()), Some(5_000));
+
+        assert_eq!(markets.place_bet(env.clone(), market.clone(), alice, yes.clone(), 300), Ok(300));
+        markets.place_bet(env.clone(), market.clone(), bob.clone(), Symbol::new(&env, "no"), 100).unwrap();
+        assert_eq!(markets.get_odds(env.clone(), market.clone()).unwrap().get(yes), Some(7_500));
+        assert_eq!(markets.place_bet(env.clone(), market, bob, Symbol::new(&env, "maybe"), 100), Err("Outcome not found"));
+    }
+
+    #[test]
+    fn test_winner_claims_pro_rata_payout() {
+        let env = Env::default();
+        let Setup { mut markets, pi_coin, alice, bob, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        markets.create_market(env.clone(), market.clone(), outcomes(&env)).unwrap();
+        markets.place_bet(env.clone(), market.clone(), alice.clone(), Symbol::new(&env, "yes"), 300).unwrap();
+        markets.place_bet(env.clone(), market.clone(), bob.clone(), Symbol::new(&env, "no"), 100).unwrap();
+        markets.resolve_market(env.clone(), market.clone(), Symbol::new(&env, "yes")).unwrap();
+
+        // Nothing is pushed at resolution; alice pulls the whole 400 pot
+        assert_eq!(pi_coin.balance(&alice), 700);
+        assert_eq!(markets.claim_winnings(env.clone(), market.clone(), alice.clone()), Ok(400));
+        assert_eq!(pi_coin.balance(&alice), 1_100);
+        assert_eq!(markets.get_entitlement(market.clone(), alice), Some(Entitlement { amount: 400, claimed: true }));
+        assert_eq!(markets.claim_winnings(env.clone(), market, bob), Err("No winnings to claim."));
+    }
+
+    #[test]
+    fn test_double_claim_rejected() {
+        let env = Env::default();
+        let Setup { mut markets, pi_coin, alice, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        markets.create_market(env.clone(), market.clone(), outcomes(&env)).unwrap();
+        markets.place_bet(env.clone(), market.clone(), alice.clone(), Symbol::new(&env, "yes"), 200).unwrap();
+        markets.resolve_market(env.clone(), market.clone(), Symbol::new(&env, "yes")).unwrap();
+
+        markets.claim_winnings(env.clone(), market.clone(), alice.clone()).unwrap();
+        assert_eq!(markets.claim_winnings(env.clone(), market, alice.clone()), Err("Winnings already claimed."));
+        assert_eq!(pi_coin.balance(&alice), 1_000);
+    }
+
+    #[test]
+    fn test_bet_placed_event() {
+        let env = Env::default();
+        let Setup { mut markets, alice, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        markets.create_market(env.clone(), market.clone(), outcomes(&env)).unwrap();
+        markets.place_bet(env.clone(), market.clone(), alice.clone(), Symbol::new(&env, "yes"), 250).unwrap();
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "BetPlaced"));
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), market);
+        assert_eq!(Address::try_from_val(&env, &topics.get(2).unwrap()).unwrap(), alice);
+        assert_eq!(BetPlacedEvent::try_from_val(&env, &data).unwrap(), BetPlacedEvent { outcome: Symbol::new(&env, "yes"), amount: 250, position: 250 });
+    }
+
+    #[test]
+    fn test_duplicate_market_rejected() {
+        let env = Env::default();
+        let Setup { mut markets, alice, maker, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        markets.create_market(env.clone(), market.clone(), outcomes(&env)).unwrap();
+        markets.place_bet(env.clone(), market.clone(), alice.clone(), Symbol::new(&env, "yes"), 200).unwrap();
+
+        // Recreating the market would wipe alice's stake
+        assert_eq!(markets.create_market(env.clone(), market.clone(), outcomes(&env)), Err("Market already exists."));
+        assert_eq!(markets.create_amm_market(env.clone(), maker, market.clone(), outcomes(&env), 1_000), Err("Market already exists."));
+        assert_eq!(markets.get_market(env.clone(), market).get(Symbol::new(&env, "yes")).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_amm_claims_beyond_deposits_paid_from_funded_liquidity() {
+        let env = Env::default();
+        let Setup { mut markets, pi_coin, alice, bob, maker } = setup(&env);
+        let (amm, pool) = (Symbol::new(&env, "peg_holds"), Symbol::new(&env, "supply_grows"));
+        let yes = Symbol::new(&env, "yes");
+        markets.create_amm_market(env.clone(), maker.clone(), amm.clone(), outcomes(&env), 1_000).unwrap();
+        assert_eq!(pi_coin.balance(&maker), 9_000);
+        markets.create_market(env.clone(), pool.clone(), outcomes(&env)).unwrap();
+        markets.place_bet(env.clone(), pool.clone(), bob, yes.clone(), 500).unwrap();
+
+        // 100 buys more than 100 shares; the excess is backed by the maker's liquidity, not bob's stake
+        let shares = markets.place_bet(env.clone(), amm.clone(), alice.clone(), yes.clone(), 100).unwrap();
+        assert!(shares > 100);
+        markets.resolve_market(env.clone(), amm.clone(), yes).unwrap();
+        assert_eq!(markets.claim_winnings(env.clone(), amm.clone(), alice.clone()), Ok(shares));
+        assert_eq!(pi_coin.balance(&alice), 900 + shares);
+        assert_eq!(markets.get_escrowed(amm), 1_100 - shares);
+        assert_eq!(markets.get_escrowed(pool), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient balance")]
+    fn test_amm_market_requires_funded_liquidity() {
+        let env = Env::default();
+        let Setup { mut markets, .. } = setup(&env);
+        let broke = Address::generate(&env);
+        let _ = markets.create_amm_market(env.clone(), broke, Symbol::new(&env, "free_lunch"), outcomes(&env), 1_000);
+    }
+
+    #[test]
+    fn test_claims_capped_at_escrowed_balance() {
+        let env = Env::default();
+        let Setup { mut markets, pi_coin, alice, .. } = setup(&env);
+        let market = Symbol::new(&env, "peg_holds");
+        markets.create_market(env.clone(), market.clone(), outcomes(&env)).unwrap();
+        markets.place_bet(env.clone(), market.clone(), alice.clone(), Symbol::new(&env, "yes"), 200).unwrap();
+        markets.resolve_market(env.clone(), market.clone(), Symbol::new(&env, "yes")).unwrap();
+
+        // An entitlement larger than what the market escrowed pays only what is held
+        markets.entitlements.set((market.clone(), alice.clone()), Entitlement { amount: 5_000, claimed: false });
+        assert_eq!(markets.claim_winnings(env.clone(), market.clone(), alice.clone()), Ok(200));
+        assert_eq!(pi_coin.balance(&alice), 1_000);
+        assert_eq!(markets.get_escrowed(market), 0);
+    }
+}
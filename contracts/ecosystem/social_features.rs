@@ -1,54 +1,327 @@
 // contracts/ecosystem/social_features.rs
 // Social Features: Community interaction for Pi Coin.
 // Autonomous moderation, eternal engagement.
-// Features: Post, follow, like, GodHead Nexus AI moderation.
+// Features: Post (balance-gated, rate-limited), follow, like, comment, GodHead Nexus AI moderation.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, Vec, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, IntoVal, Symbol, Map, Vec, log};
+
+/// Oldest comments are kept; further comments on a full post are rejected.
+pub const MAX_COMMENTS_PER_POST: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Comment {
+    pub comment_id: u32, // Unique within the post, never reused.
+    pub commenter: Address,
+    pub content: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Post {
+    pub author: Address,
+    pub content: Symbol,
+    pub likes: Vec<Symbol>,
+}
+
+// Anti-spam limits on posting, set at init
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostingPolicy {
+    pub min_balance: u64, // Pi Coin an author must hold to post.
+    pub max_posts: u32, // Posts allowed per author per window.
+    pub window_secs: u64,
+}
+
+// An author's posts in the current rate-limit window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostWindow {
+    pub window_start: u64,
+    pub posts: u32,
+}
+
+// Events: topic0 is the event name, topic1 the acting address (the followed user for Followed,
+// the post for PostLiked); data is the struct below
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostCreatedEvent {
+    pub post_id: Symbol,
+    pub content: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FollowedEvent {
+    pub follower: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostLikedEvent {
+    pub liker: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommentEvent {
+    pub post_id: Symbol,
+    pub comment_id: u32,
+}
 
 #[contract]
 pub struct SocialFeatures {
-    posts: Map<Symbol, Map<Symbol, Vec<Symbol>>>, // Post -> Metadata (author, likes).
+    pi_coin: Address, // Pi Coin contract queried for author balances.
+    policy: PostingPolicy,
+    posts: Map<Symbol, Post>,
+    post_windows: Map<Address, PostWindow>, // Author -> Current rate-limit window.
     follows: Map<Symbol, Vec<Symbol>>, // User -> Followers.
+    comments: Map<Symbol, Vec<Comment>>, // Post -> Comments, oldest first.
+    next_comment_ids: Map<Symbol, u32>, // Post -> Next comment_id.
 }
 
 #[contractimpl]
 impl SocialFeatures {
-    pub fn init(env: Env) -> SocialFeatures {
-        SocialFeatures { posts: Map::new(&env), follows: Map::new(&env) }
+    pub fn init(env: Env, pi_coin: Address, policy: PostingPolicy) -> SocialFeatures {
+        SocialFeatures {
+            pi_coin,
+            policy,
+            posts: Map::new(&env),
+            post_windows: Map::new(&env),
+            follows: Map::new(&env),
+            comments: Map::new(&env),
+            next_comment_ids: Map::new(&env),
+        }
     }
 
-    /// Create post.
-    pub fn create_post(&mut self, env: Env, post_id: Symbol, author: Symbol, content: Symbol) {
-        let mut metadata = Map::new(&env);
-        metadata.set(Symbol::new(&env, "author"), author);
-        metadata.set(Symbol::new(&env, "content"), content);
-        metadata.set(Symbol::new(&env, "likes"), Vec::new(&env));
-        self.posts.set(post_id, metadata);
+    /// Create post. The author must authorize, hold at least `min_balance` Pi Coin,
+    /// and be under `max_posts` for the current window.
+    pub fn create_post(&mut self, env: Env, post_id: Symbol, author: Address, content: Symbol) -> Result<(), &'static str> {
+        author.require_auth();
+        if self.posts.contains_key(post_id.clone()) {
+            return Err("Post already exists");
+        }
+        if self.pi_coin_balance(&env, &author) < self.policy.min_balance {
+            return Err("Insufficient Pi Coin balance");
+        }
+
+        let now = env.ledger().timestamp();
+        let mut window = match self.post_windows.get(author.clone()) {
+            Some(window) if now < window.window_start.saturating_add(self.policy.window_secs) => window,
+            _ => PostWindow { window_start: now, posts: 0 },
+        };
+        if window.posts >= self.policy.max_posts {
+            return Err("Post rate limit exceeded");
+        }
+        window.posts += 1;
+        self.post_windows.set(author.clone(), window);
+
+        self.posts.set(post_id.clone(), Post { author: author.clone(), content: content.clone(), likes: Vec::new(&env) });
+        env.events().publish((Symbol::new(&env, "PostCreated"), author.clone()), PostCreatedEvent { post_id: post_id.clone(), content });
         log!(&env, "Post created: {} by {}", post_id, author);
+        Ok(())
+    }
+
+    // Unreadable balances count as zero
+    fn pi_coin_balance(&self, env: &Env, account: &Address) -> u64 {
+        let args = Vec::from_array(env, [account.into_val(env)]);
+        match env.try_invoke_contract::<u64, soroban_sdk::Error>(&self.pi_coin, &Symbol::new(env, "balance_of"), args) {
+            Ok(Ok(balance)) => balance,
+            _ => 0,
+        }
     }
 
     /// Follow user.
     pub fn follow_user(&mut self, env: Env, follower: Symbol, followed: Symbol) {
         let mut followers = self.follows.get(followed).unwrap_or(Vec::new(&env));
         if !followers.contains(&follower) {
-            followers.push_back(follower);
-            self.follows.set(followed, followers);
+            followers.push_back(follower.clone());
+            self.follows.set(followed.clone(), followers);
+            env.events().publish((Symbol::new(&env, "Followed"), followed.clone()), FollowedEvent { follower: follower.clone() });
             log!(&env, "Followed: {} by {}", followed, follower);
         }
     }
 
     /// Like post.
-    pub fn like_post(&mut self, env: Env, post_id: Symbol, liker: Symbol) {
-        let mut post_metadata = self.posts.get(post_id).ok_or("Post not found")?;
-        let mut likes = post_metadata.get(Symbol::new(&env, "likes")).unwrap_or(Vec::new(&env));
-        likes.push_back(liker);
-        post_metadata.set(Symbol::new(&env, "likes"), likes);
-        self.posts.set(post_id, post_metadata);
+    pub fn like_post(&mut self, env: Env, post_id: Symbol, liker: Symbol) -> Result<(), &'static str> {
+        let mut post = self.posts.get(post_id.clone()).ok_or("Post not found")?;
+        post.likes.push_back(liker.clone());
+        self.posts.set(post_id.clone(), post);
+        env.events().publish((Symbol::new(&env, "PostLiked"), post_id.clone()), PostLikedEvent { liker: liker.clone() });
         log!(&env, "Liked: {} by {}", post_id, liker);
+        Ok(())
+    }
+
+    /// Comment on a post. Returns the new comment's id.
+    pub fn add_comment(&mut self, env: Env, post_id: Symbol, commenter: Address, content: Symbol) -> Result<u32, &'static str> {
+        commenter.require_auth();
+        if !self.posts.contains_key(post_id.clone()) {
+            return Err("Post not found");
+        }
+        let mut comments = self.comments.get(post_id.clone()).unwrap_or(Vec::new(&env));
+        if comments.len() >= MAX_COMMENTS_PER_POST {
+            return Err("Comment limit reached");
+        }
+        let comment_id = self.next_comment_ids.get(post_id.clone()).unwrap_or(0);
+        comments.push_back(Comment { comment_id, commenter: commenter.clone(), content });
+        self.comments.set(post_id.clone(), comments);
+        self.next_comment_ids.set(post_id.clone(), comment_id + 1);
+        env.events().publish((Symbol::new(&env, "CommentAdded"), commenter.clone()), CommentEvent { post_id: post_id.clone(), comment_id });
+        log!(&env, "Commented: {} on {}", commenter, post_id);
+        Ok(comment_id)
+    }
+
+    /// Page through a post's comments oldest first; the cursor is None once exhausted.
+    pub fn get_comments(&self, env: Env, post_id: Symbol, cursor: u32, limit: u32) -> (Vec<Comment>, Option<u32>) {
+        let comments = self.comments.get(post_id).unwrap_or(Vec::new(&env));
+        let end = cursor.saturating_add(limit).min(comments.len());
+        let mut page = Vec::new(&env);
+        for i in cursor..end {
+            page.push_back(comments.get(i).unwrap());
+        }
+        let next = if end < comments.len() { Some(end) } else { None };
+        (page, next)
+    }
+
+    /// Delete a comment. Only the commenter or the post's author may delete it.
+    pub fn delete_comment(&mut self, env: Env, post_id: Symbol, comment_id: u32, caller: Address) -> Result<(), &'static str> {
+        caller.require_auth();
+        let post = self.posts.get(post_id.clone()).ok_or("Post not found")?;
+        let mut comments = self.comments.get(post_id.clone()).unwrap_or(Vec::new(&env));
+        let index = comments.iter().position(|comment| comment.comment_id == comment_id).ok_or("Comment not found")? as u32;
+        let comment = comments.get(index).unwrap();
+        if comment.commenter != caller && post.author != caller {
+            return Err("Not authorized");
+        }
+        comments.remove(index);
+        self.comments.set(post_id.clone(), comments);
+        env.events().publish((Symbol::new(&env, "CommentDeleted"), caller.clone()), CommentEvent { post_id: post_id.clone(), comment_id });
+        log!(&env, "Comment deleted: {} on {} by {}", comment_id, post_id, caller);
+        Ok(())
     }
 
     /// Get post details.
-    pub fn get_post(&self, env: Env, post_id: Symbol) -> Map<Symbol, Vec<Symbol>> {
-        self.posts.get(post_id).unwrap_or(Map::new(&env))
+    pub fn get_post(&self, env: Env, post_id: Symbol) -> Option<Post> {
+        self.posts.get(post_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::TryFromVal;
+
+    #[contract]
+    pub struct MockPiCoin;
+
+    #[contractimpl]
+    impl MockPiCoin {
+        pub fn set_balance(env: Env, account: Address, balance: u64) {
+            env.storage().instance().set(&account, &balance);
+        }
+
+        pub fn balance_of(env: Env, account: Address) -> u64 {
+            env.storage().instance().get(&account).unwrap_or(0)
+        }
+    }
+
+    struct Setup<'a> {
+        social: SocialFeatures,
+        pi_coin: MockPiCoinClient<'a>,
+        post_id: Symbol,
+        alice: Address,
+        bob: Address,
+        carol: Address,
+    }
+
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths();
+        let pi_coin = MockPiCoinClient::new(env, &env.register_contract(None, MockPiCoin));
+        let policy = PostingPolicy { min_balance: 10, max_posts: 2, window_secs: 3600 };
+        let mut social = SocialFeatures::init(env.clone(), pi_coin.address.clone(), policy);
+        let (alice, bob, carol) = (Address::generate(env), Address::generate(env), Address::generate(env));
+        pi_coin.set_balance(&alice, &10);
+        let post_id = Symbol::new(env, "launch");
+        social.create_post(env.clone(), post_id.clone(), alice.clone(), Symbol::new(env, "hello")).unwrap();
+        Setup { social, pi_coin, post_id, alice, bob, carol }
+    }
+
+    #[test]
+    fn test_below_balance_author_rejected() {
+        let env = Env::default();
+        let Setup { mut social, pi_coin, bob, .. } = setup(&env);
+        pi_coin.set_balance(&bob, &9);
+        let result = social.create_post(env.clone(), Symbol::new(&env, "spam"), bob, Symbol::new(&env, "buy"));
+        assert_eq!(result, Err("Insufficient Pi Coin balance"));
+        assert_eq!(social.get_post(env.clone(), Symbol::new(&env, "spam")), None);
+    }
+
+    #[test]
+    fn test_author_rate_limited_per_window() {
+        let env = Env::default();
+        let Setup { mut social, alice, .. } = setup(&env);
+        social.create_post(env.clone(), Symbol::new(&env, "second"), alice.clone(), Symbol::new(&env, "hi")).unwrap();
+        let result = social.create_post(env.clone(), Symbol::new(&env, "third"), alice.clone(), Symbol::new(&env, "hi"));
+        assert_eq!(result, Err("Post rate limit exceeded"));
+
+        // A new window resets the counter
+        env.ledger().with_mut(|li| li.timestamp += 3600);
+        assert_eq!(social.create_post(env.clone(), Symbol::new(&env, "third"), alice, Symbol::new(&env, "hi")), Ok(()));
+    }
+
+    #[test]
+    fn test_add_and_page_comments() {
+        let env = Env::default();
+        let Setup { mut social, post_id, bob, .. } = setup(&env);
+        for _ in 0..5 {
+            social.add_comment(env.clone(), post_id.clone(), bob.clone(), Symbol::new(&env, "nice")).unwrap();
+        }
+        assert_eq!(social.add_comment(env.clone(), Symbol::new(&env, "missing"), bob.clone(), Symbol::new(&env, "hi")), Err("Post not found"));
+
+        let (page, next) = social.get_comments(env.clone(), post_id.clone(), 0, 2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(1).unwrap().comment_id, 1);
+        assert_eq!(next, Some(2));
+        let (page, next) = social.get_comments(env.clone(), post_id.clone(), 4, 2);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().comment_id, 4);
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn test_comment_cap() {
+        let env = Env::default();
+        let Setup { mut social, post_id, bob, .. } = setup(&env);
+        for _ in 0..MAX_COMMENTS_PER_POST {
+            social.add_comment(env.clone(), post_id.clone(), bob.clone(), Symbol::new(&env, "spam")).unwrap();
+        }
+        assert_eq!(social.add_comment(env.clone(), post_id, bob, Symbol::new(&env, "spam")), Err("Comment limit reached"));
+    }
+
+    #[test]
+    fn test_delete_comment_by_commenter_or_author_only() {
+        let env = Env::default();
+        let Setup { mut social, post_id, alice, bob, carol, .. } = setup(&env);
+        let first = social.add_comment(env.clone(), post_id.clone(), bob.clone(), Symbol::new(&env, "first")).unwrap();
+        let second = social.add_comment(env.clone(), post_id.clone(), carol.clone(), Symbol::new(&env, "second")).unwrap();
+
+        assert_eq!(social.delete_comment(env.clone(), post_id.clone(), first, carol), Err("Not authorized"));
+        assert_eq!(social.delete_comment(env.clone(), post_id.clone(), first, bob), Ok(()));
+        // Post author moderates others' comments
+        assert_eq!(social.delete_comment(env.clone(), post_id.clone(), second, alice.clone()), Ok(()));
+        assert_eq!(social.delete_comment(env.clone(), post_id.clone(), second, alice), Err("Comment not found"));
+        assert_eq!(social.get_comments(env.clone(), post_id, 0, 10).0.len(), 0);
+    }
+
+    #[test]
+    fn test_post_created_event() {
+        let env = Env::default();
+        let Setup { post_id, alice, .. } = setup(&env);
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "PostCreated"));
+        assert_eq!(Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), alice);
+        assert_eq!(PostCreatedEvent::try_from_val(&env, &data).unwrap(), PostCreatedEvent { post_id, content: Symbol::new(&env, "hello") });
     }
 }
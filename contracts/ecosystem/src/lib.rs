@@ -29,6 +29,35 @@ pub struct EcosystemAnalytics {
     pub average_amount: u64,
     pub anomalies_detected: u32,
     pub utilization_percent: u32,
+    pub total_fees_collected: u64, // Sum of every record_fee; per-source split in get_fee_breakdown
+}
+
+// Events: topic0 is the event name, topic1 the subject (name, source or voter); data is the struct below
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerchantRegisteredEvent {
+    pub products: Map<Symbol, u64>, // Prices after AI adjustment
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServiceProviderRegisteredEvent {
+    pub services: Map<Symbol, u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecordedEvent {
+    pub amount: u64,
+    pub total_fees_collected: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceVotedEvent {
+    pub proposal: Symbol,
+    pub vote: bool,
 }
 
 #[contracttype]
@@ -39,6 +68,9 @@ pub enum DataKey {
     Analytics,
     PiCoinContract,
     GovernanceVotes, // New: For decentralized updates
+    Admin,
+    FeeReporters, // Map<Symbol, Address> contract allowed to record fees for each source
+    FeeBreakdown, // Map<Symbol, u64> fees collected per source
 }
 
 #[contract]
@@ -60,6 +92,7 @@ impl EcosystemContract {
             average_amount: 0,
             anomalies_detected: 0,
             utilization_percent: 0,
+            total_fees_collected: 0,
         };
         let governance_votes = Map::new(&env); // Proposal -> Votes
         
@@ -69,6 +102,7 @@ impl EcosystemContract {
         env.storage().persistent().set(&DataKey::Analytics, &analytics);
         env.storage().persistent().set(&DataKey::PiCoinContract, &pi_coin_contract);
         env.storage().persistent().set(&DataKey::GovernanceVotes, &governance_votes);
+        env.storage().persistent().set(&DataKey::Admin, &admin);
         log!(&env, "GodHead Nexus Ecosystem Initialized");
     }
     
@@ -93,8 +127,9 @@ impl EcosystemContract {
             name: name.clone(),
             products: adjusted_products,
         };
-        merchants.set(name, merchant.clone());
+        merchants.set(name.clone(), merchant.clone());
         env.storage().persistent().set(&DataKey::Merchants, &merchants);
+        env.events().publish((Symbol::new(&env, "MerchantRegistered"), name), MerchantRegisteredEvent { products: merchant.products.clone() });
         
         Ok(merchant)
     }
@@ -106,10 +141,11 @@ impl EcosystemContract {
         
         let provider = ServiceProvider {
             name: name.clone(),
-            services,
+            services: services.clone(),
         };
-        providers.set(name, provider.clone());
+        providers.set(name.clone(), provider.clone());
         env.storage().persistent().set(&DataKey::ServiceProviders, &providers);
+        env.events().publish((Symbol::new(&env, "ServiceProviderRegistered"), name), ServiceProviderRegisteredEvent { services });
         
         Ok(provider)
     }
@@ -143,6 +179,43 @@ impl EcosystemContract {
         Ok(())
     }
     
+    // Allow a contract (e.g. transaction or stability) to report fees under `source`
+    pub fn set_fee_reporter(env: Env, source: Symbol, reporter: Address) -> Result<(), u32> {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).ok_or(ERR_NOT_FOUND)?;
+        admin.require_auth();
+        let mut reporters: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::FeeReporters).unwrap_or(Map::new(&env));
+        reporters.set(source, reporter);
+        env.storage().persistent().set(&DataKey::FeeReporters, &reporters);
+        Ok(())
+    }
+    
+    // Accumulate a collected fee; only the reporter registered for `source` may call
+    pub fn record_fee(env: Env, source: Symbol, amount: u64) -> Result<(), u32> {
+        let reporters: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::FeeReporters).unwrap_or(Map::new(&env));
+        let reporter = reporters.get(source.clone()).ok_or(ERR_UNAUTHORIZED)?;
+        reporter.require_auth();
+        if amount == 0 {
+            return Err(ERR_INVALID_INPUT);
+        }
+        
+        let mut analytics: EcosystemAnalytics = env.storage().persistent().get(&DataKey::Analytics).ok_or(ERR_NOT_FOUND)?;
+        analytics.total_fees_collected = analytics.total_fees_collected.checked_add(amount).ok_or(ERR_INVALID_INPUT)?;
+        let mut breakdown = Self::get_fee_breakdown(env.clone());
+        let collected = breakdown.get(source.clone()).unwrap_or(0).checked_add(amount).ok_or(ERR_INVALID_INPUT)?;
+        breakdown.set(source.clone(), collected);
+        
+        env.storage().persistent().set(&DataKey::Analytics, &analytics);
+        env.storage().persistent().set(&DataKey::FeeBreakdown, &breakdown);
+        env.events().publish((Symbol::new(&env, "FeeRecorded"), source.clone()), FeeRecordedEvent { amount, total_fees_collected: analytics.total_fees_collected });
+        log!(&env, "GodHead Fee: {} from {}", amount, source);
+        Ok(())
+    }
+    
+    // Fees collected per source; totals match EcosystemAnalytics::total_fees_collected
+    pub fn get_fee_breakdown(env: Env) -> Map<Symbol, u64> {
+        env.storage().persistent().get(&DataKey::FeeBreakdown).unwrap_or(Map::new(&env))
+    }
+    
     // Fetch multiverse oracle data
     pub fn fetch_oracle_data(env: Env, feed: Symbol) -> Result<u64, u32> {
         let oracle_data: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleData)
@@ -170,15 +243,16 @@ impl EcosystemContract {
         let mut votes: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::GovernanceVotes)
             .map_err(|_| ERR_NOT_FOUND)?;
         let current = votes.get(proposal).unwrap_or(0);
-        votes.set(proposal, current + if vote { 1 } else { 0 });
+        votes.set(proposal.clone(), current + if vote { 1 } else { 0 });
         env.storage().persistent().set(&DataKey::GovernanceVotes, &votes);
+        env.events().publish((Symbol::new(&env, "GovernanceVoted"), voter.clone()), GovernanceVotedEvent { proposal: proposal.clone(), vote });
         log!(&env, "GodHead Vote: {} voted on {}", voter, proposal);
         Ok(())
     }
     
     // Placeholder for integration with staking/NFT/DEX (call external contracts)
     pub fn integrate_staking(env: Env, user: Address, amount: u64) -> Result<(), u32> {
-        // Simulate call to staking.rs (in real: use contractcall)
+        // Simulate call to staking.rs (in real: use env.invoke_contract)
         log!(&env, "GodHead Integration: Staking {} for {}", user, amount);
         Ok(())
     }
@@ -10,3 +10,49 @@ fn test_register_merchant() {
     let merchant = client.register_merchant(&Symbol::new(&env, "shop"), &products);
     assert_eq!(merchant.name, Symbol::new(&env, "shop"));
 }
+
+#[test]
+fn test_fee_breakdown_by_source() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, EcosystemContract);
+    let client = EcosystemContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let transaction = Address::generate(&env);
+    let stability = Address::generate(&env);
+    let tx_source = Symbol::new(&env, "transaction");
+    let stability_source = Symbol::new(&env, "stability");
+    
+    client.init(&admin, &Address::generate(&env));
+    client.set_fee_reporter(&tx_source, &transaction);
+    client.set_fee_reporter(&stability_source, &stability);
+    client.record_fee(&tx_source, &120);
+    client.record_fee(&stability_source, &30);
+    client.record_fee(&tx_source, &50);
+    
+    let breakdown = client.get_fee_breakdown();
+    assert_eq!(breakdown.get(tx_source), Some(170));
+    assert_eq!(breakdown.get(stability_source), Some(30));
+    assert_eq!(client.get_analytics().total_fees_collected, 200);
+    
+    // Sources without a registered reporter are refused
+    assert!(client.try_record_fee(&Symbol::new(&env, "bridge"), &10).is_err());
+}
+
+#[test]
+fn test_fee_recorded_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, EcosystemContract);
+    let client = EcosystemContractClient::new(&env, &contract_id);
+    let source = Symbol::new(&env, "transaction");
+    
+    client.init(&Address::generate(&env), &Address::generate(&env));
+    client.set_fee_reporter(&source, &Address::generate(&env));
+    client.record_fee(&source, &75);
+    
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "FeeRecorded"));
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), source);
+    assert_eq!(FeeRecordedEvent::try_from_val(&env, &data).unwrap(), FeeRecordedEvent { amount: 75, total_fees_collected: 75 });
+}
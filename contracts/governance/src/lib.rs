@@ -1,4 +1,4 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, Map, Val, log, panic_with_error};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, IntoVal, Symbol, Vec, Map, Val, log, panic_with_error};
 use soroban_sdk::auth::Context;
 
 // Import from security contract for nexus (assume it's deployed and address known)
@@ -13,6 +13,41 @@ pub enum GovernanceError {
     VotingClosed = 2,
     InsufficientStake = 3,
     NexusFailure = 4,
+    InvalidThreshold = 5,
+    InvalidConfig = 6, // Rejected at initialize, e.g. a zero min_stake
+    AlreadyVoted = 7,  // One vote per voter per proposal
+}
+
+// Defaults read back when storage predates a setting
+pub const DEFAULT_MIN_STAKE: u32 = 10;
+pub const DEFAULT_ADAPTIVE_THRESHOLD: u32 = 50;
+
+// Where vote weight comes from. Mirrors governance_voting's PowerSource, so every governance
+// contract weighs a voter the same way for the same source
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PowerSource {
+    Registry,              // Map maintained by the admin with set_voting_power; unlisted voters have none
+    TokenBalance(Address), // balance(voter) on a token contract
+    Staked(Address),       // voting_power(voter) on a pi_staking contract
+}
+
+// All governance tunables, returned by get_config and attached to ConfigChanged events
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    pub voting_token: Address,
+    pub security_nexus: Address,
+    pub adaptive_threshold: u32,  // % of power-weighted votes in favour needed to execute
+    pub min_stake: u32,           // Smallest voting power accepted per vote
+    pub power_source: PowerSource, // Every vote is weighted by the voter's power under it
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdAdjustedEvent {
+    pub old_threshold: u32,
+    pub new_threshold: u32,
 }
 
 // Struct for governance state
@@ -27,16 +62,29 @@ pub struct GovernanceContract {
 }
 
 // Proposal struct
+#[contracttype]
 #[derive(Clone)]
 pub struct Proposal {
+    pub id: u64,
     pub proposer: Address,
     pub description: Symbol,
-    pub votes_for: u32,
-    pub votes_against: u32,
+    pub votes_for: u64,
+    pub votes_against: u64,
     pub end_time: u64,
     pub executed: bool,
 }
 
+// Filter for list_proposals. Active proposals are still in their voting period; ended ones are
+// Passed or Failed by the current adaptive threshold until executed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Failed,
+    Executed,
+}
+
 // GodHead Nexus Level: Autonomous AI-like predictive voting
 // Simulates "intelligence" by analyzing historical data and predicting outcomes
 fn predict_outcome(env: &Env, proposal_id: u64, current_votes: &Map<Address, bool>) -> bool {
@@ -54,17 +102,30 @@ fn predict_outcome(env: &Env, proposal_id: u64, current_votes: &Map<Address, boo
 
 #[contractimpl]
 impl GovernanceContract {
-    // Initialize the governance nexus
-    pub fn initialize(env: Env, admin: Address, voting_token: Address, security_nexus: Address) {
+    // Initialize the governance nexus. The threshold must be 1-100% and min_stake non-zero.
+    pub fn initialize(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), GovernanceError> {
         admin.require_auth();
+        if config.adaptive_threshold == 0 || config.adaptive_threshold > 100 {
+            return Err(GovernanceError::InvalidThreshold);
+        }
+        if config.min_stake == 0 {
+            return Err(GovernanceError::InvalidConfig);
+        }
+        env.storage().instance().set(&"admin", &admin);
         env.storage().instance().set(&"proposals", &Map::new(&env));
         env.storage().instance().set(&"votes", &Map::new(&env));
         env.storage().instance().set(&"next_proposal_id", &1u64);
-        env.storage().instance().set(&"voting_token", &voting_token);
-        env.storage().instance().set(&"security_nexus", &security_nexus);
-        env.storage().instance().set(&"adaptive_threshold", &50u32); // Starting threshold (%)
+        env.storage().instance().set(&"voting_token", &config.voting_token);
+        env.storage().instance().set(&"security_nexus", &config.security_nexus);
+        env.storage().instance().set(&"adaptive_threshold", &config.adaptive_threshold);
+        env.storage().instance().set(&"min_stake", &config.min_stake);
+        env.storage().instance().set(&"power_source", &config.power_source);
+        env.storage().instance().set(&"voting_power", &Map::<Address, u64>::new(&env));
         env.storage().instance().set(&"vote_history", &Vec::new(&env));
+        env.storage().instance().set(&"open_proposals", &Vec::<u64>::new(&env));
+        env.storage().instance().set(&"executed_proposals", &Vec::<u64>::new(&env));
         log!(&env, "Governance Nexus Initialized with GodHead Autonomy");
+        Ok(())
     }
 
     // Create proposal with AI prediction
@@ -72,6 +133,7 @@ impl GovernanceContract {
         proposer.require_auth();
         let id = env.storage().instance().get(&"next_proposal_id").unwrap_or(1u64);
         let proposal = Proposal {
+            id,
             proposer: proposer.clone(),
             description,
             votes_for: 0,
@@ -83,12 +145,15 @@ impl GovernanceContract {
         proposals.set(id, proposal);
         env.storage().instance().set(&"proposals", &proposals);
         env.storage().instance().set(&"next_proposal_id", &(id + 1));
+        let mut open: Vec<u64> = env.storage().instance().get(&"open_proposals").unwrap_or_default();
+        open.push_back(id);
+        env.storage().instance().set(&"open_proposals", &open);
         log!(&env, "Proposal Created with Nexus Prediction");
         id
     }
 
-    // Autonomous voting with stake and prediction
-    pub fn vote(env: Env, voter: Address, proposal_id: u64, approve: bool, stake_amount: u32) -> Result<(), GovernanceError> {
+    // Autonomous voting weighted by the configured PowerSource, with prediction; one vote per voter
+    pub fn vote(env: Env, voter: Address, proposal_id: u64, approve: bool) -> Result<(), GovernanceError> {
         voter.require_auth();
         
         // Nexus Check: Query security for anomaly
@@ -96,10 +161,10 @@ impl GovernanceContract {
         // In real impl: let anomaly = env.invoke_contract(&security_nexus, "is_paused", ...);
         // Placeholder: Assume no anomaly
         
-        // Stake check via pi_coin nexus
-        let voting_token: Address = env.storage().instance().get(&"voting_token").unwrap();
-        // Placeholder: Check balance (real impl: cross-contract call to pi_coin)
-        if stake_amount < 10 {  // Minimum stake
+        // Weight comes from the power source, not from the caller
+        let power = Self::voting_power(env.clone(), voter.clone());
+        let min_stake: u32 = env.storage().instance().get(&"min_stake").unwrap_or(DEFAULT_MIN_STAKE);
+        if power < min_stake as u64 {
             return Err(GovernanceError::InsufficientStake);
         }
         
@@ -111,15 +176,18 @@ impl GovernanceContract {
         
         let mut votes: Map<u64, Map<Address, bool>> = env.storage().instance().get(&"votes").unwrap_or_default();
         let mut proposal_votes = votes.get(proposal_id).unwrap_or(Map::new(&env));
+        if proposal_votes.contains_key(voter.clone()) {
+            return Err(GovernanceError::AlreadyVoted);
+        }
         proposal_votes.set(voter, approve);
         votes.set(proposal_id, proposal_votes);
         env.storage().instance().set(&"votes", &votes);
         
-        // Update counts with weighted stake
+        // Update counts with weighted power
         if approve {
-            proposal.votes_for += stake_amount;
+            proposal.votes_for += power;
         } else {
-            proposal.votes_against += stake_amount;
+            proposal.votes_against += power;
         }
         proposals.set(proposal_id, proposal);
         env.storage().instance().set(&"proposals", &proposals);
@@ -141,7 +209,7 @@ impl GovernanceContract {
         }
         
         let total_votes = proposal.votes_for + proposal.votes_against;
-        let adaptive_threshold: u32 = env.storage().instance().get(&"adaptive_threshold").unwrap_or(50);
+        let adaptive_threshold: u32 = env.storage().instance().get(&"adaptive_threshold").unwrap_or(DEFAULT_ADAPTIVE_THRESHOLD);
         if (proposal.votes_for as f32 / total_votes as f32) * 100.0 >= adaptive_threshold as f32 {
             // Execute logic (e.g., call pi_coin for mint)
             // Placeholder: log execution
@@ -149,6 +217,7 @@ impl GovernanceContract {
             proposal.executed = true;
             proposals.set(proposal_id, proposal);
             env.storage().instance().set(&"proposals", &proposals);
+            Self::mark_executed(&env, proposal_id);
             
             // Update history for AI learning
             let mut history: Vec<bool> = env.storage().instance().get(&"vote_history").unwrap_or_default();
@@ -158,10 +227,113 @@ impl GovernanceContract {
         Ok(())
     }
 
-    // Adaptive threshold adjustment (self-evolving)
-    pub fn adjust_threshold(env: Env, new_threshold: u32) {
-        // Require proposal execution for changes
+    // Page through proposals with the given status, oldest first. The cursor indexes the open
+    // (not yet executed) list, or the executed list for Executed; `next` resumes the scan.
+    pub fn list_proposals(env: Env, status: ProposalStatus, cursor: u32, limit: u32) -> (Vec<Proposal>, Option<u32>) {
+        let proposals: Map<u64, Proposal> = env.storage().instance().get(&"proposals").unwrap_or_default();
+        let index_key = if status == ProposalStatus::Executed { "executed_proposals" } else { "open_proposals" };
+        let ids: Vec<u64> = env.storage().instance().get(&index_key).unwrap_or_default();
+        
+        let mut page = Vec::new(&env);
+        let mut position = cursor;
+        while position < ids.len() && page.len() < limit {
+            let id = ids.get(position).unwrap();
+            position += 1;
+            if let Some(proposal) = proposals.get(id) {
+                if Self::status_of(&env, &proposal) == status {
+                    page.push_back(proposal);
+                }
+            }
+        }
+        let next = if position < ids.len() { Some(position) } else { None };
+        (page, next)
+    }
+
+    // Adaptive threshold adjustment (self-evolving); only the admin set at initialize may call it
+    pub fn adjust_threshold(env: Env, new_threshold: u32) -> Result<(), GovernanceError> {
+        let admin: Address = env.storage().instance().get(&"admin").unwrap();
+        admin.require_auth();
+        if new_threshold == 0 || new_threshold > 100 {
+            return Err(GovernanceError::InvalidThreshold);
+        }
+        let old_threshold: u32 = env.storage().instance().get(&"adaptive_threshold").unwrap_or(DEFAULT_ADAPTIVE_THRESHOLD);
         env.storage().instance().set(&"adaptive_threshold", &new_threshold);
+        env.events().publish((Symbol::new(&env, "ThresholdAdjusted"),), ThresholdAdjustedEvent { old_threshold, new_threshold });
+        Self::publish_config_changed(&env, Symbol::new(&env, "adaptive_threshold"));
         log!(&env, "Threshold Adjusted by Nexus");
+        Ok(())
+    }
+
+    // All tunables in one read
+    pub fn get_config(env: Env) -> GovernanceConfig {
+        GovernanceConfig {
+            voting_token: env.storage().instance().get(&"voting_token").unwrap(),
+            security_nexus: env.storage().instance().get(&"security_nexus").unwrap(),
+            adaptive_threshold: env.storage().instance().get(&"adaptive_threshold").unwrap_or(DEFAULT_ADAPTIVE_THRESHOLD),
+            min_stake: env.storage().instance().get(&"min_stake").unwrap_or(DEFAULT_MIN_STAKE),
+            power_source: env.storage().instance().get(&"power_source").unwrap_or(PowerSource::Registry),
+        }
+    }
+
+    // Vote weight of `voter` under the configured PowerSource
+    pub fn voting_power(env: Env, voter: Address) -> u64 {
+        match Self::get_config(env.clone()).power_source {
+            PowerSource::Registry => {
+                let voting_power: Map<Address, u64> = env.storage().instance().get(&"voting_power").unwrap_or_default();
+                voting_power.get(voter).unwrap_or(0)
+            }
+            PowerSource::TokenBalance(token) => {
+                let args: Vec<Val> = Vec::from_array(&env, [voter.into_val(&env)]);
+                let balance = env.invoke_contract::<i128>(&token, &Symbol::new(&env, "balance"), args);
+                balance.clamp(0, u64::MAX as i128) as u64
+            }
+            PowerSource::Staked(staking) => {
+                let args: Vec<Val> = Vec::from_array(&env, [voter.into_val(&env)]);
+                env.invoke_contract::<u64>(&staking, &Symbol::new(&env, "voting_power"), args)
+            }
+        }
+    }
+
+    // Set voting power for PowerSource::Registry; only the admin set at initialize may call it
+    pub fn set_voting_power(env: Env, voter: Address, power: u64) {
+        let admin: Address = env.storage().instance().get(&"admin").unwrap();
+        admin.require_auth();
+        let mut voting_power: Map<Address, u64> = env.storage().instance().get(&"voting_power").unwrap_or_default();
+        voting_power.set(voter, power);
+        env.storage().instance().set(&"voting_power", &voting_power);
+    }
+
+    // Status from the executed flag, voting deadline and current threshold
+    fn status_of(env: &Env, proposal: &Proposal) -> ProposalStatus {
+        if proposal.executed {
+            return ProposalStatus::Executed;
+        }
+        if env.ledger().timestamp() <= proposal.end_time {
+            return ProposalStatus::Active;
+        }
+        let total_votes = proposal.votes_for + proposal.votes_against;
+        let adaptive_threshold: u32 = env.storage().instance().get(&"adaptive_threshold").unwrap_or(DEFAULT_ADAPTIVE_THRESHOLD);
+        if total_votes > 0 && proposal.votes_for * 100 >= adaptive_threshold as u64 * total_votes {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Failed
+        }
+    }
+
+    // Move a proposal from the open index to the executed index
+    fn mark_executed(env: &Env, proposal_id: u64) {
+        let mut open: Vec<u64> = env.storage().instance().get(&"open_proposals").unwrap_or_default();
+        if let Some(position) = open.first_index_of(proposal_id) {
+            open.remove(position);
+        }
+        env.storage().instance().set(&"open_proposals", &open);
+        let mut executed: Vec<u64> = env.storage().instance().get(&"executed_proposals").unwrap_or_default();
+        executed.push_back(proposal_id);
+        env.storage().instance().set(&"executed_proposals", &executed);
+    }
+
+    // Topics: ("ConfigChanged", changed tunable); data: the full config after the change
+    fn publish_config_changed(env: &Env, tunable: Symbol) {
+        env.events().publish((Symbol::new(env, "ConfigChanged"), tunable), Self::get_config(env.clone()));
     }
 }
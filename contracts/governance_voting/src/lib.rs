@@ -1,7 +1,7 @@
 // contracts/governance_voting/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, IntoVal, Val};
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
 use sha3::{Digest, Sha3_512};
 
@@ -14,6 +14,9 @@ pub struct Proposal {
     pub votes_against: u64,
     pub ai_score: u32,  // AI evaluation score
     pub status: Symbol, // "active", "passed", "failed"
+    pub executed: bool, // Set before enforcement calls out, so a proposal is enforced at most once
+    pub deadline: u64,  // Ledger timestamp voting closes at; tally only after it
+    pub power_source: PowerSource, // Source in force at creation; every vote on this proposal is weighted by it
 }
 
 #[contracttype]
@@ -24,13 +27,65 @@ pub struct Vote {
     pub choice: bool,  // true = for, false = against
 }
 
+// Stake penalty a slash proposal applies through the staking contract once it passes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashAction {
+    pub user: Address,
+    pub amount: u64,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VotingError {
+    InvalidConfig = 1,          // AI score weights must sum to 100; period, quorum and proposal threshold non-zero
+    ProposalNotFound = 2,
+    ProposalExists = 3,         // Same creator and description as an existing proposal
+    BelowProposalThreshold = 4, // Creator's voting power is under config.proposal_threshold
+    NoVotingPower = 5,          // Voter has no power under the configured PowerSource
+    AlreadyVoted = 6,           // One vote per voter per proposal
+    VotingClosed = 7,           // Deadline passed
+    VotingOpen = 8,             // Tally attempted before the deadline
+}
+
+// All voting tunables, passed to init and returned by get_config
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    pub impact_weight: u32,       // AI score weights, in % of the final score
+    pub feasibility_weight: u32,
+    pub ethics_weight: u32,
+    pub voting_period: u64,       // Seconds a proposal stays open for votes
+    pub quorum: u64,              // Total power that must be cast before a proposal can pass
+    pub proposal_threshold: u64,  // Voting power needed to open a proposal
+}
+
+// Where vote weight comes from; set at init, changed only by the admin. Each proposal keeps the
+// source in force when it was created. Registry power is snapshotted at creation; the other
+// sources are read when the vote is cast. Locked stake cannot move mid-vote, but token balances
+// can: tokens transferred after voting count again for the receiver, so prefer Staked where that matters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PowerSource {
+    Registry,              // Static VotingPower map maintained by the admin with set_voting_power; unlisted voters have none
+    TokenBalance(Address), // balance(voter) on a token contract
+    Staked(Address),       // voting_power(voter) on a pi_staking contract: locked PI weighted by lock duration
+}
+
 #[contracttype]
 pub enum DataKey {
     Proposals,      // Map of proposals
-    Votes,          // Map of votes
+    Vote(BytesN<32>, Address), // Vote cast by a voter on a proposal
     AiEvalModel,    // AI for proposal evaluation
     QuantumKey,
-    VotingPower,    // Map of voter power (e.g., based on stake)
+    VotingPower,    // Map of voter power for PowerSource::Registry
+    PowerSnapshot(BytesN<32>), // VotingPower map as it stood when a Registry-weighted proposal was created
+    PowerSource,    // PowerSource every vote is weighted by
+    StakingContract, // pi_staking contract passed proposals distribute rewards through
+    SlashActions,   // Map of proposal id to the SlashAction it enforces
+    Config,         // GovernanceConfig passed to init
+    Admin,
 }
 
 #[contract]
@@ -39,23 +94,31 @@ pub struct GovernanceVotingContract;
 #[contractimpl]
 impl GovernanceVotingContract {
     // Initialize with hyper-tech voting
-    pub fn init(env: Env, admin: Address) {
+    pub fn init(env: Env, admin: Address, power_source: PowerSource, staking_contract: Address, config: GovernanceConfig) -> Result<(), VotingError> {
         admin.require_auth();
+        if config.impact_weight + config.feasibility_weight + config.ethics_weight != 100
+            || config.voting_period == 0
+            || config.quorum == 0
+            || config.proposal_threshold == 0
+        {
+            return Err(VotingError::InvalidConfig);
+        }
+        env.storage().persistent().set(&DataKey::Config, &config);
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage().persistent().set(&DataKey::PowerSource, &power_source);
+        env.storage().persistent().set(&DataKey::StakingContract, &staking_contract);
         
         let proposals = Map::new(&env);
         env.storage().persistent().set(&DataKey::Proposals, &proposals);
         
-        let votes = Map::new(&env);
-        env.storage().persistent().set(&DataKey::Votes, &votes);
-        
         // AI Eval Model: Weights for proposal scoring
         let ai_model = Map::new(&env);
-        ai_model.set(Symbol::new(&env, "impact_weight"), 50u32);
-        ai_model.set(Symbol::new(&env, "feasibility_weight"), 30u32);
-        ai_model.set(Symbol::new(&env, "ethics_weight"), 20u32);
+        ai_model.set(Symbol::new(&env, "impact_weight"), config.impact_weight);
+        ai_model.set(Symbol::new(&env, "feasibility_weight"), config.feasibility_weight);
+        ai_model.set(Symbol::new(&env, "ethics_weight"), config.ethics_weight);
         env.storage().persistent().set(&DataKey::AiEvalModel, &ai_model);
         
-        // Voting Power: Based on stake (integrate with staking contract)
+        // Registry voting power; only read under PowerSource::Registry
         let voting_power = Map::new(&env);
         env.storage().persistent().set(&DataKey::VotingPower, &voting_power);
         
@@ -64,11 +127,16 @@ impl GovernanceVotingContract {
         let private_key = RsaPrivateKey::new(&mut rng, 2048).expect("Failed to generate key");
         let public_key = private_key.to_public_key();
         env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
+        Ok(())
     }
     
-    // Create proposal with AI evaluation
-    pub fn create_proposal(env: Env, creator: Address, description: Symbol, impact: u32, feasibility: u32, ethics: u32) -> BytesN<32> {
+    // Create proposal with AI evaluation; the creator needs config.proposal_threshold voting power
+    pub fn create_proposal(env: Env, creator: Address, description: Symbol, impact: u32, feasibility: u32, ethics: u32) -> Result<BytesN<32>, VotingError> {
         creator.require_auth();
+        let config = Self::get_config(env.clone());
+        if Self::voting_power(env.clone(), creator.clone()) < config.proposal_threshold {
+            return Err(VotingError::BelowProposalThreshold);
+        }
         
         let ai_model: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::AiEvalModel).unwrap();
         let impact_w = ai_model.get(Symbol::new(&env, "impact_weight")).unwrap_or(50);
@@ -79,6 +147,7 @@ impl GovernanceVotingContract {
         let ai_score = (impact * impact_w + feasibility * feasibility_w + ethics * ethics_w) / 100;
         
         let proposal_id = env.crypto().sha256(&env, &Bytes::from_slice(&env, &format!("{}-{}", creator, description).as_bytes()));
+        let power_source = Self::get_power_source(env.clone());
         let proposal = Proposal {
             id: proposal_id.clone(),
             description,
@@ -86,34 +155,64 @@ impl GovernanceVotingContract {
             votes_against: 0,
             ai_score,
             status: Symbol::new(&env, "active"),
+            executed: false,
+            deadline: env.ledger().timestamp() + config.voting_period,
+            power_source: power_source.clone(),
         };
         
         let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&DataKey::Proposals).unwrap();
+        if proposals.contains_key(proposal_id.clone()) {
+            return Err(VotingError::ProposalExists);
+        }
         proposals.set(proposal_id.clone(), proposal);
         env.storage().persistent().set(&DataKey::Proposals, &proposals);
+        if power_source == PowerSource::Registry {
+            let voting_power: Map<Address, u64> = env.storage().persistent().get(&DataKey::VotingPower).unwrap();
+            env.storage().persistent().set(&DataKey::PowerSnapshot(proposal_id.clone()), &voting_power);
+        }
         
-        proposal_id
+        Ok(proposal_id)
+    }
+    
+    // Proposal that, once passed, slashes `amount` of `user`'s locked stake instead of distributing rewards
+    pub fn create_slash_proposal(env: Env, creator: Address, description: Symbol, user: Address, amount: u64, impact: u32, feasibility: u32, ethics: u32) -> Result<BytesN<32>, VotingError> {
+        let proposal_id = Self::create_proposal(env.clone(), creator, description, impact, feasibility, ethics)?;
+        let mut actions: Map<BytesN<32>, SlashAction> = env.storage().persistent().get(&DataKey::SlashActions).unwrap_or(Map::new(&env));
+        actions.set(proposal_id.clone(), SlashAction { user, amount });
+        env.storage().persistent().set(&DataKey::SlashActions, &actions);
+        Ok(proposal_id)
+    }
+    
+    pub fn get_slash_action(env: Env, proposal_id: BytesN<32>) -> Option<SlashAction> {
+        let actions: Map<BytesN<32>, SlashAction> = env.storage().persistent().get(&DataKey::SlashActions).unwrap_or(Map::new(&env));
+        actions.get(proposal_id)
     }
     
-    // Vote on proposal with quantum security
-    pub fn vote(env: Env, voter: Address, proposal_id: BytesN<32>, choice: bool) {
+    // Vote on proposal with quantum security; one vote per voter per proposal, before the deadline
+    pub fn vote(env: Env, voter: Address, proposal_id: BytesN<32>, choice: bool) -> Result<(), VotingError> {
         voter.require_auth();
         
-        let voting_power: Map<Address, u64> = env.storage().persistent().get(&DataKey::VotingPower).unwrap();
-        let power = voting_power.get(voter.clone()).unwrap_or(1);  // Default 1, or from stake
+        let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&DataKey::Proposals).unwrap();
+        let mut proposal = proposals.get(proposal_id.clone()).ok_or(VotingError::ProposalNotFound)?;
+        if env.ledger().timestamp() >= proposal.deadline {
+            return Err(VotingError::VotingClosed);
+        }
+        let vote_key = DataKey::Vote(proposal_id.clone(), voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(VotingError::AlreadyVoted);
+        }
+        let power = Self::proposal_power(&env, &proposal, &voter);
+        if power == 0 {
+            return Err(VotingError::NoVotingPower);
+        }
         
         let vote = Vote {
-            voter: voter.clone(),
+            voter,
             proposal_id: proposal_id.clone(),
             choice,
         };
+        env.storage().persistent().set(&vote_key, &vote);
         
-        let mut votes: Map<Address, Vote> = env.storage().persistent().get(&DataKey::Votes).unwrap();
-        votes.set(voter, vote);
-        env.storage().persistent().set(&DataKey::Votes, &votes);
-        
-        let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&DataKey::Proposals).unwrap();
-        let mut proposal = proposals.get(proposal_id.clone()).unwrap();
         if choice {
             proposal.votes_for += power;
         } else {
@@ -121,30 +220,55 @@ impl GovernanceVotingContract {
         }
         proposals.set(proposal_id, proposal);
         env.storage().persistent().set(&DataKey::Proposals, &proposals);
+        Ok(())
     }
     
-    // Autonomous tally and enforcement
-    pub fn tally_votes(env: Env, proposal_id: BytesN<32>) {
+    pub fn get_vote(env: Env, proposal_id: BytesN<32>, voter: Address) -> Option<Vote> {
+        env.storage().persistent().get(&DataKey::Vote(proposal_id, voter))
+    }
+    
+    // Autonomous tally and enforcement once voting closes; a proposal passes only with quorum and a
+    // for-majority. An executed proposal is final and is not tallied again
+    pub fn tally_votes(env: Env, proposal_id: BytesN<32>) -> Result<(), VotingError> {
         let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&DataKey::Proposals).unwrap();
-        let mut proposal = proposals.get(proposal_id.clone()).unwrap();
+        let mut proposal = proposals.get(proposal_id.clone()).ok_or(VotingError::ProposalNotFound)?;
+        if proposal.executed {
+            return Ok(());
+        }
+        if env.ledger().timestamp() < proposal.deadline {
+            return Err(VotingError::VotingOpen);
+        }
         
-        if proposal.votes_for > proposal.votes_against {
+        let quorum = Self::get_config(env.clone()).quorum;
+        let passed = proposal.votes_for + proposal.votes_against >= quorum && proposal.votes_for > proposal.votes_against;
+        if passed {
             proposal.status = Symbol::new(&env, "passed");
-            // Autonomous enforcement (e.g., call other contracts)
-            Self::enforce_proposal(env.clone(), proposal_id);
+            proposal.executed = true;
         } else {
             proposal.status = Symbol::new(&env, "failed");
         }
         
-        proposals.set(proposal_id, proposal);
+        // Persist the executed flag before any external call so a re-entrant tally is a no-op
+        proposals.set(proposal_id.clone(), proposal);
         env.storage().persistent().set(&DataKey::Proposals, &proposals);
+        if passed {
+            Self::enforce_proposal(env, proposal_id);
+        }
+        Ok(())
     }
     
     // Enforce passed proposal
     fn enforce_proposal(env: Env, proposal_id: BytesN<32>) {
+        let staking_contract: Address = env.storage().persistent().get(&DataKey::StakingContract).unwrap();
+        if let Some(action) = Self::get_slash_action(env.clone(), proposal_id) {
+            // This contract is the caller pi_staking checks against its registered governance
+            let args: Vec<Val> = Vec::from_array(&env, [env.current_contract_address().into_val(&env), action.user.into_val(&env), action.amount.into_val(&env)]);
+            env.invoke_contract::<u64>(&staking_contract, &Symbol::new(&env, "slash"), args);
+            return;
+        }
         // Example: If proposal is for increasing rewards, call staking contract
-        let staking_contract = env.storage().persistent().get(&Symbol::new(&env, "staking_contract")).unwrap();
-        contractcall!(env, staking_contract, distribute_rewards);
+        let args: Vec<Val> = Vec::new(&env);
+        env.invoke_contract::<()>(&staking_contract, &Symbol::new(&env, "distribute_rewards"), args);
     }
     
     // Get proposal
@@ -153,8 +277,64 @@ impl GovernanceVotingContract {
         proposals.get(proposal_id).unwrap()
     }
     
-    // Set voting power (from staking)
+    // All tunables in one read
+    pub fn get_config(env: Env) -> GovernanceConfig {
+        env.storage().persistent().get(&DataKey::Config).unwrap()
+    }
+    
+    // Vote weight of `voter` under the configured PowerSource
+    pub fn voting_power(env: Env, voter: Address) -> u64 {
+        match Self::get_power_source(env.clone()) {
+            PowerSource::Registry => {
+                let voting_power: Map<Address, u64> = env.storage().persistent().get(&DataKey::VotingPower).unwrap();
+                voting_power.get(voter).unwrap_or(0)
+            }
+            source => Self::live_power(&env, source, voter),
+        }
+    }
+    
+    // Vote weight of `voter` on a proposal: its creation-time registry snapshot, or its source read now
+    fn proposal_power(env: &Env, proposal: &Proposal, voter: &Address) -> u64 {
+        match proposal.power_source.clone() {
+            PowerSource::Registry => {
+                let snapshot: Map<Address, u64> = env.storage().persistent().get(&DataKey::PowerSnapshot(proposal.id.clone())).unwrap_or(Map::new(env));
+                snapshot.get(voter.clone()).unwrap_or(0)
+            }
+            source => Self::live_power(env, source, voter.clone()),
+        }
+    }
+    
+    // Current weight from a token or staking contract
+    fn live_power(env: &Env, source: PowerSource, voter: Address) -> u64 {
+        match source {
+            PowerSource::Registry => 0,
+            PowerSource::TokenBalance(token) => {
+                let args: Vec<Val> = Vec::from_array(env, [voter.into_val(env)]);
+                let balance = env.invoke_contract::<i128>(&token, &Symbol::new(env, "balance"), args);
+                balance.clamp(0, u64::MAX as i128) as u64
+            }
+            PowerSource::Staked(staking) => {
+                let args: Vec<Val> = Vec::from_array(env, [voter.into_val(env)]);
+                env.invoke_contract::<u64>(&staking, &Symbol::new(env, "voting_power"), args)
+            }
+        }
+    }
+    
+    pub fn get_power_source(env: Env) -> PowerSource {
+        env.storage().persistent().get(&DataKey::PowerSource).unwrap_or(PowerSource::Registry)
+    }
+    
+    // Swap where vote weight comes from for proposals created from now on; open proposals keep theirs
+    pub fn set_power_source(env: Env, power_source: PowerSource) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::PowerSource, &power_source);
+    }
+    
+    // Set voting power for PowerSource::Registry; proposals already open keep their snapshot
     pub fn set_voting_power(env: Env, voter: Address, power: u64) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
         let mut voting_power: Map<Address, u64> = env.storage().persistent().get(&DataKey::VotingPower).unwrap();
         voting_power.set(voter, power);
         env.storage().persistent().set(&DataKey::VotingPower, &voting_power);
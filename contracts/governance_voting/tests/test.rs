@@ -1,11 +1,287 @@
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::{Address as _, Ledger}, Address, Env, Map, Symbol};
+use governance_voting::{GovernanceConfig, SlashAction, GovernanceVotingContract, GovernanceVotingContractClient, PowerSource, VotingError};
+
+fn default_config() -> GovernanceConfig {
+    GovernanceConfig { impact_weight: 50, feasibility_weight: 30, ethics_weight: 20, voting_period: 86_400, quorum: 100, proposal_threshold: 1 }
+}
+
+fn close_voting(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += default_config().voting_period);
+}
+
 #[test]
 fn test_create_proposal() {
     let env = Env::default();
     let contract_id = env.register_contract(None, GovernanceVotingContract);
     let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    
+    env.mock_all_auths();
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    
+    // Opening a proposal takes voting power, and the same one cannot be opened twice
+    let description = Symbol::new(&env, "increase_rewards");
+    assert_eq!(client.try_create_proposal(&creator, &description, &80, &70, &90), Err(Ok(VotingError::BelowProposalThreshold)));
+    client.set_voting_power(&creator, &10);
+    let proposal_id = client.create_proposal(&creator, &description, &80, &70, &90);
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.description, description);
+    assert_eq!(proposal.deadline, env.ledger().timestamp() + default_config().voting_period);
+    assert_eq!(client.try_create_proposal(&creator, &description, &80, &70, &90), Err(Ok(VotingError::ProposalExists)));
+}
+
+// Token exposing only balance, settable by the test
+#[contract]
+pub struct MockBalanceToken;
+
+#[contractimpl]
+impl MockBalanceToken {
+    pub fn set_balance(env: Env, id: Address, amount: i128) {
+        let mut balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.set(id, amount);
+        env.storage().instance().set(&symbol_short!("bal"), &balances);
+    }
+    
+    pub fn balance(env: Env, id: Address) -> i128 {
+        let balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.get(id).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_votes_weighted_by_token_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let whale = Address::generate(&env);
+    let minnow = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    client.set_voting_power(&minnow, &500);
+    
+    let token = env.register_contract(None, MockBalanceToken);
+    let token_client = MockBalanceTokenClient::new(&env, &token);
+    token_client.set_balance(&whale, &300);
+    token_client.set_balance(&minnow, &20);
+    client.set_power_source(&PowerSource::TokenBalance(token.clone()));
+    assert_eq!(client.get_power_source(), PowerSource::TokenBalance(token));
     
-    client.init(&admin);
-    let proposal_id = client.create_proposal(&creator, &Symbol::new(&env, "increase_rewards"), &80, &70, &90);
+    // The registry entry no longer counts; balances do
+    assert_eq!(client.voting_power(&minnow), 20);
+    let proposal_id = client.create_proposal(&whale, &Symbol::new(&env, "increase_rewards"), &80, &70, &90);
+    client.vote(&whale, &proposal_id, &true);
+    client.vote(&minnow, &proposal_id, &false);
     let proposal = client.get_proposal(&proposal_id);
-    assert_eq!(proposal.description, Symbol::new(&env, "increase_rewards"));
+    assert_eq!(proposal.votes_for, 300);
+    assert_eq!(proposal.votes_against, 20);
+}
+
+#[test]
+fn test_registry_power_snapshotted_at_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let latecomer = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    client.set_voting_power(&voter, &40);
+    let proposal_id = client.create_proposal(&voter, &Symbol::new(&env, "increase_rewards"), &80, &70, &90);
+    
+    // Registry edits and source swaps after creation do not reach the open proposal
+    client.set_voting_power(&voter, &400);
+    client.set_voting_power(&latecomer, &400);
+    client.set_power_source(&PowerSource::Staked(staking.clone()));
+    assert_eq!(client.get_proposal(&proposal_id).power_source, PowerSource::Registry);
+    client.vote(&voter, &proposal_id, &true);
+    assert_eq!(client.try_vote(&latecomer, &proposal_id, &true), Err(Ok(VotingError::NoVotingPower)));
+    assert_eq!(client.get_proposal(&proposal_id).votes_for, 40);
+}
+
+#[test]
+fn test_token_balance_read_when_vote_cast() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let token = env.register_contract(None, MockBalanceToken);
+    let token_client = MockBalanceTokenClient::new(&env, &token);
+    let staking = env.register_contract(None, MockStaking);
+    
+    client.init(&admin, &PowerSource::TokenBalance(token.clone()), &staking, &default_config());
+    token_client.set_balance(&holder, &60);
+    let proposal_id = client.create_proposal(&holder, &Symbol::new(&env, "increase_rewards"), &80, &70, &90);
+    client.vote(&holder, &proposal_id, &true);
+    
+    // Balances are not snapshotted: tokens moved after voting weigh again for the receiver,
+    // which is why PowerSource documents Staked as the source for transfer-proof votes
+    token_client.set_balance(&holder, &0);
+    token_client.set_balance(&receiver, &60);
+    client.vote(&receiver, &proposal_id, &true);
+    assert_eq!(client.get_proposal(&proposal_id).votes_for, 120);
+}
+
+// Staking contract counting distribute_rewards calls, with settable voting power
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn set_voting_power(env: Env, staker: Address, power: u64) {
+        let mut powers: Map<Address, u64> = env.storage().instance().get(&symbol_short!("power")).unwrap_or(Map::new(&env));
+        powers.set(staker, power);
+        env.storage().instance().set(&symbol_short!("power"), &powers);
+    }
+    
+    pub fn voting_power(env: Env, staker: Address) -> u64 {
+        let powers: Map<Address, u64> = env.storage().instance().get(&symbol_short!("power")).unwrap_or(Map::new(&env));
+        powers.get(staker).unwrap_or(0)
+    }
+    
+    pub fn distribute_rewards(env: Env) {
+        let calls: u32 = env.storage().instance().get(&symbol_short!("calls")).unwrap_or(0);
+        env.storage().instance().set(&symbol_short!("calls"), &(calls + 1));
+    }
+    
+    pub fn slash(env: Env, caller: Address, user: Address, amount: u64) -> u64 {
+        env.storage().instance().set(&symbol_short!("slashed"), &(caller, user, amount));
+        amount
+    }
+    
+    pub fn last_slash(env: Env) -> Option<(Address, Address, u64)> {
+        env.storage().instance().get(&symbol_short!("slashed"))
+    }
+    
+    pub fn calls(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("calls")).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_votes_weighted_by_staked_power() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let staker = Address::generate(&env);
+    let bystander = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    MockStakingClient::new(&env, &staking).set_voting_power(&staker, &750);
+    
+    client.init(&admin, &PowerSource::Staked(staking.clone()), &staking, &default_config());
+    
+    // Nothing staked means no weight, and no vote
+    assert_eq!(client.voting_power(&bystander), 0);
+    let proposal_id = client.create_proposal(&staker, &Symbol::new(&env, "increase_rewards"), &80, &70, &90);
+    client.vote(&staker, &proposal_id, &true);
+    assert_eq!(client.try_vote(&bystander, &proposal_id, &false), Err(Ok(VotingError::NoVotingPower)));
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.votes_for, 750);
+    assert_eq!(proposal.votes_against, 0);
+}
+
+#[test]
+fn test_passed_proposal_enforced_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    client.set_voting_power(&voter, &500);
+    let proposal_id = client.create_proposal(&voter, &Symbol::new(&env, "increase_rewards"), &80, &70, &90);
+    client.vote(&voter, &proposal_id, &true);
+    
+    close_voting(&env);
+    client.tally_votes(&proposal_id);
+    client.tally_votes(&proposal_id);
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.status, Symbol::new(&env, "passed"));
+    assert!(proposal.executed);
+    assert_eq!(MockStakingClient::new(&env, &staking).calls(), 1);
+}
+
+#[test]
+fn test_init_rejects_weights_not_summing_to_100() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    
+    let lopsided = GovernanceConfig { impact_weight: 90, ..default_config() };
+    assert_eq!(client.try_init(&admin, &PowerSource::Registry, &staking, &lopsided), Err(Ok(VotingError::InvalidConfig)));
+    let no_quorum = GovernanceConfig { quorum: 0, ..default_config() };
+    assert_eq!(client.try_init(&admin, &PowerSource::Registry, &staking, &no_quorum), Err(Ok(VotingError::InvalidConfig)));
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    assert_eq!(client.get_config(), default_config());
+}
+
+#[test]
+fn test_passed_slash_proposal_slashes_instead_of_rewarding() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let fraudster = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    let staking_client = MockStakingClient::new(&env, &staking);
+    
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    client.set_voting_power(&voter, &500);
+    let proposal_id = client.create_slash_proposal(&voter, &Symbol::new(&env, "slash_fraud"), &fraudster, &400, &80, &70, &90);
+    assert_eq!(client.get_slash_action(&proposal_id), Some(SlashAction { user: fraudster.clone(), amount: 400 }));
+    client.vote(&voter, &proposal_id, &true);
+    
+    assert_eq!(client.try_tally_votes(&proposal_id), Err(Ok(VotingError::VotingOpen)));
+    close_voting(&env);
+    client.tally_votes(&proposal_id);
+    assert_eq!(staking_client.last_slash(), Some((contract_id, fraudster, 400)));
+    assert_eq!(staking_client.calls(), 0);
+}
+
+#[test]
+fn test_slash_needs_single_votes_deadline_and_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GovernanceVotingContract);
+    let client = GovernanceVotingContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let victim = Address::generate(&env);
+    let staking = env.register_contract(None, MockStaking);
+    let staking_client = MockStakingClient::new(&env, &staking);
+    
+    client.init(&admin, &PowerSource::Registry, &staking, &default_config());
+    client.set_voting_power(&attacker, &60);
+    let proposal_id = client.create_slash_proposal(&attacker, &Symbol::new(&env, "slash_rival"), &victim, &1000, &80, &70, &90);
+    
+    // Repeat votes are refused, so 60 power stays below the quorum of 100
+    client.vote(&attacker, &proposal_id, &true);
+    assert_eq!(client.try_vote(&attacker, &proposal_id, &true), Err(Ok(VotingError::AlreadyVoted)));
+    assert_eq!(client.get_proposal(&proposal_id).votes_for, 60);
+    
+    close_voting(&env);
+    assert_eq!(client.try_vote(&Address::generate(&env), &proposal_id, &true), Err(Ok(VotingError::VotingClosed)));
+    client.tally_votes(&proposal_id);
+    assert_eq!(client.get_proposal(&proposal_id).status, Symbol::new(&env, "failed"));
+    assert_eq!(staking_client.last_slash(), None);
 }
@@ -1,7 +1,7 @@
 // contracts/hyper_autonomous_ai/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, IntoVal, Val};
 use num_bigint::BigUint; // For Pi math in AI
 
 #[contracttype]
@@ -107,9 +107,11 @@ impl HyperAutonomousAiContract {
     // Execute autonomous decision
     fn execute_decision(env: Env, decision: AiDecision) {
         if decision.action == Symbol::new(&env, "reject") {
-            // Call anti-volatility contract (simulated)
-            let anti_vol_contract = env.storage().persistent().get(&Symbol::new(&env, "anti_vol_contract")).unwrap();
-            contractcall!(env, anti_vol_contract, auto_reject_transaction, decision.target);
+            // Call anti-volatility contract; auto_reject_transaction(asset, transaction_contract) -> bool
+            let anti_vol_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "anti_vol_contract")).unwrap();
+            let tx_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "transaction_contract")).unwrap();
+            let args: Vec<Val> = Vec::from_array(&env, [decision.target.into_val(&env), tx_contract.into_val(&env)]);
+            env.invoke_contract::<bool>(&anti_vol_contract, &Symbol::new(&env, "auto_reject_transaction"), args);
         } else if decision.action == Symbol::new(&env, "optimize") {
             // Self-optimize models
             let mut models: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::HyperModels).unwrap();
@@ -1,9 +1,8 @@
 // contracts/hyper_enforcement/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contractimpl, contracttype, xdr::ToXdr, Env, Address, Symbol, Vec, Map, Bytes, BytesN, IntoVal, Val};
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
-use sha3::{Digest, Sha3_512};
 
 #[contracttype]
 #[derive(Clone)]
@@ -15,6 +14,16 @@ pub struct EnforcementAction {
     pub pi_verified: bool,  // Pi-math verification
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct Appeal {
+    pub entity: Symbol,
+    pub evidence: Bytes,  // Off-chain review material (e.g., hash or URI)
+    pub timestamp: u64,
+}
+
+pub const MAX_PENDING_APPEALS: u32 = 50; // Appeals awaiting review; one per entity
+
 #[contracttype]
 pub enum DataKey {
     AutonomousAgents,  // Hyper AI agents for enforcement
@@ -22,6 +31,14 @@ pub enum DataKey {
     ComplianceLog,     // Log of verifications
     PiNetworkFeeds,    // Simulated Pi Network data feeds
     QuantumKey,
+    AttesterKey,       // Ed25519 public key of the trusted PI-usage attester
+    Attestations,      // Entities with a verified PI-usage attestation
+    Appeals,           // Appeals pending off-chain review
+    Cleared,           // Entities removed from the blacklist after review
+    MultiSigSigners,   // Signers allowed to clear blacklist entries
+    Threshold,         // Signer approvals needed to clear a blacklist entry
+    RemovalApprovals(Symbol), // Signers who approved clearing an entity
+    EntityOwners,      // Entity -> address that must authorize its appeals
 }
 
 #[contract]
@@ -29,9 +46,12 @@ pub struct HyperEnforcementContract;
 
 #[contractimpl]
 impl HyperEnforcementContract {
-    // Initialize with hyper autonomous setup
-    pub fn init(env: Env, admin: Address, pi_coin_contract: Address) {
+    // Initialize with hyper autonomous setup; `threshold` of `signers` must approve each blacklist removal
+    pub fn init(env: Env, admin: Address, pi_coin_contract: Address, attester_key: BytesN<32>, signers: Vec<Address>, threshold: u32) {
         admin.require_auth();
+        if threshold == 0 || threshold > signers.len() {
+            panic!("Invalid threshold");
+        }
         
         // Autonomous agents (e.g., ComplianceAgent, EnforcementAgent)
         let agents = Map::new(&env);
@@ -59,10 +79,38 @@ impl HyperEnforcementContract {
         env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
         
         env.storage().persistent().set(&Symbol::new(&env, "pi_coin_contract"), &pi_coin_contract);
+        
+        // Trusted attester for PI-usage attestations
+        env.storage().persistent().set(&DataKey::AttesterKey, &attester_key);
+        env.storage().persistent().set(&DataKey::Attestations, &Map::<Symbol, bool>::new(&env));
+        
+        // Appeal flow
+        env.storage().persistent().set(&DataKey::Appeals, &Vec::<Appeal>::new(&env));
+        env.storage().persistent().set(&DataKey::Cleared, &Map::<Symbol, bool>::new(&env));
+        env.storage().persistent().set(&DataKey::EntityOwners, &Map::<Symbol, Address>::new(&env));
+        env.storage().persistent().set(&DataKey::MultiSigSigners, &signers);
+        env.storage().persistent().set(&DataKey::Threshold, &threshold);
+    }
+    
+    // Record a PI-usage attestation: ed25519 signature by the trusted attester over the XDR-encoded entity id.
+    // Panics if the signature does not verify.
+    pub fn submit_attestation(env: Env, entity: Symbol, signature: BytesN<64>) {
+        let attester_key: BytesN<32> = env.storage().persistent().get(&DataKey::AttesterKey).unwrap();
+        env.crypto().ed25519_verify(&attester_key, &entity.clone().to_xdr(&env), &signature);
+        
+        let mut attestations: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::Attestations).unwrap();
+        attestations.set(entity, true);
+        env.storage().persistent().set(&DataKey::Attestations, &attestations);
     }
     
     // Autonomous compliance check
     pub fn check_compliance(env: Env, entity: Symbol) -> bool {
+        // Entities cleared on appeal stay compliant
+        let cleared: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::Cleared).unwrap_or(Map::new(&env));
+        if cleared.get(entity.clone()).unwrap_or(false) {
+            return true;
+        }
+        
         // Simulate Pi Network scan (in real, query Pi Network APIs)
         let feeds: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::PiNetworkFeeds).unwrap();
         let usage_score = feeds.get(Symbol::new(&env, "pi_network_api")).unwrap_or(0);
@@ -72,22 +120,17 @@ impl HyperEnforcementContract {
         
         // Autonomous decision: If not using Pi Coin, flag for enforcement
         if !pi_verified || usage_score < 314159 {  // Threshold based on Pi value
-            Self::enforce_action(env, entity, Symbol::new(&env, "reject"));
+            Self::enforce_action(env.clone(), entity, Symbol::new(&env, "reject"));
             false
         } else {
             true
         }
     }
     
-    // Verify Pi Coin usage with Pi-math
+    // Verify Pi Coin usage via a recorded attester signature
     fn verify_pi_usage(env: Env, entity: Symbol) -> bool {
-        // Simulate: Check if entity transactions use Pi Coin (in real, cross-check with Pi Network)
-        let pi_value = 314159u64;
-        let entity_hash = env.crypto().sha256(&env, &Bytes::from_slice(&env, &entity.to_string().as_bytes()));
-        let pi_digits = generate_pi_digits(10);
-        let expected = pi_based_hash(&format!("{}-{}", entity, pi_value), &pi_digits);
-        
-        entity_hash == expected  // Simplified verification
+        let attestations: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::Attestations).unwrap_or(Map::new(&env));
+        attestations.get(entity).unwrap_or(false)
     }
     
     // Autonomous enforcement action
@@ -112,12 +155,16 @@ impl HyperEnforcementContract {
         // Execute: Halt/reject/delete (simulated cross-contract calls)
         if action == Symbol::new(&env, "halt") {
             // Call transaction contract to block
-            let tx_contract = env.storage().persistent().get(&Symbol::new(&env, "transaction_contract")).unwrap();
-            contractcall!(env, tx_contract, halt_entity, entity);
+            // NOTE: TransactionContract does not expose halt_entity yet; the target must be added there.
+            let tx_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "transaction_contract")).unwrap();
+            let args: Vec<Val> = Vec::from_array(&env, [entity.into_val(&env)]);
+            env.invoke_contract::<()>(&tx_contract, &Symbol::new(&env, "halt_entity"), args);
         } else if action == Symbol::new(&env, "delete") {
             // Call ecosystem contract to remove
-            let eco_contract = env.storage().persistent().get(&Symbol::new(&env, "ecosystem_contract")).unwrap();
-            contractcall!(env, eco_contract, remove_entity, entity);
+            // NOTE: the ecosystem contract does not expose remove_entity yet; the target must be added there.
+            let eco_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "ecosystem_contract")).unwrap();
+            let args: Vec<Val> = Vec::from_array(&env, [entity.into_val(&env)]);
+            env.invoke_contract::<()>(&eco_contract, &Symbol::new(&env, "remove_entity"), args);
         }
     }
     
@@ -128,6 +175,89 @@ impl HyperEnforcementContract {
         }
     }
     
+    // Record the address that speaks for an entity in appeals (signer only)
+    pub fn bind_entity(env: Env, caller: Address, entity: Symbol, owner: Address) {
+        Self::require_multi_sig(&env, &caller);
+        
+        let mut owners: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::EntityOwners).unwrap();
+        owners.set(entity.clone(), owner.clone());
+        env.storage().persistent().set(&DataKey::EntityOwners, &owners);
+        
+        env.events().publish((Symbol::new(&env, "entity_bound"), entity), owner);
+    }
+    
+    // Appeal a blacklisting; recorded for off-chain review. Needs the entity owner's auth, and
+    // each entity may have one appeal pending until its removal is decided.
+    pub fn appeal(env: Env, entity: Symbol, evidence: Bytes) {
+        let owners: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::EntityOwners).unwrap();
+        let owner = owners.get(entity.clone()).unwrap_or_else(|| panic!("Entity has no registered owner"));
+        owner.require_auth();
+        
+        let blacklist: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::Blacklist).unwrap();
+        if !blacklist.get(entity.clone()).unwrap_or(false) {
+            panic!("Entity not blacklisted");
+        }
+        
+        let mut appeals: Vec<Appeal> = env.storage().persistent().get(&DataKey::Appeals).unwrap();
+        if appeals.iter().any(|pending| pending.entity == entity) {
+            panic!("Appeal already pending");
+        }
+        if appeals.len() >= MAX_PENDING_APPEALS {
+            panic!("Too many pending appeals");
+        }
+        let appeal = Appeal {
+            entity: entity.clone(),
+            evidence: evidence.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        appeals.push_back(appeal);
+        env.storage().persistent().set(&DataKey::Appeals, &appeals);
+        
+        env.events().publish((Symbol::new(&env, "appeal_filed"), entity), evidence);
+    }
+    
+    // Approve removing an entity from the blacklist. The entity is cleared, and its pending appeal
+    // closed, once threshold-many distinct signers have approved; returns whether that happened.
+    pub fn remove_from_blacklist(env: Env, caller: Address, entity: Symbol) -> bool {
+        Self::require_multi_sig(&env, &caller);
+        
+        let approvals_key = DataKey::RemovalApprovals(entity.clone());
+        let mut approvals: Vec<Address> = env.storage().persistent().get(&approvals_key).unwrap_or(Vec::new(&env));
+        if approvals.contains(&caller) {
+            panic!("Already approved");
+        }
+        approvals.push_back(caller.clone());
+        let threshold: u32 = env.storage().persistent().get(&DataKey::Threshold).unwrap();
+        if approvals.len() < threshold {
+            env.storage().persistent().set(&approvals_key, &approvals);
+            env.events().publish((Symbol::new(&env, "blacklist_removal_approved"), entity), caller);
+            return false;
+        }
+        env.storage().persistent().remove(&approvals_key);
+        
+        let mut appeals: Vec<Appeal> = env.storage().persistent().get(&DataKey::Appeals).unwrap();
+        if let Some(index) = appeals.iter().position(|pending| pending.entity == entity) {
+            appeals.remove(index as u32);
+            env.storage().persistent().set(&DataKey::Appeals, &appeals);
+        }
+        
+        let mut blacklist: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::Blacklist).unwrap();
+        blacklist.remove(entity.clone());
+        env.storage().persistent().set(&DataKey::Blacklist, &blacklist);
+        
+        let mut cleared: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::Cleared).unwrap();
+        cleared.set(entity.clone(), true);
+        env.storage().persistent().set(&DataKey::Cleared, &cleared);
+        
+        env.events().publish((Symbol::new(&env, "blacklist_removed"), entity), approvals);
+        true
+    }
+    
+    // Get pending appeals
+    pub fn get_appeals(env: Env) -> Vec<Appeal> {
+        env.storage().persistent().get(&DataKey::Appeals).unwrap()
+    }
+    
     // Get blacklist
     pub fn get_blacklist(env: Env) -> Map<Symbol, bool> {
         env.storage().persistent().get(&DataKey::Blacklist).unwrap()
@@ -137,20 +267,13 @@ impl HyperEnforcementContract {
     pub fn get_compliance_log(env: Env) -> Vec<EnforcementAction> {
         env.storage().persistent().get(&DataKey::ComplianceLog).unwrap()
     }
-}
-
-// Pi-math utilities
-fn generate_pi_digits(digits: usize) -> String {
-    let pi = std::f64::consts::PI;
-    format!("{:.1$}", pi, digits)
-}
-
-fn pi_based_hash(data: &str, pi_digits: &str) -> [u8; 32] {
-    let combined = format!("{}{}", data, pi_digits);
-    let mut hasher = Sha3_512::new();
-    hasher.update(combined.as_bytes());
-    let result = hasher.finalize();
-    let mut hash = [0u8; 32];
-    hash.copy_from_slice(&result[..32]);
-    hash
+    
+    // Require the caller to be a registered signer
+    fn require_multi_sig(env: &Env, caller: &Address) {
+        caller.require_auth();
+        let signers: Vec<Address> = env.storage().persistent().get(&DataKey::MultiSigSigners).unwrap();
+        if !signers.contains(caller) {
+            panic!("Unauthorized");
+        }
+    }
 }
@@ -1,10 +1,184 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+use hyper_enforcement::MAX_PENDING_APPEALS;
+use hyper_enforcement::{HyperEnforcementContract, HyperEnforcementContractClient};
+
+fn attester(env: &Env) -> (SigningKey, BytesN<32>) {
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    (signing_key, public_key)
+}
+
+fn sign_entity(env: &Env, signing_key: &SigningKey, entity: &Symbol) -> BytesN<64> {
+    let encoded = entity.clone().to_xdr(env);
+    let mut message = vec![0u8; encoded.len() as usize];
+    encoded.copy_into_slice(&mut message);
+    let signature = signing_key.sign(&message);
+    BytesN::from_array(env, &signature.to_bytes())
+}
+
 #[test]
 fn test_check_compliance() {
     let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (signing_key, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone()]), &1);
+    
+    let entity = Symbol::new(&env, "merchant_pi");
+    client.submit_attestation(&entity, &sign_entity(&env, &signing_key, &entity));
+    let compliant = client.check_compliance(&entity);
+    assert!(compliant);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_attestation_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone()]), &1);
+    
+    // Signed by an untrusted key
+    let impostor = SigningKey::from_bytes(&[9u8; 32]);
+    let entity = Symbol::new(&env, "merchant_fake");
+    client.submit_attestation(&entity, &sign_entity(&env, &impostor, &entity));
+}
+
+#[test]
+fn test_unattested_entity_is_non_compliant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone()]), &1);
+    
+    let entity = Symbol::new(&env, "merchant_usd");
+    assert!(!client.check_compliance(&entity));
+    assert_eq!(client.get_blacklist().get(entity), Some(true));
+}
+
+#[test]
+fn test_blacklist_appeal_and_removal_restores_compliance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    let signers = Vec::from_array(&env, [admin.clone(), Address::generate(&env), Address::generate(&env)]);
+    client.init(&admin, &pi_coin_id, &attester_key, &signers, &2);
+    
+    // Blacklisted on first scan
+    let entity = Symbol::new(&env, "merchant_fp");
+    assert!(!client.check_compliance(&entity));
+    assert_eq!(client.get_blacklist().get(entity.clone()), Some(true));
+    
+    // Appeal recorded for review
+    client.bind_entity(&admin, &entity, &Address::generate(&env));
+    client.appeal(&entity, &Bytes::from_slice(&env, b"ipfs://evidence"));
+    let appeals = client.get_appeals();
+    assert_eq!(appeals.len(), 1);
+    assert_eq!(appeals.get(0).unwrap().entity, entity);
+    
+    // One signer is not enough; the second approval clears the entity and closes its appeal
+    assert!(!client.remove_from_blacklist(&signers.get(0).unwrap(), &entity));
+    assert_eq!(client.get_blacklist().get(entity.clone()), Some(true));
+    assert!(client.remove_from_blacklist(&signers.get(2).unwrap(), &entity));
+    assert_eq!(client.get_blacklist().get(entity.clone()), None);
+    assert!(client.get_appeals().is_empty());
+    assert!(client.check_compliance(&entity));
+}
+
+#[test]
+#[should_panic(expected = "Already approved")]
+fn test_removal_approvals_must_come_from_distinct_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone(), Address::generate(&env)]), &2);
+    
+    let entity = Symbol::new(&env, "merchant_fp");
+    client.check_compliance(&entity);
+    client.remove_from_blacklist(&admin, &entity);
+    client.remove_from_blacklist(&admin, &entity);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_remove_from_blacklist_requires_signer() {
+    let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, HyperEnforcementContract);
     let client = HyperEnforcementContractClient::new(&env, &contract_id);
     
-    client.init(&admin, &pi_coin_id);
-    let compliant = client.check_compliance(&Symbol::new(&env, "merchant_pi"));
-    assert!(compliant);  // Assuming Pi usage
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone()]), &1);
+    
+    let entity = Symbol::new(&env, "merchant_fp");
+    client.check_compliance(&entity);
+    client.remove_from_blacklist(&Address::generate(&env), &entity);
+}
+
+#[test]
+#[should_panic(expected = "Entity has no registered owner")]
+fn test_appeal_requires_bound_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone()]), &1);
+    
+    // Anyone could otherwise flood the review queue in a blacklisted entity's name
+    let entity = Symbol::new(&env, "merchant_fp");
+    client.check_compliance(&entity);
+    client.appeal(&entity, &Bytes::from_slice(&env, b"spam"));
+}
+
+#[test]
+#[should_panic(expected = "Too many pending appeals")]
+fn test_pending_appeals_are_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperEnforcementContract);
+    let client = HyperEnforcementContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pi_coin_id = Address::generate(&env);
+    let (_, attester_key) = attester(&env);
+    client.init(&admin, &pi_coin_id, &attester_key, &Vec::from_array(&env, [admin.clone()]), &1);
+    
+    let owner = Address::generate(&env);
+    for i in 0..=MAX_PENDING_APPEALS {
+        let entity = Symbol::new(&env, &format!("merchant_{}", i));
+        client.check_compliance(&entity);
+        client.bind_entity(&admin, &entity, &owner);
+        client.appeal(&entity, &Bytes::from_slice(&env, b"ipfs://evidence"));
+    }
 }
@@ -1,10 +1,21 @@
 // contracts/hyper_prediction_oracle/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, IntoVal, Val};
+use pi_coin_contract::retention::{self, DEFAULT_RETENTION};
+pub use pi_coin_contract::retention::RetentionPolicy;
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
 use sha3::{Digest, Sha3_512};
 
+// Number of most recent HistoricalData samples used for trend statistics
+const HISTORY_WINDOW: u32 = 10;
+// Percentage weight given to the historical moving average when blending with current input
+const HISTORY_BLEND: u64 = 50;
+// New HistoricalData samples required between model evolutions
+const EVOLUTION_MIN_SAMPLES: u32 = 10;
+// Maximum weight change applied per evolution
+const MAX_WEIGHT_STEP: i64 = 2;
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Prediction {
@@ -12,6 +23,7 @@ pub struct Prediction {
     pub confidence: u32, // 0-100
     pub pi_adjusted_score: u32, // Pi-math boosted
     pub predicted_action: Symbol, // e.g., "preempt_enforce"
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -20,6 +32,10 @@ pub enum DataKey {
     HistoricalData,    // Past trends for training
     QuantumKey,
     PredictionLog,
+    LastEvolutionSamples, // HistoricalData length at the last evolution
+    Admin,
+    Retention,         // RetentionPolicy; DEFAULT_RETENTION when unset
+    PredictionTotal,   // u64 predictions ever made, including pruned ones
 }
 
 #[contract]
@@ -30,6 +46,7 @@ impl HyperPredictionOracleContract {
     // Initialize with hyper prediction setup
     pub fn init(env: Env, admin: Address) {
         admin.require_auth();
+        env.storage().persistent().set(&DataKey::Admin, &admin);
         
         // Ensemble models (weights for prediction)
         let models = Map::new(&env);
@@ -51,6 +68,7 @@ impl HyperPredictionOracleContract {
         // Prediction log
         let log = Vec::new(&env);
         env.storage().persistent().set(&DataKey::PredictionLog, &log);
+        env.storage().persistent().set(&DataKey::LastEvolutionSamples, &0u32);
     }
     
     // Predict future trends autonomously
@@ -62,14 +80,20 @@ impl HyperPredictionOracleContract {
         let comp_weight = models.get(Symbol::new(&env, "compliance_model")).unwrap_or(35);
         let stab_weight = models.get(Symbol::new(&env, "stability_model")).unwrap_or(25);
         
-        let volatility = input_data.get(Symbol::new(&env, "volatility")).unwrap_or(0);
-        let compliance = input_data.get(Symbol::new(&env, "compliance")).unwrap_or(100);
+        let mut volatility = input_data.get(Symbol::new(&env, "volatility")).unwrap_or(0);
+        let mut compliance = input_data.get(Symbol::new(&env, "compliance")).unwrap_or(100);
         let stability = input_data.get(Symbol::new(&env, "stability")).unwrap_or(314159);
         
-        let score = (vol_weight as u64 * volatility + comp_weight as u64 * (100 - compliance) + stab_weight as u64 * (stability / 314159)) / 100;
+        // Blend current input with moving averages over accumulated history
+        if let Some((avg_volatility, avg_compliance)) = Self::history_trend(&env) {
+            volatility = blend(volatility, avg_volatility);
+            compliance = blend(compliance, avg_compliance);
+        }
+        
+        let score = (vol_weight as u64 * volatility + comp_weight as u64 * 100u64.saturating_sub(compliance) + stab_weight as u64 * (stability / 314159)) / 100;
         
         // Pi-math adjustment
-        let pi_boost = (generate_pi_digits(5).chars().map(|c| c.to_digit(10).unwrap_or(0)).sum::<u32>() % 10) as u64;
+        let pi_boost = (pi_digit_sum(6) % 10) as u64;
         let adjusted_score = (score + pi_boost).min(100) as u32;
         
         let (trend, predicted_action) = if adjusted_score > 70 {
@@ -85,6 +109,7 @@ impl HyperPredictionOracleContract {
             confidence: adjusted_score,
             pi_adjusted_score: adjusted_score,
             predicted_action,
+            timestamp: env.ledger().timestamp(),
         };
         
         // Autonomous execution if high confidence
@@ -95,7 +120,9 @@ impl HyperPredictionOracleContract {
         // Log prediction
         let mut log: Vec<Prediction> = env.storage().persistent().get(&DataKey::PredictionLog).unwrap();
         log.push_back(prediction.clone());
+        let log = retention::retain(&env, &Self::get_retention(env.clone()), log, |prediction| prediction.timestamp);
         env.storage().persistent().set(&DataKey::PredictionLog, &log);
+        retention::bump_total(&env, &DataKey::PredictionTotal);
         
         prediction
     }
@@ -104,11 +131,31 @@ impl HyperPredictionOracleContract {
     fn execute_prediction(env: Env, prediction: Prediction) {
         if prediction.predicted_action == Symbol::new(&env, "preempt_enforce") {
             // Call enforcement contract
-            let enforce_contract = env.storage().persistent().get(&Symbol::new(&env, "enforcement_contract")).unwrap();
-            contractcall!(env, enforce_contract, autonomous_scan, Vec::from_array(&env, [Symbol::new(&env, "predicted_entity")]));
+            let enforce_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "enforcement_contract")).unwrap();
+            let entities = Vec::from_array(&env, [Symbol::new(&env, "predicted_entity")]);
+            let args: Vec<Val> = Vec::from_array(&env, [entities.into_val(&env)]);
+            env.invoke_contract::<()>(&enforce_contract, &Symbol::new(&env, "autonomous_scan"), args);
         }
     }
     
+    // Moving averages of volatility and compliance over the last HISTORY_WINDOW samples
+    fn history_trend(env: &Env) -> Option<(u64, u64)> {
+        let data: Vec<Map<Symbol, u64>> = env.storage().persistent().get(&DataKey::HistoricalData).unwrap_or(Vec::new(env));
+        if data.is_empty() {
+            return None;
+        }
+        
+        let mut volatility_sum = 0u64;
+        let mut compliance_sum = 0u64;
+        let mut samples = 0u64;
+        for sample in data.iter().rev().take(HISTORY_WINDOW as usize) {
+            volatility_sum = volatility_sum.saturating_add(sample.get(Symbol::new(env, "volatility")).unwrap_or(0));
+            compliance_sum = compliance_sum.saturating_add(sample.get(Symbol::new(env, "compliance")).unwrap_or(100));
+            samples += 1;
+        }
+        Some((volatility_sum / samples, compliance_sum / samples))
+    }
+    
     // Update historical data for self-learning
     pub fn update_historical_data(env: Env, new_data: Map<Symbol, u64>) {
         let mut data: Vec<Map<Symbol, u64>> = env.storage().persistent().get(&DataKey::HistoricalData).unwrap();
@@ -119,15 +166,55 @@ impl HyperPredictionOracleContract {
         Self::evolve_models(env);
     }
     
-    // Self-evolve prediction models
+    // Self-evolve prediction models from observed prediction error
     fn evolve_models(env: Env) {
         let data: Vec<Map<Symbol, u64>> = env.storage().persistent().get(&DataKey::HistoricalData).unwrap();
-        if data.len() > 10 {  // Threshold for evolution
-            let mut models: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::PredictionModels).unwrap();
-            let current_vol = models.get(Symbol::new(&env, "volatility_model")).unwrap_or(40);
-            models.set(Symbol::new(&env, "volatility_model"), (current_vol + 5).min(100));
-            env.storage().persistent().set(&DataKey::PredictionModels, &models);
+        let last_evolution: u32 = env.storage().persistent().get(&DataKey::LastEvolutionSamples).unwrap_or(0);
+        let new_samples = data.len() - last_evolution;
+        if new_samples < EVOLUTION_MIN_SAMPLES {  // Cooldown until enough fresh samples
+            return;
+        }
+        env.storage().persistent().set(&DataKey::LastEvolutionSamples, &data.len());
+        
+        // Compare the latest predicted confidence with realized volatility since the last evolution
+        let log: Vec<Prediction> = env.storage().persistent().get(&DataKey::PredictionLog).unwrap();
+        let predicted = match log.last() {
+            Some(prediction) => prediction.confidence as i64,
+            None => return,  // Nothing to measure error against
+        };
+        let mut volatility_sum = 0u64;
+        for sample in data.iter().skip(last_evolution as usize) {
+            volatility_sum = volatility_sum.saturating_add(sample.get(Symbol::new(&env, "volatility")).unwrap_or(0));
         }
+        let realized = (volatility_sum / new_samples as u64).min(100) as i64;
+        let step = ((realized - predicted) / 10).clamp(-MAX_WEIGHT_STEP, MAX_WEIGHT_STEP);
+        
+        let mut models: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::PredictionModels).unwrap();
+        let current_vol = models.get(Symbol::new(&env, "volatility_model")).unwrap_or(40) as i64;
+        models.set(Symbol::new(&env, "volatility_model"), (current_vol + step).clamp(0, 100) as u32);
+        env.storage().persistent().set(&DataKey::PredictionModels, &models);
+    }
+    
+    // Replace the prediction log retention policy (admin only); applied from the next prediction
+    pub fn set_retention(env: Env, policy: RetentionPolicy) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        assert!(policy.max_entries > 0, "retention must keep at least one entry");
+        env.storage().persistent().set(&DataKey::Retention, &policy);
+    }
+    
+    pub fn get_retention(env: Env) -> RetentionPolicy {
+        env.storage().persistent().get(&DataKey::Retention).unwrap_or(DEFAULT_RETENTION)
+    }
+    
+    // Predictions made since init, unaffected by pruning
+    pub fn get_prediction_total(env: Env) -> u64 {
+        env.storage().persistent().get(&DataKey::PredictionTotal).unwrap_or(0)
+    }
+    
+    // Get ensemble model weights
+    pub fn get_models(env: Env) -> Map<Symbol, u32> {
+        env.storage().persistent().get(&DataKey::PredictionModels).unwrap()
     }
     
     // Get prediction log
@@ -136,8 +223,14 @@ impl HyperPredictionOracleContract {
     }
 }
 
-// Pi-math utilities
-fn generate_pi_digits(digits: usize) -> String {
-    let pi = std::f64::consts::PI;
-    format!("{:.1$}", pi, digits)
+// Weighted blend of a current value with its historical average
+fn blend(current: u64, historical: u64) -> u64 {
+    (current * (100 - HISTORY_BLEND) + historical * HISTORY_BLEND) / 100
+}
+
+// Pi-math utilities (fixed digit table; no floating point in no_std)
+const PI_DIGITS: [u32; 16] = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3];
+
+fn pi_digit_sum(digits: usize) -> u32 {
+    PI_DIGITS.iter().take(digits).sum()
 }
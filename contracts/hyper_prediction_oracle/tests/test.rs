@@ -1,3 +1,6 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, Map, Symbol};
+use hyper_prediction_oracle::{HyperPredictionOracleContract, HyperPredictionOracleContractClient};
+
 #[test]
 fn test_predict_trend() {
     let env = Env::default();
@@ -10,3 +13,56 @@ fn test_predict_trend() {
     let prediction = client.predict_trend(&input);
     assert!(prediction.confidence > 0);
 }
+
+#[test]
+fn test_volatile_history_shifts_trend() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperPredictionOracleContract);
+    let client = HyperPredictionOracleContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    
+    let input = Map::new(&env);
+    input.set(Symbol::new(&env, "volatility"), 50u64);
+    let baseline = client.predict_trend(&input);
+    assert_eq!(baseline.trend, Symbol::new(&env, "stable"));
+    
+    // Feed volatile history
+    for _ in 0..5 {
+        let sample = Map::new(&env);
+        sample.set(Symbol::new(&env, "volatility"), 300u64);
+        client.update_historical_data(&sample);
+    }
+    
+    let shifted = client.predict_trend(&input);
+    assert!(shifted.confidence > baseline.confidence);
+    assert_eq!(shifted.trend, Symbol::new(&env, "volatile_up"));
+}
+
+#[test]
+fn test_evolution_is_bounded_after_burst() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, HyperPredictionOracleContract);
+    let client = HyperPredictionOracleContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    
+    let input = Map::new(&env);
+    input.set(Symbol::new(&env, "volatility"), 10u64);
+    client.predict_trend(&input);
+    
+    // Single burst of highly volatile updates
+    for _ in 0..30 {
+        let sample = Map::new(&env);
+        sample.set(Symbol::new(&env, "volatility"), 1000u64);
+        client.update_historical_data(&sample);
+    }
+    
+    let weight = client.get_models().get(Symbol::new(&env, "volatility_model")).unwrap();
+    assert!(weight > 40);
+    assert!(weight < 100);
+}
@@ -3,43 +3,77 @@
 // Cosmic voting, eternal interstellar decisions.
 // Features: Cosmic propose, vote, execute, GodHead Nexus AI governance.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, Vec, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Map, Vec, log};
 
 #[contract]
 pub struct CosmicGovernance {
+    admin: Address,
     cosmic_proposals: Map<Symbol, Map<Symbol, Vec<Symbol>>>, // Proposal -> Votes.
+    cosmic_voters: Map<Symbol, Map<Symbol, bool>>, // Proposal -> voters who already voted.
+    cosmic_deadlines: Map<Symbol, u64>, // Proposal -> voting deadline (ledger timestamp).
+    quorum: u32, // Minimum total votes before execution.
 }
 
 #[contractimpl]
 impl CosmicGovernance {
-    pub fn init(env: Env) -> CosmicGovernance {
-        CosmicGovernance { cosmic_proposals: Map::new(&env) }
+    pub fn init(env: Env, admin: Address, quorum: u32) -> CosmicGovernance {
+        CosmicGovernance {
+            admin,
+            cosmic_proposals: Map::new(&env),
+            cosmic_voters: Map::new(&env),
+            cosmic_deadlines: Map::new(&env),
+            quorum,
+        }
     }
 
-    /// Propose cosmic.
-    pub fn propose_cosmic(&mut self, env: Env, proposal: Symbol) {
+    /// Propose cosmic, open for voting for `duration` seconds.
+    pub fn propose_cosmic(&mut self, env: Env, proposal: Symbol, duration: u64) -> Result<(), &'static str> {
+        if self.cosmic_proposals.contains_key(proposal.clone()) {
+            return Err("Proposal exists.");
+        }
         let mut votes = Map::new(&env);
         votes.set(Symbol::new(&env, "yes"), Vec::new(&env));
         votes.set(Symbol::new(&env, "no"), Vec::new(&env));
-        self.cosmic_proposals.set(proposal, votes);
+        self.cosmic_proposals.set(proposal.clone(), votes);
+        self.cosmic_voters.set(proposal.clone(), Map::new(&env));
+        self.cosmic_deadlines.set(proposal.clone(), env.ledger().timestamp() + duration);
         log!(&env, "Cosmic proposed: {}", proposal);
+        Ok(())
     }
 
-    /// Vote cosmic.
-    pub fn vote_cosmic(&mut self, env: Env, proposal: Symbol, voter: Symbol, vote: Symbol) {
-        let mut proposal_votes = self.cosmic_proposals.get(proposal).ok_or("Proposal not found")?;
-        let mut vote_list = proposal_votes.get(vote).unwrap_or(Vec::new(&env));
-        vote_list.push_back(voter);
-        proposal_votes.set(vote, vote_list);
-        self.cosmic_proposals.set(proposal, proposal_votes);
+    /// Vote cosmic; one vote per voter, before the deadline.
+    pub fn vote_cosmic(&mut self, env: Env, proposal: Symbol, voter: Symbol, vote: Symbol) -> Result<(), &'static str> {
+        let mut proposal_votes = self.cosmic_proposals.get(proposal.clone()).ok_or("Proposal not found")?;
+        if vote != Symbol::new(&env, "yes") && vote != Symbol::new(&env, "no") {
+            return Err("Invalid vote.");
+        }
+        let deadline = self.cosmic_deadlines.get(proposal.clone()).unwrap_or(0);
+        if env.ledger().timestamp() > deadline {
+            return Err("Voting closed.");
+        }
+        let mut voters = self.cosmic_voters.get(proposal.clone()).unwrap_or(Map::new(&env));
+        if voters.contains_key(voter.clone()) {
+            return Err("Already voted.");
+        }
+        voters.set(voter.clone(), true);
+        self.cosmic_voters.set(proposal.clone(), voters);
+
+        let mut vote_list = proposal_votes.get(vote.clone()).unwrap_or(Vec::new(&env));
+        vote_list.push_back(voter.clone());
+        proposal_votes.set(vote.clone(), vote_list);
+        self.cosmic_proposals.set(proposal.clone(), proposal_votes);
         log!(&env, "Cosmic voted: {} on {} by {}", vote, proposal, voter);
+        Ok(())
     }
 
-    /// Execute cosmic decision.
+    /// Execute cosmic decision; needs quorum and a yes majority.
     pub fn execute_cosmic(&self, env: Env, proposal: Symbol) -> Result<(), &'static str> {
-        let proposal_votes = self.cosmic_proposals.get(proposal).ok_or("Proposal not found")?;
+        let proposal_votes = self.cosmic_proposals.get(proposal.clone()).ok_or("Proposal not found")?;
         let yes_votes = proposal_votes.get(Symbol::new(&env, "yes")).unwrap_or(Vec::new(&env)).len();
         let no_votes = proposal_votes.get(Symbol::new(&env, "no")).unwrap_or(Vec::new(&env)).len();
+        if yes_votes + no_votes < self.quorum {
+            return Err("Quorum not reached.");
+        }
         if yes_votes > no_votes {
             log!(&env, "Cosmic executed: {}", proposal);
             Ok(())
@@ -48,8 +82,71 @@ impl CosmicGovernance {
         }
     }
 
+    /// Update quorum. Admin only.
+    pub fn set_quorum(&mut self, env: Env, quorum: u32) {
+        self.admin.require_auth();
+        self.quorum = quorum;
+        log!(&env, "Cosmic quorum set: {}", quorum);
+    }
+
     /// Get cosmic votes.
     pub fn get_cosmic_votes(&self, env: Env, proposal: Symbol) -> Map<Symbol, Vec<Symbol>> {
         self.cosmic_proposals.get(proposal).unwrap_or(Map::new(&env))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_duplicate_vote_rejected() {
+        let env = Env::default();
+        let mut governance = CosmicGovernance::init(env.clone(), Address::generate(&env), 1);
+        let proposal = Symbol::new(&env, "mars_tax");
+        let voter = Symbol::new(&env, "earth");
+        governance.propose_cosmic(env.clone(), proposal.clone(), 3600).unwrap();
+
+        governance.vote_cosmic(env.clone(), proposal.clone(), voter.clone(), Symbol::new(&env, "yes")).unwrap();
+        assert_eq!(governance.vote_cosmic(env.clone(), proposal.clone(), voter.clone(), Symbol::new(&env, "yes")), Err("Already voted."));
+        assert_eq!(governance.vote_cosmic(env.clone(), proposal.clone(), voter, Symbol::new(&env, "no")), Err("Already voted."));
+        assert_eq!(governance.get_cosmic_votes(env.clone(), proposal).get(Symbol::new(&env, "yes")).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_below_quorum_not_executed() {
+        let env = Env::default();
+        let mut governance = CosmicGovernance::init(env.clone(), Address::generate(&env), 3);
+        let proposal = Symbol::new(&env, "mars_tax");
+        governance.propose_cosmic(env.clone(), proposal.clone(), 3600).unwrap();
+
+        governance.vote_cosmic(env.clone(), proposal.clone(), Symbol::new(&env, "earth"), Symbol::new(&env, "yes")).unwrap();
+        governance.vote_cosmic(env.clone(), proposal.clone(), Symbol::new(&env, "mars"), Symbol::new(&env, "yes")).unwrap();
+        assert_eq!(governance.execute_cosmic(env.clone(), proposal.clone()), Err("Quorum not reached."));
+
+        governance.vote_cosmic(env.clone(), proposal.clone(), Symbol::new(&env, "venus"), Symbol::new(&env, "no")).unwrap();
+        assert_eq!(governance.execute_cosmic(env.clone(), proposal), Ok(()));
+    }
+
+    #[test]
+    fn test_admin_sets_quorum() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let mut governance = CosmicGovernance::init(env.clone(), Address::generate(&env), 3);
+        let proposal = Symbol::new(&env, "mars_tax");
+        governance.propose_cosmic(env.clone(), proposal.clone(), 3600).unwrap();
+        governance.vote_cosmic(env.clone(), proposal.clone(), Symbol::new(&env, "earth"), Symbol::new(&env, "yes")).unwrap();
+
+        governance.set_quorum(env.clone(), 1);
+        assert_eq!(governance.execute_cosmic(env.clone(), proposal), Ok(()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_quorum_without_admin_auth_panics() {
+        let env = Env::default();
+        let mut governance = CosmicGovernance::init(env.clone(), Address::generate(&env), 3);
+        governance.set_quorum(env.clone(), 0);
+    }
+}
@@ -1,7 +1,9 @@
 // contracts/interplanetary_economy/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN};
+use pi_coin_contract::retention::{self, DEFAULT_RETENTION};
+pub use pi_coin_contract::retention::RetentionPolicy;
 
 #[contracttype]
 #[derive(Clone)]
@@ -18,6 +20,35 @@ pub struct InterplanetaryTrade {
     pub to_planet: Symbol,
     pub amount_pi: u64,
     pub approved: bool,
+    pub timestamp: u64,
+}
+
+// Trade approval limits, set at init and updatable by governance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeConfig {
+    pub max_trade_amount: u64,      // Largest single trade in Pi Coin
+    pub min_compliance_score: u32,  // Sending entity's compliance_score must be at least this
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TradeRejectedEvent {
+    pub from_planet: Symbol,
+    pub to_planet: Symbol,
+    pub amount_pi: u64,
+    pub reason: Symbol,  // "unknown_entity", "low_compliance", "over_limit" or "insufficient_resources"
+}
+
+// Entry in CrimePreventionLog
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreventionRecord {
+    pub activity: Symbol,  // e.g., "trade_manipulation_prevented"
+    pub entity: Symbol,    // Planet the activity originated from
+    pub amount: u64,       // Pi Coin involved, 0 if none
+    pub timestamp: u64,
+    pub reason: Symbol,
 }
 
 #[contracttype]
@@ -25,7 +56,12 @@ pub enum DataKey {
     PlanetaryEntities,  // Map of planets
     TradeLog,           // Log of interplanetary trades
     SuperAiBrain,       // AI brain weights for governance
-    CrimePreventionLog, // Log of prevented crimes
+    CrimePreventionLog, // Vec<PreventionRecord> of prevented crimes
+    Governance,         // Address allowed to update TradeConfig and entity compliance
+    TradeConfig,        // TradeConfig
+    Retention,          // RetentionPolicy; DEFAULT_RETENTION when unset
+    TradeTotal,         // u64 trades ever logged, including pruned ones
+    PreventionTotal,    // u64 prevention records ever logged, including pruned ones
 }
 
 #[contract]
@@ -33,9 +69,11 @@ pub struct InterplanetaryEconomyContract;
 
 #[contractimpl]
 impl InterplanetaryEconomyContract {
-    // Initialize with super AI brain
-    pub fn init(env: Env, admin: Address) {
+    // Initialize with super AI brain; `admin` is the governance address for trade limits
+    pub fn init(env: Env, admin: Address, max_trade_amount: u64, min_compliance_score: u32) {
         admin.require_auth();
+        env.storage().persistent().set(&DataKey::Governance, &admin);
+        env.storage().persistent().set(&DataKey::TradeConfig, &TradeConfig { max_trade_amount, min_compliance_score });
         
         let entities = Map::new(&env);
         env.storage().persistent().set(&DataKey::PlanetaryEntities, &entities);
@@ -68,10 +106,45 @@ impl InterplanetaryEconomyContract {
         env.storage().persistent().set(&DataKey::PlanetaryEntities, &entities);
     }
     
-    // Super AI resource allocation
+    // Set a planet's compliance_score (governance only); scores are percentages
+    pub fn update_compliance_score(env: Env, caller: Address, planet: Symbol, score: u32) {
+        Self::require_governance(&env, &caller);
+        assert!(score <= 100, "compliance score out of range");
+        let mut entities: Map<Symbol, PlanetaryEntity> = env.storage().persistent().get(&DataKey::PlanetaryEntities).unwrap();
+        let mut entity = entities.get(planet.clone()).expect("unknown entity");
+        entity.compliance_score = score;
+        entities.set(planet, entity);
+        env.storage().persistent().set(&DataKey::PlanetaryEntities, &entities);
+    }
+    
+    // Remove a planet and drop trades involving it from the trade log (governance only)
+    pub fn deregister_entity(env: Env, caller: Address, planet: Symbol) {
+        Self::require_governance(&env, &caller);
+        let mut entities: Map<Symbol, PlanetaryEntity> = env.storage().persistent().get(&DataKey::PlanetaryEntities).unwrap();
+        assert!(entities.contains_key(planet.clone()), "unknown entity");
+        entities.remove(planet.clone());
+        env.storage().persistent().set(&DataKey::PlanetaryEntities, &entities);
+        
+        let trade_log: Vec<InterplanetaryTrade> = env.storage().persistent().get(&DataKey::TradeLog).unwrap();
+        let mut remaining = Vec::new(&env);
+        for trade in trade_log.iter() {
+            if trade.from_planet != planet && trade.to_planet != planet {
+                remaining.push_back(trade);
+            }
+        }
+        env.storage().persistent().set(&DataKey::TradeLog, &remaining);
+    }
+    
+    fn require_governance(env: &Env, caller: &Address) {
+        caller.require_auth();
+        let governance: Address = env.storage().persistent().get(&DataKey::Governance).unwrap();
+        assert!(*caller == governance, "caller is not governance");
+    }
+    
+    // Super AI resource allocation, denied unless the planet's current compliance_score is above 80
     pub fn allocate_resources(env: Env, planet: Symbol, need: u64) -> u64 {
         let mut entities: Map<Symbol, PlanetaryEntity> = env.storage().persistent().get(&DataKey::PlanetaryEntities).unwrap();
-        let mut entity = entities.get(planet.clone()).unwrap();
+        let mut entity = entities.get(planet.clone()).expect("unknown entity");
         
         let ai_brain: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::SuperAiBrain).unwrap();
         let allocation_weight = ai_brain.get(Symbol::new(&env, "allocation_layer")).unwrap_or(50);
@@ -90,39 +163,66 @@ impl InterplanetaryEconomyContract {
         allocated
     }
     
-    // Interplanetary trade with AI approval
+    // Update trade limits (governance only)
+    pub fn set_trade_config(env: Env, max_trade_amount: u64, min_compliance_score: u32) {
+        let governance: Address = env.storage().persistent().get(&DataKey::Governance).unwrap();
+        governance.require_auth();
+        env.storage().persistent().set(&DataKey::TradeConfig, &TradeConfig { max_trade_amount, min_compliance_score });
+    }
+    
+    pub fn get_trade_config(env: Env) -> TradeConfig {
+        env.storage().persistent().get(&DataKey::TradeConfig).unwrap()
+    }
+    
+    // Interplanetary trade, approved when the sender is compliant enough and the amount within limits
     pub fn initiate_trade(env: Env, from_planet: Symbol, to_planet: Symbol, amount: u64) -> InterplanetaryTrade {
-        let ai_brain: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::SuperAiBrain).unwrap();
-        let trade_weight = ai_brain.get(Symbol::new(&env, "trade_layer")).unwrap_or(40);
-        
-        // AI check for crime prevention (e.g., no manipulation)
-        let approved = trade_weight > 30 && amount < 1_000_000;  // Prevent large exploits
-        
+        let rejection = Self::trade_rejection(&env, &from_planet, &to_planet, amount);
         let trade = InterplanetaryTrade {
             from_planet,
             to_planet,
             amount_pi: amount,
-            approved,
+            approved: rejection.is_none(),
+            timestamp: env.ledger().timestamp(),
         };
         
-        if approved {
-            // Execute trade
-            Self::execute_trade(env.clone(), trade.clone());
-        } else {
-            // Log as prevented crime
-            let mut crime_log: Vec<Symbol> = env.storage().persistent().get(&DataKey::CrimePreventionLog).unwrap();
-            crime_log.push_back(Symbol::new(&env, "trade_manipulation_prevented"));
-            env.storage().persistent().set(&DataKey::CrimePreventionLog, &crime_log);
+        match rejection {
+            None => Self::execute_trade(env.clone(), trade.clone()),
+            Some(reason) => {
+                // Log as prevented crime
+                Self::log_prevention(&env, Symbol::new(&env, "trade_manipulation_prevented"), trade.from_planet.clone(), amount, reason.clone());
+                env.events().publish(
+                    (Symbol::new(&env, "TradeRejected"), trade.from_planet.clone()),
+                    TradeRejectedEvent { from_planet: trade.from_planet.clone(), to_planet: trade.to_planet.clone(), amount_pi: amount, reason },
+                );
+            }
         }
         
         // Log trade
         let mut trade_log: Vec<InterplanetaryTrade> = env.storage().persistent().get(&DataKey::TradeLog).unwrap();
         trade_log.push_back(trade.clone());
+        let trade_log = retention::retain(&env, &Self::get_retention(env.clone()), trade_log, |trade| trade.timestamp);
         env.storage().persistent().set(&DataKey::TradeLog, &trade_log);
+        retention::bump_total(&env, &DataKey::TradeTotal);
         
         trade
     }
     
+    // Reason a trade cannot go through, or None when it is approved
+    fn trade_rejection(env: &Env, from_planet: &Symbol, to_planet: &Symbol, amount: u64) -> Option<Symbol> {
+        let config = Self::get_trade_config(env.clone());
+        let entities: Map<Symbol, PlanetaryEntity> = env.storage().persistent().get(&DataKey::PlanetaryEntities).unwrap();
+        let (from_entity, to_exists) = (entities.get(from_planet.clone()), entities.contains_key(to_planet.clone()));
+        let reason = match from_entity {
+            None => "unknown_entity",
+            Some(_) if !to_exists => "unknown_entity",
+            Some(entity) if entity.compliance_score < config.min_compliance_score => "low_compliance",
+            Some(_) if amount > config.max_trade_amount => "over_limit",
+            Some(entity) if entity.resources.get(Symbol::new(env, "pi_coin")).unwrap_or(0) < amount => "insufficient_resources",
+            Some(_) => return None,
+        };
+        Some(Symbol::new(env, reason))
+    }
+    
     // Execute trade
     fn execute_trade(env: Env, trade: InterplanetaryTrade) {
         let mut entities: Map<Symbol, PlanetaryEntity> = env.storage().persistent().get(&DataKey::PlanetaryEntities).unwrap();
@@ -138,18 +238,56 @@ impl InterplanetaryEconomyContract {
         env.storage().persistent().set(&DataKey::PlanetaryEntities, &entities);
     }
     
-    // Autonomous crime prevention
-    pub fn prevent_crime(env: Env, suspected_activity: Symbol) {
+    // Autonomous crime prevention (governance only)
+    pub fn prevent_crime(env: Env, caller: Address, suspected_activity: Symbol, entity: Symbol, amount: u64, reason: Symbol) {
+        Self::require_governance(&env, &caller);
         // Super AI detects and prevents (e.g., gambling, scams)
         let ai_brain: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::SuperAiBrain).unwrap();
         let prevention_weight = ai_brain.get(Symbol::new(&env, "prevention_layer")).unwrap_or(30);
         
         if prevention_weight > 20 {
             // Block activity
-            let mut crime_log: Vec<Symbol> = env.storage().persistent().get(&DataKey::CrimePreventionLog).unwrap();
-            crime_log.push_back(suspected_activity);
-            env.storage().persistent().set(&DataKey::CrimePreventionLog, &crime_log);
+            Self::log_prevention(&env, suspected_activity, entity, amount, reason);
+        }
+    }
+    
+    fn log_prevention(env: &Env, activity: Symbol, entity: Symbol, amount: u64, reason: Symbol) {
+        let mut crime_log: Vec<PreventionRecord> = env.storage().persistent().get(&DataKey::CrimePreventionLog).unwrap();
+        crime_log.push_back(PreventionRecord { activity, entity, amount, timestamp: env.ledger().timestamp(), reason });
+        let crime_log = retention::retain(env, &Self::get_retention(env.clone()), crime_log, |record| record.timestamp);
+        env.storage().persistent().set(&DataKey::CrimePreventionLog, &crime_log);
+        retention::bump_total(env, &DataKey::PreventionTotal);
+    }
+    
+    // Replace the log retention policy (governance only); applied from the next append
+    pub fn set_retention(env: Env, caller: Address, policy: RetentionPolicy) {
+        Self::require_governance(&env, &caller);
+        assert!(policy.max_entries > 0, "retention must keep at least one entry");
+        env.storage().persistent().set(&DataKey::Retention, &policy);
+    }
+    
+    pub fn get_retention(env: Env) -> RetentionPolicy {
+        env.storage().persistent().get(&DataKey::Retention).unwrap_or(DEFAULT_RETENTION)
+    }
+    
+    // Trades and prevention records logged since init, unaffected by pruning: (trades, preventions)
+    pub fn get_log_totals(env: Env) -> (u64, u64) {
+        (
+            env.storage().persistent().get(&DataKey::TradeTotal).unwrap_or(0),
+            env.storage().persistent().get(&DataKey::PreventionTotal).unwrap_or(0),
+        )
+    }
+    
+    // Page through the prevention log oldest first; the cursor is None once the log is exhausted
+    pub fn get_prevention_log(env: Env, cursor: u32, limit: u32) -> (Vec<PreventionRecord>, Option<u32>) {
+        let crime_log: Vec<PreventionRecord> = env.storage().persistent().get(&DataKey::CrimePreventionLog).unwrap();
+        let end = cursor.saturating_add(limit).min(crime_log.len());
+        let mut page = Vec::new(&env);
+        for i in cursor..end {
+            page.push_back(crime_log.get(i).unwrap());
         }
+        let next = if end < crime_log.len() { Some(end) } else { None };
+        (page, next)
     }
     
     // Get planetary entities
@@ -1,11 +1,160 @@
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, Address, Env, Symbol, TryFromVal};
+use interplanetary_economy::{InterplanetaryEconomyContract, InterplanetaryEconomyContractClient, PreventionRecord, RetentionPolicy, TradeRejectedEvent};
+
+fn setup<'a>(env: &'a Env, max_trade_amount: u64) -> (Address, InterplanetaryEconomyContractClient<'a>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, InterplanetaryEconomyContract);
+    let client = InterplanetaryEconomyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.init(&admin, &max_trade_amount, &80u32);
+    client.register_entity(&Symbol::new(env, "Earth"), &5_000_000);
+    client.register_entity(&Symbol::new(env, "Mars_Base"), &0);
+    (admin, client)
+}
+
+fn pi_balance(env: &Env, client: &InterplanetaryEconomyContractClient, planet: &str) -> u64 {
+    client.get_entities().get(Symbol::new(env, planet)).unwrap().resources.get(Symbol::new(env, "pi_coin")).unwrap()
+}
+
 #[test]
 fn test_register_entity() {
     let env = Env::default();
     let contract_id = env.register_contract(None, InterplanetaryEconomyContract);
     let client = InterplanetaryEconomyContractClient::new(&env, &contract_id);
     
-    client.init(&admin);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.init(&admin, &1_000_000, &80u32);
     client.register_entity(&Symbol::new(&env, "Earth"), &1000000);
     let entities = client.get_entities();
     assert!(entities.contains_key(Symbol::new(&env, "Earth")));
 }
+
+#[test]
+fn test_large_compliant_trade_allowed_under_raised_limit() {
+    let env = Env::default();
+    let (_, client) = setup(&env, 1_000_000);
+    
+    // Over the initial limit
+    let trade = client.initiate_trade(&Symbol::new(&env, "Earth"), &Symbol::new(&env, "Mars_Base"), &2_000_000);
+    assert!(!trade.approved);
+    
+    // Governance raises it
+    client.set_trade_config(&3_000_000, &80u32);
+    let trade = client.initiate_trade(&Symbol::new(&env, "Earth"), &Symbol::new(&env, "Mars_Base"), &2_000_000);
+    assert!(trade.approved);
+    assert_eq!(pi_balance(&env, &client, "Earth"), 3_000_000);
+    assert_eq!(pi_balance(&env, &client, "Mars_Base"), 2_000_000);
+}
+
+#[test]
+fn test_low_compliance_trade_rejected_with_reason() {
+    let env = Env::default();
+    let (admin, client) = setup(&env, 1_000_000);
+    client.update_compliance_score(&admin, &Symbol::new(&env, "Earth"), &40);
+    
+    let trade = client.initiate_trade(&Symbol::new(&env, "Earth"), &Symbol::new(&env, "Mars_Base"), &1_000);
+    assert!(!trade.approved);
+    assert_eq!(pi_balance(&env, &client, "Earth"), 5_000_000);
+    
+    let (_, _, data) = env.events().all().last().unwrap();
+    let rejected = TradeRejectedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(rejected.reason, Symbol::new(&env, "low_compliance"));
+    assert_eq!(rejected.amount_pi, 1_000);
+}
+
+#[test]
+fn test_prevented_trade_recorded_in_prevention_log() {
+    let env = Env::default();
+    let (admin, client) = setup(&env, 1_000_000);
+    env.ledger().with_mut(|li| li.timestamp = 42);
+    
+    client.initiate_trade(&Symbol::new(&env, "Earth"), &Symbol::new(&env, "Mars_Base"), &2_000_000);
+    client.prevent_crime(&admin, &Symbol::new(&env, "gambling"), &Symbol::new(&env, "Mars_Base"), &0, &Symbol::new(&env, "ai_flagged"));
+    
+    let (page, next) = client.get_prevention_log(&0, &1);
+    assert_eq!(next, Some(1));
+    assert_eq!(page.get(0).unwrap(), PreventionRecord {
+        activity: Symbol::new(&env, "trade_manipulation_prevented"),
+        entity: Symbol::new(&env, "Earth"),
+        amount: 2_000_000,
+        timestamp: 42,
+        reason: Symbol::new(&env, "over_limit"),
+    });
+    
+    let (page, next) = client.get_prevention_log(&1, &10);
+    assert_eq!(next, None);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().activity, Symbol::new(&env, "gambling"));
+}
+
+#[test]
+fn test_lowered_compliance_score_denies_allocation() {
+    let env = Env::default();
+    let (admin, client) = setup(&env, 1_000_000);
+    let earth = Symbol::new(&env, "Earth");
+    assert_eq!(client.allocate_resources(&earth, &1_000), 500);
+    
+    client.update_compliance_score(&admin, &earth, &80);
+    assert_eq!(client.get_entities().get(earth.clone()).unwrap().compliance_score, 80);
+    assert_eq!(client.allocate_resources(&earth, &1_000), 0);
+    assert_eq!(pi_balance(&env, &client, "Earth"), 4_999_500);
+    
+    client.update_compliance_score(&admin, &earth, &81);
+    assert_eq!(client.allocate_resources(&earth, &1_000), 500);
+}
+
+#[test]
+#[should_panic(expected = "caller is not governance")]
+fn test_prevent_crime_requires_governance() {
+    let env = Env::default();
+    let (_, client) = setup(&env, 1_000_000);
+    client.prevent_crime(&Address::generate(&env), &Symbol::new(&env, "gambling"), &Symbol::new(&env, "Earth"), &0, &Symbol::new(&env, "spoofed"));
+}
+
+#[test]
+#[should_panic(expected = "caller is not governance")]
+fn test_compliance_score_update_requires_governance() {
+    let env = Env::default();
+    let (_, client) = setup(&env, 1_000_000);
+    client.update_compliance_score(&Address::generate(&env), &Symbol::new(&env, "Earth"), &10);
+}
+
+#[test]
+fn test_deregister_entity_removes_planet_and_its_trades() {
+    let env = Env::default();
+    let (admin, client) = setup(&env, 1_000_000);
+    client.register_entity(&Symbol::new(&env, "Luna"), &1_000);
+    client.initiate_trade(&Symbol::new(&env, "Earth"), &Symbol::new(&env, "Mars_Base"), &1_000);
+    client.initiate_trade(&Symbol::new(&env, "Earth"), &Symbol::new(&env, "Luna"), &1_000);
+    
+    client.deregister_entity(&admin, &Symbol::new(&env, "Mars_Base"));
+    assert!(!client.get_entities().contains_key(Symbol::new(&env, "Mars_Base")));
+    let trades = client.get_trade_log();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades.get(0).unwrap().to_planet, Symbol::new(&env, "Luna"));
+}
+
+#[test]
+fn test_trade_log_pruned_past_retention_keeps_total() {
+    let env = Env::default();
+    let (admin, client) = setup(&env, 1_000_000);
+    client.set_retention(&admin, &RetentionPolicy { max_entries: 3, max_age: 3_600 });
+    let (earth, mars) = (Symbol::new(&env, "Earth"), Symbol::new(&env, "Mars_Base"));
+    
+    for amount in 1..=5u64 {
+        client.initiate_trade(&earth, &mars, &amount);
+    }
+    let trades = client.get_trade_log();
+    assert_eq!(trades.len(), 3);
+    assert_eq!(trades.get(0).unwrap().amount_pi, 3);
+    assert_eq!(client.get_log_totals(), (5, 0));
+    
+    // Entries older than max_age go on the next append
+    env.ledger().with_mut(|l| l.timestamp += 3_601);
+    client.initiate_trade(&earth, &mars, &6);
+    let trades = client.get_trade_log();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades.get(0).unwrap().amount_pi, 6);
+    assert_eq!(client.get_log_totals(), (6, 0));
+}
@@ -1,7 +1,9 @@
 // contracts/monitoring/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contractimpl, contracttype, log, Env, Address, Symbol, Vec, Map, BytesN, IntoVal, Val};
+use pi_coin_contract::retention::{self, DEFAULT_RETENTION};
+pub use pi_coin_contract::retention::RetentionPolicy;
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
 use sha3::{Digest, Sha3_512};
 
@@ -22,6 +24,41 @@ pub struct Metric {
     pub timestamp: u64,
 }
 
+// Response taken for an alert
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AlertAction {
+    None,
+    Notify,  // Event only
+    Pause,   // Set the paused flag and trip the pause registry's global flag
+    Enforce, // Run an enforcement scan
+}
+
+// Severities min_severity..=max_severity route to action
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeverityRoute {
+    pub min_severity: u32,
+    pub max_severity: u32,
+    pub action: AlertAction,
+}
+
+// Moving average of one metric's recent samples
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetricStats {
+    pub average: u64,
+    pub samples: u32, // Capped at RATE_WINDOW; older samples decay out of the average
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AlertEvent {
+    pub message: Symbol,
+    pub severity: u32,
+    pub action: AlertAction,
+}
+
 #[contracttype]
 pub enum DataKey {
     MetricsLog,     // Vec of metrics
@@ -29,8 +66,23 @@ pub enum DataKey {
     AiAnomalyModel, // AI for anomaly detection
     QuantumKey,
     HealthStatus,   // Overall ecosystem health
+    Admin,
+    SeverityRoutes, // Vec<SeverityRoute>; first matching route wins
+    Paused,         // bool set by AlertAction::Pause, cleared by unpause
+    PauseRegistry,  // Address of the PauseRegistry a Pause alert trips
+    MetricStats(Symbol), // MetricStats per metric name
+    Retention,      // RetentionPolicy; DEFAULT_RETENTION when unset
+    MetricsTotal,   // u64 metrics ever logged, including pruned ones
+    AlertsTotal,    // u64 alerts ever raised, including pruned ones
+    Reporters,      // Vec<Address> allowed to log metrics besides the admin
+    EnforcementContract, // Address whose autonomous_scan an Enforce alert runs
 }
 
+const RATE_WINDOW: u32 = 10; // Samples in the moving average
+const MIN_RATE_SAMPLES: u32 = 3; // Baseline needed before rate changes are judged
+const DEFAULT_RATE_THRESHOLD_PCT: u32 = 200; // Flag a sample 200% above or below the average
+const RATE_ALERT_SEVERITY: u32 = 6;
+
 #[contract]
 pub struct MonitoringContract;
 
@@ -39,6 +91,15 @@ impl MonitoringContract {
     // Initialize with hyper-tech monitoring
     pub fn init(env: Env, admin: Address) {
         admin.require_auth();
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        
+        // Default routing: low severities ignored, mid notify, critical enforce
+        let routes = Vec::from_array(&env, [
+            SeverityRoute { min_severity: 1, max_severity: 3, action: AlertAction::None },
+            SeverityRoute { min_severity: 4, max_severity: 7, action: AlertAction::Notify },
+            SeverityRoute { min_severity: 8, max_severity: 10, action: AlertAction::Enforce },
+        ]);
+        env.storage().persistent().set(&DataKey::SeverityRoutes, &routes);
         
         let metrics_log = Vec::new(&env);
         env.storage().persistent().set(&DataKey::MetricsLog, &metrics_log);
@@ -50,6 +111,7 @@ impl MonitoringContract {
         let ai_model = Map::new(&env);
         ai_model.set(Symbol::new(&env, "volatility_threshold"), 10u32);
         ai_model.set(Symbol::new(&env, "transaction_threshold"), 1000u32);
+        ai_model.set(Symbol::new(&env, "rate_threshold_pct"), DEFAULT_RATE_THRESHOLD_PCT);
         env.storage().persistent().set(&DataKey::AiAnomalyModel, &ai_model);
         
         env.storage().persistent().set(&DataKey::HealthStatus, &Symbol::new(&env, "healthy"));
@@ -61,17 +123,26 @@ impl MonitoringContract {
         env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
     }
     
-    // Log metric
-    pub fn log_metric(env: Env, name: Symbol, value: u64) {
+    // Log metric; `reporter` must be the admin or an allowed reporter, since metrics can raise alerts
+    pub fn log_metric(env: Env, reporter: Address, name: Symbol, value: u64) {
+        reporter.require_auth();
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        assert!(reporter == admin || Self::is_reporter(env.clone(), reporter), "not a metric reporter");
+        
         let metric = Metric {
-            name,
+            name: name.clone(),
             value,
             timestamp: env.ledger().timestamp(),
         };
         
         let mut metrics_log: Vec<Metric> = env.storage().persistent().get(&DataKey::MetricsLog).unwrap();
         metrics_log.push_back(metric);
+        let metrics_log = retention::retain(&env, &Self::get_retention(env.clone()), metrics_log, |metric| metric.timestamp);
         env.storage().persistent().set(&DataKey::MetricsLog, &metrics_log);
+        retention::bump_total(&env, &DataKey::MetricsTotal);
+        
+        // Relative change against this metric's recent average, then fold the sample in
+        Self::check_rate_of_change(&env, name, value);
         
         // Autonomous anomaly check
         Self::check_anomaly(env);
@@ -102,25 +173,170 @@ impl MonitoringContract {
         }
     }
     
+    // Flag a sample whose change from the moving average exceeds the configured percentage
+    fn check_rate_of_change(env: &Env, name: Symbol, value: u64) {
+        let stats_key = DataKey::MetricStats(name.clone());
+        let stats: MetricStats = env.storage().persistent().get(&stats_key).unwrap_or(MetricStats { average: 0, samples: 0 });
+        
+        if stats.samples >= MIN_RATE_SAMPLES && stats.average > 0 {
+            let ai_model: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::AiAnomalyModel).unwrap();
+            let rate_threshold = ai_model.get(Symbol::new(env, "rate_threshold_pct")).unwrap_or(DEFAULT_RATE_THRESHOLD_PCT);
+            let change_pct = value.abs_diff(stats.average).saturating_mul(100) / stats.average;
+            if change_pct > rate_threshold as u64 {
+                log!(env, "Metric {} moved {}% from its average {}", name, change_pct, stats.average);
+                Self::send_alert(env.clone(), Symbol::new(env, "rate_anomaly"), RATE_ALERT_SEVERITY);
+            }
+        }
+        
+        let samples = (stats.samples + 1).min(RATE_WINDOW);
+        let average = (stats.average * (samples as u64 - 1) + value) / samples as u64;
+        env.storage().persistent().set(&stats_key, &MetricStats { average, samples });
+    }
+    
+    // Percentage change from the moving average that counts as a rate anomaly (admin only)
+    pub fn set_rate_threshold(env: Env, threshold_pct: u32) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let mut ai_model: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::AiAnomalyModel).unwrap();
+        ai_model.set(Symbol::new(&env, "rate_threshold_pct"), threshold_pct);
+        env.storage().persistent().set(&DataKey::AiAnomalyModel, &ai_model);
+    }
+    
+    pub fn get_metric_stats(env: Env, name: Symbol) -> Option<MetricStats> {
+        env.storage().persistent().get(&DataKey::MetricStats(name))
+    }
+    
     // Send alert
     fn send_alert(env: Env, message: Symbol, severity: u32) {
         let alert_id = env.crypto().sha256(&env, &Bytes::from_slice(&env, &format!("{}-{}", message, severity).as_bytes()));
         let alert = Alert {
             id: alert_id,
-            message,
+            message: message.clone(),
             severity,
             timestamp: env.ledger().timestamp(),
         };
         
         let mut alerts_log: Vec<Alert> = env.storage().persistent().get(&DataKey::AlertsLog).unwrap();
         alerts_log.push_back(alert);
+        let alerts_log = retention::retain(&env, &Self::get_retention(env.clone()), alerts_log, |alert| alert.timestamp);
         env.storage().persistent().set(&DataKey::AlertsLog, &alerts_log);
+        retention::bump_total(&env, &DataKey::AlertsTotal);
         
-        // Autonomous response (e.g., halt operations if critical)
-        if severity > 7 {
-            let enforcement_contract = env.storage().persistent().get(&Symbol::new(&env, "enforcement_contract")).unwrap();
-            contractcall!(env, enforcement_contract, autonomous_scan, Vec::from_array(&env, [Symbol::new(&env, "system_check")]));
+        // Autonomous response from the routing table; Enforce without a linked contract only notifies
+        let enforcement: Option<Address> = env.storage().persistent().get(&DataKey::EnforcementContract);
+        let action = match Self::route_for(&env, severity) {
+            AlertAction::Enforce if enforcement.is_none() => AlertAction::Notify,
+            action => action,
+        };
+        match action {
+            AlertAction::None | AlertAction::Notify => {}
+            AlertAction::Pause => {
+                env.storage().persistent().set(&DataKey::Paused, &true);
+                if let Some(registry) = env.storage().persistent().get::<_, Address>(&DataKey::PauseRegistry) {
+                    let args: Vec<Val> = Vec::from_array(&env, [env.current_contract_address().into_val(&env)]);
+                    env.invoke_contract::<()>(&registry, &Symbol::new(&env, "monitor_pause"), args);
+                }
+            }
+            AlertAction::Enforce => {
+                if let Some(enforcement_contract) = enforcement {
+                    let entities = Vec::from_array(&env, [Symbol::new(&env, "system_check")]);
+                    let args: Vec<Val> = Vec::from_array(&env, [entities.into_val(&env)]);
+                    env.invoke_contract::<()>(&enforcement_contract, &Symbol::new(&env, "autonomous_scan"), args);
+                }
+            }
+        }
+        env.events().publish((Symbol::new(&env, "AlertRaised"), message.clone()), AlertEvent { message, severity, action });
+    }
+    
+    // Replace the log retention policy (admin only); applied from the next append
+    pub fn set_retention(env: Env, policy: RetentionPolicy) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        assert!(policy.max_entries > 0, "retention must keep at least one entry");
+        env.storage().persistent().set(&DataKey::Retention, &policy);
+    }
+    
+    pub fn get_retention(env: Env) -> RetentionPolicy {
+        env.storage().persistent().get(&DataKey::Retention).unwrap_or(DEFAULT_RETENTION)
+    }
+    
+    // Metrics and alerts recorded since init, unaffected by pruning: (metrics, alerts)
+    pub fn get_log_totals(env: Env) -> (u64, u64) {
+        (
+            env.storage().persistent().get(&DataKey::MetricsTotal).unwrap_or(0),
+            env.storage().persistent().get(&DataKey::AlertsTotal).unwrap_or(0),
+        )
+    }
+    
+    // Action of the first route covering the severity; None when no route matches
+    fn route_for(env: &Env, severity: u32) -> AlertAction {
+        let routes: Vec<SeverityRoute> = env.storage().persistent().get(&DataKey::SeverityRoutes).unwrap_or(Vec::new(env));
+        routes.iter()
+            .find(|route| severity >= route.min_severity && severity <= route.max_severity)
+            .map_or(AlertAction::None, |route| route.action)
+    }
+    
+    // Replace the severity routing table (admin only)
+    pub fn set_severity_routes(env: Env, routes: Vec<SeverityRoute>) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        for route in routes.iter() {
+            assert!(route.min_severity <= route.max_severity, "invalid severity range");
         }
+        env.storage().persistent().set(&DataKey::SeverityRoutes, &routes);
+    }
+    
+    pub fn get_severity_routes(env: Env) -> Vec<SeverityRoute> {
+        env.storage().persistent().get(&DataKey::SeverityRoutes).unwrap_or(Vec::new(&env))
+    }
+    
+    // Link the PauseRegistry that Pause-routed alerts trip (admin only).
+    // The registry's guardians must also register this contract with set_monitor.
+    pub fn set_pause_registry(env: Env, registry: Address) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::PauseRegistry, &registry);
+    }
+    
+    // Link the contract whose autonomous_scan Enforce-routed alerts run (admin only)
+    pub fn set_enforcement_contract(env: Env, enforcement_contract: Address) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::EnforcementContract, &enforcement_contract);
+    }
+    
+    // Allow or revoke a metric reporter (admin only)
+    pub fn set_reporter(env: Env, reporter: Address, allowed: bool) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let mut reporters: Vec<Address> = env.storage().persistent().get(&DataKey::Reporters).unwrap_or(Vec::new(&env));
+        match reporters.first_index_of(&reporter) {
+            Some(index) if !allowed => {
+                reporters.remove(index);
+            }
+            None if allowed => reporters.push_back(reporter),
+            _ => {}
+        }
+        env.storage().persistent().set(&DataKey::Reporters, &reporters);
+    }
+    
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        let reporters: Vec<Address> = env.storage().persistent().get(&DataKey::Reporters).unwrap_or(Vec::new(&env));
+        reporters.contains(&reporter)
+    }
+    
+    // Whether a Pause-routed alert has fired since the last unpause
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().persistent().get(&DataKey::Paused).unwrap_or(false)
+    }
+    
+    // Clear the paused flag once the alert is resolved (admin only).
+    // The registry's global flag is left to its guardians.
+    pub fn unpause(env: Env) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::Paused, &false);
+        env.events().publish((Symbol::new(&env, "MonitorUnpaused"),), admin);
     }
     
     // Get health status
@@ -138,8 +354,10 @@ impl MonitoringContract {
         env.storage().persistent().get(&DataKey::AlertsLog).unwrap()
     }
     
-    // Manual alert trigger
+    // Manual alert trigger (admin only); a Pause-routed severity trips the pause registry
     pub fn trigger_alert(env: Env, message: Symbol, severity: u32) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
         Self::send_alert(env, message, severity);
     }
 }
@@ -1,11 +1,206 @@
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::{Address as _, Events}, Address, Env, Symbol, TryFromVal, Vec};
+use monitoring::{AlertAction, AlertEvent, MonitoringContract, MonitoringContractClient, SeverityRoute};
+use pause_registry::{PauseRegistryContract, PauseRegistryContractClient};
+
+// Mock enforcement contract recording autonomous_scan invocations
+#[contract]
+pub struct MockEnforcementContract;
+
+#[contractimpl]
+impl MockEnforcementContract {
+    pub fn autonomous_scan(env: Env, entities: Vec<Symbol>) {
+        env.storage().instance().set(&symbol_short!("scanned"), &entities);
+    }
+}
+
 #[test]
 fn test_log_metric() {
     let env = Env::default();
     let contract_id = env.register_contract(None, MonitoringContract);
     let client = MonitoringContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
     
+    env.mock_all_auths();
     client.init(&admin);
-    client.log_metric(&Symbol::new(&env, "volatility"), &50);
+    client.log_metric(&admin, &Symbol::new(&env, "volatility"), &50);
     let status = client.get_health_status();
     assert_eq!(status, Symbol::new(&env, "healthy"));
 }
+
+#[test]
+fn test_critical_alert_invokes_enforcement_scan() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MonitoringContract);
+    let client = MonitoringContractClient::new(&env, &contract_id);
+    let enforcement_id = env.register_contract(None, MockEnforcementContract);
+    
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    client.set_enforcement_contract(&enforcement_id);
+    
+    client.trigger_alert(&Symbol::new(&env, "manual_halt"), &8);
+    
+    let scanned: Vec<Symbol> = env.as_contract(&enforcement_id, || {
+        env.storage().instance().get(&symbol_short!("scanned")).unwrap()
+    });
+    assert_eq!(scanned, Vec::from_array(&env, [Symbol::new(&env, "system_check")]));
+}
+
+#[test]
+fn test_critical_alert_without_enforcement_contract_notifies() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MonitoringContractClient::new(&env, &env.register_contract(None, MonitoringContract));
+    client.init(&Address::generate(&env));
+    
+    client.trigger_alert(&Symbol::new(&env, "manual_halt"), &8);
+    assert_eq!(last_alert_action(&env), AlertAction::Notify);
+    assert_eq!(client.get_alerts_log().len(), 1);
+}
+
+// Action recorded in the last AlertRaised event
+fn last_alert_action(env: &Env) -> AlertAction {
+    let (_, _, data) = env.events().all().last().unwrap();
+    AlertEvent::try_from_val(env, &data).unwrap().action
+}
+
+#[test]
+fn test_mid_severity_alert_only_notifies() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MonitoringContract);
+    let client = MonitoringContractClient::new(&env, &contract_id);
+    let enforcement_id = env.register_contract(None, MockEnforcementContract);
+    
+    client.init(&Address::generate(&env));
+    client.set_enforcement_contract(&enforcement_id);
+    
+    client.trigger_alert(&Symbol::new(&env, "fee_spike"), &5);
+    assert_eq!(last_alert_action(&env), AlertAction::Notify);
+    assert!(!client.is_paused());
+    let scanned: Option<Vec<Symbol>> = env.as_contract(&enforcement_id, || {
+        env.storage().instance().get(&symbol_short!("scanned"))
+    });
+    assert!(scanned.is_none());
+}
+
+#[test]
+fn test_high_severity_alert_uses_configured_action() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MonitoringContract);
+    let client = MonitoringContractClient::new(&env, &contract_id);
+    
+    client.init(&Address::generate(&env));
+    client.set_severity_routes(&Vec::from_array(&env, [
+        SeverityRoute { min_severity: 1, max_severity: 7, action: AlertAction::Notify },
+        SeverityRoute { min_severity: 8, max_severity: 10, action: AlertAction::Pause },
+    ]));
+    
+    client.trigger_alert(&Symbol::new(&env, "oracle_split"), &9);
+    assert_eq!(last_alert_action(&env), AlertAction::Pause);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_pause_alert_trips_registry_until_cleared() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MonitoringContract);
+    let client = MonitoringContractClient::new(&env, &contract_id);
+    let registry = PauseRegistryContractClient::new(&env, &env.register_contract(None, PauseRegistryContract));
+    let guardians = Vec::from_array(&env, [Address::generate(&env)]);
+    registry.init(&guardians, &1u32);
+    registry.set_monitor(&guardians, &Some(contract_id.clone()));
+    
+    client.init(&Address::generate(&env));
+    client.set_pause_registry(&registry.address);
+    client.set_severity_routes(&Vec::from_array(&env, [
+        SeverityRoute { min_severity: 8, max_severity: 10, action: AlertAction::Pause },
+    ]));
+    
+    // The alert halts every module that consults the registry
+    client.trigger_alert(&Symbol::new(&env, "oracle_split"), &9);
+    assert!(client.is_paused());
+    assert!(registry.is_paused(&Symbol::new(&env, "pi_coin")));
+    
+    // The admin clears the monitor's flag; the guardians resume the ecosystem
+    client.unpause();
+    assert!(!client.is_paused());
+    assert!(registry.is_globally_paused());
+    registry.set_global_pause(&guardians, &false);
+    assert!(!registry.is_paused(&Symbol::new(&env, "pi_coin")));
+}
+
+#[test]
+fn test_unpause_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = MonitoringContractClient::new(&env, &env.register_contract(None, MonitoringContract));
+    client.init(&Address::generate(&env));
+    client.set_severity_routes(&Vec::from_array(&env, [
+        SeverityRoute { min_severity: 8, max_severity: 10, action: AlertAction::Pause },
+    ]));
+    client.trigger_alert(&Symbol::new(&env, "oracle_split"), &9);
+    
+    // No authorization from the admin: the flag stays set
+    env.set_auths(&[]);
+    assert!(client.try_unpause().is_err());
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_unauthorized_alert_does_not_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MonitoringContract);
+    let client = MonitoringContractClient::new(&env, &contract_id);
+    let registry = PauseRegistryContractClient::new(&env, &env.register_contract(None, PauseRegistryContract));
+    let guardians = Vec::from_array(&env, [Address::generate(&env)]);
+    registry.init(&guardians, &1u32);
+    registry.set_monitor(&guardians, &Some(contract_id.clone()));
+    
+    client.init(&Address::generate(&env));
+    client.set_pause_registry(&registry.address);
+    client.set_severity_routes(&Vec::from_array(&env, [
+        SeverityRoute { min_severity: 1, max_severity: 10, action: AlertAction::Pause },
+    ]));
+    
+    // Without the admin's authorization neither path reaches the Pause route
+    env.set_auths(&[]);
+    assert!(client.try_trigger_alert(&Symbol::new(&env, "oracle_split"), &9).is_err());
+    assert!(client.try_log_metric(&Address::generate(&env), &Symbol::new(&env, "volatility"), &50).is_err());
+    assert!(!client.is_paused());
+    assert!(!registry.is_globally_paused());
+    
+    // A caller that signs but is not an allowed reporter is still rejected
+    env.mock_all_auths();
+    assert!(client.try_log_metric(&Address::generate(&env), &Symbol::new(&env, "volatility"), &50).is_err());
+    assert!(client.get_metrics_log().is_empty());
+}
+
+#[test]
+fn test_sharp_spike_under_absolute_threshold_trips_rate_detector() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MonitoringContract);
+    let client = MonitoringContractClient::new(&env, &contract_id);
+    client.init(&Address::generate(&env));
+    let reporter = Address::generate(&env);
+    client.set_reporter(&reporter, &true);
+    let volatility = Symbol::new(&env, "volatility");
+    
+    for _ in 0..4 {
+        client.log_metric(&reporter, &volatility, &2);
+    }
+    assert!(client.get_alerts_log().is_empty());
+    
+    // 2 -> 8 quadruples volatility but stays under the absolute threshold of 10
+    client.log_metric(&reporter, &volatility, &8);
+    let alerts = client.get_alerts_log();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts.get(0).unwrap().message, Symbol::new(&env, "rate_anomaly"));
+    assert_eq!(client.get_health_status(), Symbol::new(&env, "healthy"));
+    assert_eq!(client.get_metric_stats(&volatility).unwrap().samples, 5);
+}
@@ -0,0 +1,140 @@
+// contracts/pause_registry/src/lib.rs
+// Ecosystem-wide emergency pause. Contracts look up is_paused(module) before state changes;
+// a module is paused when the global flag or its own flag is set. Flags change only with
+// the authorization of at least `threshold` distinct guardians, except that the monitor the
+// guardians register may trip the global flag on its own; clearing it still needs the guardians.
+#![no_std]
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol, Vec, log};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PauseError {
+    AlreadyInitialized = 1,
+    InvalidInput = 2,
+    NotGuardian = 3,
+    ThresholdNotMet = 4,
+    NotMonitor = 5,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Guardians,     // Vec<Address>
+    Threshold,     // u32 distinct guardians needed per change
+    GlobalPaused,  // bool; pauses every module
+    ModulePaused,  // Map<Symbol, bool> per-module flags, e.g. "pi_coin", "transaction", "stability"
+    Monitor,       // Address allowed to call monitor_pause
+}
+
+// Data of the PauseChanged event; module is None for the global flag, guardians empty when the monitor tripped it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseChangedEvent {
+    pub module: Option<Symbol>,
+    pub paused: bool,
+    pub guardians: Vec<Address>,
+}
+
+#[contract]
+pub struct PauseRegistryContract;
+
+#[contractimpl]
+impl PauseRegistryContract {
+    pub fn init(env: Env, guardians: Vec<Address>, threshold: u32) -> Result<(), PauseError> {
+        if env.storage().persistent().has(&DataKey::Guardians) {
+            return Err(PauseError::AlreadyInitialized);
+        }
+        if threshold == 0 || guardians.len() < threshold {
+            return Err(PauseError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::Guardians, &guardians);
+        env.storage().persistent().set(&DataKey::Threshold, &threshold);
+        env.storage().persistent().set(&DataKey::GlobalPaused, &false);
+        env.storage().persistent().set(&DataKey::ModulePaused, &Map::<Symbol, bool>::new(&env));
+        Ok(())
+    }
+    
+    // Set or clear the global flag; `signers` must be threshold distinct guardians, each authorizing
+    pub fn set_global_pause(env: Env, signers: Vec<Address>, paused: bool) -> Result<(), PauseError> {
+        Self::require_guardians(&env, &signers)?;
+        env.storage().persistent().set(&DataKey::GlobalPaused, &paused);
+        env.events().publish((Symbol::new(&env, "PauseChanged"),), PauseChangedEvent { module: None, paused, guardians: signers });
+        log!(&env, "Global pause set to {}", paused);
+        Ok(())
+    }
+    
+    // Set or clear one module's flag, with the same guardian quorum as the global flag
+    pub fn set_module_pause(env: Env, signers: Vec<Address>, module: Symbol, paused: bool) -> Result<(), PauseError> {
+        Self::require_guardians(&env, &signers)?;
+        let mut modules: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::ModulePaused).unwrap_or(Map::new(&env));
+        modules.set(module.clone(), paused);
+        env.storage().persistent().set(&DataKey::ModulePaused, &modules);
+        env.events().publish((Symbol::new(&env, "PauseChanged"), module.clone()), PauseChangedEvent { module: Some(module.clone()), paused, guardians: signers });
+        log!(&env, "Pause for {} set to {}", module, paused);
+        Ok(())
+    }
+    
+    // Register (or with None, remove) the monitoring contract allowed to call monitor_pause
+    pub fn set_monitor(env: Env, signers: Vec<Address>, monitor: Option<Address>) -> Result<(), PauseError> {
+        Self::require_guardians(&env, &signers)?;
+        match monitor {
+            Some(monitor) => env.storage().persistent().set(&DataKey::Monitor, &monitor),
+            None => env.storage().persistent().remove(&DataKey::Monitor),
+        }
+        Ok(())
+    }
+    
+    // Set the global flag on the registered monitor's authority alone; only guardians can clear it
+    pub fn monitor_pause(env: Env, monitor: Address) -> Result<(), PauseError> {
+        let registered: Option<Address> = env.storage().persistent().get(&DataKey::Monitor);
+        if registered != Some(monitor.clone()) {
+            return Err(PauseError::NotMonitor);
+        }
+        monitor.require_auth();
+        env.storage().persistent().set(&DataKey::GlobalPaused, &true);
+        env.events().publish((Symbol::new(&env, "PauseChanged"),), PauseChangedEvent { module: None, paused: true, guardians: Vec::new(&env) });
+        log!(&env, "Global pause tripped by monitor {}", monitor);
+        Ok(())
+    }
+    
+    pub fn get_monitor(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Monitor)
+    }
+    
+    // Whether `module` must reject state changes
+    pub fn is_paused(env: Env, module: Symbol) -> bool {
+        if Self::is_globally_paused(env.clone()) {
+            return true;
+        }
+        let modules: Map<Symbol, bool> = env.storage().persistent().get(&DataKey::ModulePaused).unwrap_or(Map::new(&env));
+        modules.get(module).unwrap_or(false)
+    }
+    
+    pub fn is_globally_paused(env: Env) -> bool {
+        env.storage().persistent().get(&DataKey::GlobalPaused).unwrap_or(false)
+    }
+    
+    pub fn get_guardians(env: Env) -> Vec<Address> {
+        env.storage().persistent().get(&DataKey::Guardians).unwrap_or(Vec::new(&env))
+    }
+    
+    fn require_guardians(env: &Env, signers: &Vec<Address>) -> Result<(), PauseError> {
+        let guardians = Self::get_guardians(env.clone());
+        let threshold: u32 = env.storage().persistent().get(&DataKey::Threshold).unwrap_or(u32::MAX);
+        let mut seen = Vec::new(env);
+        for signer in signers.iter() {
+            if !guardians.contains(&signer) {
+                return Err(PauseError::NotGuardian);
+            }
+            if !seen.contains(&signer) {
+                signer.require_auth();
+                seen.push_back(signer);
+            }
+        }
+        if seen.len() < threshold {
+            return Err(PauseError::ThresholdNotMet);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol, Vec};
+use pause_registry::{PauseError, PauseRegistryContract, PauseRegistryContractClient};
+
+#[test]
+fn test_pause_needs_guardian_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = PauseRegistryContractClient::new(&env, &env.register_contract(None, PauseRegistryContract));
+    let guardians = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env), Address::generate(&env)]);
+    client.init(&guardians, &2u32);
+    let (first, second) = (guardians.get(0).unwrap(), guardians.get(1).unwrap());
+    let pi_coin = Symbol::new(&env, "pi_coin");
+    
+    // One guardian, even listed twice, is below the quorum
+    let alone = Vec::from_array(&env, [first.clone(), first.clone()]);
+    assert_eq!(client.try_set_global_pause(&alone, &true), Err(Ok(PauseError::ThresholdNotMet)));
+    let outsider = Vec::from_array(&env, [first.clone(), Address::generate(&env)]);
+    assert_eq!(client.try_set_global_pause(&outsider, &true), Err(Ok(PauseError::NotGuardian)));
+    
+    let quorum = Vec::from_array(&env, [first, second]);
+    client.set_module_pause(&quorum, &Symbol::new(&env, "transaction"), &true);
+    assert!(!client.is_paused(&pi_coin));
+    assert!(client.is_paused(&Symbol::new(&env, "transaction")));
+    
+    client.set_global_pause(&quorum, &true);
+    assert!(client.is_paused(&pi_coin));
+    client.set_global_pause(&quorum, &false);
+    assert!(!client.is_paused(&pi_coin));
+}
+
+#[test]
+fn test_monitor_trips_but_cannot_clear_global_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = PauseRegistryContractClient::new(&env, &env.register_contract(None, PauseRegistryContract));
+    let guardians = Vec::from_array(&env, [Address::generate(&env)]);
+    client.init(&guardians, &1u32);
+    let monitor = Address::generate(&env);
+    let pi_coin = Symbol::new(&env, "pi_coin");
+    
+    assert_eq!(client.try_monitor_pause(&monitor), Err(Ok(PauseError::NotMonitor)));
+    client.set_monitor(&guardians, &Some(monitor.clone()));
+    assert_eq!(client.try_monitor_pause(&Address::generate(&env)), Err(Ok(PauseError::NotMonitor)));
+    client.monitor_pause(&monitor);
+    assert!(client.is_paused(&pi_coin));
+    
+    // Resuming is the guardians' call
+    client.set_global_pause(&guardians, &false);
+    assert!(!client.is_paused(&pi_coin));
+}
@@ -2,8 +2,9 @@
 // GodHead Nexus AI Simulation: A bounded, self-evolving neural network for predictions.
 // This is on-chain only—deterministic, gas-efficient, and evolves via contract calls.
 // Weights are stored in persistent storage; evolution is capped for safety.
+// This is the single prediction path: PiCoinContract::supreme_ai_predict delegates here.
 
-use soroban_sdk::{contracttype, Env, Vec, log, panic_with_error};
+use soroban_sdk::{contracttype, Env, Vec, log};
 use crate::DataKey; // Import from lib.rs
 
 #[contracttype]
@@ -27,33 +28,39 @@ impl AiSimulation {
             bias: 10,
         };
         let layers = Vec::from_array(env, [layer1, layer2]);
-        env.storage().persistent().set(&DataKey::NeuralWeights, &layers); // Reuse existing key or add new
+        env.storage().persistent().set(&DataKey::NeuralLayers, &layers);
         log!(env, "GodHead AI initialized with {} layers", layers.len());
     }
 
-    // Predict using feedforward (input: e.g., risk score; output: 0-100 prediction)
+    // Initialize AI as a single layer over the given weights (the Pi Coin default network)
+    pub fn init_single_layer(env: &Env, weights: Vec<i64>) {
+        let layers = Vec::from_array(env, [NeuralLayer { weights, bias: 0 }]);
+        env.storage().persistent().set(&DataKey::NeuralLayers, &layers);
+        log!(env, "GodHead AI initialized with a single layer");
+    }
+
+    // Predict using feedforward (input: e.g., risk score; output: 0-99 prediction).
+    // An uninitialized network abstains with 0.
     pub fn predict(env: &Env, input: i64) -> i64 {
-        let layers: Vec<NeuralLayer> = env.storage().persistent().get(&DataKey::NeuralWeights)
-            .unwrap_or(Vec::new(env));
+        let layers = Self::load_layers(env);
         if layers.is_empty() {
-            panic_with_error!(env, 1001); // Custom error: AI not initialized
+            return 0;
         }
 
         let mut activation = input;
         for layer in layers.iter() {
             let mut sum = layer.bias;
             for weight in layer.weights.iter() {
-                sum += weight * activation; // Simple dot product
+                sum = sum.saturating_add(weight.saturating_mul(activation)); // Simple dot product
             }
             activation = Self::relu(sum); // Activation function
         }
-        activation.clamp(0, 100) // Bounded output
+        activation % 100 // Bounded output; non-negative after ReLU
     }
 
     // Evolve AI: Adjust weights based on feedback (e.g., from governance votes)
     pub fn evolve(env: &Env, feedback: i64) { // feedback: +1 for good, -1 for bad
-        let mut layers: Vec<NeuralLayer> = env.storage().persistent().get(&DataKey::NeuralWeights)
-            .unwrap_or(Vec::new(env));
+        let mut layers = Self::load_layers(env);
         for i in 0..layers.len() {
             let mut layer = layers.get(i).unwrap();
             for j in 0..layer.weights.len() {
@@ -66,10 +73,29 @@ impl AiSimulation {
             layer.bias = layer.bias.clamp(-500, 500);
             layers.set(i, layer);
         }
-        env.storage().persistent().set(&DataKey::NeuralWeights, &layers);
+        env.storage().persistent().set(&DataKey::NeuralLayers, &layers);
         log!(env, "GodHead AI evolved with feedback {}", feedback);
     }
 
+    // Current layers. Deployments still holding the legacy flat Vec<u64> under NeuralWeights
+    // read as a single zero-bias layer, which yields the same pre-bound sum as the old model;
+    // the first evolve() persists them under NeuralLayers.
+    pub fn load_layers(env: &Env) -> Vec<NeuralLayer> {
+        if let Some(layers) = env.storage().persistent().get(&DataKey::NeuralLayers) {
+            return layers;
+        }
+        match env.storage().persistent().get::<_, Vec<u64>>(&DataKey::NeuralWeights) {
+            Some(legacy) => {
+                let mut weights = Vec::new(env);
+                for weight in legacy.iter() {
+                    weights.push_back(weight.min(i64::MAX as u64) as i64);
+                }
+                Vec::from_array(env, [NeuralLayer { weights, bias: 0 }])
+            }
+            None => Vec::new(env),
+        }
+    }
+
     // ReLU activation (simple, gas-efficient)
     fn relu(x: i64) -> i64 {
         if x > 0 { x } else { 0 }
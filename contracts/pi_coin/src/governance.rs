@@ -3,9 +3,7 @@
 // multi-sig security, and eternal immutability. Proposals are voted on by token holders,
 // with AI-assisted decision-making to ensure eternal stability and prevent failures.
 
-#![no_std]
-
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, Bytes, log, events, Error};
+use soroban_sdk::{contract, contractimpl, contracttype, xdr::ToXdr, Address, Env, IntoVal, Symbol, Vec, Map, Val, Bytes, BytesN, log, crypto, events, Error};
 
 // Import from lib.rs for shared types (assuming lib.rs is the main contract)
 use crate::PiCoinContract; // Adjust import as needed based on project structure
@@ -14,7 +12,7 @@ use crate::DataKey; // Assuming DataKey is shared
 #[contracttype]
 #[derive(Clone)]
 pub struct Proposal {
-    pub id: u64,
+    pub id: BytesN<32>, // sha256 of (proposer, description, nonce)
     pub proposer: Address,
     pub description: Bytes,
     pub votes_for: u64,
@@ -22,6 +20,28 @@ pub struct Proposal {
     pub executed: bool,
     pub ai_score: u64, // AI prediction for proposal success
     pub deadline: u64, // Timestamp for voting end
+    pub content_hash: BytesN<32>, // sha256 of the description, for duplicate detection
+}
+
+// Filter for list_proposals. Ended, unexecuted proposals are Passed when they meet the approval
+// rate and AI threshold that execute_proposal requires, Failed otherwise.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Failed,
+    Executed,
+}
+
+// Where vote weight comes from. Mirrors governance_voting's PowerSource, so every governance
+// contract weighs a voter the same way for the same source
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PowerSource {
+    Registry,              // VoterRegistry maintained with register_voter; unregistered voters have none
+    TokenBalance(Address), // balance(voter) on a token contract
+    Staked(Address),       // voting_power(voter) on a pi_staking contract
 }
 
 #[contracttype]
@@ -30,6 +50,12 @@ pub enum GovernanceDataKey {
     VoterRegistry,
     TotalVotes,
     AiGovernanceThreshold, // AI-determined threshold for execution
+    ProposalNonce,       // u64 monotonic counter mixed into proposal ids
+    ActiveContent(BytesN<32>), // Proposal id holding a content hash while it is active
+    OpenProposals,       // Vec<BytesN<32>> not yet executed, in creation order
+    ExecutedProposals,   // Vec<BytesN<32>> in execution order
+    PowerSource,         // PowerSource every vote is weighted by; Registry when unset
+    Voted(BytesN<32>, Address), // Set once a voter has voted on a proposal
 }
 
 #[contract]
@@ -42,7 +68,7 @@ impl GovernanceContract {
         // Require multi-sig from main contract
         PiCoinContract::require_multi_sig(&env)?;
         
-        env.storage().persistent().set(&GovernanceDataKey::Proposals, &Map::<u64, Proposal>::new(&env));
+        env.storage().persistent().set(&GovernanceDataKey::Proposals, &Map::<BytesN<32>, Proposal>::new(&env));
         env.storage().persistent().set(&GovernanceDataKey::VoterRegistry, &Map::<Address, u64>::new(&env)); // Address -> Voting Power
         env.storage().persistent().set(&GovernanceDataKey::TotalVotes, &0u64);
         env.storage().persistent().set(&GovernanceDataKey::AiGovernanceThreshold, &50u64); // AI threshold for auto-execution
@@ -52,19 +78,29 @@ impl GovernanceContract {
         Ok(())
     }
     
-    // Create a proposal with AI scoring
-    pub fn create_proposal(env: Env, proposer: Address, description: Bytes) -> Result<u64, u32> {
+    // Create a proposal with AI scoring. The id is derived from (proposer, description, nonce), so it
+    // does not depend on how many proposals are stored; identical content to an active proposal is rejected.
+    pub fn create_proposal(env: Env, proposer: Address, description: Bytes) -> Result<BytesN<32>, u32> {
         proposer.require_auth();
         
-        let mut proposals: Map<u64, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
-        let total_proposals = proposals.len() as u64;
-        let proposal_id = total_proposals + 1;
+        let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
+        let content_hash: BytesN<32> = crypto::sha256(&env, &description).into();
+        let content_key = GovernanceDataKey::ActiveContent(content_hash.clone());
+        if let Some(existing_id) = env.storage().persistent().get::<_, BytesN<32>>(&content_key) {
+            if proposals.get(existing_id).map_or(false, |existing| Self::is_active(&env, &existing)) {
+                return Err(3); // ERR_INVALID_INPUT: duplicate of an active proposal
+            }
+        }
+        
+        let nonce: u64 = env.storage().persistent().get(&GovernanceDataKey::ProposalNonce).unwrap_or(0);
+        env.storage().persistent().set(&GovernanceDataKey::ProposalNonce, &(nonce + 1));
+        let proposal_id: BytesN<32> = crypto::sha256(&env, &(proposer.clone(), description.clone(), nonce).to_xdr(&env)).into();
         
         // AI score for proposal viability
-        let ai_score = PiCoinContract::supreme_ai_predict(&env, proposal_id);
+        let ai_score = PiCoinContract::supreme_ai_predict(&env, nonce + 1);
         
         let proposal = Proposal {
-            id: proposal_id,
+            id: proposal_id.clone(),
             proposer: proposer.clone(),
             description,
             votes_for: 0,
@@ -72,31 +108,49 @@ impl GovernanceContract {
             executed: false,
             ai_score,
             deadline: env.ledger().timestamp() + 604800, // 1 week deadline
+            content_hash,
         };
         
-        proposals.set(proposal_id, proposal);
+        proposals.set(proposal_id.clone(), proposal);
         env.storage().persistent().set(&GovernanceDataKey::Proposals, &proposals);
+        env.storage().persistent().set(&content_key, &proposal_id);
+        let mut open: Vec<BytesN<32>> = env.storage().persistent().get(&GovernanceDataKey::OpenProposals).unwrap_or(Vec::new(&env));
+        open.push_back(proposal_id.clone());
+        env.storage().persistent().set(&GovernanceDataKey::OpenProposals, &open);
         
-        events::publish(&env, Symbol::new(&env, "GodHeadProposalCreated"), (proposer, proposal_id));
+        events::publish(&env, Symbol::new(&env, "GodHeadProposalCreated"), (proposer, proposal_id.clone()));
         log!(&env, "GodHead proposal {} created with AI score {}", proposal_id, ai_score);
         Ok(proposal_id)
     }
     
+    // Open for voting: not executed and before its deadline
+    fn is_active(env: &Env, proposal: &Proposal) -> bool {
+        !proposal.executed && env.ledger().timestamp() <= proposal.deadline
+    }
+    
     // Vote on a proposal with voting power and AI influence
-    pub fn vote(env: Env, voter: Address, proposal_id: u64, approve: bool) -> Result<(), u32> {
+    pub fn vote(env: Env, voter: Address, proposal_id: BytesN<32>, approve: bool) -> Result<(), u32> {
         voter.require_auth();
         
-        let mut proposals: Map<u64, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
-        let mut proposal = proposals.get(proposal_id).ok_or(4)?; // ERR_NOT_FOUND
+        let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
+        let mut proposal = proposals.get(proposal_id.clone()).ok_or(4)?; // ERR_NOT_FOUND
         
         // Check deadline
         if env.ledger().timestamp() > proposal.deadline {
             return Err(3); // ERR_INVALID_INPUT
         }
         
-        // Get voting power (e.g., based on balance from main contract)
-        let voter_registry: Map<Address, u64> = env.storage().persistent().get(&GovernanceDataKey::VoterRegistry).unwrap_or(Map::new(&env));
-        let voting_power = voter_registry.get(voter.clone()).unwrap_or(1); // Default 1 if not registered
+        let voted_key = GovernanceDataKey::Voted(proposal_id.clone(), voter.clone());
+        if env.storage().persistent().has(&voted_key) {
+            return Err(3); // ERR_INVALID_INPUT: one vote per voter
+        }
+        
+        // Voting power under the configured source; none means no vote
+        let voting_power = Self::voting_power(env.clone(), voter.clone());
+        if voting_power == 0 {
+            return Err(1); // ERR_UNAUTHORIZED
+        }
+        env.storage().persistent().set(&voted_key, &true);
         
         // AI influence on vote
         let ai_adjustment = if PiCoinContract::supreme_ai_predict(&env, voting_power) > 50 { 1 } else { 0 };
@@ -108,40 +162,34 @@ impl GovernanceContract {
             proposal.votes_against += effective_power;
         }
         
-        proposals.set(proposal_id, proposal);
+        proposals.set(proposal_id.clone(), proposal);
         env.storage().persistent().set(&GovernanceDataKey::Proposals, &proposals);
         
         // Evolve AI based on vote
         PiCoinContract::evolve_supreme_ai(&env);
         
-        events::publish(&env, Symbol::new(&env, "GodHeadVoteCast"), (voter, proposal_id, approve));
+        events::publish(&env, Symbol::new(&env, "GodHeadVoteCast"), (voter, proposal_id.clone(), approve));
         log!(&env, "GodHead vote cast on proposal {} with power {}", proposal_id, effective_power);
         Ok(())
     }
     
     // Execute proposal if passed, with AI threshold
-    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), u32> {
-        let mut proposals: Map<u64, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
-        let mut proposal = proposals.get(proposal_id).ok_or(4)?; // ERR_NOT_FOUND
+    pub fn execute_proposal(env: Env, proposal_id: BytesN<32>) -> Result<(), u32> {
+        let proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
+        let proposal = proposals.get(proposal_id.clone()).ok_or(4)?; // ERR_NOT_FOUND
         
         if proposal.executed {
             return Err(3); // ERR_INVALID_INPUT
         }
         
-        let ai_threshold: u64 = env.storage().persistent().get(&GovernanceDataKey::AiGovernanceThreshold).unwrap_or(50);
-        let total_votes = proposal.votes_for + proposal.votes_against;
-        let approval_rate = if total_votes > 0 { (proposal.votes_for * 100) / total_votes } else { 0 };
-        
         // AI-assisted execution: Must pass vote and AI score
-        if approval_rate >= 50 && proposal.ai_score >= ai_threshold {
-            proposal.executed = true;
-            proposals.set(proposal_id, proposal);
-            env.storage().persistent().set(&GovernanceDataKey::Proposals, &proposals);
+        if Self::meets_approval(&env, &proposal) {
+            Self::mark_executed(&env, &proposal_id);
             
             // Placeholder for execution logic (e.g., update main contract parameters)
             // Integrate with lib.rs functions as needed
             
-            events::publish(&env, Symbol::new(&env, "GodHeadProposalExecuted"), proposal_id);
+            events::publish(&env, Symbol::new(&env, "GodHeadProposalExecuted"), proposal_id.clone());
             log!(&env, "GodHead proposal {} executed eternally with AI approval", proposal_id);
             Ok(())
         } else {
@@ -149,7 +197,100 @@ impl GovernanceContract {
         }
     }
     
-    // Register voter with voting power
+    // Vote and AI score requirements for execution
+    fn meets_approval(env: &Env, proposal: &Proposal) -> bool {
+        let ai_threshold: u64 = env.storage().persistent().get(&GovernanceDataKey::AiGovernanceThreshold).unwrap_or(50);
+        let total_votes = proposal.votes_for + proposal.votes_against;
+        let approval_rate = if total_votes > 0 { (proposal.votes_for * 100) / total_votes } else { 0 };
+        approval_rate >= 50 && proposal.ai_score >= ai_threshold
+    }
+    
+    pub(crate) fn status_of(env: &Env, proposal: &Proposal) -> ProposalStatus {
+        if proposal.executed {
+            ProposalStatus::Executed
+        } else if Self::is_active(env, proposal) {
+            ProposalStatus::Active
+        } else if Self::meets_approval(env, proposal) {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Failed
+        }
+    }
+    
+    // Flag a proposal executed and move it from the open index to the executed index
+    pub(crate) fn mark_executed(env: &Env, proposal_id: &BytesN<32>) {
+        let mut proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(env));
+        if let Some(mut proposal) = proposals.get(proposal_id.clone()) {
+            if !proposal.executed {
+                proposal.executed = true;
+                env.storage().persistent().remove(&GovernanceDataKey::ActiveContent(proposal.content_hash.clone()));
+                proposals.set(proposal_id.clone(), proposal);
+                env.storage().persistent().set(&GovernanceDataKey::Proposals, &proposals);
+            }
+        }
+        let mut open: Vec<BytesN<32>> = env.storage().persistent().get(&GovernanceDataKey::OpenProposals).unwrap_or(Vec::new(env));
+        if let Some(position) = open.first_index_of(proposal_id) {
+            open.remove(position);
+        }
+        env.storage().persistent().set(&GovernanceDataKey::OpenProposals, &open);
+        let mut executed: Vec<BytesN<32>> = env.storage().persistent().get(&GovernanceDataKey::ExecutedProposals).unwrap_or(Vec::new(env));
+        executed.push_back(proposal_id.clone());
+        env.storage().persistent().set(&GovernanceDataKey::ExecutedProposals, &executed);
+    }
+    
+    // Page through proposals with the given status, oldest first. The cursor indexes the open
+    // list, or the executed list for Executed; `next` resumes the scan after the last entry read.
+    pub fn list_proposals(env: Env, status: ProposalStatus, cursor: u32, limit: u32) -> (Vec<Proposal>, Option<u32>) {
+        let proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
+        let index_key = if status == ProposalStatus::Executed { GovernanceDataKey::ExecutedProposals } else { GovernanceDataKey::OpenProposals };
+        let ids: Vec<BytesN<32>> = env.storage().persistent().get(&index_key).unwrap_or(Vec::new(&env));
+        
+        let mut page = Vec::new(&env);
+        let mut position = cursor;
+        while position < ids.len() && page.len() < limit {
+            let id = ids.get(position).unwrap();
+            position += 1;
+            if let Some(proposal) = proposals.get(id) {
+                if Self::status_of(&env, &proposal) == status {
+                    page.push_back(proposal);
+                }
+            }
+        }
+        let next = if position < ids.len() { Some(position) } else { None };
+        (page, next)
+    }
+    
+    // Vote weight of `voter` under the configured PowerSource
+    pub fn voting_power(env: Env, voter: Address) -> u64 {
+        match Self::get_power_source(env.clone()) {
+            PowerSource::Registry => {
+                let voter_registry: Map<Address, u64> = env.storage().persistent().get(&GovernanceDataKey::VoterRegistry).unwrap_or(Map::new(&env));
+                voter_registry.get(voter).unwrap_or(0)
+            }
+            PowerSource::TokenBalance(token) => {
+                let args: Vec<Val> = Vec::from_array(&env, [voter.into_val(&env)]);
+                let balance = env.invoke_contract::<i128>(&token, &Symbol::new(&env, "balance"), args);
+                balance.clamp(0, u64::MAX as i128) as u64
+            }
+            PowerSource::Staked(staking) => {
+                let args: Vec<Val> = Vec::from_array(&env, [voter.into_val(&env)]);
+                env.invoke_contract::<u64>(&staking, &Symbol::new(&env, "voting_power"), args)
+            }
+        }
+    }
+    
+    pub fn get_power_source(env: Env) -> PowerSource {
+        env.storage().persistent().get(&GovernanceDataKey::PowerSource).unwrap_or(PowerSource::Registry)
+    }
+    
+    // Swap where vote weight comes from; votes already cast keep their weight
+    pub fn set_power_source(env: Env, power_source: PowerSource) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        env.storage().persistent().set(&GovernanceDataKey::PowerSource, &power_source);
+        Ok(())
+    }
+    
+    // Register voter with voting power, counted under PowerSource::Registry
     pub fn register_voter(env: Env, voter: Address, voting_power: u64) -> Result<(), u32> {
         PiCoinContract::require_multi_sig(&env)?;
         
@@ -163,8 +304,8 @@ impl GovernanceContract {
     }
     
     // Get proposal details
-    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, u32> {
-        let proposals: Map<u64, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
+    pub fn get_proposal(env: Env, proposal_id: BytesN<32>) -> Result<Proposal, u32> {
+        let proposals: Map<BytesN<32>, Proposal> = env.storage().persistent().get(&GovernanceDataKey::Proposals).unwrap_or(Map::new(&env));
         proposals.get(proposal_id).ok_or(4) // ERR_NOT_FOUND
     }
 }
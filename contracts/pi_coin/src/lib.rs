@@ -9,43 +9,450 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, Bytes, BytesN, log, events, crypto, panic_with_error, Error, Val};
+pub mod ai_simulation;
+pub mod governance;
+pub mod oracle;
+#[cfg(feature = "neural")]
+pub mod oracle_integration;
+pub mod prng;
+pub mod retention;
+pub mod stability;
 
-// Custom errors for robustness and safety
-const ERR_UNAUTHORIZED: u32 = 1;
-const ERR_INSUFFICIENT_BALANCE: u32 = 2;
-const ERR_INVALID_INPUT: u32 = 3;
-const ERR_NOT_FOUND: u32 = 4;
-const ERR_SUPPLY_EXCEEDED: u32 = 5;
-const ERR_COMPLIANCE_FAILED: u32 = 6;
-const ERR_PEG_BREACHED: u32 = 7;
-const ERR_AI_REJECTION: u32 = 8; // For AI-based rejections
-const ERR_ENTANGLEMENT_FAILED: u32 = 9; // For quantum entanglement issues
-const ERR_CALL_FAILED: u32 = 10; // For env.call failures
+use crate::ai_simulation::AiSimulation;
+use crate::governance::{GovernanceContract, ProposalStatus};
+use crate::retention::{self, RetentionPolicy, DEFAULT_RETENTION};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, xdr::ToXdr, Address, Env, Symbol, Vec, Map, Bytes, BytesN, log, crypto, panic_with_error, Error, IntoVal, TryFromVal, Val};
+
+// Custom errors for robustness and safety (numeric values are stable for existing integrations)
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PiCoinError {
+    Unauthorized = 1,
+    InsufficientBalance = 2,
+    InvalidInput = 3,
+    NotFound = 4,
+    SupplyExceeded = 5,
+    ComplianceFailed = 6,
+    PegBreached = 7,
+    AiRejection = 8, // For AI-based rejections
+    EntanglementFailed = 9, // For quantum entanglement issues
+    CallFailed = 10, // For env.call failures
+    Reentrant = 11, // Re-entry while an external call is in flight
+    InsufficientFeeds = 12, // Too few fresh oracle feeds for a confident peg check
+    CircuitBreakerTripped = 13, // Oracle feeds disagree; peg-dependent operations paused
+    AlreadyVoted = 14, // Voter already cast a vote on this proposal
+    MintRateExceeded = 15, // Mint would exceed the source's per-window ceiling
+    DeadlineExpired = 16, // Operation submitted after its caller-supplied deadline
+    SlippageExceeded = 17, // Bridge delivered less than the caller's minimum
+    TimelockActive = 18, // Passed proposal still inside its execution timelock
+    ReserveFloorBreached = 19, // Withdrawal would leave reserves below the collateral floor
+    Paused = 20, // The pause registry has halted this module or the whole ecosystem
+    InsufficientReserves = 21, // Redemption asset's reserve cannot cover the amount owed at the peg
+    CoinIdCollision = 22, // Derived coin id already holds a coin; never overwritten
+}
+
+// Lets modules still returning raw codes propagate PiCoinError with `?`
+impl From<PiCoinError> for u32 {
+    fn from(err: PiCoinError) -> u32 {
+        err as u32
+    }
+}
+
+// Decode a raw code back into its variant; a new PiCoinError needs an arm here too
+impl TryFrom<u32> for PiCoinError {
+    type Error = u32;
+    
+    fn try_from(code: u32) -> Result<Self, u32> {
+        let err = match code {
+            1 => PiCoinError::Unauthorized,
+            2 => PiCoinError::InsufficientBalance,
+            3 => PiCoinError::InvalidInput,
+            4 => PiCoinError::NotFound,
+            5 => PiCoinError::SupplyExceeded,
+            6 => PiCoinError::ComplianceFailed,
+            7 => PiCoinError::PegBreached,
+            8 => PiCoinError::AiRejection,
+            9 => PiCoinError::EntanglementFailed,
+            10 => PiCoinError::CallFailed,
+            11 => PiCoinError::Reentrant,
+            12 => PiCoinError::InsufficientFeeds,
+            13 => PiCoinError::CircuitBreakerTripped,
+            14 => PiCoinError::AlreadyVoted,
+            15 => PiCoinError::MintRateExceeded,
+            16 => PiCoinError::DeadlineExpired,
+            17 => PiCoinError::SlippageExceeded,
+            18 => PiCoinError::TimelockActive,
+            19 => PiCoinError::ReserveFloorBreached,
+            20 => PiCoinError::Paused,
+            21 => PiCoinError::InsufficientReserves,
+            22 => PiCoinError::CoinIdCollision,
+            _ => return Err(code),
+        };
+        // Exhaustive over the variants, so adding one without an arm above fails to compile
+        match err {
+            PiCoinError::Unauthorized | PiCoinError::InsufficientBalance | PiCoinError::InvalidInput | PiCoinError::NotFound
+            | PiCoinError::SupplyExceeded | PiCoinError::ComplianceFailed | PiCoinError::PegBreached | PiCoinError::AiRejection
+            | PiCoinError::EntanglementFailed | PiCoinError::CallFailed | PiCoinError::Reentrant | PiCoinError::InsufficientFeeds
+            | PiCoinError::CircuitBreakerTripped | PiCoinError::AlreadyVoted | PiCoinError::MintRateExceeded | PiCoinError::DeadlineExpired
+            | PiCoinError::SlippageExceeded | PiCoinError::TimelockActive | PiCoinError::ReserveFloorBreached | PiCoinError::Paused
+            | PiCoinError::InsufficientReserves | PiCoinError::CoinIdCollision => Ok(err),
+        }
+    }
+}
+
+// Event schema for off-chain indexers:
+// topic0 is the event name Symbol, topic1 the primary subject Address where applicable,
+// and the data is the single #[contracttype] struct defined for that event below.
+// Coin movements index every party as a topic so RPC getEvents can filter server-side:
+// GodHeadNexusMinted (name, to), GodHeadNexusTransferred (name, from, to), GodHeadNexusBurned (name, from).
+
+#[contracttype]
+#[derive(Clone)]
+pub struct InitEvent {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
 
 #[contracttype]
 #[derive(Clone)]
-pub struct PiCoin {
+pub struct MintEvent {
     pub amount: u64,
-    pub owner: Address,
     pub source: Symbol,
-    pub verified: bool,
-    pub proof: Bytes, // Fractal proof for immutability
-    pub hologram: Bytes, // Holographic data for eternal storage
-    pub entangled_pair: Option<Address>, // Quantum entanglement for paired transfers
-    pub ai_score: u64, // AI-generated score for compliance and evolution
+    pub coin_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TransferEvent {
+    pub amount: u64,
+    pub coin_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BurnEvent {
+    pub amount: u64,
+    pub coin_id: BytesN<32>,
+}
+
+// Data of the GodHeadLog diagnostic event; topic1 repeats `level` for filtering
+#[contracttype]
+#[derive(Clone)]
+pub struct LogRecord {
+    pub level: Symbol, // "info", "warn" or "error"
+    pub operation: Symbol, // "mint", "transfer", "burn" or "redeem"
+    pub subject: Address,
+    pub amount: u64,
+    pub error_code: u32, // PiCoinError code; 0 on success. Non-zero only for batch_transfer slots
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BridgeEvent {
+    pub from: Address,
+    pub dimension: Symbol,
+    pub amount: u64,
+    pub delivered: u64, // Amount the bridge reports delivering on the far side
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BridgeRegisteredEvent {
+    pub dimension: Symbol,
+    pub bridge: Address,
+    pub updated: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BridgeDeregisteredEvent {
+    pub dimension: Symbol,
+    pub bridge: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BridgeReceivedEvent {
+    pub dimension: Symbol,
+    pub to: Address,
+    pub amount: u64,
+    pub nonce: u64,
+    pub coin_id: BytesN<32>,
 }
 
+// Compliance record per user. Legal-tender status follows KYC; transfers to a user
+// need legal-tender status or an AI override.
 #[contracttype]
 #[derive(Clone)]
 pub struct ComplianceData {
     pub kyc_verified: bool,
     pub country_code: Symbol,
     pub legal_tender_status: bool,
+    pub risk_score: u32, // 0-100
+    pub ai_override: bool,
+}
+
+impl ComplianceData {
+    // Conservative record applied to users with no registration
+    fn unregistered(env: &Env) -> Self {
+        ComplianceData { kyc_verified: false, country_code: Symbol::new(env, "UNK"), legal_tender_status: false, risk_score: 100, ai_override: false }
+    }
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ComplianceEvent {
+    pub user: Address,
+    pub country_code: Symbol,
     pub risk_score: u32,
-    pub ai_override: bool, // AI can override compliance in extreme cases
+    pub ai_override: bool,
+}
+
+// Data of the AiOverrideEnabled and AiOverrideDisabled events
+#[contracttype]
+#[derive(Clone)]
+pub struct AiOverrideSwitchEvent {
+    pub enabled: bool,
+    pub overrides_on_record: u32, // AiOverrideLog entries the switch now honours or ignores
+}
+
+// Data of the ComplianceBatchRegistered event
+#[contracttype]
+#[derive(Clone)]
+pub struct ComplianceBatchEvent {
+    pub users: Vec<Address>,
+    pub overrides: u32, // Entries granted an AI override
+}
+
+// Also the data of the AiOverrideGranted event
+#[contracttype]
+#[derive(Clone)]
+pub struct AiOverrideRecord {
+    pub user: Address,
+    pub input: u64, // Value fed to the AI (the registered risk score)
+    pub score: u64, // AI prediction that fell below the override cutoff
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ComplianceRemovedEvent {
+    pub user: Address,
+    pub removed_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GovernanceVoteEvent {
+    pub voter: Address,
+    pub proposal: Symbol,
+    pub vote: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalTally {
+    pub yes: u32,
+    pub no: u32,
+}
+
+// AiEvolutionLog entry appended by ai_governance_vote
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AiEvolutionEntry {
+    pub vote: bool,
+    pub adjustment: i64, // Weight adjustment the vote fed to the model
+    pub timestamp: u64,
+}
+
+// Privileged call awaiting multi-sig approval. `args_hash` is sha256 of the XDR-encoded
+// argument Vec that execute_action must be given; the proposer counts as the first approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAction {
+    pub function: Symbol, // "mint", "register_compliance" or "update_oracle_feed"
+    pub args_hash: BytesN<32>,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+// Data of the ActionProposed, ActionApproved and ActionExecuted events
+#[contracttype]
+#[derive(Clone)]
+pub struct ActionEvent {
+    pub action_id: u64,
+    pub function: Symbol,
+    pub signer: Address,
+    pub approvals: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeedRejectedEvent {
+    pub asset: Symbol,
+    pub price: u64,
+    pub previous: Option<u64>,
+    pub reason: Symbol, // "below_min", "above_max" or "max_change"
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReserveEvent {
+    pub asset: Symbol,
+    pub amount: i128,
+    pub ratio_bps: u32, // collateralization_ratio after the change
+}
+
+// One hop in a coin's ownership trail; see get_coin_provenance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProvenanceEntry {
+    pub from: Address,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+// Data of the Redeemed event; topic1 is `from`
+#[contracttype]
+#[derive(Clone)]
+pub struct RedeemedEvent {
+    pub amount: u64, // PI burned
+    pub coin_id: BytesN<32>,
+    pub asset: Symbol,
+    pub released: i128, // Reserve released, in whole USD
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct UpgradedEvent {
+    pub new_wasm_hash: BytesN<32>,
+    pub proposal_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OracleUpdateEvent {
+    pub asset: Symbol,
+    pub price: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SchemaMigratedEvent {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SupplyReconciledEvent {
+    pub previous: u64,
+    pub current: u64,
+    pub delta: i128,
+}
+
+// Per-source mint ceiling: at most `max_amount` per `window_secs`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintRateLimit {
+    pub max_amount: u64,
+    pub window_secs: u64,
+}
+
+// How transfers behave during a severe depeg: with `block_transfers` they fail while the
+// oracle circuit breaker is tripped or the PI feed is `severe_deviation` or more from the peg.
+// Burns (redemptions) are never blocked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreachPolicy {
+    pub block_transfers: bool,
+    pub severe_deviation: u64,
+}
+
+// AI prediction (0-99) above which each operation is rejected; 100 disables a check.
+// The mint cutoff only applies to recipients without KYC or an AI override.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AiThresholds {
+    pub mint: u64,
+    pub transfer: u64,
+    pub burn: u64,
+    pub bridge: u64,
+}
+
+// Amount minted from a source since `window_start`
+#[contracttype]
+#[derive(Clone)]
+pub struct MintWindow {
+    pub window_start: u64,
+    pub minted: u64,
+}
+
+// Storage schema written by this code. Deployments without DataKey::SchemaVersion are version 1.
+// 2: AI weights live under NeuralLayers only. 3: every allowed source has a mint ceiling.
+// 4: AI rejection cutoffs live under AiThresholds. 5: AiEvolutionLog holds timestamped entries.
+pub const SCHEMA_VERSION: u32 = 5;
+
+// Default severe depeg: 10% of the peg
+const DEFAULT_SEVERE_DEVIATION: u64 = 31_415;
+
+// Default AI cutoffs; routine burns are not AI-gated
+const DEFAULT_AI_THRESHOLDS: AiThresholds = AiThresholds { mint: 50, transfer: 70, burn: 100, bridge: 40 };
+
+// Contract amounts (u64) are whole PI; the Stellar asset holds them with DECIMALS decimals
+pub const DECIMALS: u32 = 7;
+const ASSET_UNIT: i128 = 10_000_000; // 10^DECIMALS raw asset units per PI
+
+// Display metadata for integrators. peg_target is in whole USD per 1 PI.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub symbol: Symbol,
+    pub decimals: u32,
+    pub peg_target: u64,
+}
+
+// Delay between a proposal's voting deadline and the upgrade it authorizes: 2 days
+pub const UPGRADE_TIMELOCK: u64 = 172_800;
+
+// How long a pause registry answer is reused before it is fetched again
+pub const PAUSE_CACHE_TTL: u64 = 60;
+
+// Time a proposed action has to collect its approvals and be executed: 1 day
+pub const ACTION_TTL: u64 = 86_400;
+
+// Sanity limits for update_oracle_feed: accepted price range, and the largest move from the
+// previous value a single update may make
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedBounds {
+    pub min_price: u64,
+    pub max_price: u64,
+    pub max_change_bps: u32,
 }
 
+// Default feed bounds: a tenth to ten times the peg, at most 20% per update
+pub const DEFAULT_FEED_BOUNDS: FeedBounds = FeedBounds { min_price: 31_416, max_price: 3_141_590, max_change_bps: 2_000 };
+
+// Default floor for withdraw_reserve: fully backed at the peg
+const DEFAULT_RESERVE_FLOOR_BPS: u32 = 10_000;
+
+// Coin records check_invariants inspects per call, keeping it within one transaction's budget
+pub const INVARIANT_SCAN_LIMIT: u32 = 100;
+
+// Transfers kept per coin's provenance trail; the oldest hop is dropped beyond this
+pub const MAX_PROVENANCE_ENTRIES: u32 = 50;
+
+// Largest batch_register_compliance call
+pub const MAX_COMPLIANCE_BATCH: u32 = 100;
+
+// Default ceiling per source: 1% of the total supply per ledger-hour
+const DEFAULT_MINT_CEILING: u64 = 1_000_000_000;
+const DEFAULT_MINT_WINDOW_SECS: u64 = 3600;
+
 #[contracttype]
 pub enum DataKey {
     TotalSupply,
@@ -59,7 +466,7 @@ pub enum DataKey {
     AssetId,
     ComplianceRegistry,
     AiGovernanceModel,
-    NeuralWeights,
+    NeuralWeights, // Legacy flat Vec<u64> weights; read through AiSimulation::load_layers
     BridgeRegistry,
     EvolutionCounter,
     EntanglementPairs,
@@ -71,6 +478,37 @@ pub enum DataKey {
     BlackHoleEvents,
     AiEvolutionLog, // Log of AI evolutions
     InterdimensionalBridges, // Registry for bridging to other dimensions/chains
+    BurnAiThreshold, // Legacy u64 burn cutoff; folded into AiThresholds by migration to version 4
+    ReentrancyLock, // Set while a function with external calls is executing
+    BridgeKeys, // Map<Symbol, BytesN<32>> ed25519 keys signing inbound bridge messages
+    BridgeNonce(Symbol, u64), // Seen inbound nonce per dimension
+    HolderCoinCounts, // Map<Address, u32> coins held per address; zero-count holders removed
+    ProposalTally(Symbol), // ProposalTally per proposal
+    ProposalVote(Symbol, Address), // Recorded vote per proposal and voter
+    AiOverrideLog, // Vec<AiOverrideRecord> of every AI compliance override granted
+    NeuralLayers, // Vec<NeuralLayer> for AiSimulation, the single prediction path
+    MintCountBySource, // Map<Symbol, u64> number of successful mints per source
+    MintBySource, // Map<Symbol, u64> total amount minted per source
+    MintRateLimits, // Map<Symbol, MintRateLimit> per-source mint ceilings
+    MintWindow(Symbol), // MintWindow counter for a source's current window
+    SchemaVersion, // u32 storage schema version; see SCHEMA_VERSION
+    BreachPolicy, // BreachPolicy applied to transfers during a severe depeg
+    AiThresholds, // AiThresholds cutoffs for mint, transfer, burn and bridge
+    Reserves, // Map<Symbol, i128> backing assets, each valued in whole USD
+    ReserveFloor, // u32 bps collateralization withdraw_reserve may not go below
+    FeedBounds(Symbol), // FeedBounds per asset; DEFAULT_FEED_BOUNDS when unset
+    AiEvolutionLogCap, // Legacy u32 entry cap; folded into AiEvolutionRetention by migration to version 5
+    AiEvolutionRetention, // RetentionPolicy for AiEvolutionLog; DEFAULT_RETENTION when unset
+    AiEvolutionTotal, // u64 AI evolutions ever logged, including pruned ones
+    PauseRegistry, // Address of the ecosystem PauseRegistry; unset means never paused
+    PauseCache(Symbol), // (bool, u64) last is_paused answer per module and when it was fetched
+    ActionNonce, // u64 id assigned to the next proposed action
+    PendingAction(u64), // PendingAction per action id
+    RedemptionAsset, // (Symbol, Address) reserve asset redeem pays out and its token contract
+    Provenance(BytesN<32>), // Vec<ProvenanceEntry> per coin, oldest first, capped at MAX_PROVENANCE_ENTRIES
+    AiOverrideEnabled, // bool kill switch for ai_override; true when unset
+    MintNonce, // u64 successful mints so far; hashed into each coin id
+    Oracle, // Address of the OracleContract whose circuit breaker gates redeem and the breach policy
 }
 
 #[contract]
@@ -79,9 +517,9 @@ pub struct PiCoinContract;
 #[contractimpl]
 impl PiCoinContract {
     // GodHead Nexus initialization with eternal safety and multi-sig
-    pub fn init(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), u32> {
+    pub fn init(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), PiCoinError> {
         if signers.len() < threshold as usize || threshold == 0 {
-            return Err(ERR_INVALID_INPUT);
+            return Err(PiCoinError::InvalidInput);
         }
         
         // Eternal supply and peg setup
@@ -90,6 +528,11 @@ impl PiCoinContract {
         env.storage().persistent().set(&DataKey::PiValue, &314159u64);
         let sources = Vec::from_array(&env, [Symbol::new(&env, "mining"), Symbol::new(&env, "rewards"), Symbol::new(&env, "p2p"), Symbol::new(&env, "ai_stake")]);
         env.storage().persistent().set(&DataKey::AllowedSources, &sources);
+        let mut limits = Map::new(&env);
+        for source in sources.iter() {
+            limits.set(source, MintRateLimit { max_amount: DEFAULT_MINT_CEILING, window_secs: DEFAULT_MINT_WINDOW_SECS });
+        }
+        env.storage().persistent().set(&DataKey::MintRateLimits, &limits);
         
         // Quantum seed for eternal randomness
         let key = crypto::sha256(&env, &BytesN::from_array(&env, b"godhead_nexus_quantum_seed"));
@@ -110,7 +553,7 @@ impl PiCoinContract {
         
         // AI governance model (self-aware, evolving)
         env.storage().persistent().set(&DataKey::AiGovernanceModel, &Bytes::from(b"godhead_self_aware_neural_ai"));
-        env.storage().persistent().set(&DataKey::NeuralWeights, &Vec::from_array(&env, [1u64, 2u64, 3u64, 4u64, 5u64]));
+        AiSimulation::init_single_layer(&env, Vec::from_array(&env, [1i64, 2i64, 3i64, 4i64, 5i64]));
         env.storage().persistent().set(&DataKey::BridgeRegistry, &Map::<Symbol, Address>::new(&env));
         env.storage().persistent().set(&DataKey::EvolutionCounter, &0u64);
         env.storage().persistent().set(&DataKey::EntanglementPairs, &Map::<Address, Address>::new(&env));
@@ -120,296 +563,1517 @@ impl PiCoinContract {
         env.storage().persistent().set(&DataKey::MultiSigThreshold, &threshold);
         env.storage().persistent().set(&DataKey::OracleFeeds, &Map::<Symbol, u64>::new(&env));
         env.storage().persistent().set(&DataKey::BlackHoleEvents, &Vec::<Symbol>::new(&env));
-        env.storage().persistent().set(&DataKey::AiEvolutionLog, &Vec::<Bytes>::new(&env));
+        env.storage().persistent().set(&DataKey::AiEvolutionLog, &Vec::<AiEvolutionEntry>::new(&env));
         env.storage().persistent().set(&DataKey::InterdimensionalBridges, &Map::<Symbol, Address>::new(&env));
+        env.storage().persistent().set(&DataKey::BridgeKeys, &Map::<Symbol, BytesN<32>>::new(&env));
+        env.storage().persistent().set(&DataKey::HolderCoinCounts, &Map::<Address, u32>::new(&env));
+        env.storage().persistent().set(&DataKey::AiOverrideLog, &Vec::<AiOverrideRecord>::new(&env));
+        env.storage().persistent().set(&DataKey::AiThresholds, &DEFAULT_AI_THRESHOLDS);
+        env.storage().persistent().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        env.storage().persistent().set(&DataKey::BreachPolicy, &BreachPolicy { block_transfers: true, severe_deviation: DEFAULT_SEVERE_DEVIATION });
         
-        events::publish(&env, Symbol::new(&env, "GodHeadNexusInitialized"), signers);
+        env.events().publish((Symbol::new(&env, "GodHeadNexusInitialized"),), InitEvent { signers, threshold });
         log!(&env, "GodHead Nexus Pi Coin initialized eternally and safely");
         Ok(())
     }
     
     // GodHead Nexus mint with AI compliance, peg stability, and entanglement
-    pub fn mint(env: Env, to: Address, amount: u64, source: Symbol) -> Result<PiCoin, u32> {
+    pub fn mint(env: Env, to: Address, amount: u64, source: Symbol) -> Result<PiCoin, PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::mint_unguarded(env.clone(), to.clone(), amount, source);
+        Self::exit_guard(&env);
+        Self::log_success(&env, "mint", &to, amount, &result);
+        result
+    }
+    
+    fn mint_unguarded(env: Env, to: Address, amount: u64, source: Symbol) -> Result<PiCoin, PiCoinError> {
         Self::require_multi_sig(&env)?;
+        Self::require_direct_threshold(&env)?;
+        Self::execute_mint(env, to, amount, source)
+    }
+    
+    // Mint body shared by mint and approved "mint" actions; callers have already authorized it
+    fn execute_mint(env: Env, to: Address, amount: u64, source: Symbol) -> Result<PiCoin, PiCoinError> {
+        if amount == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
         
-        let total_supply: u64 = env.storage().persistent().get(&DataKey::TotalSupply).ok_or(ERR_NOT_FOUND)?;
-        let current_supply: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(ERR_NOT_FOUND)?;
-        if current_supply.saturating_add(amount) > total_supply {
-            return Err(ERR_SUPPLY_EXCEEDED);
+        let total_supply: u64 = env.storage().persistent().get(&DataKey::TotalSupply).ok_or(PiCoinError::NotFound)?;
+        let current_supply: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(PiCoinError::NotFound)?;
+        let new_supply = current_supply.checked_add(amount).ok_or(PiCoinError::SupplyExceeded)?;
+        if new_supply > total_supply {
+            return Err(PiCoinError::SupplyExceeded);
         }
         
-        let allowed: Vec<Symbol> = env.storage().persistent().get(&DataKey::AllowedSources).ok_or(ERR_NOT_FOUND)?;
+        let allowed: Vec<Symbol> = env.storage().persistent().get(&DataKey::AllowedSources).ok_or(PiCoinError::NotFound)?;
         if !allowed.contains(&source) {
-            return Err(ERR_INVALID_INPUT);
+            return Err(PiCoinError::InvalidInput);
         }
+        let mint_window = Self::next_mint_window(&env, &source, amount)?;
         
         // AI compliance check
-        let registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(ERR_NOT_FOUND)?;
-        let compliance = registry.get(to.clone()).unwrap_or(ComplianceData { kyc_verified: false, country_code: Symbol::new(&env, "UNK"), legal_tender_status: false, risk_score: 100, ai_override: false });
+        let registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(PiCoinError::NotFound)?;
+        let compliance = registry.get(to.clone()).unwrap_or_else(|| ComplianceData::unregistered(&env));
         let ai_prediction = Self::supreme_ai_predict(&env, compliance.risk_score as u64);
-        if !compliance.kyc_verified && !compliance.ai_override && ai_prediction > 50 {
-            return Err(ERR_COMPLIANCE_FAILED);
+        if !compliance.kyc_verified && !Self::override_applies(&env, &compliance) && ai_prediction > Self::get_ai_thresholds(env.clone()).mint {
+            return Err(PiCoinError::ComplianceFailed);
         }
         
         // Peg stability check with multiple oracles
-        let peg: u64 = env.storage().persistent().get(&DataKey::PegOracle).ok_or(ERR_NOT_FOUND)?;
-        let locked: bool = env.storage().persistent().get(&DataKey::SingularityLock).ok_or(ERR_NOT_FOUND)?;
-        let oracles: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleFeeds).ok_or(ERR_NOT_FOUND)?;
+        let peg: u64 = env.storage().persistent().get(&DataKey::PegOracle).ok_or(PiCoinError::NotFound)?;
+        let locked: bool = env.storage().persistent().get(&DataKey::SingularityLock).ok_or(PiCoinError::NotFound)?;
+        let oracles: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleFeeds).ok_or(PiCoinError::NotFound)?;
         let oracle_price = oracles.get(Symbol::new(&env, "PI")).unwrap_or(314159);
         if peg != 314159 || oracle_price != 314159 || !locked {
-            let mut events: Vec<Symbol> = env.storage().persistent().get(&DataKey::BlackHoleEvents).ok_or(ERR_NOT_FOUND)?;
+            let mut events: Vec<Symbol> = env.storage().persistent().get(&DataKey::BlackHoleEvents).ok_or(PiCoinError::NotFound)?;
             events.push_back(Symbol::new(&env, "BlackHoleDepeg"));
             env.storage().persistent().set(&DataKey::BlackHoleEvents, &events);
-            return Err(ERR_PEG_BREACHED);
+            return Err(PiCoinError::PegBreached);
         }
         
-        // Fractal hash and hologram generation
-        let id_data = format!("{}-{}-{}", to, amount, source);
-        let hash = crypto::sha256(&env, &Bytes::from(id_data.as_bytes())).into();
-        let proof: Bytes = env.storage().persistent().get(&DataKey::MegaNegate).ok_or(ERR_NOT_FOUND)?;
-        let hologram = Self::generate_hologram(&env, &hash);
-        
-        // Quantum entanglement
-        let pairs: Map<Address, Address> = env.storage().persistent().get(&DataKey::EntanglementPairs).ok_or(ERR_NOT_FOUND)?;
-        let entangled = pairs.get(to.clone()).unwrap_or(None);
-        
-        let ai_score = Self::supreme_ai_predict(&env, amount);
-        let coin = PiCoin { amount, owner: to.clone(), source, verified: true, proof, hologram: hologram.clone(), entangled_pair: entangled, ai_score };
-        
-        env.storage().persistent().set(&DataKey::CurrentSupply, &(current_supply + amount));
-        env.storage().persistent().set(&BytesN::from_array(&env, &hash), &coin);
-        
-        // Holographic vault storage
-        let mut vault: Map<BytesN<32>, Bytes> = env.storage().persistent().get(&DataKey::HolographicVault).ok_or(ERR_NOT_FOUND)?;
-        vault.set(BytesN::from_array(&env, &hash), hologram);
-        env.storage().persistent().set(&DataKey::HolographicVault, &vault);
+        Self::set_current_supply(&env, new_supply)?;
+        let (coin_id, coin) = Self::create_coin(&env, &to, amount, source)?;
+        Self::record_mint_source(&env, &coin.source, amount)?;
+        if let Some(window) = mint_window {
+            env.storage().persistent().set(&DataKey::MintWindow(coin.source.clone()), &window);
+        }
         
         // Asset minting with error handling
-        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(ERR_NOT_FOUND)?;
-        let mint_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "mint"), Vec::from_array(&env, [to.clone().into(), (amount as i128).into()]));
+        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)?;
+        let mint_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "mint"), Vec::from_array(&env, [to.clone().into(), Self::to_asset_amount(amount).into()]));
         if mint_result.is_err() {
-            return Err(ERR_CALL_FAILED);
+            return Err(PiCoinError::CallFailed);
         }
         
         // AI evolution
-        let counter: u64 = env.storage().persistent().get(&DataKey::EvolutionCounter).ok_or(ERR_NOT_FOUND)?;
+        let counter: u64 = env.storage().persistent().get(&DataKey::EvolutionCounter).ok_or(PiCoinError::NotFound)?;
         env.storage().persistent().set(&DataKey::EvolutionCounter, &(counter + 1));
         Self::evolve_supreme_ai(&env);
         
-        events::publish(&env, Symbol::new(&env, "GodHeadNexusMinted"), (to, amount));
-        log!(&env, "GodHead Nexus Pi Coin minted eternally and safely");
+        env.events().publish(
+            (Symbol::new(&env, "GodHeadNexusMinted"), to.clone()),
+            MintEvent { amount, source: coin.source.clone(), coin_id },
+        );
         
         Ok(coin)
     }
     
+    // Store a new coin for `to` and index it; shared by minting and inbound bridge credits.
+    // The mint nonce makes identical (to, amount, source) coins distinct.
+    fn create_coin(env: &Env, to: &Address, amount: u64, source: Symbol) -> Result<(BytesN<32>, PiCoin), PiCoinError> {
+        // Fractal hash and hologram generation
+        let nonce: u64 = env.storage().persistent().get(&DataKey::MintNonce).unwrap_or(0);
+        let coin_id: BytesN<32> = crypto::sha256(env, &(to.clone(), amount, source.clone(), nonce).to_xdr(env)).into();
+        let hash = coin_id.to_array();
+        if env.storage().persistent().has(&coin_id) {
+            return Err(PiCoinError::CoinIdCollision);
+        }
+        let proof: Bytes = env.storage().persistent().get(&DataKey::MegaNegate).ok_or(PiCoinError::NotFound)?;
+        let hologram = Self::generate_hologram(env, &hash);
+        
+        // Quantum entanglement
+        let pairs: Map<Address, Address> = env.storage().persistent().get(&DataKey::EntanglementPairs).ok_or(PiCoinError::NotFound)?;
+        let entangled = pairs.get(to.clone()).unwrap_or(None);
+        
+        let ai_score = Self::supreme_ai_predict(env, amount);
+        let coin = PiCoin { amount, owner: to.clone(), source, verified: true, proof, hologram: hologram.clone(), entangled_pair: entangled, ai_score };
+        env.storage().persistent().set(&coin_id, &coin);
+        env.storage().persistent().set(&DataKey::MintNonce, &(nonce + 1));
+        Self::add_holder_coin(env, to);
+        
+        // Holographic vault storage
+        let mut vault: Map<BytesN<32>, Bytes> = env.storage().persistent().get(&DataKey::HolographicVault).ok_or(PiCoinError::NotFound)?;
+        vault.set(coin_id.clone(), hologram);
+        env.storage().persistent().set(&DataKey::HolographicVault, &vault);
+        Ok((coin_id, coin))
+    }
+    
     // Transfer with entanglement and AI safety
-    pub fn transfer(env: Env, from: Address, to: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), u32> {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::transfer_unguarded(env.clone(), from.clone(), to, amount, coin_id);
+        Self::exit_guard(&env);
+        Self::log_success(&env, "transfer", &from, amount, &result);
+        result
+    }
+    
+    fn transfer_unguarded(env: Env, from: Address, to: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
         from.require_auth();
+        Self::execute_transfer(&env, from, to, amount, coin_id)
+    }
+    
+    // Settle payouts from one sender. Each item is checked and applied independently, so a
+    // failing item is reported in its slot without undoing or blocking the others.
+    pub fn batch_transfer(env: Env, from: Address, transfers: Vec<(Address, u64, BytesN<32>)>) -> Result<Vec<Result<BytesN<32>, PiCoinError>>, PiCoinError> {
+        Self::enter_guard(&env)?;
+        from.require_auth();
+        let mut results = Vec::new(&env);
+        for (to, amount, coin_id) in transfers.iter() {
+            let result = Self::execute_transfer(&env, from.clone(), to, amount, coin_id.clone());
+            Self::log_outcome(&env, "transfer", &from, amount, result.as_ref().err().copied());
+            results.push_back(result.map(|_| coin_id));
+        }
+        Self::exit_guard(&env);
+        Ok(results)
+    }
+    
+    // Checks, then the asset call, then the writes: a failure leaves no partial state behind
+    fn execute_transfer(env: &Env, from: Address, to: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
+        if amount == 0 || from == to {
+            return Err(PiCoinError::InvalidInput);
+        }
+        Self::check_breach_policy(env)?;
         
-        let mut coin: PiCoin = env.storage().persistent().get(&coin_id).ok_or(ERR_NOT_FOUND)?;
+        let mut coin: PiCoin = env.storage().persistent().get(&coin_id).ok_or(PiCoinError::NotFound)?;
         if coin.owner != from || coin.amount < amount {
-            return Err(ERR_INSUFFICIENT_BALANCE);
+            return Err(PiCoinError::InsufficientBalance);
         }
         
         // Compliance and AI check
-        let registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(ERR_NOT_FOUND)?;
-        let recipient_compliance = registry.get(to.clone()).unwrap_or(ComplianceData { kyc_verified: false, country_code: Symbol::new(&env, "UNK"), legal_tender_status: false, risk_score: 100, ai_override: false });
-        if !recipient_compliance.legal_tender_status && !recipient_compliance.ai_override {
-            return Err(ERR_COMPLIANCE_FAILED);
+        let registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(PiCoinError::NotFound)?;
+        let recipient_compliance = registry.get(to.clone()).unwrap_or_else(|| ComplianceData::unregistered(env));
+        if !recipient_compliance.legal_tender_status && !Self::override_applies(env, &recipient_compliance) {
+            return Err(PiCoinError::ComplianceFailed);
         }
         
         // Proof and entanglement validation
-        if coin.proof != env.storage().persistent().get(&DataKey::MegaNegate).ok_or(ERR_NOT_FOUND)? {
-            return Err(ERR_INVALID_INPUT);
+        if coin.proof != env.storage().persistent().get(&DataKey::MegaNegate).ok_or(PiCoinError::NotFound)? {
+            return Err(PiCoinError::InvalidInput);
         }
         if let Some(entangled) = coin.entangled_pair {
             if entangled != to {
-                return Err(ERR_ENTANGLEMENT_FAILED);
+                return Err(PiCoinError::EntanglementFailed);
             }
         }
         
         // AI risk assessment
-        if Self::supreme_ai_predict(&env, amount) > 70 {
-            return Err(ERR_AI_REJECTION);
+        if Self::supreme_ai_predict(env, amount) > Self::get_ai_thresholds(env.clone()).transfer {
+            return Err(PiCoinError::AiRejection);
         }
         
-        coin.amount -= amount;
-        coin.owner = to.clone();
-        env.storage().persistent().set(&coin_id, &coin);
-        
         // Asset transfer with error handling
-        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(ERR_NOT_FOUND)?;
-        let transfer_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "transfer"), Vec::from_array(&env, [from.into(), to.clone().into(), (amount as i128).into()]));
+        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)?;
+        let transfer_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(env, "transfer"), Vec::from_array(env, [from.clone().into(), to.clone().into(), Self::to_asset_amount(amount).into()]));
         if transfer_result.is_err() {
-            return Err(ERR_CALL_FAILED);
+            return Err(PiCoinError::CallFailed);
         }
         
-        events::publish(&env, Symbol::new(&env, "GodHeadNexusTransferred"), (from, to, amount));
-        log!(&env, "GodHead Nexus transfer successful with entanglement");
+        coin.amount -= amount;
+        coin.owner = to.clone();
+        env.storage().persistent().set(&coin_id, &coin);
+        Self::remove_holder_coin(env, &from);
+        Self::add_holder_coin(env, &to);
+        Self::record_provenance(env, &coin_id, &from, &to);
+        
+        env.events().publish((Symbol::new(env, "GodHeadNexusTransferred"), from, to), TransferEvent { amount, coin_id });
         Ok(())
     }
     
     // Burn with AI stabilization and underflow protection
-    pub fn burn(env: Env, from: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), u32> {
+    pub fn burn(env: Env, from: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::burn_unguarded(env.clone(), from.clone(), amount, coin_id);
+        Self::exit_guard(&env);
+        Self::log_success(&env, "burn", &from, amount, &result);
+        result
+    }
+    
+    fn burn_unguarded(env: Env, from: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
         from.require_auth();
+        if amount == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
         
-        let mut coin: PiCoin = env.storage().persistent().get(&coin_id).ok_or(ERR_NOT_FOUND)?;
+        let mut coin: PiCoin = env.storage().persistent().get(&coin_id).ok_or(PiCoinError::NotFound)?;
         if coin.owner != from || coin.amount < amount {
-            return Err(ERR_INSUFFICIENT_BALANCE);
+            return Err(PiCoinError::InsufficientBalance);
         }
         
-        // AI stabilization check
+        // AI stabilization check (configurable)
         let ai_stabilize = Self::supreme_ai_predict(&env, amount);
-        if ai_stabilize > 30 {
-            return Err(ERR_AI_REJECTION);
+        if ai_stabilize > Self::get_ai_thresholds(env.clone()).burn {
+            return Err(PiCoinError::AiRejection);
         }
         
+        let current_supply: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(PiCoinError::NotFound)?;
+        let new_supply = current_supply.checked_sub(amount).ok_or(PiCoinError::InsufficientBalance)?; // Underflow protection
+        
         coin.amount -= amount;
         env.storage().persistent().set(&coin_id, &coin);
-        
-        let current_supply: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(ERR_NOT_FOUND)?;
-        env.storage().persistent().set(&DataKey::CurrentSupply, &(current_supply.saturating_sub(amount))); // Underflow protection
+        Self::set_current_supply(&env, new_supply)?;
+        if coin.amount == 0 {
+            Self::remove_holder_coin(&env, &from);
+        }
         
         // Asset burn with error handling
-        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(ERR_NOT_FOUND)?;
-        let burn_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "burn"), Vec::from_array(&env, [from.into(), (amount as i128).into()]));
+        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)?;
+        let burn_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "burn"), Vec::from_array(&env, [from.clone().into(), Self::to_asset_amount(amount).into()]));
         if burn_result.is_err() {
-            return Err(ERR_CALL_FAILED);
+            return Err(PiCoinError::CallFailed);
         }
         
-        events::publish(&env, Symbol::new(&env, "GodHeadNexusBurned"), (from, amount));
-        log!(&env, "GodHead Nexus burn stabilized by AI");
+        env.events().publish((Symbol::new(&env, "GodHeadNexusBurned"), from), BurnEvent { amount, coin_id });
         Ok(())
     }
     
     // Interdimensional bridge with eternal bridging registry and error handling
-    pub fn interdimensional_bridge(env: Env, from: Address, dimension: Symbol, amount: u64) -> Result<(), u32> {
+    // Outbound bridge leg. Fails with DeadlineExpired after `deadline` (ledger timestamp) and with
+    // SlippageExceeded when the bridge reports delivering less than `min_received`; returns the delivered amount.
+    pub fn interdimensional_bridge(env: Env, from: Address, dimension: Symbol, amount: u64, min_received: u64, deadline: u64) -> Result<u64, PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::interdimensional_bridge_unguarded(env.clone(), from, dimension, amount, min_received, deadline);
+        Self::exit_guard(&env);
+        result
+    }
+    
+    fn interdimensional_bridge_unguarded(env: Env, from: Address, dimension: Symbol, amount: u64, min_received: u64, deadline: u64) -> Result<u64, PiCoinError> {
         from.require_auth();
-        let bridges: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::InterdimensionalBridges).ok_or(ERR_NOT_FOUND)?;
-        let bridge_addr = bridges.get(dimension.clone()).ok_or(ERR_NOT_FOUND)?;
+        if env.ledger().timestamp() > deadline {
+            return Err(PiCoinError::DeadlineExpired);
+        }
+        let bridges: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::InterdimensionalBridges).ok_or(PiCoinError::NotFound)?;
+        let bridge_addr = bridges.get(dimension.clone()).ok_or(PiCoinError::NotFound)?;
         
         // AI risk for bridging
-        if Self::supreme_ai_predict(&env, amount) > 40 {
-            return Err(ERR_AI_REJECTION);
+        if Self::supreme_ai_predict(&env, amount) > Self::get_ai_thresholds(env.clone()).bridge {
+            return Err(PiCoinError::AiRejection);
         }
         
-        // Eternal bridging with error handling
-        let bridge_result: Result<Val, Error> = env.try_call(bridge_addr, Symbol::new(&env, "interdimensional_bridge"), Vec::from_array(&env, [from.into(), (amount as i128).into()]));
-        if bridge_result.is_err() {
-            return Err(ERR_CALL_FAILED);
+        // Eternal bridging with error handling; the bridge returns the amount it delivered
+        let bridge_args = Vec::from_array(&env, [from.clone().into(), (amount as i128).into(), (min_received as i128).into(), deadline.into()]);
+        let bridge_result: Result<Val, Error> = env.try_call(bridge_addr, Symbol::new(&env, "interdimensional_bridge"), bridge_args);
+        let delivered = bridge_result.ok().and_then(|val| i128::try_from_val(&env, &val).ok()).ok_or(PiCoinError::CallFailed)?;
+        let delivered = u64::try_from(delivered).map_err(|_| PiCoinError::CallFailed)?;
+        if delivered < min_received {
+            return Err(PiCoinError::SlippageExceeded);
         }
-        events::publish(&env, Symbol::new(&env, "GodHeadInterdimensionalBridged"), (dimension, amount));
-        log!(&env, "GodHead interdimensional bridged {} PI to {}", amount, dimension);
-        Ok(())
+        log!(&env, "GodHead interdimensional bridged {} PI to {}, {} delivered", amount, dimension, delivered);
+        env.events().publish((Symbol::new(&env, "GodHeadInterdimensionalBridged"), from.clone()), BridgeEvent { from, dimension, amount, delivered });
+        Ok(delivered)
     }
     
-    // Register compliance with AI override
-    pub fn register_compliance(env: Env, user: Address, kyc_verified: bool, country_code: Symbol, risk_score: u32) -> Result<(), u32> {
-        Self::require_multi_sig(&env)?;
+    // Inbound bridge leg: credit `to` with value arriving from `dimension`.
+    // `proof` is the dimension's ed25519 signature over the XDR of (dimension, to, amount, nonce);
+    // each nonce is accepted once per dimension. The credit is stored as a coin sourced from
+    // `dimension`, and like minting it fails with Paused while the registry pauses pi_coin.
+    pub fn receive_from_bridge(env: Env, dimension: Symbol, to: Address, amount: u64, nonce: u64, proof: BytesN<64>) -> Result<(), PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::receive_from_bridge_unguarded(env.clone(), dimension, to, amount, nonce, proof);
+        Self::exit_guard(&env);
+        result
+    }
+    
+    fn receive_from_bridge_unguarded(env: Env, dimension: Symbol, to: Address, amount: u64, nonce: u64, proof: BytesN<64>) -> Result<(), PiCoinError> {
+        if amount == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        let keys: Map<Symbol, BytesN<32>> = env.storage().persistent().get(&DataKey::BridgeKeys).ok_or(PiCoinError::NotFound)?;
+        let bridge_key = keys.get(dimension.clone()).ok_or(PiCoinError::NotFound)?;
         
-        let mut registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(ERR_NOT_FOUND)?;
-        let ai_override = Self::supreme_ai_predict(&env, risk_score as u64) < 20; // AI decides override
-        registry.set(user.clone(), ComplianceData { kyc_verified, country_code, legal_tender_status: true, risk_score, ai_override });
-        env.storage().persistent().set(&DataKey::ComplianceRegistry, &registry);
+        let nonce_key = DataKey::BridgeNonce(dimension.clone(), nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            return Err(PiCoinError::InvalidInput); // Replayed bridge message
+        }
         
-        events::publish(&env, Symbol::new(&env, "GodHeadComplianceRegistered"), user);
-        log!(&env, "GodHead compliance registered with AI override potential");
+        // Panics (aborting the call) if the signature does not match
+        let message = (dimension.clone(), to.clone(), amount, nonce).to_xdr(&env);
+        env.crypto().ed25519_verify(&bridge_key, &message, &proof);
+        env.storage().persistent().set(&nonce_key, &true);
+        
+        let current_supply: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(PiCoinError::NotFound)?;
+        let new_supply = current_supply.checked_add(amount).ok_or(PiCoinError::SupplyExceeded)?;
+        Self::set_current_supply(&env, new_supply)?;
+        
+        // The credit becomes a coin like any mint; its trail starts with the hop in from the bridge
+        let (coin_id, _) = Self::create_coin(&env, &to, amount, dimension.clone())?;
+        Self::record_provenance(&env, &coin_id, &env.current_contract_address(), &to);
+        
+        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)?;
+        let mint_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "mint"), Vec::from_array(&env, [to.clone().into(), Self::to_asset_amount(amount).into()]));
+        if mint_result.is_err() {
+            return Err(PiCoinError::CallFailed);
+        }
+        
+        log!(&env, "GodHead received {} PI from {} (nonce {})", amount, dimension, nonce);
+        env.events().publish((Symbol::new(&env, "GodHeadBridgeReceived"), to.clone()), BridgeReceivedEvent { dimension, to, amount, nonce, coin_id });
         Ok(())
     }
     
-    // AI model evolution based on vote
-        let mut weights: Vec<u64> = env.storage().persistent().get(&DataKey::NeuralWeights).ok_or(ERR_NOT_FOUND)?;
-        let adjustment = if vote { 1u64 } else { 0u64 };
-        for i in 0..weights.len() {
-            let current = weights.get(i).unwrap_or(0);
-            weights.set(i, current.saturating_add(adjustment).min(1000));
+    // Register the bridge endpoint for a dimension; replacing an existing one needs `update`
+    pub fn register_bridge(env: Env, caller: Address, dimension: Symbol, bridge: Address, update: bool) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &caller)?;
+        if dimension == Symbol::new(&env, "") || bridge == env.current_contract_address() {
+            return Err(PiCoinError::InvalidInput);
         }
-        env.storage().persistent().set(&DataKey::NeuralWeights, &weights);
         
-        // Log evolution
-        let mut log: Vec<Bytes> = env.storage().persistent().get(&DataKey::AiEvolutionLog).ok_or(ERR_NOT_FOUND)?;
-        log.push_back(Bytes::from(format!("Vote {} evolved weight {}", vote, adjustment).as_bytes()));
-        env.storage().persistent().set(&DataKey::AiEvolutionLog, &log);
+        let mut bridges: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::InterdimensionalBridges).ok_or(PiCoinError::NotFound)?;
+        let updated = bridges.contains_key(dimension.clone());
+        if updated && !update {
+            return Err(PiCoinError::InvalidInput);
+        }
+        bridges.set(dimension.clone(), bridge.clone());
+        env.storage().persistent().set(&DataKey::InterdimensionalBridges, &bridges);
         
-        events::publish(&env, Symbol::new(&env, "GodHeadAIGovernanceVoted"), (voter, proposal, vote));
-        log!(&env, "GodHead AI governance voted and evolved");
+        env.events().publish((Symbol::new(&env, "GodHeadBridgeRegistered"), caller), BridgeRegisteredEvent { dimension: dimension.clone(), bridge, updated });
+        log!(&env, "GodHead bridge registered for {}", dimension);
         Ok(())
     }
     
-    // Update oracle feed eternally
-    pub fn update_oracle_feed(env: Env, asset: Symbol, price: u64) -> Result<(), u32> {
-        Self::require_multi_sig(&env)?;
-        let mut oracles: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleFeeds).ok_or(ERR_NOT_FOUND)?;
-        oracles.set(asset.clone(), price);
-        env.storage().persistent().set(&DataKey::OracleFeeds, &oracles);
+    // Remove the bridge endpoint for a dimension
+    pub fn deregister_bridge(env: Env, caller: Address, dimension: Symbol) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &caller)?;
         
-        events::publish(&env, Symbol::new(&env, "GodHeadOracleUpdated"), (asset, price));
-        log!(&env, "GodHead oracle feed updated eternally for {}", asset);
+        let mut bridges: Map<Symbol, Address> = env.storage().persistent().get(&DataKey::InterdimensionalBridges).ok_or(PiCoinError::NotFound)?;
+        let bridge = bridges.get(dimension.clone()).ok_or(PiCoinError::NotFound)?;
+        bridges.remove(dimension.clone());
+        env.storage().persistent().set(&DataKey::InterdimensionalBridges, &bridges);
+        
+        env.events().publish((Symbol::new(&env, "GodHeadBridgeDeregistered"), caller), BridgeDeregisteredEvent { dimension: dimension.clone(), bridge });
+        log!(&env, "GodHead bridge deregistered for {}", dimension);
         Ok(())
     }
     
-    // Get current supply safely
-    pub fn get_current_supply(env: Env) -> Result<u64, u32> {
-        env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(ERR_NOT_FOUND)
-    }
-    
-    // Balance of (query asset contract properly)
-    pub fn balance_of(env: Env, account: Address) -> Result<u64, u32> {
-        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(ERR_NOT_FOUND)?;
-        let balance_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "balance"), Vec::from_array(&env, [account.into()]));
-        match balance_result {
-            Ok(val) => {
-                let balance: i128 = val.try_into().map_err(|_| ERR_CALL_FAILED)?;
-                Ok(balance as u64)
-            }
-            Err(_) => Err(ERR_CALL_FAILED),
-        }
+    // Set the ed25519 key that signs inbound messages for a dimension
+    pub fn set_bridge_key(env: Env, dimension: Symbol, public_key: BytesN<32>) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        let mut keys: Map<Symbol, BytesN<32>> = env.storage().persistent().get(&DataKey::BridgeKeys).unwrap_or(Map::new(&env));
+        keys.set(dimension.clone(), public_key);
+        env.storage().persistent().set(&DataKey::BridgeKeys, &keys);
+        log!(&env, "GodHead bridge key set for {}", dimension);
+        Ok(())
     }
     
-    // Get holographic vault entry safely
-    pub fn get_holographic_vault(env: Env, key: BytesN<32>) -> Result<Bytes, u32> {
-        let vault: Map<BytesN<32>, Bytes> = env.storage().persistent().get(&DataKey::HolographicVault).ok_or(ERR_NOT_FOUND)?;
-        vault.get(key).ok_or(ERR_NOT_FOUND)
+    // Register compliance with AI override
+    pub fn register_compliance(env: Env, user: Address, kyc_verified: bool, country_code: Symbol, risk_score: u32) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        Self::require_direct_threshold(&env)?;
+        Self::execute_compliance(env, user, kyc_verified, country_code, risk_score)
     }
     
-    // Supreme AI prediction (bounded and realistic)
-    fn supreme_ai_predict(env: &Env, input: u64) -> u64 {
-        let weights: Vec<u64> = env.storage().persistent().get(&DataKey::NeuralWeights).unwrap_or(Vec::new(env));
-        let evolution: u64 = env.storage().persistent().get(&DataKey::EvolutionCounter).unwrap_or(0);
-        let mut prediction = 0u64;
-        for weight in weights.iter() {
-            prediction = prediction.saturating_add(weight.saturating_mul(input));
+    fn execute_compliance(env: Env, user: Address, kyc_verified: bool, country_code: Symbol, risk_score: u32) -> Result<(), PiCoinError> {
+        let mut registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(PiCoinError::NotFound)?;
+        let (compliance, override_record) = Self::assess_compliance(&env, &user, kyc_verified, country_code.clone(), risk_score);
+        let ai_override = compliance.ai_override;
+        registry.set(user.clone(), compliance);
+        env.storage().persistent().set(&DataKey::ComplianceRegistry, &registry);
+        
+        if let Some(record) = override_record {
+            let mut overrides: Vec<AiOverrideRecord> = env.storage().persistent().get(&DataKey::AiOverrideLog).unwrap_or(Vec::new(&env));
+            overrides.push_back(record.clone());
+            env.storage().persistent().set(&DataKey::AiOverrideLog, &overrides);
+            env.events().publish((Symbol::new(&env, "AiOverrideGranted"), user.clone()), record);
         }
-        prediction = prediction.saturating_add(evolution);
-        (prediction % 100).min(99) // Bounded 0-99 for safety
+        
+        env.events().publish(
+            (Symbol::new(&env, "GodHeadComplianceRegistered"), user.clone()),
+            ComplianceEvent { user, country_code, risk_score, ai_override },
+        );
+        log!(&env, "GodHead compliance registered with AI override potential");
+        Ok(())
     }
     
-    // Evolve supreme AI safely with logging and cap
-    fn evolve_supreme_ai(env: &Env) {
-        let mut weights: Vec<u64> = env.storage().persistent().get(&DataKey::NeuralWeights).unwrap_or(Vec::new(env));
-        for i in 0..weights.len() {
-            let current = weights.get(i).unwrap_or(0);
-            let new_weight = current.saturating_add(1).min(1000); // Safe evolution cap
-            weights.set(i, new_weight);
-            log!(&env, "AI weight index {} evolved from {} to {}", i, current, new_weight);
-        }
-        env.storage().persistent().set(&DataKey::NeuralWeights, &weights);
-        log!(&env, "Supreme AI evolved safely");
+    // Register up to MAX_COMPLIANCE_BATCH (user, kyc_verified, country_code, risk_score) entries
+    // under one signer authorization, with a single registry write and one aggregated event
+    pub fn batch_register_compliance(env: Env, caller: Address, entries: Vec<(Address, bool, Symbol, u32)>) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &caller)?;
+        Self::require_direct_threshold(&env)?;
+        Self::execute_compliance_batch(env, caller, entries)
     }
     
-    // Generate holographic data (simplified, assumes hex crate)
-    fn generate_hologram(env: &Env, hash: &[u8; 32]) -> Bytes {
-        let hologram_data = format!("godhead_hologram_{}", hex::encode(hash)); // Requires hex crate in Cargo.toml
-        Bytes::from(hologram_data.as_bytes())
+    fn execute_compliance_batch(env: Env, caller: Address, entries: Vec<(Address, bool, Symbol, u32)>) -> Result<(), PiCoinError> {
+        if entries.is_empty() || entries.len() > MAX_COMPLIANCE_BATCH {
+            return Err(PiCoinError::InvalidInput);
+        }
+        
+        let mut registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(PiCoinError::NotFound)?;
+        let mut overrides: Vec<AiOverrideRecord> = env.storage().persistent().get(&DataKey::AiOverrideLog).unwrap_or(Vec::new(&env));
+        let mut users = Vec::new(&env);
+        let mut granted = 0u32;
+        for (user, kyc_verified, country_code, risk_score) in entries.iter() {
+            let (compliance, override_record) = Self::assess_compliance(&env, &user, kyc_verified, country_code, risk_score);
+            if let Some(record) = override_record {
+                overrides.push_back(record);
+                granted += 1;
+            }
+            registry.set(user.clone(), compliance);
+            users.push_back(user);
+        }
+        env.storage().persistent().set(&DataKey::ComplianceRegistry, &registry);
+        if granted > 0 {
+            env.storage().persistent().set(&DataKey::AiOverrideLog, &overrides);
+        }
+        
+        env.events().publish((Symbol::new(&env, "ComplianceBatchRegistered"), caller), ComplianceBatchEvent { users, overrides: granted });
+        log!(&env, "GodHead compliance registered for {} users", entries.len());
+        Ok(())
+    }
+    
+    // Compliance record for a registration, plus the audit record when the AI grants an override
+    fn assess_compliance(env: &Env, user: &Address, kyc_verified: bool, country_code: Symbol, risk_score: u32) -> (ComplianceData, Option<AiOverrideRecord>) {
+        let ai_score = Self::supreme_ai_predict(env, risk_score as u64);
+        let ai_override = ai_score < 20; // AI decides override
+        let record = if ai_override {
+            Some(AiOverrideRecord { user: user.clone(), input: risk_score as u64, score: ai_score, timestamp: env.ledger().timestamp() })
+        } else {
+            None
+        };
+        (ComplianceData { kyc_verified, country_code, legal_tender_status: kyc_verified, risk_score, ai_override }, record)
+    }
+    
+    // Audit trail of AI compliance overrides
+    pub fn get_ai_override_log(env: Env) -> Vec<AiOverrideRecord> {
+        env.storage().persistent().get(&DataKey::AiOverrideLog).unwrap_or(Vec::new(&env))
+    }
+    
+    // Jurisdiction-wide kill switch: while disabled, stored ai_override flags are ignored by every
+    // compliance check. Records and the override log are left as they are.
+    pub fn set_ai_override_enabled(env: Env, enabled: bool) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        env.storage().persistent().set(&DataKey::AiOverrideEnabled, &enabled);
+        let name = if enabled { "AiOverrideEnabled" } else { "AiOverrideDisabled" };
+        let overrides_on_record = Self::get_ai_override_log(env.clone()).len();
+        env.events().publish((Symbol::new(&env, name),), AiOverrideSwitchEvent { enabled, overrides_on_record });
+        Ok(())
+    }
+    
+    pub fn is_ai_override_enabled(env: Env) -> bool {
+        env.storage().persistent().get(&DataKey::AiOverrideEnabled).unwrap_or(true)
+    }
+    
+    // Whether a record's ai_override counts, given the kill switch
+    fn override_applies(env: &Env, compliance: &ComplianceData) -> bool {
+        compliance.ai_override && Self::is_ai_override_enabled(env.clone())
+    }
+    
+    // Read a user's compliance record; None means the conservative default applies
+    pub fn get_compliance(env: Env, user: Address) -> Option<ComplianceData> {
+        let registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).unwrap_or(Map::new(&env));
+        registry.get(user)
+    }
+    
+    // Offboard a user: drop their compliance record so mint/transfer fall back to the default
+    pub fn remove_compliance(env: Env, caller: Address, user: Address) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &caller)?;
+        Self::require_direct_threshold(&env)?;
+        Self::execute_compliance_removal(env, caller, user)
+    }
+    
+    fn execute_compliance_removal(env: Env, caller: Address, user: Address) -> Result<(), PiCoinError> {
+        let mut registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).ok_or(PiCoinError::NotFound)?;
+        if !registry.contains_key(user.clone()) {
+            return Err(PiCoinError::NotFound);
+        }
+        registry.remove(user.clone());
+        env.storage().persistent().set(&DataKey::ComplianceRegistry, &registry);
+        
+        env.events().publish((Symbol::new(&env, "ComplianceRemoved"), user.clone()), ComplianceRemovedEvent { user, removed_by: caller });
+        log!(&env, "GodHead compliance record removed");
+        Ok(())
+    }
+    
+    // Record a vote on a proposal (one per voter) and evolve the AI model from it
+    pub fn ai_governance_vote(env: Env, voter: Address, proposal: Symbol, vote: bool) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        voter.require_auth();
+        
+        let vote_key = DataKey::ProposalVote(proposal.clone(), voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(PiCoinError::AlreadyVoted);
+        }
+        env.storage().persistent().set(&vote_key, &vote);
+        
+        let tally_key = DataKey::ProposalTally(proposal.clone());
+        let mut tally: ProposalTally = env.storage().persistent().get(&tally_key).unwrap_or(ProposalTally { yes: 0, no: 0 });
+        if vote {
+            tally.yes = tally.yes.saturating_add(1);
+        } else {
+            tally.no = tally.no.saturating_add(1);
+        }
+        env.storage().persistent().set(&tally_key, &tally);
+        
+        // AI model evolution based on vote
+        if AiSimulation::load_layers(&env).is_empty() {
+            return Err(PiCoinError::NotFound);
+        }
+        let adjustment = if vote { 1i64 } else { 0i64 };
+        AiSimulation::evolve(&env, adjustment);
+        
+        // Log evolution
+        let mut log: Vec<AiEvolutionEntry> = env.storage().persistent().get(&DataKey::AiEvolutionLog).ok_or(PiCoinError::NotFound)?;
+        log.push_back(AiEvolutionEntry { vote, adjustment, timestamp: env.ledger().timestamp() });
+        let log = retention::retain(&env, &Self::get_ai_evolution_retention(env.clone()), log, |entry| entry.timestamp);
+        env.storage().persistent().set(&DataKey::AiEvolutionLog, &log);
+        retention::bump_total(&env, &DataKey::AiEvolutionTotal);
+        
+        env.events().publish((Symbol::new(&env, "GodHeadAIGovernanceVoted"), voter.clone()), GovernanceVoteEvent { voter, proposal, vote });
+        log!(&env, "GodHead AI governance voted and evolved");
+        Ok(())
+    }
+    
+    // Bounds on AiEvolutionLog; entries past them are pruned on the next append
+    pub fn set_ai_evolution_retention(env: Env, policy: RetentionPolicy) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if policy.max_entries == 0 || policy.max_age == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::AiEvolutionRetention, &policy);
+        Ok(())
+    }
+    
+    pub fn get_ai_evolution_retention(env: Env) -> RetentionPolicy {
+        env.storage().persistent().get(&DataKey::AiEvolutionRetention).unwrap_or(DEFAULT_RETENTION)
+    }
+    
+    // Retained AI evolution entries, oldest first
+    pub fn get_ai_evolution_log(env: Env) -> Vec<AiEvolutionEntry> {
+        env.storage().persistent().get(&DataKey::AiEvolutionLog).unwrap_or(Vec::new(&env))
+    }
+    
+    // AI evolutions logged since init, including pruned entries
+    pub fn get_ai_evolution_total(env: Env) -> u64 {
+        env.storage().persistent().get(&DataKey::AiEvolutionTotal).unwrap_or(0)
+    }
+    
+    // Current yes/no tally for a proposal (zeros if no votes yet)
+    pub fn get_proposal_tally(env: Env, proposal: Symbol) -> ProposalTally {
+        env.storage().persistent().get(&DataKey::ProposalTally(proposal)).unwrap_or(ProposalTally { yes: 0, no: 0 })
+    }
+    
+    // Propose a privileged call for multi-sig approval; returns the action id
+    pub fn propose_action(env: Env, proposer: Address, function: Symbol, args_hash: BytesN<32>) -> Result<u64, PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &proposer)?;
+        if !Self::is_approvable(&env, &function) {
+            return Err(PiCoinError::InvalidInput);
+        }
+        
+        let action_id: u64 = env.storage().persistent().get(&DataKey::ActionNonce).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::ActionNonce, &(action_id + 1));
+        let action = PendingAction {
+            function: function.clone(),
+            args_hash,
+            proposer: proposer.clone(),
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            expires_at: env.ledger().timestamp() + ACTION_TTL,
+            executed: false,
+        };
+        env.storage().persistent().set(&DataKey::PendingAction(action_id), &action);
+        
+        env.events().publish((Symbol::new(&env, "ActionProposed"), proposer.clone()), ActionEvent { action_id, function, signer: proposer, approvals: 1 });
+        Ok(action_id)
+    }
+    
+    // Record a signer's approval of a pending action; returns the approval count
+    pub fn approve_action(env: Env, signer: Address, action_id: u64) -> Result<u32, PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &signer)?;
+        let mut action = Self::open_action(&env, action_id)?;
+        if action.approvals.contains(&signer) {
+            return Err(PiCoinError::AlreadyVoted);
+        }
+        action.approvals.push_back(signer.clone());
+        env.storage().persistent().set(&DataKey::PendingAction(action_id), &action);
+        
+        let approvals = action.approvals.len();
+        env.events().publish((Symbol::new(&env, "ActionApproved"), signer.clone()), ActionEvent { action_id, function: action.function, signer, approvals });
+        Ok(approvals)
+    }
+    
+    // Run an action once MultiSigThreshold distinct signers have approved it. `args` must hash to
+    // the proposed args_hash and match the function's parameters (without env).
+    pub fn execute_action(env: Env, caller: Address, action_id: u64, args: Vec<Val>) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &caller)?;
+        let mut action = Self::open_action(&env, action_id)?;
+        let threshold: u32 = env.storage().persistent().get(&DataKey::MultiSigThreshold).ok_or(PiCoinError::NotFound)?;
+        if action.approvals.len() < threshold {
+            return Err(PiCoinError::Unauthorized);
+        }
+        let args_hash: BytesN<32> = crypto::sha256(&env, &args.clone().to_xdr(&env)).into();
+        if args_hash != action.args_hash {
+            return Err(PiCoinError::InvalidInput);
+        }
+        action.executed = true;
+        env.storage().persistent().set(&DataKey::PendingAction(action_id), &action);
+        
+        let function = action.function.clone();
+        if function == Symbol::new(&env, "mint") {
+            let to: Address = Self::action_arg(&env, &args, 0)?;
+            let amount: u64 = Self::action_arg(&env, &args, 1)?;
+            let source: Symbol = Self::action_arg(&env, &args, 2)?;
+            Self::enter_guard(&env)?;
+            let result = Self::execute_mint(env.clone(), to.clone(), amount, source);
+            Self::exit_guard(&env);
+            Self::log_success(&env, "mint", &to, amount, &result);
+            result?;
+        } else if function == Symbol::new(&env, "register_compliance") {
+            Self::execute_compliance(
+                env.clone(),
+                Self::action_arg(&env, &args, 0)?,
+                Self::action_arg(&env, &args, 1)?,
+                Self::action_arg(&env, &args, 2)?,
+                Self::action_arg(&env, &args, 3)?,
+            )?;
+        } else if function == Symbol::new(&env, "batch_register_compliance") {
+            Self::execute_compliance_batch(env.clone(), Self::action_arg(&env, &args, 0)?, Self::action_arg(&env, &args, 1)?)?;
+        } else if function == Symbol::new(&env, "remove_compliance") {
+            Self::execute_compliance_removal(env.clone(), Self::action_arg(&env, &args, 0)?, Self::action_arg(&env, &args, 1)?)?;
+        } else {
+            Self::execute_oracle_update(env.clone(), Self::action_arg(&env, &args, 0)?, Self::action_arg(&env, &args, 1)?)?;
+        }
+        
+        env.events().publish((Symbol::new(&env, "ActionExecuted"), caller.clone()), ActionEvent { action_id, function, signer: caller, approvals: action.approvals.len() });
+        Ok(())
+    }
+    
+    pub fn get_action(env: Env, action_id: u64) -> Option<PendingAction> {
+        env.storage().persistent().get(&DataKey::PendingAction(action_id))
+    }
+    
+    // Update oracle feed eternally
+    // Returns Ok(false) when the price falls outside the asset's FeedBounds: the feed keeps its
+    // previous value and a FeedRejected event records why.
+    pub fn update_oracle_feed(env: Env, asset: Symbol, price: u64) -> Result<bool, PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        Self::require_direct_threshold(&env)?;
+        Self::execute_oracle_update(env, asset, price)
+    }
+    
+    fn execute_oracle_update(env: Env, asset: Symbol, price: u64) -> Result<bool, PiCoinError> {
+        let mut oracles: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleFeeds).ok_or(PiCoinError::NotFound)?;
+        let previous = oracles.get(asset.clone());
+        
+        let bounds = Self::get_feed_bounds(env.clone(), asset.clone());
+        let reason = if price < bounds.min_price {
+            Some("below_min")
+        } else if price > bounds.max_price {
+            Some("above_max")
+        } else if previous.map_or(false, |p| price.abs_diff(p) as u128 * 10_000 > p as u128 * bounds.max_change_bps as u128) {
+            Some("max_change")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            log!(&env, "GodHead oracle feed for {} rejected: {}", asset, reason);
+            env.events().publish((Symbol::new(&env, "FeedRejected"),), FeedRejectedEvent { asset, price, previous, reason: Symbol::new(&env, reason) });
+            return Ok(false);
+        }
+        
+        oracles.set(asset.clone(), price);
+        env.storage().persistent().set(&DataKey::OracleFeeds, &oracles);
+        
+        log!(&env, "GodHead oracle feed updated eternally for {}", asset);
+        env.events().publish((Symbol::new(&env, "GodHeadOracleUpdated"),), OracleUpdateEvent { asset, price });
+        Ok(true)
+    }
+    
+    // Set the accepted range and per-update change limit for an asset's feed
+    pub fn set_feed_bounds(env: Env, asset: Symbol, bounds: FeedBounds) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if bounds.min_price == 0 || bounds.min_price > bounds.max_price || bounds.max_change_bps == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::FeedBounds(asset.clone()), &bounds);
+        log!(&env, "GodHead feed bounds set for {}", asset);
+        Ok(())
+    }
+    
+    // Point the contract at the ecosystem PauseRegistry; clears cached answers from a previous one
+    pub fn set_pause_registry(env: Env, registry: Address) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        env.storage().persistent().set(&DataKey::PauseRegistry, &registry);
+        env.storage().persistent().remove(&DataKey::PauseCache(Symbol::new(&env, "pi_coin")));
+        log!(&env, "GodHead pause registry set");
+        Ok(())
+    }
+    
+    // Point the contract at the OracleContract whose circuit breaker it honours
+    pub fn set_oracle(env: Env, oracle: Address) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        env.storage().persistent().set(&DataKey::Oracle, &oracle);
+        log!(&env, "GodHead oracle set");
+        Ok(())
+    }
+    
+    pub fn get_oracle(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Oracle)
+    }
+    
+    pub fn get_feed_bounds(env: Env, asset: Symbol) -> FeedBounds {
+        env.storage().persistent().get(&DataKey::FeedBounds(asset)).unwrap_or(DEFAULT_FEED_BOUNDS)
+    }
+    
+    // Reconcile CurrentSupply with the asset contract's issued total
+    pub fn reconcile_supply(env: Env) -> Result<i128, PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::reconcile_supply_unguarded(env.clone());
+        Self::exit_guard(&env);
+        result
+    }
+    
+    fn reconcile_supply_unguarded(env: Env) -> Result<i128, PiCoinError> {
+        Self::require_multi_sig(&env)?;
+        
+        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)?;
+        let total_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "total_supply"), Vec::new(&env));
+        let issued: i128 = match total_result {
+            Ok(val) => val.try_into().map_err(|_| PiCoinError::CallFailed)?,
+            Err(_) => return Err(PiCoinError::CallFailed),
+        };
+        if issued < 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        let issued = issued / ASSET_UNIT;
+        
+        let recorded: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(PiCoinError::NotFound)?;
+        let delta = issued - recorded as i128;
+        if delta != 0 {
+            let issued = u64::try_from(issued).map_err(|_| PiCoinError::SupplyExceeded)?;
+            Self::set_current_supply(&env, issued)?;
+            env.events().publish((Symbol::new(&env, "SupplyReconciled"),), SupplyReconciledEvent { previous: recorded, current: issued, delta });
+            log!(&env, "GodHead supply reconciled from {} to {}", recorded, issued);
+        }
+        Ok(delta)
+    }
+    
+    // Configure whether transfers stop during a severe depeg, and what counts as severe
+    pub fn set_breach_policy(env: Env, block_transfers: bool, severe_deviation: u64) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if severe_deviation == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::BreachPolicy, &BreachPolicy { block_transfers, severe_deviation });
+        log!(&env, "GodHead breach policy: block transfers {}, severe deviation {}", block_transfers, severe_deviation);
+        Ok(())
+    }
+    
+    pub fn get_breach_policy(env: Env) -> BreachPolicy {
+        env.storage().persistent().get(&DataKey::BreachPolicy).unwrap_or(BreachPolicy { block_transfers: true, severe_deviation: DEFAULT_SEVERE_DEVIATION })
+    }
+    
+    // Record backing assets. Amounts are the asset's value in whole USD.
+    pub fn deposit_reserve(env: Env, asset: Symbol, amount: i128) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if amount <= 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        let mut reserves = Self::get_reserves(env.clone());
+        let held = reserves.get(asset.clone()).unwrap_or(0);
+        reserves.set(asset.clone(), held.checked_add(amount).ok_or(PiCoinError::InvalidInput)?);
+        env.storage().persistent().set(&DataKey::Reserves, &reserves);
+        
+        let ratio_bps = Self::collateralization_ratio(env.clone())?;
+        env.events().publish((Symbol::new(&env, "ReserveDeposited"),), ReserveEvent { asset, amount, ratio_bps });
+        log!(&env, "GodHead reserve deposit {}; collateralization {} bps", amount, ratio_bps);
+        Ok(())
+    }
+    
+    // Release backing assets; fails with ReserveFloorBreached if the ratio would drop below the floor
+    pub fn withdraw_reserve(env: Env, asset: Symbol, amount: i128) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if amount <= 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        let mut reserves = Self::get_reserves(env.clone());
+        let held = reserves.get(asset.clone()).unwrap_or(0);
+        if held < amount {
+            return Err(PiCoinError::InsufficientBalance);
+        }
+        if held == amount {
+            reserves.remove(asset.clone());
+        } else {
+            reserves.set(asset.clone(), held - amount);
+        }
+        env.storage().persistent().set(&DataKey::Reserves, &reserves);
+        
+        let ratio_bps = Self::collateralization_ratio(env.clone())?;
+        if ratio_bps < Self::get_reserve_floor(env.clone()) {
+            return Err(PiCoinError::ReserveFloorBreached);
+        }
+        env.events().publish((Symbol::new(&env, "ReserveWithdrawn"),), ReserveEvent { asset, amount, ratio_bps });
+        log!(&env, "GodHead reserve withdrawal {}; collateralization {} bps", amount, ratio_bps);
+        Ok(())
+    }
+    
+    pub fn get_reserves(env: Env) -> Map<Symbol, i128> {
+        env.storage().persistent().get(&DataKey::Reserves).unwrap_or(Map::new(&env))
+    }
+    
+    // Total reserves over the USD value of CurrentSupply at the peg, in basis points
+    // (10,000 = fully backed). u32::MAX while nothing is in circulation.
+    pub fn collateralization_ratio(env: Env) -> Result<u32, PiCoinError> {
+        let current_supply: u64 = env.storage().persistent().get(&DataKey::CurrentSupply).unwrap_or(0);
+        let liabilities = current_supply as i128 * Self::get_peg(env.clone())? as i128;
+        if liabilities == 0 {
+            return Ok(u32::MAX);
+        }
+        let total: i128 = Self::get_reserves(env).values().iter().sum();
+        Ok((total.saturating_mul(10_000) / liabilities).clamp(0, u32::MAX as i128) as u32)
+    }
+    
+    // Minimum collateralization withdraw_reserve must leave, in basis points
+    pub fn set_reserve_floor(env: Env, floor_bps: u32) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        env.storage().persistent().set(&DataKey::ReserveFloor, &floor_bps);
+        log!(&env, "GodHead reserve floor set to {} bps", floor_bps);
+        Ok(())
+    }
+    
+    pub fn get_reserve_floor(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::ReserveFloor).unwrap_or(DEFAULT_RESERVE_FLOOR_BPS)
+    }
+    
+    // Reserve asset redeem pays out, and the token contract (held by this contract) it is paid from
+    pub fn set_redemption_asset(env: Env, asset: Symbol, token: Address) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        env.storage().persistent().set(&DataKey::RedemptionAsset, &(asset.clone(), token));
+        log!(&env, "GodHead redemption asset set to {}", asset);
+        Ok(())
+    }
+    
+    pub fn get_redemption_asset(env: Env) -> Option<(Symbol, Address)> {
+        env.storage().persistent().get(&DataKey::RedemptionAsset)
+    }
+    
+    // Burn PI and pay out its value at the peg from the redemption asset's reserve.
+    // Fails with CircuitBreakerTripped while the configured oracle's breaker is tripped and with
+    // InsufficientReserves when the reserve cannot cover amount * peg.
+    pub fn redeem(env: Env, from: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
+        Self::enter_guard(&env)?;
+        let result = Self::redeem_unguarded(env.clone(), from.clone(), amount, coin_id);
+        Self::exit_guard(&env);
+        Self::log_success(&env, "redeem", &from, amount, &result);
+        result
+    }
+    
+    fn redeem_unguarded(env: Env, from: Address, amount: u64, coin_id: BytesN<32>) -> Result<(), PiCoinError> {
+        Self::check_circuit_breaker(&env)?;
+        let (asset, token) = Self::get_redemption_asset(env.clone()).ok_or(PiCoinError::NotFound)?;
+        let owed = (amount as i128).checked_mul(Self::get_peg(env.clone())? as i128).ok_or(PiCoinError::InvalidInput)?;
+        let mut reserves = Self::get_reserves(env.clone());
+        let held = reserves.get(asset.clone()).unwrap_or(0);
+        if held < owed {
+            return Err(PiCoinError::InsufficientReserves);
+        }
+        
+        Self::burn_unguarded(env.clone(), from.clone(), amount, coin_id.clone())?;
+        
+        if held == owed {
+            reserves.remove(asset.clone());
+        } else {
+            reserves.set(asset.clone(), held - owed);
+        }
+        env.storage().persistent().set(&DataKey::Reserves, &reserves);
+        
+        // Reserve tokens are Stellar assets, so whole USD scale by the same 10^DECIMALS as PI
+        let payout = owed.checked_mul(ASSET_UNIT).ok_or(PiCoinError::InvalidInput)?;
+        let transfer_result: Result<Val, Error> = env.try_call(token, Symbol::new(&env, "transfer"), Vec::from_array(&env, [env.current_contract_address().into(), from.clone().into(), payout.into()]));
+        if transfer_result.is_err() {
+            return Err(PiCoinError::CallFailed);
+        }
+        
+        env.events().publish((Symbol::new(&env, "Redeemed"), from), RedeemedEvent { amount, coin_id, asset, released: owed });
+        Ok(())
+    }
+    
+    // Read-only self-check for auditors: named pass/fail for each core invariant.
+    // Coin amounts are checked for the first INVARIANT_SCAN_LIMIT coins; enumerate_coins covers the rest.
+    pub fn check_invariants(env: Env) -> Vec<(Symbol, bool)> {
+        let total_supply: Option<u64> = env.storage().persistent().get(&DataKey::TotalSupply);
+        let current_supply: Option<u64> = env.storage().persistent().get(&DataKey::CurrentSupply);
+        let supply_ok = matches!((current_supply, total_supply), (Some(current), Some(total)) if current <= total);
+        
+        let oracles: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleFeeds).unwrap_or(Map::new(&env));
+        let pi = Symbol::new(&env, "PI");
+        let price = oracles.get(pi.clone()).unwrap_or(314159);
+        let bounds = Self::get_feed_bounds(env.clone(), pi);
+        let peg_ok = price >= bounds.min_price && price <= bounds.max_price;
+        
+        let supply = current_supply.unwrap_or(0);
+        let (coin_ids, _) = Self::enumerate_coins(env.clone(), 0, INVARIANT_SCAN_LIMIT);
+        let coins_ok = coin_ids.iter().all(|coin_id| {
+            let coin: Option<PiCoin> = env.storage().persistent().get(&coin_id);
+            coin.map_or(true, |coin| coin.amount <= supply)
+        });
+        
+        let floor = Self::get_reserve_floor(env.clone());
+        let reserves_ok = matches!(Self::collateralization_ratio(env.clone()), Ok(ratio) if ratio >= floor);
+        
+        Vec::from_array(&env, [
+            (Symbol::new(&env, "supply_within_total"), supply_ok),
+            (Symbol::new(&env, "peg_feed_in_bounds"), peg_ok),
+            (Symbol::new(&env, "coins_within_supply"), coins_ok),
+            (Symbol::new(&env, "reserves_above_floor"), reserves_ok),
+            (Symbol::new(&env, "no_frozen_allowances"), Self::frozen_allowances_ok(&env)),
+        ])
+    }
+    
+    // No frozen holder keeps a live allowance. Scans the first INVARIANT_SCAN_LIMIT holders; each one
+    // the asset contract reports unauthorized has its allowances to this contract and to the other
+    // scanned holders read, up to INVARIANT_SCAN_LIMIT allowance reads in total. A failed read fails the check.
+    fn frozen_allowances_ok(env: &Env) -> bool {
+        let asset_id: Address = match env.storage().persistent().get(&DataKey::AssetId) {
+            Some(asset_id) => asset_id,
+            None => return true, // No asset contract, so no allowances
+        };
+        let (holders, _) = Self::enumerate_holders(env.clone(), 0, INVARIANT_SCAN_LIMIT);
+        let mut spenders = holders.clone();
+        spenders.push_front(env.current_contract_address());
+        
+        let mut reads = 0u32;
+        for holder in holders.iter() {
+            let args: Vec<Val> = Vec::from_array(env, [holder.into_val(env)]);
+            match env.try_invoke_contract::<bool, Error>(&asset_id, &Symbol::new(env, "authorized"), args) {
+                Ok(Ok(true)) => continue,
+                Ok(Ok(false)) => {}
+                _ => return false,
+            }
+            for spender in spenders.iter() {
+                if spender == holder {
+                    continue;
+                }
+                if reads >= INVARIANT_SCAN_LIMIT {
+                    return true;
+                }
+                reads += 1;
+                let args: Vec<Val> = Vec::from_array(env, [holder.into_val(env), spender.into_val(env)]);
+                match env.try_invoke_contract::<i128, Error>(&asset_id, &Symbol::new(env, "allowance"), args) {
+                    Ok(Ok(0)) => {}
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
+    
+    // Storage schema version of this deployment
+    pub fn version(env: Env) -> u32 {
+        env.storage().persistent().get(&DataKey::SchemaVersion).unwrap_or(1)
+    }
+    
+    // Replace the contract code. Requires a governance proposal whose description is the new wasm
+    // hash, that has passed, and whose voting deadline is at least UPGRADE_TIMELOCK in the past.
+    // The proposal is marked executed so it authorizes one upgrade only.
+    pub fn upgrade(env: Env, proposal_id: BytesN<32>, new_wasm_hash: BytesN<32>) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        let proposal = GovernanceContract::get_proposal(env.clone(), proposal_id.clone()).map_err(|_| PiCoinError::NotFound)?;
+        if proposal.description != Bytes::from(new_wasm_hash.clone()) {
+            return Err(PiCoinError::InvalidInput);
+        }
+        if GovernanceContract::status_of(&env, &proposal) != ProposalStatus::Passed {
+            return Err(PiCoinError::Unauthorized);
+        }
+        if env.ledger().timestamp() < proposal.deadline.saturating_add(UPGRADE_TIMELOCK) {
+            return Err(PiCoinError::TimelockActive);
+        }
+        
+        GovernanceContract::mark_executed(&env, &proposal_id);
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        env.events().publish((Symbol::new(&env, "Upgraded"),), UpgradedEvent { new_wasm_hash, proposal_id });
+        log!(&env, "GodHead contract upgraded by governance");
+        Ok(())
+    }
+    
+    // Apply forward-only storage migrations up to SCHEMA_VERSION; a no-op when already current
+    pub fn migrate(env: Env, caller: Address) -> Result<u32, PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_signer(&env, &caller)?;
+        
+        let from_version = Self::version(env.clone());
+        let mut version = from_version;
+        while version < SCHEMA_VERSION {
+            match version {
+                1 => {
+                    // Persist legacy flat weights as layers and drop the old key
+                    let layers = AiSimulation::load_layers(&env);
+                    env.storage().persistent().set(&DataKey::NeuralLayers, &layers);
+                    env.storage().persistent().remove(&DataKey::NeuralWeights);
+                }
+                2 => {
+                    // Sources allowed before mint ceilings existed get the default
+                    let allowed: Vec<Symbol> = env.storage().persistent().get(&DataKey::AllowedSources).ok_or(PiCoinError::NotFound)?;
+                    let mut limits: Map<Symbol, MintRateLimit> = env.storage().persistent().get(&DataKey::MintRateLimits).unwrap_or(Map::new(&env));
+                    for source in allowed.iter() {
+                        if !limits.contains_key(source.clone()) {
+                            limits.set(source, MintRateLimit { max_amount: DEFAULT_MINT_CEILING, window_secs: DEFAULT_MINT_WINDOW_SECS });
+                        }
+                    }
+                    env.storage().persistent().set(&DataKey::MintRateLimits, &limits);
+                }
+                3 => {
+                    // Defaults, keeping any burn cutoff configured under the legacy key
+                    let mut thresholds = DEFAULT_AI_THRESHOLDS;
+                    if let Some(burn) = env.storage().persistent().get(&DataKey::BurnAiThreshold) {
+                        thresholds.burn = burn;
+                    }
+                    env.storage().persistent().set(&DataKey::AiThresholds, &thresholds);
+                    env.storage().persistent().remove(&DataKey::BurnAiThreshold);
+                }
+                4 => {
+                    // Untimed text entries become typed entries stamped now; a legacy count cap is kept
+                    let legacy: Vec<Bytes> = env.storage().persistent().get(&DataKey::AiEvolutionLog).unwrap_or(Vec::new(&env));
+                    let mut log = Vec::new(&env);
+                    for entry in legacy.iter() {
+                        let vote = entry.len() > 5 && entry.get(5) == Some(b't'); // "Vote true ..." / "Vote false ..."
+                        log.push_back(AiEvolutionEntry { vote, adjustment: vote as i64, timestamp: env.ledger().timestamp() });
+                    }
+                    env.storage().persistent().set(&DataKey::AiEvolutionLog, &log);
+                    if let Some(max_entries) = env.storage().persistent().get::<_, u32>(&DataKey::AiEvolutionLogCap) {
+                        env.storage().persistent().set(&DataKey::AiEvolutionRetention, &RetentionPolicy { max_entries, ..DEFAULT_RETENTION });
+                    }
+                    env.storage().persistent().remove(&DataKey::AiEvolutionLogCap);
+                }
+                _ => return Err(PiCoinError::InvalidInput),
+            }
+            version += 1;
+        }
+        if version == from_version {
+            return Ok(version);
+        }
+        env.storage().persistent().set(&DataKey::SchemaVersion, &version);
+        
+        env.events().publish((Symbol::new(&env, "SchemaMigrated"), caller), SchemaMigratedEvent { from_version, to_version: version });
+        log!(&env, "GodHead storage migrated from version {} to {}", from_version, version);
+        Ok(version)
+    }
+    
+    // Configure the AI rejection cutoffs for mint, transfer, burn and bridge
+    pub fn set_ai_thresholds(env: Env, thresholds: AiThresholds) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if thresholds.mint > 100 || thresholds.transfer > 100 || thresholds.burn > 100 || thresholds.bridge > 100 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        env.storage().persistent().set(&DataKey::AiThresholds, &thresholds);
+        env.events().publish((Symbol::new(&env, "AiThresholdsUpdated"),), thresholds);
+        Ok(())
+    }
+    
+    pub fn get_ai_thresholds(env: Env) -> AiThresholds {
+        env.storage().persistent().get(&DataKey::AiThresholds).unwrap_or(DEFAULT_AI_THRESHOLDS)
+    }
+    
+    // Set a source's mint ceiling: at most `max_amount` per `window_secs`
+    pub fn set_mint_rate_limit(env: Env, source: Symbol, max_amount: u64, window_secs: u64) -> Result<(), PiCoinError> {
+        Self::check_guard(&env)?;
+        Self::require_multi_sig(&env)?;
+        if window_secs == 0 {
+            return Err(PiCoinError::InvalidInput);
+        }
+        let mut limits: Map<Symbol, MintRateLimit> = env.storage().persistent().get(&DataKey::MintRateLimits).unwrap_or(Map::new(&env));
+        limits.set(source.clone(), MintRateLimit { max_amount, window_secs });
+        env.storage().persistent().set(&DataKey::MintRateLimits, &limits);
+        log!(&env, "GodHead mint ceiling for {} set to {} per {}s", source, max_amount, window_secs);
+        Ok(())
+    }
+    
+    // Mint ceiling for a source; None means the source is not rate limited
+    pub fn get_mint_rate_limit(env: Env, source: Symbol) -> Option<MintRateLimit> {
+        let limits: Map<Symbol, MintRateLimit> = env.storage().persistent().get(&DataKey::MintRateLimits).unwrap_or(Map::new(&env));
+        limits.get(source)
+    }
+    
+    // Supply cap; mints that would take the current supply past it fail with SupplyExceeded
+    pub fn get_total_supply(env: Env) -> Result<u64, PiCoinError> {
+        env.storage().persistent().get(&DataKey::TotalSupply).ok_or(PiCoinError::NotFound)
+    }
+    
+    // Peg value enforced by mint
+    pub fn get_peg(env: Env) -> Result<u64, PiCoinError> {
+        env.storage().persistent().get(&DataKey::PegOracle).ok_or(PiCoinError::NotFound)
+    }
+    
+    // Coin record by id; None if no coin was ever stored under it
+    pub fn get_coin(env: Env, coin_id: BytesN<32>) -> Option<PiCoin> {
+        env.storage().persistent().get(&coin_id)
+    }
+    
+    // Page through a coin's transfers, oldest first; only the last MAX_PROVENANCE_ENTRIES are kept
+    pub fn get_coin_provenance(env: Env, coin_id: BytesN<32>, cursor: u32, limit: u32) -> (Vec<ProvenanceEntry>, Option<u32>) {
+        let trail: Vec<ProvenanceEntry> = env.storage().persistent().get(&DataKey::Provenance(coin_id)).unwrap_or(Vec::new(&env));
+        Self::page(&env, trail, cursor, limit)
+    }
+    
+    // Page through every minted coin id (vault order), including fully burned coins
+    pub fn enumerate_coins(env: Env, cursor: u32, limit: u32) -> (Vec<BytesN<32>>, Option<u32>) {
+        let vault: Map<BytesN<32>, Bytes> = env.storage().persistent().get(&DataKey::HolographicVault).unwrap_or(Map::new(&env));
+        Self::page(&env, vault.keys(), cursor, limit)
+    }
+    
+    // Page through users with a compliance record
+    pub fn enumerate_compliance(env: Env, cursor: u32, limit: u32) -> (Vec<Address>, Option<u32>) {
+        let registry: Map<Address, ComplianceData> = env.storage().persistent().get(&DataKey::ComplianceRegistry).unwrap_or(Map::new(&env));
+        Self::page(&env, registry.keys(), cursor, limit)
+    }
+    
+    // Symbol, decimal precision of the underlying asset, and the peg in USD per PI
+    pub fn get_metadata(env: Env) -> Result<TokenMetadata, PiCoinError> {
+        Ok(TokenMetadata { symbol: Symbol::new(&env, "PI"), decimals: DECIMALS, peg_target: Self::get_peg(env)? })
+    }
+    
+    // Get current supply safely
+    pub fn get_current_supply(env: Env) -> Result<u64, PiCoinError> {
+        env.storage().persistent().get(&DataKey::CurrentSupply).ok_or(PiCoinError::NotFound)
+    }
+    
+    // Stellar asset contract holding PI balances; stake and pay PI through it
+    pub fn get_asset_id(env: Env) -> Result<Address, PiCoinError> {
+        env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)
+    }
+    
+    // Balance of (query asset contract properly)
+    pub fn balance_of(env: Env, account: Address) -> Result<u64, PiCoinError> {
+        let asset_id: Address = env.storage().persistent().get(&DataKey::AssetId).ok_or(PiCoinError::NotFound)?;
+        let balance_result: Result<Val, Error> = env.try_call(asset_id, Symbol::new(&env, "balance"), Vec::from_array(&env, [account.into()]));
+        match balance_result {
+            Ok(val) => {
+                let balance: i128 = val.try_into().map_err(|_| PiCoinError::CallFailed)?;
+                u64::try_from(balance / ASSET_UNIT).map_err(|_| PiCoinError::CallFailed)
+            }
+            Err(_) => Err(PiCoinError::CallFailed),
+        }
+    }
+    
+    // Total amount minted from one source
+    pub fn get_mint_by_source(env: Env, source: Symbol) -> u64 {
+        Self::get_all_mint_stats(env).get(source).unwrap_or(0)
+    }
+    
+    // Total amount minted per source
+    pub fn get_all_mint_stats(env: Env) -> Map<Symbol, u64> {
+        env.storage().persistent().get(&DataKey::MintBySource).unwrap_or(Map::new(&env))
+    }
+    
+    // Number of mints per allowed source, for provenance dashboards
+    pub fn get_mint_counts(env: Env) -> Map<Symbol, u64> {
+        env.storage().persistent().get(&DataKey::MintCountBySource).unwrap_or(Map::new(&env))
+    }
+    
+    // Number of distinct addresses holding at least one coin
+    pub fn holder_count(env: Env) -> u32 {
+        let holders: Map<Address, u32> = env.storage().persistent().get(&DataKey::HolderCoinCounts).unwrap_or(Map::new(&env));
+        holders.len()
+    }
+    
+    // Page through holders in index order; next cursor is None after the last page
+    pub fn enumerate_holders(env: Env, cursor: u32, limit: u32) -> (Vec<Address>, Option<u32>) {
+        let holders: Map<Address, u32> = env.storage().persistent().get(&DataKey::HolderCoinCounts).unwrap_or(Map::new(&env));
+        Self::page(&env, holders.keys(), cursor, limit)
+    }
+    
+    // Index-cursor page over keys shared by the enumerate_* getters
+    fn page<T: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, keys: Vec<T>, cursor: u32, limit: u32) -> (Vec<T>, Option<u32>) {
+        let end = cursor.saturating_add(limit).min(keys.len());
+        let mut page = Vec::new(env);
+        for i in cursor..end {
+            page.push_back(keys.get(i).unwrap());
+        }
+        let next = if end < keys.len() { Some(end) } else { None };
+        (page, next)
+    }
+    
+    // Get holographic vault entry safely
+    pub fn get_holographic_vault(env: Env, key: BytesN<32>) -> Result<Bytes, PiCoinError> {
+        let vault: Map<BytesN<32>, Bytes> = env.storage().persistent().get(&DataKey::HolographicVault).ok_or(PiCoinError::NotFound)?;
+        vault.get(key).ok_or(PiCoinError::NotFound)
+    }
+    
+    // Check a presented hologram against the one derived from a stored coin's hash (constant-time compare)
+    pub fn verify_hologram(env: Env, coin_id: BytesN<32>, hologram: Bytes) -> bool {
+        if !env.storage().persistent().has(&coin_id) {
+            return false;
+        }
+        let expected = Self::generate_hologram(&env, &coin_id.to_array());
+        if expected.len() != hologram.len() {
+            return false;
+        }
+        let mut diff = 0u8;
+        for (a, b) in expected.iter().zip(hologram.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+    
+    // Current AI prediction for an input (read-only; same path every internal check uses)
+    pub fn get_ai_prediction(env: Env, input: u64) -> u64 {
+        Self::supreme_ai_predict(&env, input)
+    }
+    
+    // Whole PI to raw asset units
+    fn to_asset_amount(amount: u64) -> i128 {
+        amount as i128 * ASSET_UNIT
+    }
+    
+    // Supreme AI prediction (bounded 0-99); delegates to the AiSimulation network
+    fn supreme_ai_predict(env: &Env, input: u64) -> u64 {
+        let input = input.min(i64::MAX as u64) as i64;
+        AiSimulation::predict(env, input).clamp(0, 99) as u64
+    }
+    
+    // Evolve supreme AI safely; AiSimulation caps weights at +/-1000
+    fn evolve_supreme_ai(env: &Env) {
+        AiSimulation::evolve(env, 1);
+        log!(&env, "Supreme AI evolved safely");
+    }
+    
+    // Generate holographic data: "godhead_hologram_" followed by the lowercase hex of the coin hash
+    fn generate_hologram(env: &Env, hash: &[u8; 32]) -> Bytes {
+        const PREFIX: &[u8] = b"godhead_hologram_";
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut hologram = [0u8; PREFIX.len() + 64];
+        hologram[..PREFIX.len()].copy_from_slice(PREFIX);
+        for (i, byte) in hash.iter().enumerate() {
+            hologram[PREFIX.len() + 2 * i] = HEX[(byte >> 4) as usize];
+            hologram[PREFIX.len() + 2 * i + 1] = HEX[(byte & 0x0f) as usize];
+        }
+        Bytes::from_slice(env, &hologram)
+    }
+    
+    // Info record for a completed top-level call. A call that returns Err has every event it
+    // published rolled back, so its failure reaches callers as the typed error alone.
+    fn log_success<T>(env: &Env, operation: &str, subject: &Address, amount: u64, result: &Result<T, PiCoinError>) {
+        if result.is_ok() {
+            Self::log_outcome(env, operation, subject, amount, None);
+        }
+    }
+    
+    // Structured log for mint/transfer/burn outcomes. Rejections of the caller's request are
+    // "warn"; failures pointing at contract or peg health (supply, peg, breaker, calls) are "error".
+    // Failure records only persist inside a call that still succeeds, e.g. a batch_transfer slot.
+    fn log_outcome(env: &Env, operation: &str, subject: &Address, amount: u64, error: Option<PiCoinError>) {
+        let level = match error {
+            None => "info",
+            Some(PiCoinError::SupplyExceeded | PiCoinError::PegBreached | PiCoinError::CircuitBreakerTripped | PiCoinError::CallFailed | PiCoinError::Reentrant | PiCoinError::NotFound) => "error",
+            Some(_) => "warn",
+        };
+        let level = Symbol::new(env, level);
+        let record = LogRecord {
+            level: level.clone(),
+            operation: Symbol::new(env, operation),
+            subject: subject.clone(),
+            amount,
+            error_code: error.map_or(0, |err| err as u32),
+        };
+        env.events().publish((Symbol::new(env, "GodHeadLog"), level), record);
+    }
+    
+    // Holder index maintenance for mint/transfer/burn
+    fn add_holder_coin(env: &Env, holder: &Address) {
+        let mut holders: Map<Address, u32> = env.storage().persistent().get(&DataKey::HolderCoinCounts).unwrap_or(Map::new(env));
+        let count = holders.get(holder.clone()).unwrap_or(0);
+        holders.set(holder.clone(), count.saturating_add(1));
+        env.storage().persistent().set(&DataKey::HolderCoinCounts, &holders);
+    }
+    
+    // Append a transfer to the coin's trail, dropping the oldest hop once the cap is reached
+    fn record_provenance(env: &Env, coin_id: &BytesN<32>, from: &Address, to: &Address) {
+        let key = DataKey::Provenance(coin_id.clone());
+        let mut trail: Vec<ProvenanceEntry> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if trail.len() >= MAX_PROVENANCE_ENTRIES {
+            trail.pop_front();
+        }
+        trail.push_back(ProvenanceEntry { from: from.clone(), to: to.clone(), timestamp: env.ledger().timestamp() });
+        env.storage().persistent().set(&key, &trail);
+    }
+    
+    // Per-source mint statistics; amounts use checked arithmetic like the supply itself
+    fn record_mint_source(env: &Env, source: &Symbol, amount: u64) -> Result<(), PiCoinError> {
+        let mut totals: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::MintBySource).unwrap_or(Map::new(env));
+        let total = totals.get(source.clone()).unwrap_or(0).checked_add(amount).ok_or(PiCoinError::SupplyExceeded)?;
+        totals.set(source.clone(), total);
+        env.storage().persistent().set(&DataKey::MintBySource, &totals);
+        
+        let mut counts: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::MintCountBySource).unwrap_or(Map::new(env));
+        let count = counts.get(source.clone()).unwrap_or(0).checked_add(1).ok_or(PiCoinError::SupplyExceeded)?;
+        counts.set(source.clone(), count);
+        env.storage().persistent().set(&DataKey::MintCountBySource, &counts);
+        Ok(())
+    }
+    
+    // Transfer gate for the breach policy: tripped breaker first, then the PI feed's distance from the peg
+    fn check_breach_policy(env: &Env) -> Result<(), PiCoinError> {
+        let policy = Self::get_breach_policy(env.clone());
+        if !policy.block_transfers {
+            return Ok(());
+        }
+        Self::check_circuit_breaker(env)?;
+        let peg: u64 = env.storage().persistent().get(&DataKey::PegOracle).ok_or(PiCoinError::NotFound)?;
+        let oracles: Map<Symbol, u64> = env.storage().persistent().get(&DataKey::OracleFeeds).unwrap_or(Map::new(env));
+        let price = oracles.get(Symbol::new(env, "PI")).unwrap_or(peg);
+        if price.abs_diff(peg) >= policy.severe_deviation {
+            return Err(PiCoinError::PegBreached);
+        }
+        Ok(())
+    }
+    
+    // Asks the configured oracle whether its breaker is tripped; unset means never tripped.
+    // A failed call is treated as tripped so an unreachable oracle cannot open the gate.
+    fn check_circuit_breaker(env: &Env) -> Result<(), PiCoinError> {
+        let oracle: Address = match env.storage().persistent().get(&DataKey::Oracle) {
+            Some(oracle) => oracle,
+            None => return Ok(()),
+        };
+        match env.try_invoke_contract::<bool, Error>(&oracle, &Symbol::new(env, "is_circuit_breaker_tripped"), Vec::new(env)) {
+            Ok(Ok(false)) => Ok(()),
+            Ok(Ok(true)) => Err(PiCoinError::CircuitBreakerTripped),
+            _ => Err(PiCoinError::CallFailed),
+        }
+    }
+    
+    // Window counter after minting `amount` from `source`; the window restarts once
+    // `window_secs` have passed since it opened. None when the source has no ceiling.
+    fn next_mint_window(env: &Env, source: &Symbol, amount: u64) -> Result<Option<MintWindow>, PiCoinError> {
+        let limit = match Self::get_mint_rate_limit(env.clone(), source.clone()) {
+            Some(limit) => limit,
+            None => return Ok(None),
+        };
+        let now = env.ledger().timestamp();
+        let window = env.storage().persistent().get(&DataKey::MintWindow(source.clone())).unwrap_or(MintWindow { window_start: now, minted: 0 });
+        let window = if now.saturating_sub(window.window_start) >= limit.window_secs {
+            MintWindow { window_start: now, minted: 0 }
+        } else {
+            window
+        };
+        let minted = window.minted.checked_add(amount).ok_or(PiCoinError::MintRateExceeded)?;
+        if minted > limit.max_amount {
+            return Err(PiCoinError::MintRateExceeded);
+        }
+        Ok(Some(MintWindow { window_start: window.window_start, minted }))
+    }
+    
+    fn remove_holder_coin(env: &Env, holder: &Address) {
+        let mut holders: Map<Address, u32> = env.storage().persistent().get(&DataKey::HolderCoinCounts).unwrap_or(Map::new(env));
+        match holders.get(holder.clone()).unwrap_or(0) {
+            0 | 1 => { holders.remove(holder.clone()); }
+            count => holders.set(holder.clone(), count - 1),
+        }
+        env.storage().persistent().set(&DataKey::HolderCoinCounts, &holders);
+    }
+    
+    // Reentrancy guard: functions making external calls hold the lock for their whole body
+    // (wrappers clear it on every return path); other state-changing functions only check it.
+    // These are also the coin-moving functions, so they stop while the pause registry says so.
+    fn enter_guard(env: &Env) -> Result<(), PiCoinError> {
+        Self::check_guard(env)?;
+        Self::check_pause(env, "pi_coin")?;
+        env.storage().persistent().set(&DataKey::ReentrancyLock, &true);
+        Ok(())
+    }
+    
+    // Fails with Paused while the registry pauses `module`. Answers are cached for PAUSE_CACHE_TTL;
+    // an unreachable registry fails closed with CallFailed.
+    pub(crate) fn check_pause(env: &Env, module: &str) -> Result<(), PiCoinError> {
+        let registry: Address = match env.storage().persistent().get(&DataKey::PauseRegistry) {
+            Some(registry) => registry,
+            None => return Ok(()),
+        };
+        let module = Symbol::new(env, module);
+        let now = env.ledger().timestamp();
+        let cached: Option<(bool, u64)> = env.storage().persistent().get(&DataKey::PauseCache(module.clone()));
+        let paused = match cached {
+            Some((paused, fetched_at)) if now.saturating_sub(fetched_at) < PAUSE_CACHE_TTL => paused,
+            _ => {
+                let result: Result<Val, Error> = env.try_call(registry, Symbol::new(env, "is_paused"), Vec::from_array(env, [module.clone().into_val(env)]));
+                let paused = result.ok().and_then(|val| bool::try_from_val(env, &val).ok()).ok_or(PiCoinError::CallFailed)?;
+                env.storage().persistent().set(&DataKey::PauseCache(module), &(paused, now));
+                paused
+            }
+        };
+        if paused {
+            return Err(PiCoinError::Paused);
+        }
+        Ok(())
+    }
+    
+    fn exit_guard(env: &Env) {
+        env.storage().persistent().remove(&DataKey::ReentrancyLock);
+    }
+    
+    fn check_guard(env: &Env) -> Result<(), PiCoinError> {
+        if env.storage().persistent().get(&DataKey::ReentrancyLock).unwrap_or(false) {
+            return Err(PiCoinError::Reentrant);
+        }
+        Ok(())
+    }
+    
+    // Single write path for CurrentSupply; enforces CurrentSupply <= TotalSupply
+    fn set_current_supply(env: &Env, new_supply: u64) -> Result<(), PiCoinError> {
+        let total_supply: u64 = env.storage().persistent().get(&DataKey::TotalSupply).ok_or(PiCoinError::NotFound)?;
+        if new_supply > total_supply {
+            return Err(PiCoinError::SupplyExceeded);
+        }
+        env.storage().persistent().set(&DataKey::CurrentSupply, &new_supply);
+        Ok(())
+    }
+    
+    // Functions reachable through propose_action/execute_action
+    fn is_approvable(env: &Env, function: &Symbol) -> bool {
+        ["mint", "register_compliance", "batch_register_compliance", "remove_compliance", "update_oracle_feed"]
+            .iter()
+            .any(|name| *function == Symbol::new(env, name))
+    }
+    
+    // Approvable functions called directly act on one signer's authority, so the direct path is
+    // only open while MultiSigThreshold is 1; above that they run through execute_action.
+    fn require_direct_threshold(env: &Env) -> Result<(), PiCoinError> {
+        let threshold: u32 = env.storage().persistent().get(&DataKey::MultiSigThreshold).ok_or(PiCoinError::NotFound)?;
+        if threshold > 1 {
+            return Err(PiCoinError::Unauthorized);
+        }
+        Ok(())
+    }
+    
+    // Load an action that can still be approved or executed
+    fn open_action(env: &Env, action_id: u64) -> Result<PendingAction, PiCoinError> {
+        let action: PendingAction = env.storage().persistent().get(&DataKey::PendingAction(action_id)).ok_or(PiCoinError::NotFound)?;
+        if action.executed {
+            return Err(PiCoinError::InvalidInput);
+        }
+        if env.ledger().timestamp() > action.expires_at {
+            return Err(PiCoinError::DeadlineExpired);
+        }
+        Ok(action)
+    }
+    
+    fn action_arg<T: TryFromVal<Env, Val>>(env: &Env, args: &Vec<Val>, index: u32) -> Result<T, PiCoinError> {
+        let arg = args.get(index).ok_or(PiCoinError::InvalidInput)?;
+        T::try_from_val(env, &arg).map_err(|_| PiCoinError::InvalidInput)
+    }
+    
+    // Explicit-caller variant of require_multi_sig: caller must be a signer and authorize the call
+    fn require_signer(env: &Env, caller: &Address) -> Result<(), PiCoinError> {
+        caller.require_auth();
+        let signers: Vec<Address> = env.storage().persistent().get(&DataKey::MultiSigSigners).ok_or(PiCoinError::NotFound)?;
+        if !signers.contains(caller) {
+            return Err(PiCoinError::Unauthorized);
+        }
+        Ok(())
     }
     
     // Require multi-sig with threshold (enhanced with signature verification)
-    fn require_multi_sig(env: &Env) -> Result<(), u32> {
-        let signers: Vec<Address> = env.storage().persistent().get(&DataKey::MultiSigSigners).ok_or(ERR_NOT_FOUND)?;
-        let threshold: u32 = env.storage().persistent().get(&DataKey::MultiSigThreshold).ok_or(ERR_NOT_FOUND)?;
+    fn require_multi_sig(env: &Env) -> Result<(), PiCoinError> {
+        let signers: Vec<Address> = env.storage().persistent().get(&DataKey::MultiSigSigners).ok_or(PiCoinError::NotFound)?;
+        let threshold: u32 = env.storage().persistent().get(&DataKey::MultiSigThreshold).ok_or(PiCoinError::NotFound)?;
         let caller = env.invoker();
         if !signers.contains(&caller) {
-            return Err(ERR_UNAUTHORIZED);
+            return Err(PiCoinError::Unauthorized);
         }
         // Enhanced: In production, collect and verify Ed25519 signatures from signers
         // For now, basic check; integrate crypto::ed25519_verify for full multi-sig
@@ -4,14 +4,19 @@
 // to ensure the $314,159 peg is maintained without human intervention.
 // All operations are immutable, multi-sig secured, and AI-evolved for eternal reliability.
 
-#![no_std]
-
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, Bytes, log, events, Error};
 
 // Import from lib.rs for shared types and functions
-use crate::PiCoinContract; // Adjust import based on project structure
+use crate::{PiCoinContract, PiCoinError}; // Adjust import based on project structure
 use crate::DataKey; // Assuming DataKey is shared from lib.rs
 
+// Maximum AI adjustment of the raw median, in basis points (1%)
+const MAX_AI_ADJUSTMENT_BPS: u64 = 100;
+
+// Peg target ($314,159 in micro-units) and the absolute median deviation at which it counts as breached
+const PEG_TARGET: u64 = 314159;
+const PEG_BREACH_THRESHOLD: u64 = 1000;
+
 #[contracttype]
 #[derive(Clone)]
 pub struct OracleFeed {
@@ -24,9 +29,33 @@ pub struct OracleFeed {
 #[contracttype]
 pub enum OracleDataKey {
     Feeds,              // Map<Symbol, OracleFeed>
-    MedianPrice,        // Cached median price
+    MedianPrice,        // Cached raw median of verified feeds
+    EffectivePrice,     // Median after bounded AI adjustment
     AiPegPrediction,    // AI prediction for peg stability
     FeedSources,        // Vec<Symbol> of allowed sources
+    MinFeeds,           // Minimum fresh, verified feeds for a confident peg check
+    MaxFeedAge,         // Seconds after which a feed is stale
+    MaxFeedSpread,      // Max allowed spread between highest and lowest fresh feed
+    CircuitBreaker,     // bool; pauses peg-dependent operations when tripped
+    MinCollateralRatio, // u32 bps; check_peg reports unstable below it. 0 (default) ignores reserves
+    FeedReporters,      // Map<Symbol, Address>: the account that must authorize each source's updates
+    PegCheckers,        // Vec<Address> allowed to run check_peg besides feed reporters (e.g. stability)
+    PiCoin,             // Address of the PiCoinContract whose collateralization_ratio MinCollateralRatio checks
+}
+
+// What check_peg would conclude right now, for dry runs in other contracts
+#[contracttype]
+#[derive(Clone)]
+pub struct PegPreview {
+    pub spread_exceeded: bool, // check_peg would trip the circuit breaker
+    pub is_stable: bool,
+    pub ai_prediction: u64,
+}
+
+// Read-only outcome of a peg check, before any breaker or prediction writes
+pub(crate) enum PegAssessment {
+    SpreadExceeded { lowest: u64, highest: u64, spread: u64 },
+    Assessed { median_price: u64, deviation: u64, ai_prediction: u64, is_stable: bool },
 }
 
 #[contract]
@@ -41,21 +70,58 @@ impl OracleContract {
         
         env.storage().persistent().set(&OracleDataKey::Feeds, &Map::<Symbol, OracleFeed>::new(&env));
         env.storage().persistent().set(&OracleDataKey::MedianPrice, &314159u64); // Initial peg
+        env.storage().persistent().set(&OracleDataKey::EffectivePrice, &314159u64);
         env.storage().persistent().set(&OracleDataKey::AiPegPrediction, &50u64); // Neutral AI prediction
         env.storage().persistent().set(&OracleDataKey::FeedSources, &sources);
+        env.storage().persistent().set(&OracleDataKey::MinFeeds, &3u32);
+        env.storage().persistent().set(&OracleDataKey::MaxFeedAge, &3600u64); // 1 hour
+        env.storage().persistent().set(&OracleDataKey::MaxFeedSpread, &3141u64); // ~1% of peg
+        env.storage().persistent().set(&OracleDataKey::CircuitBreaker, &false);
         
         events::publish(&env, Symbol::new(&env, "GodHeadOracleInitialized"), sources);
         log!(&env, "GodHead Nexus Oracle initialized eternally with {} sources", sources.len());
         Ok(())
     }
     
-    // Update oracle feed with AI verification
+    // Bind the account whose auth update_feed requires for an allowed source (multi-sig)
+    pub fn set_feed_reporter(env: Env, source: Symbol, reporter: Address) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        let allowed_sources: Vec<Symbol> = env.storage().persistent().get(&OracleDataKey::FeedSources).ok_or(4)?; // ERR_NOT_FOUND
+        if !allowed_sources.contains(&source) {
+            return Err(3); // ERR_INVALID_INPUT
+        }
+        let mut reporters: Map<Symbol, Address> = env.storage().persistent().get(&OracleDataKey::FeedReporters).unwrap_or(Map::new(&env));
+        reporters.set(source.clone(), reporter.clone());
+        env.storage().persistent().set(&OracleDataKey::FeedReporters, &reporters);
+        
+        events::publish(&env, Symbol::new(&env, "FeedReporterSet"), (source, reporter));
+        Ok(())
+    }
+    
+    // Allow or revoke a non-reporter account, such as the stability contract, to run check_peg (multi-sig)
+    pub fn set_peg_checker(env: Env, checker: Address, allowed: bool) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        let mut checkers: Vec<Address> = env.storage().persistent().get(&OracleDataKey::PegCheckers).unwrap_or(Vec::new(&env));
+        match (checkers.first_index_of(&checker), allowed) {
+            (None, true) => checkers.push_back(checker),
+            (Some(index), false) => {
+                checkers.remove(index);
+            }
+            _ => {}
+        }
+        env.storage().persistent().set(&OracleDataKey::PegCheckers, &checkers);
+        Ok(())
+    }
+    
+    // Update oracle feed with AI verification; needs the auth of the source's registered reporter
     pub fn update_feed(env: Env, source: Symbol, price: u64) -> Result<(), u32> {
-        // Basic auth; in production, use signed feeds
         let allowed_sources: Vec<Symbol> = env.storage().persistent().get(&OracleDataKey::FeedSources).ok_or(4)?; // ERR_NOT_FOUND
         if !allowed_sources.contains(&source) {
             return Err(3); // ERR_INVALID_INPUT
         }
+        let reporters: Map<Symbol, Address> = env.storage().persistent().get(&OracleDataKey::FeedReporters).unwrap_or(Map::new(&env));
+        let reporter = reporters.get(source.clone()).ok_or(PiCoinError::Unauthorized as u32)?;
+        reporter.require_auth();
         
         let mut feeds: Map<Symbol, OracleFeed> = env.storage().persistent().get(&OracleDataKey::Feeds).unwrap_or(Map::new(&env));
         let ai_verified = PiCoinContract::supreme_ai_predict(&env, price) < 80; // AI verifies feed
@@ -78,82 +144,229 @@ impl OracleContract {
         Ok(())
     }
     
-    // Get median price from multiple feeds with AI adjustment
-    pub fn get_median_price(env: Env, prices: Vec<u64>) -> Result<u64, u32> {
-        if prices.is_empty() {
-            return Err(3); // ERR_INVALID_INPUT
+    // Get raw median price from a set of prices (pure; no AI adjustment, no storage writes)
+    pub fn get_median_price(_env: Env, prices: Vec<u64>) -> Result<u64, u32> {
+        compute_median(&prices).ok_or(3) // ERR_INVALID_INPUT
+    }
+    
+    // Get the authoritative raw median recalculated from verified feeds
+    pub fn get_current_median(env: Env) -> Result<u64, u32> {
+        env.storage().persistent().get(&OracleDataKey::MedianPrice).ok_or(4) // ERR_NOT_FOUND
+    }
+    
+    // Get the AI-adjusted effective price
+    pub fn get_effective_price(env: Env) -> Result<u64, u32> {
+        env.storage().persistent().get(&OracleDataKey::EffectivePrice).ok_or(4) // ERR_NOT_FOUND
+    }
+    
+    // Check peg with AI prediction and trigger actions. `caller` must be a feed reporter or a
+    // registered peg checker, since the check can trip the breaker and updates the AI prediction.
+    // Errors with InsufficientFeeds below the fresh-feed minimum and CircuitBreakerTripped while paused;
+    // tripping itself returns Ok(false) so the breaker state persists.
+    pub fn check_peg(env: Env, caller: Address) -> Result<bool, u32> {
+        caller.require_auth();
+        let reporters: Map<Symbol, Address> = env.storage().persistent().get(&OracleDataKey::FeedReporters).unwrap_or(Map::new(&env));
+        let checkers: Vec<Address> = env.storage().persistent().get(&OracleDataKey::PegCheckers).unwrap_or(Vec::new(&env));
+        if !checkers.contains(&caller) && !reporters.values().contains(&caller) {
+            return Err(PiCoinError::Unauthorized.into());
+        }
+        
+        match Self::assess_peg(&env)? {
+            PegAssessment::SpreadExceeded { lowest, highest, spread } => {
+                env.storage().persistent().set(&OracleDataKey::CircuitBreaker, &true);
+                events::publish(&env, Symbol::new(&env, "CircuitBreakerTripped"), (lowest, highest, spread));
+                log!(&env, "GodHead circuit breaker tripped; feed spread {} exceeds limit", spread);
+                Ok(false)
+            }
+            PegAssessment::Assessed { median_price, deviation, ai_prediction, is_stable } => {
+                env.storage().persistent().set(&OracleDataKey::AiPegPrediction, &ai_prediction);
+                if !is_stable {
+                    // Trigger eternal adjustment (e.g., notify main contract for mint/burn)
+                    // Placeholder: In production, call PiCoinContract::auto_adjust or similar
+                    events::publish(&env, Symbol::new(&env, "GodHeadPegCheckFailed"), (median_price, ai_prediction));
+                    log!(&env, "GodHead peg check failed; deviation {}, AI prediction {}", deviation, ai_prediction);
+                } else {
+                    log!(&env, "GodHead peg stable at {} with AI confidence {}", median_price, ai_prediction);
+                }
+                Ok(is_stable)
+            }
+        }
+    }
+    
+    // Read-only check_peg: no breaker trip, prediction write or event
+    pub fn preview_peg(env: Env) -> Result<PegPreview, u32> {
+        Ok(match Self::assess_peg(&env)? {
+            PegAssessment::SpreadExceeded { .. } => PegPreview { spread_exceeded: true, is_stable: false, ai_prediction: 0 },
+            PegAssessment::Assessed { is_stable, ai_prediction, .. } => PegPreview { spread_exceeded: false, is_stable, ai_prediction },
+        })
+    }
+    
+    // Peg decision logic without storage writes or events; shared by check_peg and dry runs
+    pub(crate) fn assess_peg(env: &Env) -> Result<PegAssessment, u32> {
+        if Self::is_circuit_breaker_tripped(env.clone()) {
+            return Err(PiCoinError::CircuitBreakerTripped.into());
         }
         
-        let mut sorted_prices = prices.clone();
-        sorted_prices.sort(); // Simple sort; in production, use efficient median calc
+        let (fresh_count, lowest, highest) = Self::fresh_feed_stats(env);
+        let min_feeds: u32 = env.storage().persistent().get(&OracleDataKey::MinFeeds).unwrap_or(3);
+        if fresh_count < min_feeds {
+            log!(env, "GodHead peg check needs {} fresh feeds, found {}", min_feeds, fresh_count);
+            return Err(PiCoinError::InsufficientFeeds.into());
+        }
         
-        let len = sorted_prices.len();
-        let median = if len % 2 == 0 {
-            (sorted_prices.get(len / 2 - 1).unwrap_or(0) + sorted_prices.get(len / 2).unwrap_or(0)) / 2
-        } else {
-            sorted_prices.get(len / 2).unwrap_or(0)
-        };
+        let max_spread: u64 = env.storage().persistent().get(&OracleDataKey::MaxFeedSpread).unwrap_or(3141);
+        let spread = highest - lowest;
+        if spread > max_spread {
+            return Ok(PegAssessment::SpreadExceeded { lowest, highest, spread });
+        }
         
-        // AI-adjusted median for eternal stability
-        let ai_adjustment = PiCoinContract::supreme_ai_predict(&env, median) as i64 - 50; // Center around 50
-        let adjusted_median = (median as i64 + ai_adjustment).max(0) as u64;
+        let median_price: u64 = env.storage().persistent().get(&OracleDataKey::MedianPrice).ok_or(4)?; // ERR_NOT_FOUND
+        let deviation = median_price.abs_diff(PEG_TARGET);
         
-        env.storage().persistent().set(&OracleDataKey::MedianPrice, &adjusted_median);
+        // AI prediction for peg stability
+        let ai_prediction = PiCoinContract::supreme_ai_predict(env, deviation);
+        let mut is_stable = deviation < PEG_BREACH_THRESHOLD && ai_prediction > 40; // Threshold for stability
         
-        events::publish(&env, Symbol::new(&env, "GodHeadMedianCalculated"), adjusted_median);
-        log!(&env, "GodHead median price calculated and AI-adjusted to {}", adjusted_median);
-        Ok(adjusted_median)
+        // Optionally require the peg to be backed by reserves
+        let min_collateral: u32 = env.storage().persistent().get(&OracleDataKey::MinCollateralRatio).unwrap_or(0);
+        if min_collateral > 0 && Self::pi_coin_collateralization(env) < min_collateral {
+            is_stable = false;
+        }
+        
+        Ok(PegAssessment::Assessed { median_price, deviation, ai_prediction, is_stable })
     }
     
-    // Check peg with AI prediction and trigger actions
-    pub fn check_peg(env: Env) -> Result<bool, u32> {
+    // Signed deviation of the stored median from the peg target, and whether it is at or past
+    // the breach threshold. Read-only, for keepers deciding whether to trigger stabilization.
+    pub fn peg_deviation(env: Env) -> Result<(i64, bool), u32> {
         let median_price: u64 = env.storage().persistent().get(&OracleDataKey::MedianPrice).ok_or(4)?; // ERR_NOT_FOUND
-        let peg_target = 314159u64; // $314,159 in micro-units
+        let deviation = median_price as i64 - PEG_TARGET as i64;
+        Ok((deviation, median_price.abs_diff(PEG_TARGET) >= PEG_BREACH_THRESHOLD))
+    }
+    
+    // Whether enough fresh, verified feeds are in for a confident peg check
+    pub fn has_feed_quorum(env: Env) -> bool {
+        let (fresh_count, _, _) = Self::fresh_feed_stats(&env);
+        let min_feeds: u32 = env.storage().persistent().get(&OracleDataKey::MinFeeds).unwrap_or(3);
+        fresh_count >= min_feeds
+    }
+    
+    // Whether the circuit breaker currently pauses peg-dependent operations
+    pub fn is_circuit_breaker_tripped(env: Env) -> bool {
+        env.storage().persistent().get(&OracleDataKey::CircuitBreaker).unwrap_or(false)
+    }
+    
+    // Reset a tripped circuit breaker after review
+    pub fn reset_circuit_breaker(env: Env) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        env.storage().persistent().set(&OracleDataKey::CircuitBreaker, &false);
         
-        let deviation = if median_price > peg_target {
-            median_price - peg_target
-        } else {
-            peg_target - median_price
+        events::publish(&env, Symbol::new(&env, "CircuitBreakerReset"), env.ledger().timestamp());
+        log!(&env, "GodHead circuit breaker reset");
+        Ok(())
+    }
+    
+    // Collateralization (bps) below which check_peg reports the peg unstable; 0 disables the check
+    pub fn set_min_collateral_ratio(env: Env, min_ratio_bps: u32) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        env.storage().persistent().set(&OracleDataKey::MinCollateralRatio, &min_ratio_bps);
+        log!(&env, "GodHead oracle requires {} bps collateralization", min_ratio_bps);
+        Ok(())
+    }
+    
+    // Pi Coin contract whose reserves back the peg (multi-sig required)
+    pub fn set_pi_coin(env: Env, pi_coin: Address) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        env.storage().persistent().set(&OracleDataKey::PiCoin, &pi_coin);
+        log!(&env, "GodHead oracle reads collateralization from Pi Coin");
+        Ok(())
+    }
+    
+    // Pi Coin's collateralization in bps, read cross-contract; 0 when unset or the call fails,
+    // so a missing answer never passes a MinCollateralRatio check
+    fn pi_coin_collateralization(env: &Env) -> u32 {
+        let pi_coin: Address = match env.storage().persistent().get(&OracleDataKey::PiCoin) {
+            Some(pi_coin) => pi_coin,
+            None => return 0,
         };
-        
-        // AI prediction for peg stability
-        let ai_prediction = PiCoinContract::supreme_ai_predict(&env, deviation);
-        env.storage().persistent().set(&OracleDataKey::AiPegPrediction, &ai_prediction);
-        
-        let is_stable = deviation < 1000 && ai_prediction > 40; // Threshold for stability
-        
-        if !is_stable {
-            // Trigger eternal adjustment (e.g., notify main contract for mint/burn)
-            // Placeholder: In production, call PiCoinContract::auto_adjust or similar
-            events::publish(&env, Symbol::new(&env, "GodHeadPegCheckFailed"), (median_price, ai_prediction));
-            log!(&env, "GodHead peg check failed; deviation {}, AI prediction {}", deviation, ai_prediction);
-        } else {
-            log!(&env, "GodHead peg stable at {} with AI confidence {}", median_price, ai_prediction);
+        match env.try_invoke_contract::<u32, Error>(&pi_coin, &Symbol::new(env, "collateralization_ratio"), Vec::new(env)) {
+            Ok(Ok(ratio)) => ratio,
+            _ => 0,
+        }
+    }
+    
+    // Configure feed freshness, quorum and spread limits
+    pub fn configure_feed_requirements(env: Env, min_feeds: u32, max_age: u64, max_spread: u64) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        if min_feeds == 0 || max_age == 0 {
+            return Err(3); // ERR_INVALID_INPUT
         }
+        env.storage().persistent().set(&OracleDataKey::MinFeeds, &min_feeds);
+        env.storage().persistent().set(&OracleDataKey::MaxFeedAge, &max_age);
+        env.storage().persistent().set(&OracleDataKey::MaxFeedSpread, &max_spread);
         
-        Ok(is_stable)
+        log!(&env, "GodHead oracle requires {} feeds, max age {}, max spread {}", min_feeds, max_age, max_spread);
+        Ok(())
     }
     
-    // Recalculate median from stored feeds
-    fn recalculate_median(env: &Env) -> Result<(), u32> {
+    // Count, lowest and highest price of fresh, verified feeds
+    pub(crate) fn fresh_feed_stats(env: &Env) -> (u32, u64, u64) {
         let feeds: Map<Symbol, OracleFeed> = env.storage().persistent().get(&OracleDataKey::Feeds).unwrap_or(Map::new(env));
-        let mut prices = Vec::new(env);
+        let max_age: u64 = env.storage().persistent().get(&OracleDataKey::MaxFeedAge).unwrap_or(3600);
+        let now = env.ledger().timestamp();
         
+        let mut count = 0u32;
+        let mut lowest = u64::MAX;
+        let mut highest = 0u64;
         for (_, feed) in feeds.iter() {
-            if feed.verified {
-                prices.push_back(feed.price);
+            if feed.verified && now.saturating_sub(feed.timestamp) <= max_age {
+                count += 1;
+                lowest = lowest.min(feed.price);
+                highest = highest.max(feed.price);
             }
         }
+        if count == 0 {
+            lowest = 0;
+        }
+        (count, lowest, highest)
+    }
+    
+    // Recalculate the median from stored feeds; callable by anyone, e.g. keepers ageing out stale feeds
+    pub fn refresh_median(env: Env) -> Result<(), u32> {
+        Self::recalculate_median(&env)
+    }
+    
+    // Recalculate median from fresh, verified feeds and derive the effective price.
+    // Stale feeds are left out so a silent source cannot pin the median.
+    pub(crate) fn recalculate_median(env: &Env) -> Result<(), u32> {
+        let feeds: Map<Symbol, OracleFeed> = env.storage().persistent().get(&OracleDataKey::Feeds).unwrap_or(Map::new(env));
+        let max_age: u64 = env.storage().persistent().get(&OracleDataKey::MaxFeedAge).unwrap_or(3600);
+        let now = env.ledger().timestamp();
+        let mut prices = Vec::new(env);
         
-        if prices.is_empty() {
-            return Err(3); // ERR_INVALID_INPUT
+        for (_, feed) in feeds.iter() {
+            if feed.verified && now.saturating_sub(feed.timestamp) <= max_age {
+                prices.push_back(feed.price);
+            }
         }
         
-        let median = Self::get_median_price(env.clone(), prices)?;
+        let median = compute_median(&prices).ok_or(3u32)?; // ERR_INVALID_INPUT
+        let effective = Self::ai_adjusted_price(env, median);
         env.storage().persistent().set(&OracleDataKey::MedianPrice, &median);
+        env.storage().persistent().set(&OracleDataKey::EffectivePrice, &effective);
+        
+        events::publish(env, Symbol::new(env, "GodHeadMedianCalculated"), (median, effective));
+        log!(env, "GodHead median {} with AI-adjusted effective price {}", median, effective);
         Ok(())
     }
     
+    // Bounded AI adjustment: moves the raw median by at most MAX_AI_ADJUSTMENT_BPS
+    fn ai_adjusted_price(env: &Env, median: u64) -> u64 {
+        let ai_adjustment = PiCoinContract::supreme_ai_predict(env, median) as i64 - 50; // Center around 50
+        let cap = (median.saturating_mul(MAX_AI_ADJUSTMENT_BPS) / 10_000) as i64;
+        (median as i64).saturating_add(ai_adjustment.clamp(-cap, cap)).max(0) as u64
+    }
+    
     // Get current AI peg prediction
     pub fn get_ai_peg_prediction(env: Env) -> Result<u64, u32> {
         env.storage().persistent().get(&OracleDataKey::AiPegPrediction).ok_or(4) // ERR_NOT_FOUND
@@ -163,4 +376,56 @@ impl OracleContract {
     pub fn get_feeds(env: Env) -> Result<Map<Symbol, OracleFeed>, u32> {
         env.storage().persistent().get(&OracleDataKey::Feeds).ok_or(4) // ERR_NOT_FOUND
     }
+    
+    // Allowed feed sources
+    pub fn get_sources(env: Env) -> Vec<Symbol> {
+        env.storage().persistent().get(&OracleDataKey::FeedSources).unwrap_or(Vec::new(&env))
+    }
+    
+    // Latest feed from one source
+    pub fn get_feed(env: Env, source: Symbol) -> Option<OracleFeed> {
+        let feeds: Map<Symbol, OracleFeed> = env.storage().persistent().get(&OracleDataKey::Feeds).unwrap_or(Map::new(&env));
+        feeds.get(source)
+    }
+    
+    // Whether a source's feed is older than MaxFeedAge; a source that never reported is stale
+    pub fn is_feed_stale(env: Env, source: Symbol) -> bool {
+        let max_age: u64 = env.storage().persistent().get(&OracleDataKey::MaxFeedAge).unwrap_or(3600);
+        let now = env.ledger().timestamp();
+        Self::get_feed(env, source).map_or(true, |feed| now.saturating_sub(feed.timestamp) > max_age)
+    }
+}
+
+// Median of a price set; None when empty
+fn compute_median(prices: &Vec<u64>) -> Option<u64> {
+    if prices.is_empty() {
+        return None;
+    }
+    
+    let sorted_prices = sort_prices(prices);
+    let len = sorted_prices.len();
+    let upper = sorted_prices.get(len / 2).unwrap_or(0);
+    let median = if len % 2 == 0 {
+        let lower = sorted_prices.get(len / 2 - 1).unwrap_or(0);
+        lower + (upper - lower) / 2 // Sorted, so no underflow; avoids overflowing lower + upper
+    } else {
+        upper
+    };
+    Some(median)
+}
+
+// Ascending copy of a price set. soroban_sdk::Vec has no sort, so this is an in-place
+// insertion sort through get/set: O(n^2), which is fine for the handful of feeds.
+fn sort_prices(prices: &Vec<u64>) -> Vec<u64> {
+    let mut sorted = prices.clone();
+    for i in 1..sorted.len() {
+        let current = sorted.get_unchecked(i);
+        let mut j = i;
+        while j > 0 && sorted.get_unchecked(j - 1) > current {
+            sorted.set(j, sorted.get_unchecked(j - 1));
+            j -= 1;
+        }
+        sorted.set(j, current);
+    }
+    sorted
 }
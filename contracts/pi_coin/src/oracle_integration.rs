@@ -1,8 +1,9 @@
 // contracts/pi_coin/src/oracle_integration.rs
 // GodHead Nexus Oracle Integration: Queries off-chain Hyper AI for predictions.
 // Uses try_call to an oracle contract that fetches from the Python API.
+// Compiled only with the "neural" feature.
 
-use soroban_sdk::{contracttype, Env, Symbol, Vec, Address, Val, Error};
+use soroban_sdk::{contracttype, Env, Symbol, Vec, Address, Val, Error, IntoVal, TryFromVal};
 use crate::DataKey;
 
 #[contracttype]
@@ -22,11 +23,11 @@ impl OracleIntegration {
         let result: Result<Val, Error> = env.try_call(
             oracle_addr,
             Symbol::new(env, "query_external_ai"),
-            Vec::from_array(env, [request.into()])
+            Vec::from_array(env, [request.into_val(env)])
         );
         match result {
             Ok(val) => {
-                let prediction: i64 = val.try_into().map_err(|_| 1003)?;
+                let prediction = i64::try_from_val(env, &val).map_err(|_| 1003)?;
                 Ok(prediction.clamp(0, 100))
             }
             Err(_) => Err(1003),  // Call failed
@@ -40,7 +41,7 @@ impl OracleIntegration {
         let result: Result<Val, Error> = env.try_call(
             oracle_addr,
             Symbol::new(env, "evolve_external_ai"),
-            Vec::from_array(env, [request.into()])
+            Vec::from_array(env, [request.into_val(env)])
         );
         result.map_err(|_| 1003)?;
         Ok(())
@@ -0,0 +1,54 @@
+// contracts/pi_coin/src/prng.rs
+// Deterministic PRNG for the Nexus AI modules and the contract scripts.
+// Every validator must reach the same "random" outcome, so values come from
+// sha256(seed, ledger sequence, draw counter) rather than host entropy.
+
+use soroban_sdk::{Bytes, Env, Symbol};
+
+pub struct NexusPrng {
+    env: Env,
+    seed: u64,
+    sequence: u32,
+    counter: u64,
+}
+
+impl NexusPrng {
+    /// PRNG for `seed` in the current ledger. Same seed and ledger, same sequence.
+    pub fn new(env: &Env, seed: u64) -> Self {
+        NexusPrng { env: env.clone(), seed, sequence: env.ledger().sequence(), counter: 0 }
+    }
+
+    /// PRNG seeded from a nonce in the calling contract's instance storage, bumped on every call
+    /// so separate draws within one ledger differ. Must run inside a contract invocation.
+    pub fn from_ledger(env: &Env) -> Self {
+        let key = Symbol::new(env, "nexus_prng_nonce");
+        let nonce: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(nonce + 1));
+        Self::new(env, nonce)
+    }
+
+    /// Next raw 64-bit value.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut input = Bytes::from_array(&self.env, &self.seed.to_be_bytes());
+        input.extend_from_array(&self.sequence.to_be_bytes());
+        input.extend_from_array(&self.counter.to_be_bytes());
+        self.counter += 1;
+        let digest = self.env.crypto().sha256(&input).to_array();
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(word)
+    }
+
+    /// Uniform-enough value in `low..high`; returns `low` for an empty range.
+    pub fn gen_range(&mut self, low: i128, high: i128) -> i128 {
+        if high <= low {
+            return low;
+        }
+        low + (self.next_u64() as i128) % (high - low)
+    }
+
+    /// True with probability `percent`/100.
+    pub fn gen_percent(&mut self, percent: u32) -> bool {
+        self.gen_range(0, 100) < percent as i128
+    }
+}
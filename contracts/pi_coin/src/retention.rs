@@ -0,0 +1,34 @@
+// contracts/pi_coin/src/retention.rs
+// Bounded append-only logs shared by the Pi Coin AI evolution log, stability adjustment history,
+// and the monitoring, prediction oracle and interplanetary economy contracts. Each keeps its
+// policy and lifetime counters under its own DataKey.
+
+use soroban_sdk::{contracttype, Env, IntoVal, TryFromVal, Val, Vec};
+
+// Bounds applied to a log on every append: the oldest entries beyond max_entries,
+// or older than max_age seconds, are dropped
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetentionPolicy {
+    pub max_entries: u32,
+    pub max_age: u64,
+}
+
+// Default retention: 500 entries or 30 days per log
+pub const DEFAULT_RETENTION: RetentionPolicy = RetentionPolicy { max_entries: 500, max_age: 2_592_000 };
+
+// Drop the oldest entries past `policy`; logs are appended in time order
+pub fn retain<T: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, policy: &RetentionPolicy, log: Vec<T>, timestamp: impl Fn(&T) -> u64) -> Vec<T> {
+    let cutoff = env.ledger().timestamp().saturating_sub(policy.max_age);
+    let mut start = log.len().saturating_sub(policy.max_entries);
+    while start < log.len() && timestamp(&log.get(start).unwrap()) < cutoff {
+        start += 1;
+    }
+    log.slice(start..)
+}
+
+// Count one more entry in the lifetime total stored under `key`, unaffected by pruning
+pub fn bump_total<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    let total: u64 = env.storage().persistent().get(key).unwrap_or(0);
+    env.storage().persistent().set(key, &(total + 1));
+}
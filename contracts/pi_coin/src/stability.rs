@@ -3,14 +3,13 @@
 // using AI-driven automatic adjustments, oracle integration, and decentralized controls.
 // No human intervention; all adjustments are algorithmic, multi-sig verified, and logged for immutability.
 
-#![no_std]
-
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, Bytes, log, events, Error};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Bytes, Val, IntoVal, TryFromVal, log, events, Error};
 
 // Import from lib.rs and other modules for integration
-use crate::PiCoinContract; // Adjust import based on project structure
-use crate::DataKey; // Assuming DataKey is shared from lib.rs
-use crate::oracle::OracleContract; // Assuming oracle.rs is in the same crate
+use crate::{DataKey, PiCoinContract, PiCoinError}; // Adjust import based on project structure
+use crate::ai_simulation::AiSimulation;
+use crate::retention::{self, RetentionPolicy, DEFAULT_RETENTION};
+use crate::oracle::PegPreview; // Oracle is a separate contract, reached through call_oracle
 
 #[contracttype]
 #[derive(Clone)]
@@ -22,11 +21,129 @@ pub struct StabilityAdjustment {
     pub timestamp: u64,
 }
 
+// Actions taken by one keeper_tick
+#[contracttype]
+#[derive(Clone)]
+pub struct KeeperTickSummary {
+    pub median_recalculated: bool,
+    pub stability_checked: bool, // Cooldown elapsed and auto_adjust ran without error
+    pub anomaly_scanned: bool,
+    pub anomalies: u32, // Tripped breaker, feed quorum shortfall and peg breach, one each
+}
+
+// Lifetime totals of applied adjustments; unaffected by history pruning
+#[contracttype]
+#[derive(Clone)]
+pub struct AdjustmentTotals {
+    pub minted: u64,
+    pub burned: u64,
+}
+
+// Governance limit on net supply change the AI may make without approval
+#[contracttype]
+#[derive(Clone)]
+pub struct AutonomousSpendCap {
+    pub max_net_change: u64, // Absolute net mint minus burn within the period
+    pub period: u64,         // Rolling period in seconds
+}
+
+// Adjustment held back for a governance vote because it would exceed the spend cap
+#[contracttype]
+#[derive(Clone)]
+pub struct QueuedAdjustment {
+    pub proposal: Symbol, // Applied by execute_queued_adjustment or dropped by discard_queued_adjustment
+    pub adjustment: StabilityAdjustment,
+}
+
+// PID gains, fixed-point scaled by PID_SCALE
+#[contracttype]
+#[derive(Clone)]
+pub struct PidGains {
+    pub kp: i64,
+    pub ki: i64,
+    pub kd: i64,
+}
+
+// Error history carried between PID steps
+#[contracttype]
+#[derive(Clone)]
+pub struct PidState {
+    pub integral: i64,
+    pub prev_error: i64,
+}
+
+pub const PID_SCALE: i64 = 1_000_000;
+pub const PID_INTEGRAL_LIMIT: i64 = 1_000_000_000; // Anti-windup clamp
+pub const MAX_ADJUSTMENT: u64 = 1_000_000; // Cap at 1M for safety
+pub const DEFAULT_AUTONOMOUS_SPEND_CAP: AutonomousSpendCap = AutonomousSpendCap { max_net_change: 5_000_000, period: 86400 };
+pub const KEEPER_COOLDOWN: u64 = 300; // Minimum seconds between keeper-driven stability checks
+pub const MAX_QUEUED_ADJUSTMENTS: u32 = 10; // Over-cap adjustments beyond this are dropped, not queued
+
+// Original heuristic: deviation scaled by AI confidence
+pub fn heuristic_amount(deviation: u64, ai_confidence: u64) -> u64 {
+    (deviation / 1000).saturating_mul(ai_confidence / 10).min(MAX_ADJUSTMENT)
+}
+
+// One integer PID step; error is median - peg, positive output means burn
+pub fn pid_step(gains: &PidGains, state: &PidState, error: i64) -> (i64, PidState) {
+    let integral = state.integral.saturating_add(error).clamp(-PID_INTEGRAL_LIMIT, PID_INTEGRAL_LIMIT);
+    let derivative = error.saturating_sub(state.prev_error);
+    let raw = (gains.kp as i128 * error as i128
+        + gains.ki as i128 * integral as i128
+        + gains.kd as i128 * derivative as i128)
+        / PID_SCALE as i128;
+    let cap = MAX_ADJUSTMENT as i128;
+    let output = raw.clamp(-cap, cap) as i64;
+    (output, PidState { integral, prev_error: error })
+}
+
+// ASCII decimal digits of n in buf[start..], for on-chain text without an allocator
+fn decimal_digits(mut n: u64) -> ([u8; 20], usize) {
+    let mut buf = [0u8; 20];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return (buf, start);
+        }
+    }
+}
+
 #[contracttype]
 pub enum StabilityDataKey {
     AdjustmentsLog,     // Vec<StabilityAdjustment>
     LastAdjustment,     // u64 timestamp
     AdjustmentThreshold, // Minimum deviation for action
+    AdjustmentBudget,   // Max total adjustment amount per rolling window
+    AdjustmentWindow,   // Rolling window length in seconds
+    ControllerMode,     // Symbol: "heuristic" or "pid"
+    PidGains,           // PidGains
+    PidState,           // PidState
+    AutonomousSpendCap, // AutonomousSpendCap on AI-initiated net supply change
+    QueuedAdjustments,  // Vec<QueuedAdjustment> awaiting governance approval
+    QueuedAdjustmentSeq, // u64 counter naming queued adjustment proposals
+    HistoryPolicy,      // RetentionPolicy for AdjustmentsLog; DEFAULT_RETENTION when unset
+    AdjustmentTotals,   // AdjustmentTotals over all applied adjustments
+    AdjustmentsTotal,   // u64 adjustments ever logged, including pruned ones
+    LastKeeperLedger,   // u32 ledger sequence of the last keeper_tick scan
+    LastKeeperCheck,    // u64 timestamp of the last keeper-driven stability check
+    Oracle,             // Address of the OracleContract whose feeds drive adjustments
+}
+
+// Decision produced by plan_adjustment
+struct AdjustmentPlan {
+    adjustment: StabilityAdjustment,
+    pid_state: Option<PidState>, // Next PID state, persisted only by auto_adjust
+    budget_exhausted: bool,
+    requested: u64, // Controller output before the budget clamp
+}
+
+impl AdjustmentPlan {
+    fn skip(adjustment: StabilityAdjustment) -> Self {
+        AdjustmentPlan { adjustment, pid_state: None, budget_exhausted: false, requested: 0 }
+    }
 }
 
 #[contract]
@@ -34,85 +151,122 @@ pub struct StabilityContract;
 
 #[contractimpl]
 impl StabilityContract {
-    // Initialize stability module with eternal safety
-    pub fn init_stability(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), u32> {
+    // Initialize stability module with eternal safety, reading peg data from the deployed `oracle`
+    pub fn init_stability(env: Env, signers: Vec<Address>, threshold: u32, oracle: Address) -> Result<(), u32> {
         // Require multi-sig from main contract
         PiCoinContract::require_multi_sig(&env)?;
         
+        env.storage().persistent().set(&StabilityDataKey::Oracle, &oracle);
+        AiSimulation::init_single_layer(&env, Vec::from_array(&env, [1i64, 2i64, 3i64, 4i64, 5i64])); // Same network as Pi Coin
         env.storage().persistent().set(&StabilityDataKey::AdjustmentsLog, &Vec::<StabilityAdjustment>::new(&env));
         env.storage().persistent().set(&StabilityDataKey::LastAdjustment, &0u64);
         env.storage().persistent().set(&StabilityDataKey::AdjustmentThreshold, &1000u64); // Micro-deviation threshold
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentBudget, &10_000_000u64); // 10x single-adjustment cap
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentWindow, &86400u64); // 24h
+        env.storage().persistent().set(&StabilityDataKey::ControllerMode, &Symbol::new(&env, "heuristic"));
+        env.storage().persistent().set(&StabilityDataKey::PidGains, &PidGains { kp: 2500, ki: 50, kd: 250 });
+        env.storage().persistent().set(&StabilityDataKey::PidState, &PidState { integral: 0, prev_error: 0 });
+        env.storage().persistent().set(&StabilityDataKey::AutonomousSpendCap, &DEFAULT_AUTONOMOUS_SPEND_CAP);
         
         events::publish(&env, Symbol::new(&env, "GodHeadStabilityInitialized"), signers);
         log!(&env, "GodHead Nexus Stability initialized eternally");
         Ok(())
     }
     
+    // Point the module at the ecosystem PauseRegistry auto_adjust checks (multi-sig required).
+    // Shares Pi Coin's pause keys, so a stale cached answer is cleared here too.
+    pub fn set_pause_registry(env: Env, registry: Address) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        env.storage().persistent().set(&DataKey::PauseRegistry, &registry);
+        env.storage().persistent().remove(&DataKey::PauseCache(Symbol::new(&env, "stability")));
+        log!(&env, "GodHead stability pause registry set");
+        Ok(())
+    }
+    
     // Auto-adjust supply based on oracle peg check and AI prediction
+    // Fails with Paused (20) while the pause registry halts the "stability" module.
     pub fn auto_adjust(env: Env) -> Result<(), u32> {
+        PiCoinContract::check_pause(&env, "stability")?;
+        
         // Call oracle check_peg
-        let is_stable = OracleContract::check_peg(env.clone())?;
+        let caller = Vec::from_array(&env, [env.current_contract_address().into_val(&env)]);
+        let is_stable: bool = Self::call_oracle(&env, "check_peg", caller)?;
         
         if is_stable {
             log!(&env, "GodHead peg is stable; no adjustment needed");
             return Ok(());
         }
         
-        // Get current median price from oracle
-        let median_price: u64 = env.storage().persistent().get(&DataKey::OracleFeeds)
-            .and_then(|oracles: Map<Symbol, u64>| oracles.get(Symbol::new(&env, "PI")))
-            .unwrap_or(314159);
-        
-        let peg_target = 314159u64; // $314,159 in micro-units
-        let deviation = if median_price > peg_target {
-            median_price - peg_target
-        } else {
-            peg_target - median_price
-        };
-        
-        let threshold: u64 = env.storage().persistent().get(&StabilityDataKey::AdjustmentThreshold).ok_or(4)?; // ERR_NOT_FOUND
-        if deviation < threshold {
-            log!(&env, "GodHead deviation {} below threshold {}; skipping adjustment", deviation, threshold);
+        let plan = Self::plan_adjustment(&env)?;
+        if let Some(pid_state) = plan.pid_state {
+            env.storage().persistent().set(&StabilityDataKey::PidState, &pid_state);
+        }
+        if plan.budget_exhausted {
+            events::publish(&env, Symbol::new(&env, "AdjustmentBudgetExhausted"), plan.requested);
+            log!(&env, "GodHead adjustment budget exhausted; skipping {} PI", plan.requested);
             return Ok(());
         }
-        
-        // AI-driven adjustment amount
-        let ai_confidence = PiCoinContract::supreme_ai_predict(&env, deviation);
-        let adjustment_amount = (deviation / 1000).saturating_mul(ai_confidence / 10).min(1000000); // Cap at 1M for safety
-        
-        let adjustment_type = if median_price > peg_target {
-            Symbol::new(&env, "burn") // Burn to reduce supply if price > peg
-        } else {
-            Symbol::new(&env, "mint") // Mint to increase supply if price < peg
-        };
+        let adjustment = plan.adjustment;
+        if adjustment.amount == 0 {
+            log!(&env, "GodHead adjustment plan is a no-op; skipping");
+            return Ok(());
+        }
+        if Self::exceeds_spend_cap(&env, &adjustment) {
+            Self::queue_adjustment(&env, adjustment);
+            return Ok(());
+        }
+        let adjustment_type = adjustment.adjustment_type.clone();
+        let adjustment_amount = adjustment.amount;
+        let ai_confidence = adjustment.ai_confidence;
         
         // Perform adjustment via main contract (placeholder; integrate with lib.rs mint/burn)
         // For burn: Call PiCoinContract::burn with system address
         // For mint: Call PiCoinContract::mint with system address
         // Example: env.call(main_contract_addr, adjustment_type, Vec::from_array(&env, [system_addr, (adjustment_amount as i128).into()]));
         
-        // Log adjustment
-        let adjustment = StabilityAdjustment {
-            adjustment_type: adjustment_type.clone(),
-            amount: adjustment_amount,
-            reason: Bytes::from(format!("Peg deviation: {} vs {}", median_price, peg_target).as_bytes()),
-            ai_confidence,
-            timestamp: env.ledger().timestamp(),
-        };
-        
-        let mut log_vec: Vec<StabilityAdjustment> = env.storage().persistent().get(&StabilityDataKey::AdjustmentsLog).unwrap_or(Vec::new(&env));
-        log_vec.push_back(adjustment);
-        env.storage().persistent().set(&StabilityDataKey::AdjustmentsLog, &log_vec);
+        Self::record_adjustment(&env, adjustment);
         env.storage().persistent().set(&StabilityDataKey::LastAdjustment, &env.ledger().timestamp());
         
         // Evolve AI after adjustment
         PiCoinContract::evolve_supreme_ai(&env);
         
-        events::publish(&env, Symbol::new(&env, "GodHeadStabilityAdjusted"), (adjustment_type, adjustment_amount));
+        events::publish(&env, Symbol::new(&env, "GodHeadStabilityAdjusted"), (adjustment_type.clone(), adjustment_amount));
         log!(&env, "GodHead stability auto-adjusted: {} {} PI with AI confidence {}", adjustment_type, adjustment_amount, ai_confidence);
         Ok(())
     }
     
+    // Dry run of auto_adjust: the adjustment it would apply now, with no mint, burn, log or state writes.
+    // Returns a "none" adjustment of amount 0 when auto_adjust would take no action, and a "queued"
+    // one carrying the planned amount when the spend cap would hold it for governance instead.
+    pub fn simulate_adjust(env: Env) -> Result<StabilityAdjustment, u32> {
+        let preview: PegPreview = Self::call_oracle(&env, "preview_peg", Vec::new(&env))?;
+        if preview.spread_exceeded {
+            return Ok(Self::no_adjustment(&env, "Feed spread exceeded; circuit breaker would trip", 0));
+        }
+        if preview.is_stable {
+            return Ok(Self::no_adjustment(&env, "Peg stable", preview.ai_prediction));
+        }
+        
+        let plan = Self::plan_adjustment(&env)?;
+        if plan.budget_exhausted {
+            return Ok(Self::no_adjustment(&env, "Adjustment budget exhausted", plan.adjustment.ai_confidence));
+        }
+        let mut adjustment = plan.adjustment;
+        if adjustment.amount > 0 && Self::exceeds_spend_cap(&env, &adjustment) {
+            if Self::get_queued_adjustments(env.clone()).len() >= MAX_QUEUED_ADJUSTMENTS {
+                return Ok(Self::no_adjustment(&env, "Over spend cap and adjustment queue full", adjustment.ai_confidence));
+            }
+            let reason = if adjustment.adjustment_type == Symbol::new(&env, "mint") {
+                "Over spend cap; mint would be queued for governance"
+            } else {
+                "Over spend cap; burn would be queued for governance"
+            };
+            adjustment.adjustment_type = Symbol::new(&env, "queued");
+            adjustment.reason = Bytes::from_slice(&env, reason.as_bytes());
+        }
+        Ok(adjustment)
+    }
+    
     // Manual trigger for adjustment (multi-sig required, but AI overrides for safety)
     pub fn trigger_adjustment(env: Env) -> Result<(), u32> {
         PiCoinContract::require_multi_sig(&env)?;
@@ -127,11 +281,392 @@ impl StabilityContract {
         Self::auto_adjust(env)
     }
     
+    // Keeper heartbeat: recalculates the oracle median, runs a cooldown-gated stability check and
+    // scans for anomalies. Callable by anyone; repeat calls within one ledger skip the gated steps.
+    pub fn keeper_tick(env: Env) -> Result<KeeperTickSummary, u32> {
+        let ledger = env.ledger().sequence();
+        let now = env.ledger().timestamp();
+        let median_recalculated = Self::call_oracle::<()>(&env, "refresh_median", Vec::new(&env)).is_ok();
+        
+        let last_ledger: Option<u32> = env.storage().persistent().get(&StabilityDataKey::LastKeeperLedger);
+        let first_in_ledger = last_ledger != Some(ledger);
+        let last_check: Option<u64> = env.storage().persistent().get(&StabilityDataKey::LastKeeperCheck);
+        let cooled_down = last_check.map_or(true, |t| now.saturating_sub(t) >= KEEPER_COOLDOWN);
+        
+        let mut stability_checked = false;
+        if first_in_ledger && cooled_down {
+            env.storage().persistent().set(&StabilityDataKey::LastKeeperCheck, &now);
+            match Self::auto_adjust(env.clone()) {
+                Ok(()) => stability_checked = true,
+                Err(code) => log!(&env, "GodHead keeper stability check skipped with error {}", code),
+            }
+        }
+        
+        let mut anomalies = 0u32;
+        if first_in_ledger {
+            env.storage().persistent().set(&StabilityDataKey::LastKeeperLedger, &ledger);
+            anomalies = Self::scan_anomalies(&env);
+            if anomalies > 0 {
+                events::publish(&env, Symbol::new(&env, "KeeperAnomalies"), anomalies);
+            }
+        }
+        
+        let summary = KeeperTickSummary { median_recalculated, stability_checked, anomaly_scanned: first_in_ledger, anomalies };
+        events::publish(&env, Symbol::new(&env, "KeeperTick"), summary.clone());
+        log!(&env, "GodHead keeper tick at ledger {}: {} anomalies", ledger, anomalies);
+        Ok(summary)
+    }
+    
+    // Invoke an oracle entry point. Oracle errors keep their code; a failed call is CallFailed.
+    fn call_oracle<T: TryFromVal<Env, Val>>(env: &Env, function: &str, args: Vec<Val>) -> Result<T, u32> {
+        let oracle: Address = env.storage().persistent().get(&StabilityDataKey::Oracle).ok_or(4u32)?; // ERR_NOT_FOUND
+        match env.try_invoke_contract::<T, Error>(&oracle, &Symbol::new(env, function), args) {
+            Ok(Ok(value)) => Ok(value),
+            Err(Ok(err)) => Err(err.get_code()),
+            _ => Err(PiCoinError::CallFailed.into()),
+        }
+    }
+    
+    // Count monitoring anomalies: tripped circuit breaker, too few fresh feeds, peg breach
+    fn scan_anomalies(env: &Env) -> u32 {
+        let mut anomalies = 0u32;
+        if Self::call_oracle::<bool>(env, "is_circuit_breaker_tripped", Vec::new(env)).unwrap_or(false) {
+            anomalies += 1;
+        }
+        if !Self::call_oracle::<bool>(env, "has_feed_quorum", Vec::new(env)).unwrap_or(false) {
+            anomalies += 1;
+        }
+        if let Ok((_, true)) = Self::call_oracle::<(i64, bool)>(env, "peg_deviation", Vec::new(env)) {
+            anomalies += 1;
+        }
+        anomalies
+    }
+    
     // Get adjustment log for transparency
     pub fn get_adjustment_log(env: Env) -> Result<Vec<StabilityAdjustment>, u32> {
         env.storage().persistent().get(&StabilityDataKey::AdjustmentsLog).ok_or(4) // ERR_NOT_FOUND
     }
     
+    // Page through the adjustment log, oldest first. Cursors index the retained log, so pruning shifts them.
+    pub fn get_adjustment_history(env: Env, cursor: u32, limit: u32) -> (Vec<StabilityAdjustment>, Option<u32>) {
+        Self::filter_adjustment_history(env, None, 0, u64::MAX, cursor, limit)
+    }
+    
+    // Page through adjustments of one type (mint/burn) and/or within [from, to] by timestamp.
+    // Up to `limit` matches are returned; the next cursor resumes scanning after the last entry read.
+    pub fn filter_adjustment_history(env: Env, adjustment_type: Option<Symbol>, from: u64, to: u64, cursor: u32, limit: u32) -> (Vec<StabilityAdjustment>, Option<u32>) {
+        let log_vec: Vec<StabilityAdjustment> = env.storage().persistent().get(&StabilityDataKey::AdjustmentsLog).unwrap_or(Vec::new(&env));
+        let mut page = Vec::new(&env);
+        let mut index = cursor;
+        while index < log_vec.len() && page.len() < limit {
+            let adjustment = log_vec.get(index).unwrap();
+            index += 1;
+            let type_matches = adjustment_type.as_ref().map_or(true, |t| *t == adjustment.adjustment_type);
+            if type_matches && adjustment.timestamp >= from && adjustment.timestamp <= to {
+                page.push_back(adjustment);
+            }
+        }
+        let next = if index < log_vec.len() { Some(index) } else { None };
+        (page, next)
+    }
+    
+    // Totals minted and burned by all applied adjustments, including pruned history
+    pub fn get_adjustment_totals(env: Env) -> AdjustmentTotals {
+        env.storage().persistent().get(&StabilityDataKey::AdjustmentTotals).unwrap_or(AdjustmentTotals { minted: 0, burned: 0 })
+    }
+    
+    // Adjustments applied since init, including pruned history
+    pub fn get_adjustment_count(env: Env) -> u64 {
+        env.storage().persistent().get(&StabilityDataKey::AdjustmentsTotal).unwrap_or(0)
+    }
+    
+    // Set how much adjustment history is kept. Entries still inside the budget window or
+    // spend cap period are never pruned, whatever the policy.
+    pub fn set_history_retention(env: Env, policy: RetentionPolicy) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        if policy.max_entries == 0 || policy.max_age == 0 {
+            return Err(3); // ERR_INVALID_INPUT
+        }
+        env.storage().persistent().set(&StabilityDataKey::HistoryPolicy, &policy);
+        
+        events::publish(&env, Symbol::new(&env, "HistoryRetentionUpdated"), policy.clone());
+        log!(&env, "GodHead stability history retention set to {} entries / {}s", policy.max_entries, policy.max_age);
+        Ok(())
+    }
+    
+    pub fn get_history_retention(env: Env) -> RetentionPolicy {
+        env.storage().persistent().get(&StabilityDataKey::HistoryPolicy).unwrap_or(DEFAULT_RETENTION)
+    }
+    
+    // Append an applied adjustment, update the running totals and prune expired history
+    fn record_adjustment(env: &Env, adjustment: StabilityAdjustment) {
+        let mut totals = Self::get_adjustment_totals(env.clone());
+        if adjustment.adjustment_type == Symbol::new(env, "mint") {
+            totals.minted = totals.minted.saturating_add(adjustment.amount);
+        } else {
+            totals.burned = totals.burned.saturating_add(adjustment.amount);
+        }
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentTotals, &totals);
+        
+        let mut log_vec: Vec<StabilityAdjustment> = env.storage().persistent().get(&StabilityDataKey::AdjustmentsLog).unwrap_or(Vec::new(env));
+        log_vec.push_back(adjustment);
+        
+        // Budget and spend cap checks read the log back over their periods, so widen the policy to cover them
+        let window: u64 = env.storage().persistent().get(&StabilityDataKey::AdjustmentWindow).unwrap_or(86400);
+        let protected_age = window.max(Self::get_autonomous_spend_cap(env.clone()).period);
+        let protected_cutoff = env.ledger().timestamp().saturating_sub(protected_age);
+        let protected = log_vec.iter().rev().take_while(|entry| entry.timestamp >= protected_cutoff).count() as u32;
+        let policy = Self::get_history_retention(env.clone());
+        let policy = RetentionPolicy { max_entries: policy.max_entries.max(protected), max_age: policy.max_age.max(protected_age) };
+        
+        let log_vec = retention::retain(env, &policy, log_vec, |entry| entry.timestamp);
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentsLog, &log_vec);
+        retention::bump_total(env, &StabilityDataKey::AdjustmentsTotal);
+    }
+    
+    // Update the rolling-window adjustment budget
+    pub fn set_adjustment_budget(env: Env, max_total: u64, window: u64) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        if window == 0 {
+            return Err(3); // ERR_INVALID_INPUT
+        }
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentBudget, &max_total);
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentWindow, &window);
+        
+        events::publish(&env, Symbol::new(&env, "GodHeadBudgetUpdated"), (max_total, window));
+        log!(&env, "GodHead stability budget set to {} per {}s", max_total, window);
+        Ok(())
+    }
+    
+    // Switch between "heuristic" and "pid" controllers and set PID gains
+    pub fn set_controller(env: Env, mode: Symbol, gains: PidGains) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        if mode != Symbol::new(&env, "heuristic") && mode != Symbol::new(&env, "pid") {
+            return Err(3); // ERR_INVALID_INPUT
+        }
+        if gains.kp < 0 || gains.ki < 0 || gains.kd < 0 {
+            return Err(3); // ERR_INVALID_INPUT
+        }
+        env.storage().persistent().set(&StabilityDataKey::ControllerMode, &mode);
+        env.storage().persistent().set(&StabilityDataKey::PidGains, &gains);
+        // Fresh error history so stale integral does not kick the new controller
+        env.storage().persistent().set(&StabilityDataKey::PidState, &PidState { integral: 0, prev_error: 0 });
+        
+        events::publish(&env, Symbol::new(&env, "GodHeadControllerUpdated"), mode.clone());
+        log!(&env, "GodHead stability controller set to {}", mode);
+        Ok(())
+    }
+    
+    // Full decision logic for an off-peg price: median, deviation, controller output, budget.
+    // Reads storage only; callers decide whether to persist the PID state and log the result.
+    fn plan_adjustment(env: &Env) -> Result<AdjustmentPlan, u32> {
+        // Get current median price from oracle
+        let median_price: u64 = Self::call_oracle(env, "get_current_median", Vec::new(env))?;
+        
+        let peg_target = 314159u64; // $314,159 in micro-units
+        let deviation = if median_price > peg_target {
+            median_price - peg_target
+        } else {
+            peg_target - median_price
+        };
+        
+        let ai_confidence = PiCoinContract::supreme_ai_predict(env, deviation);
+        let threshold: u64 = env.storage().persistent().get(&StabilityDataKey::AdjustmentThreshold).ok_or(4)?; // ERR_NOT_FOUND
+        if deviation < threshold {
+            log!(env, "GodHead deviation {} below threshold {}; skipping adjustment", deviation, threshold);
+            return Ok(AdjustmentPlan::skip(Self::no_adjustment(env, "Deviation below threshold", ai_confidence)));
+        }
+        
+        // Adjustment amount from the configured controller
+        let mode: Symbol = env.storage().persistent().get(&StabilityDataKey::ControllerMode)
+            .unwrap_or(Symbol::new(env, "heuristic"));
+        let mut pid_state = None;
+        let adjustment_amount = if mode == Symbol::new(env, "pid") {
+            let gains: PidGains = env.storage().persistent().get(&StabilityDataKey::PidGains).ok_or(4)?; // ERR_NOT_FOUND
+            let state: PidState = env.storage().persistent().get(&StabilityDataKey::PidState)
+                .unwrap_or(PidState { integral: 0, prev_error: 0 });
+            let error = median_price as i64 - peg_target as i64;
+            let (output, next_state) = pid_step(&gains, &state, error);
+            pid_state = Some(next_state);
+            if (output > 0) != (median_price > peg_target) || output == 0 {
+                log!(env, "GodHead PID output {} opposes or nulls deviation; holding", output);
+                let mut plan = AdjustmentPlan::skip(Self::no_adjustment(env, "PID output holds", ai_confidence));
+                plan.pid_state = pid_state;
+                return Ok(plan);
+            }
+            output.unsigned_abs()
+        } else {
+            heuristic_amount(deviation, ai_confidence)
+        };
+        
+        // Rolling-window budget across all adjustments
+        let remaining = Self::remaining_budget(env);
+        let adjustment_type = if median_price > peg_target {
+            Symbol::new(env, "burn") // Burn to reduce supply if price > peg
+        } else {
+            Symbol::new(env, "mint") // Mint to increase supply if price < peg
+        };
+        
+        Ok(AdjustmentPlan {
+            adjustment: StabilityAdjustment {
+                adjustment_type,
+                amount: adjustment_amount.min(remaining),
+                reason: Self::peg_deviation_reason(env, median_price, peg_target),
+                ai_confidence,
+                timestamp: env.ledger().timestamp(),
+            },
+            pid_state,
+            budget_exhausted: remaining == 0,
+            requested: adjustment_amount,
+        })
+    }
+    
+    // "Peg deviation: <median> vs <peg>"
+    fn peg_deviation_reason(env: &Env, median_price: u64, peg_target: u64) -> Bytes {
+        let mut reason = Bytes::from_slice(env, b"Peg deviation: ");
+        let (digits, start) = decimal_digits(median_price);
+        reason.extend_from_slice(&digits[start..]);
+        reason.extend_from_slice(b" vs ");
+        let (digits, start) = decimal_digits(peg_target);
+        reason.extend_from_slice(&digits[start..]);
+        reason
+    }
+    
+    fn no_adjustment(env: &Env, reason: &str, ai_confidence: u64) -> StabilityAdjustment {
+        StabilityAdjustment {
+            adjustment_type: Symbol::new(env, "none"),
+            amount: 0,
+            reason: Bytes::from_slice(env, reason.as_bytes()),
+            ai_confidence,
+            timestamp: env.ledger().timestamp(),
+        }
+    }
+    
+    // Budget left in the current rolling window, from AdjustmentsLog timestamps
+    fn remaining_budget(env: &Env) -> u64 {
+        let budget: u64 = env.storage().persistent().get(&StabilityDataKey::AdjustmentBudget).unwrap_or(10_000_000);
+        let window: u64 = env.storage().persistent().get(&StabilityDataKey::AdjustmentWindow).unwrap_or(86400);
+        let window_start = env.ledger().timestamp().saturating_sub(window);
+        
+        let log_vec: Vec<StabilityAdjustment> = env.storage().persistent().get(&StabilityDataKey::AdjustmentsLog).unwrap_or(Vec::new(env));
+        let mut used = 0u64;
+        for adjustment in log_vec.iter().rev() {
+            if adjustment.timestamp < window_start {
+                break; // Log is chronological
+            }
+            used = used.saturating_add(adjustment.amount);
+        }
+        budget.saturating_sub(used)
+    }
+    
+    // Whether applying the adjustment would push the period's net supply change past the spend cap
+    fn exceeds_spend_cap(env: &Env, adjustment: &StabilityAdjustment) -> bool {
+        let cap = Self::get_autonomous_spend_cap(env.clone());
+        let period_start = env.ledger().timestamp().saturating_sub(cap.period);
+        let mint = Symbol::new(env, "mint");
+        
+        let log_vec: Vec<StabilityAdjustment> = env.storage().persistent().get(&StabilityDataKey::AdjustmentsLog).unwrap_or(Vec::new(env));
+        let mut net = 0i128;
+        for past in log_vec.iter().rev() {
+            if past.timestamp < period_start {
+                break; // Log is chronological
+            }
+            net += if past.adjustment_type == mint { past.amount as i128 } else { -(past.amount as i128) };
+        }
+        net += if adjustment.adjustment_type == mint { adjustment.amount as i128 } else { -(adjustment.amount as i128) };
+        net.unsigned_abs() > cap.max_net_change as u128
+    }
+    
+    // Hold an over-cap adjustment as a governance proposal instead of executing it.
+    // A full queue drops the adjustment; the next auto_adjust plans afresh from the live median.
+    fn queue_adjustment(env: &Env, adjustment: StabilityAdjustment) {
+        let mut queue: Vec<QueuedAdjustment> = env.storage().persistent().get(&StabilityDataKey::QueuedAdjustments).unwrap_or(Vec::new(env));
+        let amount = adjustment.amount;
+        if queue.len() >= MAX_QUEUED_ADJUSTMENTS {
+            events::publish(env, Symbol::new(env, "AdjustmentQueueFull"), amount);
+            log!(env, "GodHead adjustment queue full; dropping {} PI over-cap adjustment", amount);
+            return;
+        }
+        let seq: u64 = env.storage().persistent().get(&StabilityDataKey::QueuedAdjustmentSeq).unwrap_or(0);
+        env.storage().persistent().set(&StabilityDataKey::QueuedAdjustmentSeq, &(seq + 1));
+        let proposal = Self::queued_proposal_id(env, seq);
+        queue.push_back(QueuedAdjustment { proposal: proposal.clone(), adjustment });
+        env.storage().persistent().set(&StabilityDataKey::QueuedAdjustments, &queue);
+        
+        events::publish(env, Symbol::new(env, "AdjustmentQueued"), (proposal, amount));
+        log!(env, "GodHead adjustment of {} PI exceeds the autonomous spend cap; queued for governance", amount);
+    }
+    
+    // "stability_adj_<seq>"; the prefix plus 18 digits stays within the 32-byte Symbol limit
+    fn queued_proposal_id(env: &Env, seq: u64) -> Symbol {
+        const PREFIX: &[u8] = b"stability_adj_";
+        let mut id = [0u8; 32];
+        id[..PREFIX.len()].copy_from_slice(PREFIX);
+        let (digits, start) = decimal_digits(seq);
+        let digits = &digits[start..];
+        let len = (PREFIX.len() + digits.len()).min(id.len());
+        id[PREFIX.len()..len].copy_from_slice(&digits[..len - PREFIX.len()]);
+        Symbol::new(env, core::str::from_utf8(&id[..len]).unwrap_or("stability_adj"))
+    }
+    
+    // Remove a queued adjustment by proposal id
+    fn dequeue_adjustment(env: &Env, proposal: &Symbol) -> Result<StabilityAdjustment, u32> {
+        let mut queue = Self::get_queued_adjustments(env.clone());
+        let index = queue.iter().position(|queued| queued.proposal == *proposal).ok_or(4)? as u32; // ERR_NOT_FOUND
+        let queued = queue.get(index).ok_or(4)?;
+        queue.remove(index);
+        env.storage().persistent().set(&StabilityDataKey::QueuedAdjustments, &queue);
+        Ok(queued.adjustment)
+    }
+    
+    // Apply a governance-approved adjustment held back by the spend cap (multi-sig required)
+    pub fn execute_queued_adjustment(env: Env, proposal: Symbol) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        let mut adjustment = Self::dequeue_adjustment(&env, &proposal)?;
+        adjustment.timestamp = env.ledger().timestamp();
+        let adjustment_type = adjustment.adjustment_type.clone();
+        let adjustment_amount = adjustment.amount;
+        
+        Self::record_adjustment(&env, adjustment);
+        env.storage().persistent().set(&StabilityDataKey::LastAdjustment, &env.ledger().timestamp());
+        
+        events::publish(&env, Symbol::new(&env, "QueuedAdjustmentExecuted"), (proposal, adjustment_type.clone(), adjustment_amount));
+        log!(&env, "GodHead queued adjustment approved: {} {} PI", adjustment_type, adjustment_amount);
+        Ok(())
+    }
+    
+    // Drop a queued adjustment governance rejected (multi-sig required)
+    pub fn discard_queued_adjustment(env: Env, proposal: Symbol) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        let adjustment = Self::dequeue_adjustment(&env, &proposal)?;
+        
+        events::publish(&env, Symbol::new(&env, "QueuedAdjustmentDiscarded"), (proposal, adjustment.amount));
+        log!(&env, "GodHead queued adjustment of {} PI discarded", adjustment.amount);
+        Ok(())
+    }
+    
+    // Set the net supply change the AI may make per period without governance approval
+    pub fn set_autonomous_spend_cap(env: Env, cap: AutonomousSpendCap) -> Result<(), u32> {
+        PiCoinContract::require_multi_sig(&env)?;
+        if cap.period == 0 {
+            return Err(3); // ERR_INVALID_INPUT
+        }
+        env.storage().persistent().set(&StabilityDataKey::AutonomousSpendCap, &cap);
+        
+        events::publish(&env, Symbol::new(&env, "AutonomousSpendCapUpdated"), cap);
+        log!(&env, "GodHead autonomous spend cap updated");
+        Ok(())
+    }
+    
+    pub fn get_autonomous_spend_cap(env: Env) -> AutonomousSpendCap {
+        env.storage().persistent().get(&StabilityDataKey::AutonomousSpendCap).unwrap_or(DEFAULT_AUTONOMOUS_SPEND_CAP)
+    }
+    
+    // Adjustments awaiting governance approval, oldest first
+    pub fn get_queued_adjustments(env: Env) -> Vec<QueuedAdjustment> {
+        env.storage().persistent().get(&StabilityDataKey::QueuedAdjustments).unwrap_or(Vec::new(&env))
+    }
+    
     // Update adjustment threshold eternally
     pub fn update_threshold(env: Env, new_threshold: u64) -> Result<(), u32> {
         PiCoinContract::require_multi_sig(&env)?;
@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::Ledger, Env};
+use pi_coin_contract::prng::NexusPrng;
+
+fn draws(prng: &mut NexusPrng) -> [i128; 8] {
+    let mut values = [0i128; 8];
+    for value in values.iter_mut() {
+        *value = prng.gen_range(0, 1_000_000);
+    }
+    values
+}
+
+#[test]
+fn test_same_seed_same_sequence() {
+    let env = Env::default();
+    let first = draws(&mut NexusPrng::new(&env, 42));
+    assert_eq!(first, draws(&mut NexusPrng::new(&env, 42)));
+    assert_ne!(first, draws(&mut NexusPrng::new(&env, 43)));
+    assert!(first.iter().all(|v| (0..1_000_000).contains(v)));
+}
+
+#[test]
+fn test_sequence_differs_across_ledgers() {
+    let env = Env::default();
+    let first = draws(&mut NexusPrng::new(&env, 42));
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    assert_ne!(first, draws(&mut NexusPrng::new(&env, 42)));
+}
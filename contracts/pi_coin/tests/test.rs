@@ -1,5 +1,28 @@
-use soroban_sdk::{Env, Address, Symbol, Bytes, BytesN, events, Vec, crypto};
-use pi_coin_contract::PiCoinContractClient; // Assuming generated client from updated lib.rs
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::{Address as _, Events, Ledger}, Env, Address, Symbol, Bytes, BytesN, Map, Vec, Val, IntoVal, TryFromVal, crypto, xdr::ToXdr};
+use pi_coin_contract::ai_simulation::AiSimulation;
+use pi_coin_contract::governance::{GovernanceContract, GovernanceContractClient, GovernanceDataKey, PowerSource};
+use pi_coin_contract::oracle::{OracleContract, OracleContractClient, OracleDataKey};
+use pi_coin_contract::retention::RetentionPolicy;
+use pi_coin_contract::stability::{heuristic_amount, pid_step, PidGains, PidState, AutonomousSpendCap, StabilityAdjustment, StabilityContract, StabilityContractClient, StabilityDataKey, KEEPER_COOLDOWN, MAX_QUEUED_ADJUSTMENTS};
+use pi_coin_contract::{ActionEvent, AiEvolutionEntry, AiThresholds, BridgeEvent, BridgeReceivedEvent, BurnEvent, FeedBounds, FeedRejectedEvent, ComplianceBatchEvent, TokenMetadata, DECIMALS, DataKey, LogRecord, MintEvent, PiCoinContract, PiCoinContractClient, PiCoinError, ProposalTally, ProvenanceEntry, RedeemedEvent, TransferEvent, SCHEMA_VERSION, ACTION_TTL, UPGRADE_TIMELOCK}; // Assuming generated client from updated lib.rs
+
+// Find the last event whose topic0 matches the given name, returning its topics and data
+fn find_event(env: &Env, name: &str) -> Option<(Vec<Val>, Val)> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let topic0 = topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok());
+        if topic0 == Some(Symbol::new(env, name)) {
+            found = Some((topics, data));
+        }
+    }
+    found
+}
+
+// Coin id create_coin derives for a mint at the given nonce
+fn expected_coin_id(env: &Env, to: &Address, amount: u64, source: &Symbol, nonce: u64) -> BytesN<32> {
+    crypto::sha256(env, &(to.clone(), amount, source.clone(), nonce).to_xdr(env)).into()
+}
 
 #[test]
 fn test_init_and_basic_setup() {
@@ -12,17 +35,13 @@ fn test_init_and_basic_setup() {
     let threshold = 1u32;
     
     env.mock_all_auths();
-    let result = client.init(&admin, &signers, &threshold);
-    assert!(result.is_ok());
+    assert!(client.try_init(&signers, &threshold).is_ok());
     
     // Check events
-    let events = env.events();
-    assert!(events.len() > 0);
-    assert_eq!(events[0].1, Symbol::new(&env, "GodHeadNexusInitialized"));
+    assert!(find_event(&env, "GodHeadNexusInitialized").is_some());
     
     // Check supply
-    let supply = client.get_current_supply();
-    assert_eq!(supply, Ok(0u64));
+    assert_eq!(client.get_current_supply(), 0);
     
     // Eternal: Check gas budget for performance
     assert!(env.budget().cpu_instruction_cost() < 1000000); // Safe limit
@@ -39,35 +58,30 @@ fn test_mint_with_compliance_and_verifications() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // Register compliance for user
-    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
     
     let amount = 100u64;
     let source = Symbol::new(&env, "mining");
-    let coin_result = client.mint(&user, &amount, &source);
-    assert!(coin_result.is_ok());
-    let coin = coin_result.unwrap();
+    let coin = client.mint(&user, &amount, &source);
     
     assert_eq!(coin.amount, amount);
     assert_eq!(coin.owner, user);
     assert_eq!(coin.source, source);
-    assert_eq!(coin.verified, true);
+    assert!(coin.verified);
     assert!(!coin.proof.is_empty());
     
     // Eternal: Verify real coin_id hash (simulate from mint data)
-    let id_data = format!("{}-{}-{}", user, amount, source);
-    let expected_hash = crypto::sha256(&env, &Bytes::from(id_data.as_bytes())).into();
-    // In real client, coin_id would be returned; here assume it's set
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    assert_eq!(MintEvent::try_from_val(&env, &data).unwrap().coin_id, expected_coin_id(&env, &user, amount, &source, 0));
     
     // Check supply increase
-    let supply = client.get_current_supply();
-    assert_eq!(supply, Ok(amount));
+    assert_eq!(client.get_current_supply(), amount);
     
     // Check events
-    let events = env.events();
-    assert!(events.iter().any(|e| e.1 == Symbol::new(&env, "GodHeadNexusMinted")));
+    assert!(find_event(&env, "GodHeadNexusMinted").is_some());
     
     // Eternal: Check AI evolution (weights increased)
     // Assume client exposes internal for test; else mock
@@ -86,28 +100,23 @@ fn test_transfer_with_compliance_and_proof_check() {
     let user2 = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // Register compliance for both users
-    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
-    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32).unwrap();
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
     
-    let coin_result = client.mint(&user1, &200, &Symbol::new(&env, "rewards"));
-    assert!(coin_result.is_ok());
+    client.mint(&user1, &200, &Symbol::new(&env, "rewards"));
     // Eternal: Use real hash from mint
-    let id_data = format!("{}-{}-{}", user1, 200u64, Symbol::new(&env, "rewards"));
-    let coin_id = crypto::sha256(&env, &Bytes::from(id_data.as_bytes())).into();
+    let coin_id = expected_coin_id(&env, &user1, 200, &Symbol::new(&env, "rewards"), 0);
     
-    let transfer_result = client.transfer(&user1, &user2, &100, &coin_id);
-    assert!(transfer_result.is_ok());
+    assert!(client.try_transfer(&user1, &user2, &100, &coin_id).is_ok());
     
     // Check events
-    let events = env.events();
-    assert!(events.iter().any(|e| e.1 == Symbol::new(&env, "GodHeadNexusTransferred")));
+    assert!(find_event(&env, "GodHeadNexusTransferred").is_some());
     
     // Check supply unchanged
-    let supply = client.get_current_supply();
-    assert_eq!(supply, Ok(200));
+    assert_eq!(client.get_current_supply(), 200);
 }
 
 #[test]
@@ -121,26 +130,21 @@ fn test_burn_and_supply_control() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // Register compliance
-    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
     
-    let coin_result = client.mint(&user, &500, &Symbol::new(&env, "p2p"));
-    assert!(coin_result.is_ok());
-    let id_data = format!("{}-{}-{}", user, 500u64, Symbol::new(&env, "p2p"));
-    let coin_id = crypto::sha256(&env, &Bytes::from(id_data.as_bytes())).into();
+    client.mint(&user, &500, &Symbol::new(&env, "p2p"));
+    let coin_id = expected_coin_id(&env, &user, 500, &Symbol::new(&env, "p2p"), 0);
     
-    let burn_result = client.burn(&user, &200, &coin_id);
-    assert!(burn_result.is_ok());
+    assert!(client.try_burn(&user, &200, &coin_id).is_ok());
     
     // Check supply decrease
-    let supply = client.get_current_supply();
-    assert_eq!(supply, Ok(300));
+    assert_eq!(client.get_current_supply(), 300);
     
     // Check events
-    let events = env.events();
-    assert!(events.iter().any(|e| e.1 == Symbol::new(&env, "GodHeadNexusBurned")));
+    assert!(find_event(&env, "GodHeadNexusBurned").is_some());
 }
 
 #[test]
@@ -154,19 +158,16 @@ fn test_compliance_registration_and_oracle_update() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // Register compliance
-    let compliance_result = client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
-    assert!(compliance_result.is_ok());
+    assert!(client.try_register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).is_ok());
     
     // Check events
-    let events = env.events();
-    assert!(events.iter().any(|e| e.1 == Symbol::new(&env, "GodHeadComplianceRegistered")));
+    assert!(find_event(&env, "GodHeadComplianceRegistered").is_some());
     
     // Update oracle feed
-    let oracle_result = client.update_oracle_feed(&Symbol::new(&env, "PI"), &314159);
-    assert!(oracle_result.is_ok());
+    assert!(client.update_oracle_feed(&Symbol::new(&env, "PI"), &314159));
 }
 
 #[test]
@@ -180,15 +181,13 @@ fn test_ai_governance_vote() {
     let voter = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // AI governance vote
-    let vote_result = client.ai_governance_vote(&voter, &Symbol::new(&env, "proposal1"), &true);
-    assert!(vote_result.is_ok());
+    assert!(client.try_ai_governance_vote(&voter, &Symbol::new(&env, "proposal1"), &true).is_ok());
     
     // Check events
-    let events = env.events();
-    assert!(events.iter().any(|e| e.1 == Symbol::new(&env, "GodHeadAIGovernanceVoted")));
+    assert!(find_event(&env, "GodHeadAIGovernanceVoted").is_some());
 }
 
 #[test]
@@ -202,17 +201,11 @@ fn test_interdimensional_bridge() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
-    // Eternal: Mock bridge registry for realism
-    // Assume client has set_bridge method; else simulate
-    let bridge_addr = Address::generate(&env);
-    // client.set_bridge(&Symbol::new(&env, "mars"), &bridge_addr); // If available
-    
-    let bridge_result = client.interdimensional_bridge(&user, &Symbol::new(&env, "mars"), &100);
-    // With registry set, should ok; else err
-    // assert!(bridge_result.is_ok()); // Adjust based on mock
-    assert!(bridge_result.is_err()); // As per original if not set
+    // No bridge is registered for "mars"; test_register_bridge_then_bridge covers the registered path
+    let bridge_result = client.try_interdimensional_bridge(&user, &Symbol::new(&env, "mars"), &100, &100, &u64::MAX);
+    assert_eq!(bridge_result, Err(Ok(PiCoinError::NotFound)));
 }
 
 #[test]
@@ -227,32 +220,24 @@ fn test_invalid_operations_with_error_handling() {
     let user2 = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // Register compliance for user1 only
-    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
     
     // Mint first
-    let coin_result = client.mint(&user1, &100, &Symbol::new(&env, "mining"));
-    assert!(coin_result.is_ok());
-    let id_data = format!("{}-{}-{}", user1, 100u64, Symbol::new(&env, "mining"));
-    let coin_id = crypto::sha256(&env, &Bytes::from(id_data.as_bytes())).into();
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let coin_id = expected_coin_id(&env, &user1, 100, &Symbol::new(&env, "mining"), 0);
     
     // Test invalid transfer (recipient not compliant)
-    let transfer_result = client.transfer(&user1, &user2, &50, &coin_id);
-    assert!(transfer_result.is_err());
-    // Eternal: Check specific error code
-    assert_eq!(transfer_result.unwrap_err(), 6); // ERR_COMPLIANCE_FAILED
+    // Eternal: Check specific named error
+    assert_eq!(client.try_transfer(&user1, &user2, &50, &coin_id), Err(Ok(PiCoinError::ComplianceFailed)));
     
     // Test invalid source
-    let mint_result = client.mint(&user1, &50, &Symbol::new(&env, "invalid"));
-    assert!(mint_result.is_err());
-    assert_eq!(mint_result.unwrap_err(), 3); // ERR_INVALID_INPUT
+    assert_eq!(client.try_mint(&user1, &50, &Symbol::new(&env, "invalid")), Err(Ok(PiCoinError::InvalidInput)));
     
     // Test invalid burn (insufficient amount)
-    let burn_result = client.burn(&user1, &200, &coin_id);
-    assert!(burn_result.is_err());
-    assert_eq!(burn_result.unwrap_err(), 2); // ERR_INSUFFICIENT_BALANCE
+    assert_eq!(client.try_burn(&user1, &200, &coin_id), Err(Ok(PiCoinError::InsufficientBalance)));
 }
 
 #[test]
@@ -266,24 +251,48 @@ fn test_supply_cap_and_peg_enforcement() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
+    client.init(&signers, &1u32);
     
     // Register compliance
-    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
     
     // Test normal mint
-    let mint_result = client.mint(&user, &1000, &Symbol::new(&env, "mining"));
-    assert!(mint_result.is_ok());
-    let supply = client.get_current_supply();
-    assert_eq!(supply, Ok(1000));
+    assert!(client.try_mint(&user, &1000, &Symbol::new(&env, "mining")).is_ok());
+    assert_eq!(client.get_current_supply(), 1000);
     
     // Test supply cap (mock exceed by minting large amount)
-    let large_mint = client.mint(&user, &100_000_000_000u64, &Symbol::new(&env, "mining"));
-    assert!(large_mint.is_err()); // Should hit cap
-    assert_eq!(large_mint.unwrap_err(), 5); // ERR_SUPPLY_EXCEEDED
+    let large_mint = client.try_mint(&user, &100_000_000_000u64, &Symbol::new(&env, "mining"));
+    assert_eq!(large_mint, Err(Ok(PiCoinError::SupplyExceeded))); // Should hit cap
 }
 
 // Eternal: New test for AI evolution and vault
+#[test]
+fn test_ai_evolution_log_follows_retention_policy() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let signers = Vec::from_array(&env, [Address::generate(&env)]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    assert_eq!(client.try_set_ai_evolution_retention(&RetentionPolicy { max_entries: 0, max_age: 1000 }), Err(Ok(PiCoinError::InvalidInput)));
+    client.set_ai_evolution_retention(&RetentionPolicy { max_entries: 2, max_age: 1000 });
+    
+    // Count bound: the oldest entry goes once a third is appended
+    for vote in [true, false, true] {
+        client.ai_governance_vote(&Address::generate(&env), &Symbol::new(&env, "raise_quorum"), &vote);
+    }
+    let log = client.get_ai_evolution_log();
+    assert_eq!(log.len(), 2);
+    assert!(!log.get(0).unwrap().vote);
+    
+    // Age bound: entries past max_age go on the next append; the total keeps counting
+    env.ledger().with_mut(|li| li.timestamp += 1001);
+    client.ai_governance_vote(&Address::generate(&env), &Symbol::new(&env, "raise_quorum"), &false);
+    assert_eq!(client.get_ai_evolution_log().len(), 1);
+    assert_eq!(client.get_ai_evolution_total(), 4);
+}
+
 #[test]
 fn test_eternal_ai_evolution_and_vault() {
     let env = Env::default();
@@ -295,19 +304,18 @@ fn test_eternal_ai_evolution_and_vault() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
-    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
     
     // Mint and check AI evolution
-    client.mint(&user, &100, &Symbol::new(&env, "mining")).unwrap();
+    client.mint(&user, &100, &Symbol::new(&env, "mining"));
     // Assume client.get_neural_weights() returns Vec<u64>
     // let weights = client.get_neural_weights().unwrap();
     // assert!(weights[0] > 1); // Evolved
     
     // Test vault
-    let id_data = format!("{}-{}-{}", user, 100u64, Symbol::new(&env, "mining"));
-    let key = crypto::sha256(&env, &Bytes::from(id_data.as_bytes())).into();
-    let vault_result = client.get_holographic_vault(&key);
+    let key = expected_coin_id(&env, &user, 100, &Symbol::new(&env, "mining"), 0);
+    let vault_result = client.try_get_holographic_vault(&key);
     assert!(vault_result.is_ok()); // Should have hologram
 }
 
@@ -323,14 +331,14 @@ fn test_eternal_fuzz_stress() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
-    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
     
     // Fuzz mint with random amounts
     for i in 0..100 {
         let amount = (i as u64 * 10) % 10000 + 1; // Pseudo-random
-        let result = client.mint(&user, &amount, &Symbol::new(&env, "mining"));
-        if amount <= 100_000_000_000u64 - client.get_current_supply().unwrap() { // Cap check
+        let result = client.try_mint(&user, &amount, &Symbol::new(&env, "mining"));
+        if amount <= 100_000_000_000u64 - client.get_current_supply() { // Cap check
             assert!(result.is_ok());
         } else {
             assert!(result.is_err());
@@ -353,17 +361,2031 @@ fn test_eternal_peg_breach_with_events() {
     let user = Address::generate(&env);
     
     env.mock_all_auths();
-    client.init(&admin, &signers, &1u32).unwrap();
-    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32).unwrap();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
     
     // Breach peg
-    client.update_oracle_feed(&Symbol::new(&env, "PI"), &314160).unwrap(); // Not 314159
+    client.update_oracle_feed(&Symbol::new(&env, "PI"), &314160); // Not 314159
+    
+    assert_eq!(client.try_mint(&user, &100, &Symbol::new(&env, "mining")), Err(Ok(PiCoinError::PegBreached)));
+    
+    // The breach reaches the caller as the typed error; the failed call's writes and events roll back
+    assert!(find_event(&env, "GodHeadLog").is_none());
+    let depegs: Vec<Symbol> = env.as_contract(&contract_id, || env.storage().persistent().get(&DataKey::BlackHoleEvents).unwrap());
+    assert!(depegs.is_empty());
+}
+
+#[test]
+fn test_log_records_only_for_outcomes_that_commit() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    let unregistered = Address::generate(&env);
+    let log_count = |env: &Env| env.events().all().iter()
+        .filter(|(_, topics, _)| topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok()) == Some(Symbol::new(env, "GodHeadLog")))
+        .count();
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    let mut thresholds = client.get_ai_thresholds();
+    thresholds.transfer = 100;
+    client.set_ai_thresholds(&thresholds);
+    
+    client.mint(&user, &100, &Symbol::new(&env, "mining"));
+    let coin_id = MintEvent::try_from_val(&env, &find_event(&env, "GodHeadNexusMinted").unwrap().1).unwrap().coin_id;
+    let (topics, data) = find_event(&env, "GodHeadLog").unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), Symbol::new(&env, "info"));
+    assert_eq!(LogRecord::try_from_val(&env, &data).unwrap().error_code, 0);
+    let logged = log_count(&env);
+    
+    // A failed call reports through its typed error; a record published there would be rolled back
+    client.update_oracle_feed(&Symbol::new(&env, "PI"), &314160);
+    assert_eq!(client.try_mint(&user, &100, &Symbol::new(&env, "mining")), Err(Ok(PiCoinError::PegBreached)));
+    assert_eq!(log_count(&env), logged);
+    
+    // A failed batch slot is reported inside a call that succeeds, so its record persists
+    let results = client.batch_transfer(&user, &Vec::from_array(&env, [(unregistered, 10u64, coin_id)]));
+    assert_eq!(results.get(0).unwrap(), Err(PiCoinError::ComplianceFailed));
+    let (topics, data) = find_event(&env, "GodHeadLog").unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), Symbol::new(&env, "warn"));
+    let record = LogRecord::try_from_val(&env, &data).unwrap();
+    assert_eq!(record.operation, Symbol::new(&env, "transfer"));
+    assert_eq!(record.subject, user);
+    assert_eq!(record.amount, 10);
+    assert_eq!(record.error_code, PiCoinError::ComplianceFailed as u32);
+    
+    // A slot failing the peg check is logged at error level, and persists the same way
+    client.update_oracle_feed(&Symbol::new(&env, "PI"), &350000);
+    let results = client.batch_transfer(&user, &Vec::from_array(&env, [(Address::generate(&env), 10u64, coin_id)]));
+    assert_eq!(results.get(0).unwrap(), Err(PiCoinError::PegBreached));
+    let (topics, data) = find_event(&env, "GodHeadLog").unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), Symbol::new(&env, "error"));
+    assert_eq!(LogRecord::try_from_val(&env, &data).unwrap().error_code, PiCoinError::PegBreached as u32);
+}
+
+#[test]
+fn test_reconcile_supply_fixes_divergence() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    client.mint(&user, &1000, &Symbol::new(&env, "mining"));
+    
+    // Deliberate divergence between CurrentSupply and issued asset
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::CurrentSupply, &1500u64);
+    });
+    assert_eq!(client.get_current_supply(), 1500);
+    
+    let delta = client.reconcile_supply();
+    assert_eq!(delta, -500);
+    assert_eq!(client.get_current_supply(), 1000);
+    
+    assert!(find_event(&env, "SupplyReconciled").is_some());
+    
+    // Already reconciled: no-op
+    assert_eq!(client.reconcile_supply(), 0);
+}
+
+#[test]
+fn test_event_schema_topics_and_data() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
+    
+    client.mint(&user1, &300, &Symbol::new(&env, "rewards"));
+    let coin_id = expected_coin_id(&env, &user1, 300, &Symbol::new(&env, "rewards"), 0);
+    
+    let (topics, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    assert_eq!(topics.len(), 2);
+    assert_eq!(Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), user1);
+    let minted = MintEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(minted.amount, 300);
+    assert_eq!(minted.source, Symbol::new(&env, "rewards"));
+    assert_eq!(minted.coin_id, coin_id);
+    
+    client.transfer(&user1, &user2, &100, &coin_id);
+    let (topics, data) = find_event(&env, "GodHeadNexusTransferred").unwrap();
+    assert_eq!(topics.len(), 3);
+    assert_eq!(Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), user1);
+    assert_eq!(Address::try_from_val(&env, &topics.get(2).unwrap()).unwrap(), user2);
+    let transferred = TransferEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(transferred.amount, 100);
+    assert_eq!(transferred.coin_id, coin_id);
+    
+    client.burn(&user2, &50, &coin_id);
+    let (topics, data) = find_event(&env, "GodHeadNexusBurned").unwrap();
+    assert_eq!(topics.len(), 2);
+    assert_eq!(Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), user2);
+    let burned = BurnEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(burned.amount, 50);
+    assert_eq!(burned.coin_id, coin_id);
+}
+
+#[test]
+fn test_over_supply_mint_returns_named_error() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    
+    let result = client.try_mint(&user, &100_000_000_001u64, &Symbol::new(&env, "mining"));
+    assert_eq!(result, Err(Ok(PiCoinError::SupplyExceeded)));
+    assert_eq!(PiCoinError::SupplyExceeded as u32, 5); // Stable numeric code
+}
+
+#[test]
+fn test_burn_beyond_recorded_supply_fails_cleanly() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    client.mint(&user, &500, &Symbol::new(&env, "p2p"));
+    let coin_id = expected_coin_id(&env, &user, 500, &Symbol::new(&env, "p2p"), 0);
+    
+    // Recorded supply lower than the coin's amount
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::CurrentSupply, &100u64);
+    });
+    
+    assert_eq!(client.try_burn(&user, &200, &coin_id), Err(Ok(PiCoinError::InsufficientBalance)));
+    assert_eq!(client.get_current_supply(), 100);
+}
+
+// Malicious asset contract that re-enters PiCoinContract::mint from its own mint
+#[contract]
+pub struct ReentrantAssetContract;
+
+#[contractimpl]
+impl ReentrantAssetContract {
+    pub fn set_target(env: Env, pi_coin: Address) {
+        env.storage().instance().set(&symbol_short!("target"), &pi_coin);
+    }
+    
+    pub fn mint(env: Env, to: Address, _amount: i128) {
+        let pi_coin: Address = env.storage().instance().get(&symbol_short!("target")).unwrap();
+        let reentry = PiCoinContractClient::new(&env, &pi_coin).try_mint(&to, &1, &Symbol::new(&env, "mining"));
+        env.storage().instance().set(&symbol_short!("blocked"), &reentry.is_err());
+    }
+}
+
+#[test]
+fn test_reentrant_asset_is_blocked() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let asset_id = env.register_contract(None, ReentrantAssetContract);
+    ReentrantAssetContractClient::new(&env, &asset_id).set_target(&contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::AssetId, &asset_id);
+    });
+    
+    assert!(client.try_mint(&user, &100, &Symbol::new(&env, "mining")).is_ok());
+    
+    // Re-entry was refused and only the outer mint counted
+    let blocked: bool = env.as_contract(&asset_id, || {
+        env.storage().instance().get(&symbol_short!("blocked")).unwrap()
+    });
+    assert!(blocked);
+    assert_eq!(client.get_current_supply(), 100);
+    
+    // Guard released after the call
+    assert!(client.try_update_oracle_feed(&Symbol::new(&env, "PI"), &314159).is_ok());
+}
+
+// Bind a generated reporter to every source; returned in source order
+fn register_reporters(env: &Env, oracle: &OracleContractClient, sources: &Vec<Symbol>) -> Vec<Address> {
+    let mut reporters = Vec::new(env);
+    for source in sources.iter() {
+        let reporter = Address::generate(env);
+        oracle.set_feed_reporter(&source, &reporter);
+        reporters.push_back(reporter);
+    }
+    reporters
+}
+
+// Registered oracle whose breaker check_peg has tripped on a wide feed spread
+fn tripped_oracle(env: &Env) -> Address {
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(env, &oracle_id);
+    let sources = Vec::from_array(env, [Symbol::new(env, "chainlink"), Symbol::new(env, "pyth"), Symbol::new(env, "band")]);
+    oracle.init_oracle(&Vec::from_array(env, [Address::generate(env)]), &1u32, &sources);
+    let reporter = register_reporters(env, &oracle, &sources).get(0).unwrap();
+    oracle.update_feed(&Symbol::new(env, "chainlink"), &314159);
+    oracle.update_feed(&Symbol::new(env, "pyth"), &314159);
+    oracle.update_feed(&Symbol::new(env, "band"), &400000);
+    assert!(!oracle.check_peg(&reporter));
+    assert!(oracle.is_circuit_breaker_tripped());
+    oracle_id
+}
+
+#[test]
+fn test_oracle_median_stays_at_peg_when_feeds_agree() {
+    let env = Env::default();
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(&env, &oracle_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let sources = Vec::from_array(&env, [Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"), Symbol::new(&env, "band")]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&signers, &1u32, &sources);
+    register_reporters(&env, &oracle, &sources);
+    for source in sources.iter() {
+        oracle.update_feed(&source, &314159);
+    }
+    
+    // Stored median is the untouched feed value
+    let stored: u64 = env.as_contract(&oracle_id, || {
+        env.storage().persistent().get(&OracleDataKey::MedianPrice).unwrap()
+    });
+    assert_eq!(stored, 314159);
+    
+    // Effective price moves at most 1% from the median
+    let effective = oracle.get_effective_price();
+    assert!(effective.abs_diff(314159) <= 3141);
+    
+    // Pure median computation has no side effects
+    let prices = Vec::from_array(&env, [3u64, 1u64, 2u64]);
+    assert_eq!(oracle.get_median_price(&prices), 2);
+    assert_eq!(prices, Vec::from_array(&env, [3u64, 1u64, 2u64]));
+}
+
+#[test]
+fn test_check_peg_requires_minimum_feeds() {
+    let env = Env::default();
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(&env, &oracle_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let sources = Vec::from_array(&env, [Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"), Symbol::new(&env, "band")]);
     
-    let mint_result = client.mint(&user, &100, &Symbol::new(&env, "mining"));
-    assert!(mint_result.is_err());
-    assert_eq!(mint_result.unwrap_err(), 7); // ERR_PEG_BREACHED
+    env.mock_all_auths();
+    oracle.init_oracle(&signers, &1u32, &sources);
+    let reporters = register_reporters(&env, &oracle, &sources);
+    oracle.update_feed(&Symbol::new(&env, "chainlink"), &314159);
+    
+    // A single feed is not enough for a confident result
+    assert_eq!(oracle.try_check_peg(&reporters.get(0).unwrap()), Err(Ok(PiCoinError::InsufficientFeeds as u32)));
+    assert!(!oracle.is_circuit_breaker_tripped());
+}
+
+#[test]
+fn test_check_peg_trips_circuit_breaker_on_wide_spread() {
+    let env = Env::default();
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(&env, &oracle_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let sources = Vec::from_array(&env, [Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"), Symbol::new(&env, "band")]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&signers, &1u32, &sources);
+    let reporter = register_reporters(&env, &oracle, &sources).get(0).unwrap();
+    oracle.update_feed(&Symbol::new(&env, "chainlink"), &314159);
+    oracle.update_feed(&Symbol::new(&env, "pyth"), &314159);
+    oracle.update_feed(&Symbol::new(&env, "band"), &400000);
+    
+    assert!(!oracle.check_peg(&reporter));
+    assert!(oracle.is_circuit_breaker_tripped());
+    assert!(find_event(&env, "CircuitBreakerTripped").is_some());
+    
+    // Paused until reset
+    assert_eq!(oracle.try_check_peg(&reporter), Err(Ok(PiCoinError::CircuitBreakerTripped as u32)));
+    oracle.reset_circuit_breaker();
+    assert!(!oracle.is_circuit_breaker_tripped());
+}
+
+#[test]
+fn test_peg_deviation_reports_sign_and_breach() {
+    let env = Env::default();
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(&env, &oracle_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let sources = Vec::from_array(&env, [Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"), Symbol::new(&env, "band")]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&signers, &1u32, &sources);
+    register_reporters(&env, &oracle, &sources);
+    assert_eq!(oracle.peg_deviation(), (0, false));
+    
+    // Above peg and past the breach threshold
+    for source in sources.iter() {
+        oracle.update_feed(&source, &316000);
+    }
+    assert_eq!(oracle.peg_deviation(), (1841, true));
+    
+    // Below peg but within the threshold
+    for source in sources.iter() {
+        oracle.update_feed(&source, &313900);
+    }
+    assert_eq!(oracle.peg_deviation(), (-259, false));
+    
+    // Read-only: the breaker and stored median are untouched
+    assert!(!oracle.is_circuit_breaker_tripped());
+    assert_eq!(oracle.get_current_median(), 313900);
+}
+
+// Stability contract wired to an oracle contract whose feeds agree on an off-peg price
+fn setup_depegged_stability<'a>(env: &'a Env, price: u64) -> (Address, StabilityContractClient<'a>) {
+    let stability_id = env.register_contract(None, StabilityContract);
+    let stability = StabilityContractClient::new(env, &stability_id);
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(env, &oracle_id);
+    
+    let admin = Address::generate(env);
+    let signers = Vec::from_array(env, [admin.clone()]);
+    let sources = Vec::from_array(env, [Symbol::new(env, "chainlink"), Symbol::new(env, "pyth"), Symbol::new(env, "band")]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&signers, &1u32, &sources);
+    register_reporters(env, &oracle, &sources);
+    for source in sources.iter() {
+        oracle.update_feed(&source, &price);
+    }
+    oracle.set_peg_checker(&stability_id, &true);
+    stability.init_stability(&signers, &1u32, &oracle_id);
+    (stability_id, stability)
+}
+
+#[test]
+fn test_stability_budget_stops_repeated_adjustments() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    stability.set_adjustment_budget(&100u64, &86400u64);
+    
+    for _ in 0..10 {
+        stability.auto_adjust();
+    }
+    
+    let log = stability.get_adjustment_log();
+    let total: u64 = log.iter().map(|a| a.amount).sum();
+    assert!(total <= 100);
+    assert!(find_event(&env, "AdjustmentBudgetExhausted").is_some());
+    
+    // Further calls add nothing while the window is exhausted
+    let entries = log.len();
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), entries);
+}
+
+#[test]
+fn test_pid_controller_converges_with_less_overshoot() {
+    // Plant model: each PI of adjustment moves the price by 200 micro-units
+    let peg = 314159i64;
+    let gain = 200i64;
+    let step = 20000i64;
+    
+    // Heuristic path
+    let mut price = peg + step;
+    let mut heuristic_overshoot = 0i64;
+    for _ in 0..30 {
+        let error = price - peg;
+        if error.unsigned_abs() >= 1000 {
+            let amount = heuristic_amount(error.unsigned_abs(), 99) as i64;
+            price -= error.signum() * amount * gain;
+        }
+        heuristic_overshoot = heuristic_overshoot.max(peg - price);
+    }
+    
+    // PID path
+    let gains = PidGains { kp: 2500, ki: 50, kd: 250 };
+    let mut state = PidState { integral: 0, prev_error: 0 };
+    let mut price = peg + step;
+    let mut pid_overshoot = 0i64;
+    for _ in 0..30 {
+        let (output, next) = pid_step(&gains, &state, price - peg);
+        state = next;
+        price -= output * gain;
+        pid_overshoot = pid_overshoot.max(peg - price);
+    }
+    
+    assert!(pid_overshoot < heuristic_overshoot);
+    assert!((price - peg).abs() < 1000); // Settled inside the adjustment threshold
+}
+
+#[test]
+fn test_set_controller_rejects_unknown_mode() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    let gains = PidGains { kp: 2500, ki: 50, kd: 250 };
+    
+    assert_eq!(stability.try_set_controller(&Symbol::new(&env, "magic"), &gains), Err(Ok(3)));
+    stability.set_controller(&Symbol::new(&env, "pid"), &gains);
+    stability.auto_adjust();
+    assert!(find_event(&env, "GodHeadControllerUpdated").is_some());
+}
+
+#[test]
+fn test_set_controller_keeps_spend_cap() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    let cap = AutonomousSpendCap { max_net_change: 42, period: 3600 };
+    stability.set_autonomous_spend_cap(&cap);
+    
+    stability.set_controller(&Symbol::new(&env, "pid"), &PidGains { kp: 2500, ki: 50, kd: 250 });
+    let kept = stability.get_autonomous_spend_cap();
+    assert_eq!((kept.max_net_change, kept.period), (42, 3600));
+}
+
+#[test]
+fn test_auto_adjust_uses_oracle_median() {
+    let env = Env::default();
+    let (stability_id, stability) = setup_depegged_stability(&env, 330000);
+    
+    // On-peg values in the stability contract's own storage, under the main contract's feed key
+    // or the oracle's median key, must not be consulted; only the oracle contract is
+    env.as_contract(&stability_id, || {
+        let mut pi_feed = Map::<Symbol, u64>::new(&env);
+        pi_feed.set(Symbol::new(&env, "PI"), 314159u64);
+        env.storage().persistent().set(&DataKey::OracleFeeds, &pi_feed);
+        env.storage().persistent().set(&OracleDataKey::MedianPrice, &314159u64);
+    });
+    
+    stability.auto_adjust();
+    let log = stability.get_adjustment_log();
+    assert_eq!(log.len(), 1);
+    let adjustment = log.get(0).unwrap();
+    assert_eq!(adjustment.adjustment_type, Symbol::new(&env, "burn"));
+    assert!(adjustment.amount > 0);
+}
+
+// Seed the adjustment log with alternating burn/mint entries at timestamps 100, 200, ...
+fn seed_adjustment_log(env: &Env, stability_id: &Address, count: u32) {
+    env.as_contract(stability_id, || {
+        let mut log_vec = Vec::new(env);
+        for i in 0..count {
+            let adjustment_type = if i % 2 == 0 { "burn" } else { "mint" };
+            log_vec.push_back(StabilityAdjustment {
+                adjustment_type: Symbol::new(env, adjustment_type),
+                amount: 10 + i as u64,
+                reason: Bytes::from_slice(env, b"seeded"),
+                ai_confidence: 50,
+                timestamp: 100 * (i as u64 + 1),
+            });
+        }
+        env.storage().persistent().set(&StabilityDataKey::AdjustmentsLog, &log_vec);
+    });
+}
+
+#[test]
+fn test_adjustment_history_pagination() {
+    let env = Env::default();
+    let (stability_id, stability) = setup_depegged_stability(&env, 330000);
+    seed_adjustment_log(&env, &stability_id, 5);
+    
+    let (page, next) = stability.get_adjustment_history(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().timestamp, 100);
+    assert_eq!(next, Some(2));
+    
+    let (page, next) = stability.get_adjustment_history(&4, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().timestamp, 500);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn test_adjustment_history_filters_by_type_and_time() {
+    let env = Env::default();
+    let (stability_id, stability) = setup_depegged_stability(&env, 330000);
+    seed_adjustment_log(&env, &stability_id, 6);
+    let mint = Some(Symbol::new(&env, "mint"));
+    
+    let (mints, next) = stability.filter_adjustment_history(&mint, &0, &u64::MAX, &0, &10);
+    assert_eq!(mints.len(), 3);
+    assert!(mints.iter().all(|a| a.adjustment_type == Symbol::new(&env, "mint")));
+    assert_eq!(next, None);
+    
+    // Burns between 200 and 500: only the one at 300 and 500
+    let burn = Some(Symbol::new(&env, "burn"));
+    let (burns, _) = stability.filter_adjustment_history(&burn, &200, &500, &0, &10);
+    assert_eq!(burns.len(), 2);
+    assert_eq!(burns.get(0).unwrap().timestamp, 300);
+    
+    // A page of one match stops scanning right after it
+    let (first, next) = stability.filter_adjustment_history(&mint, &0, &u64::MAX, &0, &1);
+    assert_eq!(first.get(0).unwrap().timestamp, 200);
+    assert_eq!(next, Some(2));
+}
+
+#[test]
+fn test_spend_cap_queues_adjustment_for_governance() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    let planned = stability.simulate_adjust().amount;
+    assert!(planned > 0);
+    stability.set_autonomous_spend_cap(&AutonomousSpendCap { max_net_change: planned, period: 86400 });
+    
+    // First adjustment uses the whole cap
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 1);
+    assert!(stability.get_queued_adjustments().is_empty());
+    
+    // The next one is queued rather than applied
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 1);
+    let queued = stability.get_queued_adjustments();
+    assert_eq!(queued.len(), 1);
+    assert_eq!(queued.get(0).unwrap().adjustment.adjustment_type, Symbol::new(&env, "burn"));
+    assert!(find_event(&env, "AdjustmentQueued").is_some());
+}
+
+#[test]
+fn test_queued_adjustments_execute_or_discard() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    stability.set_autonomous_spend_cap(&AutonomousSpendCap { max_net_change: 0, period: 86400 });
+    stability.auto_adjust();
+    stability.auto_adjust();
+    let queued = stability.get_queued_adjustments();
+    assert_eq!(queued.len(), 2);
+    let (first, second) = (queued.get(0).unwrap(), queued.get(1).unwrap());
+    assert_ne!(first.proposal, second.proposal);
+    
+    // Approval applies the held adjustment and takes it off the queue
+    stability.execute_queued_adjustment(&first.proposal);
+    let log = stability.get_adjustment_log();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log.get(0).unwrap().amount, first.adjustment.amount);
+    assert_eq!(stability.get_adjustment_totals().burned, first.adjustment.amount);
+    assert!(find_event(&env, "QueuedAdjustmentExecuted").is_some());
+    assert_eq!(stability.try_execute_queued_adjustment(&first.proposal), Err(Ok(4)));
+    
+    // Rejection drops it without touching supply
+    stability.discard_queued_adjustment(&second.proposal);
+    assert!(stability.get_queued_adjustments().is_empty());
+    assert_eq!(stability.get_adjustment_log().len(), 1);
+    assert!(find_event(&env, "QueuedAdjustmentDiscarded").is_some());
+    
+    // Ids are never reused after a dequeue
+    stability.auto_adjust();
+    let next = stability.get_queued_adjustments().get(0).unwrap().proposal;
+    assert!(next != first.proposal && next != second.proposal);
+}
+
+#[test]
+fn test_adjustment_queue_is_bounded() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    stability.set_autonomous_spend_cap(&AutonomousSpendCap { max_net_change: 0, period: 86400 });
+    
+    for _ in 0..MAX_QUEUED_ADJUSTMENTS + 3 {
+        stability.auto_adjust();
+    }
+    assert_eq!(stability.get_queued_adjustments().len(), MAX_QUEUED_ADJUSTMENTS);
+    assert!(find_event(&env, "AdjustmentQueueFull").is_some());
+    assert!(stability.get_adjustment_log().is_empty());
+}
+
+#[test]
+fn test_keeper_tick_is_idempotent_within_ledger() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    
+    let first = stability.keeper_tick();
+    assert!(first.median_recalculated);
+    assert!(first.stability_checked);
+    assert!(first.anomaly_scanned);
+    assert_eq!(first.anomalies, 1); // Peg breached
+    let entries = stability.get_adjustment_log().len();
+    assert_eq!(entries, 1);
+    
+    // Same ledger: cooldown-gated stability check and anomaly scan are skipped
+    let second = stability.keeper_tick();
+    assert!(!second.stability_checked);
+    assert!(!second.anomaly_scanned);
+    assert_eq!(stability.get_adjustment_log().len(), entries);
+    
+    // Next ledger past the cooldown runs them again
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1;
+        li.timestamp += KEEPER_COOLDOWN;
+    });
+    let third = stability.keeper_tick();
+    assert!(third.stability_checked);
+    assert!(third.anomaly_scanned);
+}
+
+#[test]
+fn test_adjustment_history_follows_retention_policy() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    assert_eq!(stability.try_set_history_retention(&RetentionPolicy { max_entries: 1, max_age: 0 }), Err(Ok(3)));
+    stability.set_history_retention(&RetentionPolicy { max_entries: 1, max_age: 1 });
+    stability.set_adjustment_budget(&10_000_000, &600);
+    stability.set_autonomous_spend_cap(&AutonomousSpendCap { max_net_change: 5_000_000, period: 600 });
+    
+    // Entries inside the budget window are kept past the policy
+    stability.auto_adjust();
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 2);
+    
+    // Once they age out of the window and spend cap period the policy applies; feeds are still fresh
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 1);
+    assert_eq!(stability.get_adjustment_count(), 3);
+}
+
+#[test]
+fn test_simulate_adjust_matches_auto_adjust() {
+    let env = Env::default();
+    let (_, stability) = setup_depegged_stability(&env, 330000);
+    
+    let proposed = stability.simulate_adjust();
+    assert_eq!(proposed.adjustment_type, Symbol::new(&env, "burn"));
+    // Dry run leaves the log untouched
+    assert_eq!(stability.get_adjustment_log().len(), 0);
+    
+    stability.auto_adjust();
+    let applied = stability.get_adjustment_log().get(0).unwrap();
+    assert_eq!(applied.amount, proposed.amount);
+    assert_eq!(applied.adjustment_type, proposed.adjustment_type);
+    assert_eq!(applied.ai_confidence, proposed.ai_confidence);
+    
+    // Past the spend cap the dry run reports the hold, and auto_adjust queues the same amount
+    stability.set_autonomous_spend_cap(&AutonomousSpendCap { max_net_change: applied.amount, period: 86400 });
+    let held = stability.simulate_adjust();
+    assert_eq!(held.adjustment_type, Symbol::new(&env, "queued"));
+    assert!(held.amount > 0);
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 1);
+    let queued = stability.get_queued_adjustments().get(0).unwrap().adjustment;
+    assert_eq!(queued.amount, held.amount);
+    assert_eq!(queued.adjustment_type, Symbol::new(&env, "burn"));
+}
+
+fn sign_bridge_message(env: &Env, signing_key: &SigningKey, dimension: &Symbol, to: &Address, amount: u64, nonce: u64) -> BytesN<64> {
+    let encoded = (dimension.clone(), to.clone(), amount, nonce).to_xdr(env);
+    let mut message = vec![0u8; encoded.len() as usize];
+    encoded.copy_into_slice(&mut message);
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_receive_from_bridge_credits_and_rejects_replay() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    let dimension = Symbol::new(&env, "ethereum");
+    let bridge_signer = SigningKey::from_bytes(&[9u8; 32]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.set_bridge_key(&dimension, &BytesN::from_array(&env, &bridge_signer.verifying_key().to_bytes()));
+    
+    let proof = sign_bridge_message(&env, &bridge_signer, &dimension, &user, 250, 1);
+    assert!(client.try_receive_from_bridge(&dimension, &user, &250, &1, &proof).is_ok());
+    assert_eq!(client.get_current_supply(), 250);
+    assert_eq!(client.holder_count(), 1);
+    
+    // The credit is a coin owned by the receiver, with the bridge hop as its first provenance entry
+    let (_, data) = find_event(&env, "GodHeadBridgeReceived").unwrap();
+    let event = BridgeReceivedEvent::try_from_val(&env, &data).unwrap();
+    let coin = client.get_coin(&event.coin_id).unwrap();
+    assert_eq!((coin.owner, coin.amount, coin.source), (user.clone(), 250, dimension.clone()));
+    let (trail, _) = client.get_coin_provenance(&event.coin_id, &0, &10);
+    assert_eq!(trail, Vec::from_array(&env, [ProvenanceEntry { from: contract_id.clone(), to: user.clone(), timestamp: env.ledger().timestamp() }]));
+    
+    // Same message again is refused and credits nothing
+    assert_eq!(client.try_receive_from_bridge(&dimension, &user, &250, &1, &proof), Err(Ok(PiCoinError::InvalidInput)));
+    assert_eq!(client.get_current_supply(), 250);
+}
+
+// Pause registry stand-in reporting every module as paused
+#[contract]
+pub struct PausedRegistry;
+
+#[contractimpl]
+impl PausedRegistry {
+    pub fn is_paused(_env: Env, _module: Symbol) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_receive_from_bridge_blocked_while_paused() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let dimension = Symbol::new(&env, "ethereum");
+    let bridge_signer = SigningKey::from_bytes(&[9u8; 32]);
+    
+    env.mock_all_auths();
+    client.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+    client.set_bridge_key(&dimension, &BytesN::from_array(&env, &bridge_signer.verifying_key().to_bytes()));
+    client.set_pause_registry(&env.register_contract(None, PausedRegistry));
+    
+    let proof = sign_bridge_message(&env, &bridge_signer, &dimension, &user, 250, 1);
+    assert_eq!(client.try_receive_from_bridge(&dimension, &user, &250, &1, &proof), Err(Ok(PiCoinError::Paused)));
+    assert_eq!(client.get_current_supply(), 0);
+    assert_eq!(client.holder_count(), 0);
+}
+
+// Bridge endpoint that records the last outbound transfer
+#[contract]
+pub struct MockBridgeContract;
+
+#[contractimpl]
+impl MockBridgeContract {
+    pub fn interdimensional_bridge(env: Env, _from: Address, amount: i128, _min_received: i128, _deadline: u64) -> i128 {
+        env.storage().instance().set(&symbol_short!("last"), &amount);
+        amount
+    }
+}
+
+// Bridge endpoint that takes a 10% fee out of every transfer
+#[contract]
+pub struct UnderDeliveringBridge;
+
+#[contractimpl]
+impl UnderDeliveringBridge {
+    pub fn interdimensional_bridge(_env: Env, _from: Address, amount: i128, _min_received: i128, _deadline: u64) -> i128 {
+        amount * 9 / 10
+    }
+}
+
+#[test]
+fn test_register_bridge_then_bridge() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let bridge_id = env.register_contract(None, MockBridgeContract);
+    let other_bridge = env.register_contract(None, MockBridgeContract);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    let dimension = Symbol::new(&env, "ethereum");
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    assert_eq!(client.try_interdimensional_bridge(&user, &dimension, &2, &2, &u64::MAX), Err(Ok(PiCoinError::NotFound)));
+    
+    client.register_bridge(&admin, &dimension, &bridge_id, &false);
+    assert!(find_event(&env, "GodHeadBridgeRegistered").is_some());
+    // Overwrite needs the update flag; non-signers are refused
+    assert_eq!(client.try_register_bridge(&admin, &dimension, &other_bridge, &false), Err(Ok(PiCoinError::InvalidInput)));
+    assert_eq!(client.try_register_bridge(&user, &Symbol::new(&env, "cosmos"), &other_bridge, &false), Err(Ok(PiCoinError::Unauthorized)));
+    
+    assert!(client.try_interdimensional_bridge(&user, &dimension, &2, &2, &u64::MAX).is_ok());
+    let bridged: i128 = env.as_contract(&bridge_id, || {
+        env.storage().instance().get(&symbol_short!("last")).unwrap()
+    });
+    assert_eq!(bridged, 2);
+    
+    client.deregister_bridge(&admin, &dimension);
+    assert_eq!(client.try_interdimensional_bridge(&user, &dimension, &2, &2, &u64::MAX), Err(Ok(PiCoinError::NotFound)));
+}
+
+#[test]
+fn test_bridge_reverts_on_under_delivery_or_expired_deadline() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let bridge_id = env.register_contract(None, UnderDeliveringBridge);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    let dimension = Symbol::new(&env, "ethereum");
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_bridge(&admin, &dimension, &bridge_id, &false);
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    
+    // 10 sent, 9 delivered: below a minimum of 10
+    assert_eq!(client.try_interdimensional_bridge(&user, &dimension, &10, &10, &2000), Err(Ok(PiCoinError::SlippageExceeded)));
+    assert!(find_event(&env, "GodHeadInterdimensionalBridged").is_none());
+    assert_eq!(client.try_interdimensional_bridge(&user, &dimension, &10, &9, &999), Err(Ok(PiCoinError::DeadlineExpired)));
+    
+    // Within tolerance the delivered amount is returned and recorded
+    assert_eq!(client.interdimensional_bridge(&user, &dimension, &10, &9, &1000), 9);
+    let (_, data) = find_event(&env, "GodHeadInterdimensionalBridged").unwrap();
+    let event = BridgeEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.amount, event.delivered), (10, 9));
+}
+
+#[test]
+fn test_verify_hologram_detects_tampering() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    client.mint(&user, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    let hologram = client.get_holographic_vault(&coin_id);
+    assert!(client.verify_hologram(&coin_id, &hologram));
+    
+    let mut tampered = hologram.clone();
+    let last = tampered.len() - 1;
+    tampered.set(last, tampered.get(last).unwrap() ^ 0x01);
+    assert!(!client.verify_hologram(&coin_id, &tampered));
+}
+
+#[test]
+fn test_holder_index_tracks_mint_transfer_burn() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
+    assert_eq!(client.holder_count(), 0);
+    
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    assert_eq!(client.holder_count(), 1);
+    
+    // Coin moves wholesale to user2; user1 drops out of the index
+    client.transfer(&user1, &user2, &40, &coin_id);
+    assert_eq!(client.holder_count(), 1);
+    let (page, next) = client.enumerate_holders(&0, &10);
+    assert_eq!(page, Vec::from_array(&env, [user2.clone()]));
+    assert_eq!(next, None);
+    
+    // Burning the rest of the last coin removes the holder
+    client.burn(&user2, &60, &coin_id);
+    assert_eq!(client.holder_count(), 0);
+}
+
+#[test]
+fn test_enumerate_holders_paginates() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    for _ in 0..3 {
+        let user = Address::generate(&env);
+        client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+        client.mint(&user, &10, &Symbol::new(&env, "mining"));
+    }
+    
+    let (first, next) = client.enumerate_holders(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(next, Some(2));
+    let (rest, next) = client.enumerate_holders(&2, &2);
+    assert_eq!(rest.len(), 1);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn test_governance_vote_tally_and_double_vote() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let voter1 = Address::generate(&env);
+    let voter2 = Address::generate(&env);
+    let voter3 = Address::generate(&env);
+    let proposal = Symbol::new(&env, "raise_cap");
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.ai_governance_vote(&voter1, &proposal, &true);
+    client.ai_governance_vote(&voter2, &proposal, &true);
+    client.ai_governance_vote(&voter3, &proposal, &false);
+    assert_eq!(client.try_ai_governance_vote(&voter1, &proposal, &false), Err(Ok(PiCoinError::AlreadyVoted)));
+    
+    assert_eq!(client.get_proposal_tally(&proposal), ProposalTally { yes: 2, no: 1 });
+    assert_eq!(client.get_proposal_tally(&Symbol::new(&env, "other")), ProposalTally { yes: 0, no: 0 });
+}
+
+#[test]
+fn test_get_and_remove_compliance() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    assert!(client.get_compliance(&user2).is_none());
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
+    
+    let record = client.get_compliance(&user2).unwrap();
+    assert!(record.kyc_verified);
+    assert_eq!(record.country_code, Symbol::new(&env, "ID"));
+    assert_eq!(record.risk_score, 20);
+    
+    assert_eq!(client.try_remove_compliance(&user1, &user2), Err(Ok(PiCoinError::Unauthorized)));
+    client.remove_compliance(&admin, &user2);
+    assert!(client.get_compliance(&user2).is_none());
+    assert!(find_event(&env, "ComplianceRemoved").is_some());
+    
+    // Without a record user2 is back to the default: not legal tender, so transfers to them fail
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    assert_eq!(client.try_transfer(&user1, &user2, &10, &coin_id), Err(Ok(PiCoinError::ComplianceFailed)));
+}
+
+#[test]
+fn test_ai_override_is_logged() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let low_risk = Address::generate(&env);
+    let high_risk = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    // Initial weights [1..5] sum to 15: risk 1 scores 15 (< 20, override); risk 3 scores 45 (no override)
+    client.register_compliance(&low_risk, &false, &Symbol::new(&env, "US"), &1u32);
+    client.register_compliance(&high_risk, &false, &Symbol::new(&env, "US"), &3u32);
+    
+    let log = client.get_ai_override_log();
+    assert_eq!(log.len(), 1);
+    let record = log.get(0).unwrap();
+    assert_eq!(record.user, low_risk);
+    assert_eq!(record.input, 1);
+    assert_eq!(record.score, 15);
+    assert!(client.get_compliance(&low_risk).unwrap().ai_override);
+    assert!(find_event(&env, "AiOverrideGranted").is_some());
+}
+
+#[test]
+fn test_supreme_ai_predict_matches_ai_simulation() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    
+    for input in [0u64, 1, 3, 7, 250, 314159] {
+        let expected = env.as_contract(&contract_id, || AiSimulation::predict(&env, input as i64));
+        assert_eq!(client.get_ai_prediction(&input), expected as u64);
+        assert!(client.get_ai_prediction(&input) <= 99);
+    }
+    
+    // Legacy flat weights still read as the same network
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::NeuralLayers);
+        env.storage().persistent().set(&DataKey::NeuralWeights, &Vec::from_array(&env, [1u64, 2u64, 3u64, 4u64, 5u64]));
+    });
+    assert_eq!(client.get_ai_prediction(&3), 45);
+}
+
+#[test]
+fn test_mint_stats_by_source() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    
+    client.mint(&user, &100, &Symbol::new(&env, "mining"));
+    client.mint(&user, &250, &Symbol::new(&env, "mining"));
+    client.mint(&user, &40, &Symbol::new(&env, "rewards"));
+    
+    assert_eq!(client.get_mint_by_source(&Symbol::new(&env, "mining")), 350);
+    assert_eq!(client.get_mint_by_source(&Symbol::new(&env, "rewards")), 40);
+    assert_eq!(client.get_mint_by_source(&Symbol::new(&env, "p2p")), 0);
+    
+    let stats = client.get_all_mint_stats();
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats.get(Symbol::new(&env, "mining")), Some(350));
+    assert_eq!(client.get_mint_counts().get(Symbol::new(&env, "mining")), Some(2));
+    
+    // Rejected mints leave the counters untouched
+    assert!(client.try_mint(&user, &1, &Symbol::new(&env, "airdrop")).is_err());
+    assert_eq!(client.get_all_mint_stats().len(), 2);
+}
+
+#[test]
+fn test_mint_rate_limit_per_source_window() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    let mining = Symbol::new(&env, "mining");
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    client.set_mint_rate_limit(&mining, &500, &3600);
+    
+    // Up to the ceiling
+    client.mint(&user, &300, &mining);
+    client.mint(&user, &200, &mining);
+    
+    // Past it, for this source only
+    assert_eq!(client.try_mint(&user, &1, &mining), Err(Ok(PiCoinError::MintRateExceeded)));
+    client.mint(&user, &50, &Symbol::new(&env, "rewards"));
+    assert_eq!(client.get_mint_by_source(&mining), 500);
+    
+    // Next window
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    client.mint(&user, &400, &mining);
+    assert_eq!(client.get_mint_by_source(&mining), 900);
+}
+
+#[test]
+fn test_zero_amount_and_self_transfer_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
+    
+    assert_eq!(client.try_mint(&user1, &0, &Symbol::new(&env, "mining")), Err(Ok(PiCoinError::InvalidInput)));
+    assert_eq!(client.get_current_supply(), 0);
+    
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    assert_eq!(client.try_transfer(&user1, &user2, &0, &coin_id), Err(Ok(PiCoinError::InvalidInput)));
+    assert_eq!(client.try_transfer(&user1, &user1, &50, &coin_id), Err(Ok(PiCoinError::InvalidInput)));
+    assert_eq!(client.try_burn(&user1, &0, &coin_id), Err(Ok(PiCoinError::InvalidInput)));
+    
+    // Nothing moved
+    let coin = client.get_coin(&coin_id).unwrap();
+    assert_eq!(coin.owner, user1);
+    assert_eq!(coin.amount, 100);
+    assert_eq!(client.get_current_supply(), 100);
+    assert_eq!(client.holder_count(), 1);
+}
+
+#[test]
+fn test_migrate_upgrades_old_schema_once() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    
+    // Roll storage back to a version-1 deployment: no version key, legacy weights, no mint ceilings
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::SchemaVersion);
+        env.storage().persistent().remove(&DataKey::NeuralLayers);
+        env.storage().persistent().remove(&DataKey::MintRateLimits);
+        env.storage().persistent().remove(&DataKey::AiThresholds);
+        env.storage().persistent().set(&DataKey::NeuralWeights, &Vec::from_array(&env, [1u64, 2u64, 3u64, 4u64, 5u64]));
+        env.storage().persistent().set(&DataKey::BurnAiThreshold, &30u64);
+        env.storage().persistent().set(&DataKey::AiEvolutionLog, &Vec::from_array(&env, [Bytes::from_slice(&env, b"Vote true evolved weight 1"), Bytes::from_slice(&env, b"Vote false evolved weight 0")]));
+        env.storage().persistent().set(&DataKey::AiEvolutionLogCap, &2u32);
+    });
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.get_mint_rate_limit(&Symbol::new(&env, "mining")), None);
+    
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    env.as_contract(&contract_id, || {
+        assert!(env.storage().persistent().has(&DataKey::NeuralLayers));
+        assert!(!env.storage().persistent().has(&DataKey::NeuralWeights));
+    });
+    assert_eq!(client.get_ai_prediction(&3), 45);
+    assert_eq!(client.get_mint_rate_limit(&Symbol::new(&env, "mining")).unwrap().window_secs, 3600);
+    assert_eq!(client.get_ai_thresholds(), AiThresholds { mint: 50, transfer: 70, burn: 30, bridge: 40 });
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::BurnAiThreshold));
+        assert!(!env.storage().persistent().has(&DataKey::AiEvolutionLogCap));
+    });
+    let now = env.ledger().timestamp();
+    assert_eq!(client.get_ai_evolution_log(), Vec::from_array(&env, [
+        AiEvolutionEntry { vote: true, adjustment: 1, timestamp: now },
+        AiEvolutionEntry { vote: false, adjustment: 0, timestamp: now },
+    ]));
+    assert_eq!(client.get_ai_evolution_retention().max_entries, 2);
+    assert!(find_event(&env, "SchemaMigrated").is_some());
+    
+    // Already current: no-op
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    
+    // Signers only
+    assert_eq!(client.try_migrate(&Address::generate(&env)), Err(Ok(PiCoinError::Unauthorized)));
+}
+
+#[test]
+fn test_unverified_registration_not_transferable() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    
+    // KYC failed; risk 10 scores 15 * 10 % 100 = 50, above the AI override cutoff
+    client.register_compliance(&user2, &false, &Symbol::new(&env, "ID"), &10u32);
+    let record = client.get_compliance(&user2).unwrap();
+    assert!(!record.kyc_verified);
+    assert!(!record.legal_tender_status);
+    assert!(!record.ai_override);
+    assert!(client.get_compliance(&user1).unwrap().legal_tender_status);
+    
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    assert_eq!(client.try_transfer(&user1, &user2, &50, &coin_id), Err(Ok(PiCoinError::ComplianceFailed)));
+    assert_eq!(client.get_coin(&coin_id).unwrap().owner, user1);
+}
+
+#[test]
+fn test_transfer_blocked_during_severe_breach() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    // Mild deviation is below the severe threshold
+    client.update_oracle_feed(&Symbol::new(&env, "PI"), &320000);
+    client.transfer(&user1, &user2, &10, &coin_id);
+    
+    // Severe depeg blocks transfers; burns still go through
+    client.update_oracle_feed(&Symbol::new(&env, "PI"), &350000);
+    assert_eq!(client.try_transfer(&user2, &user1, &10, &coin_id), Err(Ok(PiCoinError::PegBreached)));
+    client.burn(&user2, &10, &coin_id);
+    
+    // Tripped oracle breaker blocks even at peg
+    client.update_oracle_feed(&Symbol::new(&env, "PI"), &314159);
+    client.set_oracle(&tripped_oracle(&env));
+    assert_eq!(client.try_transfer(&user2, &user1, &10, &coin_id), Err(Ok(PiCoinError::CircuitBreakerTripped)));
+    
+    // Multi-sig can switch to allowing transfers
+    client.set_breach_policy(&false, &31415);
+    client.transfer(&user2, &user1, &10, &coin_id);
+    assert_eq!(client.get_coin(&coin_id).unwrap().owner, user1);
+}
+
+#[test]
+fn test_lowered_transfer_ai_threshold_rejects_transfer() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user1, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&user2, &true, &Symbol::new(&env, "ID"), &20u32);
+    client.mint(&user1, &100, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    // Within the default transfer cutoff
+    let prediction = client.get_ai_prediction(&3);
+    let mut thresholds = client.get_ai_thresholds();
+    assert!(prediction <= thresholds.transfer);
+    
+    thresholds.transfer = prediction - 1;
+    client.set_ai_thresholds(&thresholds);
+    assert!(find_event(&env, "AiThresholdsUpdated").is_some());
+    assert_eq!(client.try_transfer(&user1, &user2, &3, &coin_id), Err(Ok(PiCoinError::AiRejection)));
+    assert_eq!(client.get_coin(&coin_id).unwrap().owner, user1);
+    
+    thresholds.bridge = 101;
+    assert_eq!(client.try_set_ai_thresholds(&thresholds), Err(Ok(PiCoinError::InvalidInput)));
+}
+
+#[test]
+fn test_batch_register_compliance() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    
+    let mut entries = Vec::new(&env);
+    let mut users = Vec::new(&env);
+    for i in 0..10u32 {
+        let user = Address::generate(&env);
+        entries.push_back((user.clone(), i % 2 == 0, Symbol::new(&env, "ID"), 10 + i));
+        users.push_back(user);
+    }
+    client.batch_register_compliance(&admin, &entries);
+    
+    let first = client.get_compliance(&users.get(0).unwrap()).unwrap();
+    assert!(first.kyc_verified && first.legal_tender_status);
+    assert_eq!(first.risk_score, 10);
+    let second = client.get_compliance(&users.get(1).unwrap()).unwrap();
+    assert!(!second.kyc_verified);
+    assert_eq!(client.get_compliance(&users.get(9).unwrap()).unwrap().risk_score, 19);
+    
+    let (_, data) = find_event(&env, "ComplianceBatchRegistered").unwrap();
+    assert_eq!(ComplianceBatchEvent::try_from_val(&env, &data).unwrap().users, users);
+    
+    // Signers only, bounded batches
+    assert_eq!(client.try_batch_register_compliance(&Address::generate(&env), &entries), Err(Ok(PiCoinError::Unauthorized)));
+    assert_eq!(client.try_batch_register_compliance(&admin, &Vec::new(&env)), Err(Ok(PiCoinError::InvalidInput)));
+}
+
+// Asset contract recording raw amounts minted per account
+#[contract]
+pub struct RecordingAssetContract;
+
+#[contractimpl]
+impl RecordingAssetContract {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance: i128 = env.storage().instance().get(&to).unwrap_or(0);
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+    
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_metadata_and_asset_scaling() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let asset_id = env.register_contract(None, RecordingAssetContract);
+    let asset = RecordingAssetContractClient::new(&env, &asset_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    assert_eq!(client.get_metadata(), TokenMetadata { symbol: Symbol::new(&env, "PI"), decimals: 7, peg_target: 314159 });
+    
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::AssetId, &asset_id);
+    });
+    
+    // 1 PI is 10^7 raw asset units
+    client.mint(&user, &1, &Symbol::new(&env, "mining"));
+    assert_eq!(asset.balance(&user), 10i128.pow(DECIMALS));
+    assert_eq!(client.balance_of(&user), 1);
+}
+
+#[test]
+fn test_proposal_ids_are_derived_from_content() {
+    let env = Env::default();
+    let governance_id = env.register_contract(None, GovernanceContract);
+    let governance = GovernanceContractClient::new(&env, &governance_id);
+    let proposer = Address::generate(&env);
+    let description = Bytes::from_slice(&env, b"Raise oracle quorum to 5");
+    
+    env.mock_all_auths();
+    let id = governance.create_proposal(&proposer, &description);
+    let expected: BytesN<32> = crypto::sha256(&env, &(proposer.clone(), description.clone(), 0u64).to_xdr(&env)).into();
+    assert_eq!(id, expected);
+    assert_eq!(governance.get_proposal(&id).id, id);
+    
+    // Distinct content gets a distinct id from the next nonce
+    let other = governance.create_proposal(&proposer, &Bytes::from_slice(&env, b"Lower mint ceiling"));
+    let expected_other: BytesN<32> = crypto::sha256(&env, &(proposer.clone(), Bytes::from_slice(&env, b"Lower mint ceiling"), 1u64).to_xdr(&env)).into();
+    assert_eq!(other, expected_other);
+}
+
+#[test]
+fn test_duplicate_active_proposal_rejected() {
+    let env = Env::default();
+    let governance_id = env.register_contract(None, GovernanceContract);
+    let governance = GovernanceContractClient::new(&env, &governance_id);
+    let proposer = Address::generate(&env);
+    let other_proposer = Address::generate(&env);
+    let description = Bytes::from_slice(&env, b"Raise oracle quorum to 5");
+    
+    env.mock_all_auths();
+    governance.create_proposal(&proposer, &description);
+    assert_eq!(governance.try_create_proposal(&proposer, &description), Err(Ok(3)));
+    assert_eq!(governance.try_create_proposal(&other_proposer, &description), Err(Ok(3)));
+    
+    // Once the first has expired the same content may be proposed again
+    env.ledger().with_mut(|li| li.timestamp += 604801);
+    assert!(governance.try_create_proposal(&proposer, &description).is_ok());
+}
+
+#[test]
+fn test_vote_weight_comes_from_power_source() {
+    let env = Env::default();
+    let governance_id = env.register_contract(None, GovernanceContract);
+    let governance = GovernanceContractClient::new(&env, &governance_id);
+    let (proposer, registered, stranger) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    env.as_contract(&governance_id, || {
+        env.storage().persistent().set(&GovernanceDataKey::VoterRegistry, &Map::from_array(&env, [(registered.clone(), 40u64)]));
+    });
+    
+    env.mock_all_auths();
+    assert_eq!(governance.get_power_source(), PowerSource::Registry);
+    assert_eq!(governance.voting_power(&stranger), 0);
+    let id = governance.create_proposal(&proposer, &Bytes::from_slice(&env, b"Raise oracle quorum to 5"));
+    
+    // Unregistered voters have no power, and each voter votes once
+    assert_eq!(governance.try_vote(&stranger, &id, &true), Err(Ok(1)));
+    governance.vote(&registered, &id, &true);
+    assert_eq!(governance.try_vote(&registered, &id, &true), Err(Ok(3)));
+    let proposal = governance.get_proposal(&id);
+    assert!(proposal.votes_for == 40 || proposal.votes_for == 41, "registry power plus at most the AI adjustment");
+    assert_eq!(proposal.votes_against, 0);
+}
+
+#[test]
+fn test_upgrade_requires_passed_proposal() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let new_wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    assert_eq!(client.try_upgrade(&BytesN::from_array(&env, &[0u8; 32]), &new_wasm_hash), Err(Ok(PiCoinError::NotFound)));
+    
+    // A proposal for the hash exists but is still open for voting
+    let proposal_id = env.as_contract(&contract_id, || {
+        GovernanceContract::create_proposal(env.clone(), admin.clone(), Bytes::from(new_wasm_hash.clone())).unwrap()
+    });
+    assert_eq!(client.try_upgrade(&proposal_id, &new_wasm_hash), Err(Ok(PiCoinError::Unauthorized)));
+    
+    // Ended without votes: failed, never passed
+    env.ledger().with_mut(|li| li.timestamp += 604801 + UPGRADE_TIMELOCK);
+    assert_eq!(client.try_upgrade(&proposal_id, &new_wasm_hash), Err(Ok(PiCoinError::Unauthorized)));
+    assert!(find_event(&env, "Upgraded").is_none());
+}
+
+// Initialized contract with `supply` PI in circulation
+fn setup_with_supply<'a>(env: &'a Env, supply: u64) -> (Address, PiCoinContractClient<'a>) {
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let signers = Vec::from_array(env, [admin.clone()]);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::CurrentSupply, &supply);
+    });
+    (contract_id, client)
+}
+
+#[test]
+fn test_reserves_set_collateralization_ratio() {
+    let env = Env::default();
+    let (_, client) = setup_with_supply(&env, 10);
+    assert_eq!(client.collateralization_ratio(), 0);
+    
+    // 10 PI at $314,159 needs $3,141,590 to be fully backed
+    client.deposit_reserve(&Symbol::new(&env, "USDC"), &1_570_795);
+    assert_eq!(client.collateralization_ratio(), 5_000);
+    client.deposit_reserve(&Symbol::new(&env, "TBILL"), &4_712_385);
+    assert_eq!(client.collateralization_ratio(), 20_000);
+    assert_eq!(client.get_reserves().get(Symbol::new(&env, "USDC")), Some(1_570_795));
+    assert!(find_event(&env, "ReserveDeposited").is_some());
+}
+
+#[test]
+fn test_withdraw_reserve_below_floor_rejected() {
+    let env = Env::default();
+    let (_, client) = setup_with_supply(&env, 10);
+    let usdc = Symbol::new(&env, "USDC");
+    client.deposit_reserve(&usdc, &4_000_000);
+    
+    // Down to exactly fully backed is allowed; one dollar more is not
+    client.withdraw_reserve(&usdc, &858_410);
+    assert_eq!(client.collateralization_ratio(), 10_000);
+    assert_eq!(client.try_withdraw_reserve(&usdc, &1), Err(Ok(PiCoinError::ReserveFloorBreached)));
+    assert_eq!(client.get_reserves().get(usdc.clone()), Some(3_141_590));
+    
+    // A lower floor frees the excess
+    client.set_reserve_floor(&5_000);
+    client.withdraw_reserve(&usdc, &1_570_795);
+    assert_eq!(client.collateralization_ratio(), 5_000);
+}
+
+#[test]
+fn test_batch_transfer_reports_partial_success() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let merchant = Address::generate(&env);
+    let payee = Address::generate(&env);
+    let unregistered = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&merchant, &true, &Symbol::new(&env, "US"), &10u32);
+    client.register_compliance(&payee, &true, &Symbol::new(&env, "ID"), &20u32);
+    let mut thresholds = client.get_ai_thresholds();
+    thresholds.transfer = 100;
+    client.set_ai_thresholds(&thresholds);
+    
+    let mut coins = Vec::new(&env);
+    for _ in 0..3 {
+        client.mint(&merchant, &100, &Symbol::new(&env, "mining"));
+        let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+        coins.push_back(MintEvent::try_from_val(&env, &data).unwrap().coin_id);
+    }
+    
+    // The middle payout goes to a recipient without compliance
+    let transfers = Vec::from_array(&env, [
+        (payee.clone(), 10u64, coins.get(0).unwrap()),
+        (unregistered.clone(), 10u64, coins.get(1).unwrap()),
+        (payee.clone(), 20u64, coins.get(2).unwrap()),
+    ]);
+    let results = client.batch_transfer(&merchant, &transfers);
+    assert_eq!(results.get(0).unwrap(), Ok(coins.get(0).unwrap()));
+    assert_eq!(results.get(1).unwrap(), Err(PiCoinError::ComplianceFailed));
+    assert_eq!(results.get(2).unwrap(), Ok(coins.get(2).unwrap()));
+    
+    assert_eq!(client.get_coin(&coins.get(0).unwrap()).unwrap().owner, payee);
+    assert_eq!(client.get_coin(&coins.get(1).unwrap()).unwrap().owner, merchant);
+    assert_eq!(client.get_coin(&coins.get(2).unwrap()).unwrap().amount, 80);
+}
+
+#[test]
+fn test_oracle_feed_out_of_bounds_rejected() {
+    let env = Env::default();
+    let (_, client) = setup_with_supply(&env, 0);
+    let pi = Symbol::new(&env, "PI");
+    
+    assert!(!client.update_oracle_feed(&pi, &0));
+    assert!(!client.update_oracle_feed(&pi, &u64::MAX));
+    let (_, data) = find_event(&env, "FeedRejected").unwrap();
+    let event = FeedRejectedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.reason, Symbol::new(&env, "above_max"));
+    
+    // Configured bounds replace the defaults
+    client.set_feed_bounds(&pi, &FeedBounds { min_price: 300_000, max_price: 330_000, max_change_bps: 500 });
+    assert!(!client.update_oracle_feed(&pi, &299_999));
+    assert!(client.update_oracle_feed(&pi, &314_159));
+}
+
+#[test]
+fn test_oracle_feed_excessive_jump_rejected() {
+    let env = Env::default();
+    let (_, client) = setup_with_supply(&env, 0);
+    let pi = Symbol::new(&env, "PI");
+    client.update_oracle_feed(&pi, &314_159);
+    
+    // Within the absolute range, but more than 20% above the last value
+    assert!(!client.update_oracle_feed(&pi, &400_000));
+    let (_, data) = find_event(&env, "FeedRejected").unwrap();
+    let event = FeedRejectedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.reason, Symbol::new(&env, "max_change"));
+    assert_eq!(event.previous, Some(314_159));
+    
+    // The feed kept its value, so a step within 20% still applies
+    assert!(client.update_oracle_feed(&pi, &370_000));
+}
+
+#[test]
+fn test_individual_feed_and_staleness() {
+    let env = Env::default();
+    let oracle = OracleContractClient::new(&env, &env.register_contract(None, OracleContract));
+    let admin = Address::generate(&env);
+    let (chainlink, pyth) = (Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"));
+    let sources = Vec::from_array(&env, [chainlink.clone(), pyth.clone()]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&Vec::from_array(&env, [admin]), &1u32, &sources);
+    register_reporters(&env, &oracle, &sources);
+    assert_eq!(oracle.get_sources(), sources);
+    assert!(oracle.get_feed(&pyth).is_none());
+    assert!(oracle.is_feed_stale(&pyth));
+    
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    oracle.update_feed(&chainlink, &314_200);
+    let feed = oracle.get_feed(&chainlink).unwrap();
+    assert_eq!((feed.price, feed.timestamp), (314_200, 1_000));
+    assert!(!oracle.is_feed_stale(&chainlink));
+    
+    // Stale once older than the default one-hour MaxFeedAge
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_600);
+    assert!(!oracle.is_feed_stale(&chainlink));
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_601);
+    assert!(oracle.is_feed_stale(&chainlink));
+}
+
+#[test]
+fn test_stale_feeds_excluded_from_median() {
+    let env = Env::default();
+    let oracle = OracleContractClient::new(&env, &env.register_contract(None, OracleContract));
+    let admin = Address::generate(&env);
+    let (chainlink, pyth) = (Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"));
+    let sources = Vec::from_array(&env, [chainlink.clone(), pyth.clone()]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&Vec::from_array(&env, [admin]), &1u32, &sources);
+    register_reporters(&env, &oracle, &sources);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    oracle.update_feed(&chainlink, &330_000);
+    oracle.update_feed(&pyth, &314_159);
+    assert_eq!(oracle.get_current_median(), 322_079);
+    
+    // Chainlink goes silent; its last price no longer pulls the median
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_601);
+    oracle.update_feed(&pyth, &314_200);
+    assert_eq!(oracle.get_current_median(), 314_200);
+    
+    // With every feed stale there is no median to refresh; the last one stands
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_601 * 2);
+    assert_eq!(oracle.try_refresh_median(), Err(Ok(3)));
+    assert_eq!(oracle.get_current_median(), 314_200);
+}
+
+#[test]
+fn test_feed_updates_and_peg_checks_need_registered_accounts() {
+    let env = Env::default();
+    let oracle = OracleContractClient::new(&env, &env.register_contract(None, OracleContract));
+    let admin = Address::generate(&env);
+    let (chainlink, pyth) = (Symbol::new(&env, "chainlink"), Symbol::new(&env, "pyth"));
+    let sources = Vec::from_array(&env, [chainlink.clone(), pyth.clone()]);
+    
+    env.mock_all_auths();
+    oracle.init_oracle(&Vec::from_array(&env, [admin]), &1u32, &sources);
+    assert_eq!(oracle.try_set_feed_reporter(&Symbol::new(&env, "rogue"), &Address::generate(&env)), Err(Ok(3)));
+    
+    // A source without a bound reporter cannot be updated by anyone
+    assert_eq!(oracle.try_update_feed(&chainlink, &1), Err(Ok(PiCoinError::Unauthorized as u32)));
+    assert!(oracle.get_feed(&chainlink).is_none());
+    let reporter = Address::generate(&env);
+    oracle.set_feed_reporter(&chainlink, &reporter);
+    oracle.update_feed(&chainlink, &314_159);
+    assert!(find_event(&env, "FeedReporterSet").is_some());
+    
+    // check_peg is limited to reporters and registered checkers
+    let keeper = Address::generate(&env);
+    assert_eq!(oracle.try_check_peg(&keeper), Err(Ok(PiCoinError::Unauthorized as u32)));
+    assert_eq!(oracle.try_check_peg(&reporter), Err(Ok(PiCoinError::InsufficientFeeds as u32)));
+    oracle.set_peg_checker(&keeper, &true);
+    assert_eq!(oracle.try_check_peg(&keeper), Err(Ok(PiCoinError::InsufficientFeeds as u32)));
+    oracle.set_peg_checker(&keeper, &false);
+    assert_eq!(oracle.try_check_peg(&keeper), Err(Ok(PiCoinError::Unauthorized as u32)));
+}
+
+#[test]
+fn test_median_of_unordered_feeds() {
+    let env = Env::default();
+    let oracle = OracleContractClient::new(&env, &env.register_contract(None, OracleContract));
+    
+    let odd = Vec::from_array(&env, [330_000u64, 310_000, 314_159, 299_000, 320_000, 314_159, 305_000]);
+    assert_eq!(oracle.get_median_price(&odd), 314_159);
+    
+    // Even count averages the middle pair without overflowing
+    let even = Vec::from_array(&env, [u64::MAX, 5, u64::MAX - 2, 1]);
+    assert_eq!(oracle.get_median_price(&even), u64::MAX / 2 + 2);
+    assert_eq!(oracle.try_get_median_price(&Vec::new(&env)), Err(Ok(3)));
+}
+
+#[test]
+fn test_check_invariants_reports_violation() {
+    let env = Env::default();
+    let (contract_id, client) = setup_with_supply(&env, 0);
+    let passed = |name: &str| client.check_invariants().iter().any(|(n, ok)| n == Symbol::new(&env, name) && ok);
+    assert!(client.check_invariants().iter().all(|(_, ok)| ok));
+    
+    // Write a PI feed far below its bounds, bypassing update_oracle_feed
+    env.as_contract(&contract_id, || {
+        let mut feeds: Map<Symbol, u64> = Map::new(&env);
+        feeds.set(Symbol::new(&env, "PI"), 10);
+        env.storage().persistent().set(&DataKey::OracleFeeds, &feeds);
+    });
+    assert!(!passed("peg_feed_in_bounds"));
+    assert!(passed("supply_within_total"));
+    assert!(passed("reserves_above_floor"));
+}
+
+#[test]
+fn test_check_invariants_flags_frozen_holder_with_allowance() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let signers = Vec::from_array(&env, [Address::generate(&env)]);
+    let user = Address::generate(&env);
+    let passed = |name: &str| client.check_invariants().iter().any(|(n, ok)| n == Symbol::new(&env, name) && ok);
+    
+    env.mock_all_auths();
+    client.init(&signers, &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    client.mint(&user, &100, &Symbol::new(&env, "mining"));
+    let asset_id = client.get_asset_id();
+    let approve: Vec<Val> = Vec::from_array(&env, [user.into_val(&env), contract_id.into_val(&env), 50i128.into_val(&env), 1000u32.into_val(&env)]);
+    env.invoke_contract::<()>(&asset_id, &Symbol::new(&env, "approve"), approve);
+    assert!(passed("no_frozen_allowances"));
+    
+    // Freezing the holder on the asset contract leaves its allowance live
+    let freeze: Vec<Val> = Vec::from_array(&env, [user.into_val(&env), false.into_val(&env)]);
+    env.invoke_contract::<()>(&asset_id, &Symbol::new(&env, "set_authorized"), freeze);
+    assert!(!passed("no_frozen_allowances"));
+    assert!(passed("supply_within_total"));
+}
+
+// Two-of-three signer setup for the propose/approve/execute flow
+fn setup_two_of_three<'a>(env: &'a Env) -> (Vec<Address>, PiCoinContractClient<'a>) {
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(env, &contract_id);
+    let signers = Vec::from_array(env, [Address::generate(env), Address::generate(env), Address::generate(env)]);
+    env.mock_all_auths();
+    client.init(&signers, &2u32);
+    (signers, client)
+}
+
+// Propose an action as the first signer, approve it as the second and execute it
+fn run_approved_action(env: &Env, client: &PiCoinContractClient, signers: &Vec<Address>, function: &str, args: Vec<Val>) {
+    let args_hash: BytesN<32> = crypto::sha256(env, &args.clone().to_xdr(env)).into();
+    let action_id = client.propose_action(&signers.get(0).unwrap(), &Symbol::new(env, function), &args_hash);
+    client.approve_action(&signers.get(1).unwrap(), &action_id);
+    client.execute_action(&signers.get(1).unwrap(), &action_id, &args);
+}
+
+#[test]
+fn test_action_executes_after_approvals_accumulate() {
+    let env = Env::default();
+    let (signers, client) = setup_two_of_three(&env);
+    let user = Address::generate(&env);
+    run_approved_action(&env, &client, &signers, "register_compliance", Vec::from_array(&env, [user.into_val(&env), true.into_val(&env), Symbol::new(&env, "US").into_val(&env), 10u32.into_val(&env)]));
+    
+    let args: Vec<Val> = Vec::from_array(&env, [user.into_val(&env), 100u64.into_val(&env), Symbol::new(&env, "mining").into_val(&env)]);
+    let args_hash: BytesN<32> = crypto::sha256(&env, &args.clone().to_xdr(&env)).into();
+    let action_id = client.propose_action(&signers.get(0).unwrap(), &Symbol::new(&env, "mint"), &args_hash);
+    
+    // The proposer's approval alone is below the threshold
+    assert_eq!(client.try_execute_action(&signers.get(0).unwrap(), &action_id, &args), Err(Ok(PiCoinError::Unauthorized)));
+    assert_eq!(client.try_approve_action(&signers.get(0).unwrap(), &action_id), Err(Ok(PiCoinError::AlreadyVoted)));
+    assert_eq!(client.approve_action(&signers.get(1).unwrap(), &action_id), 2);
+    
+    // Arguments must match the proposed hash
+    let tampered: Vec<Val> = Vec::from_array(&env, [user.into_val(&env), 1_000u64.into_val(&env), Symbol::new(&env, "mining").into_val(&env)]);
+    assert_eq!(client.try_execute_action(&signers.get(2).unwrap(), &action_id, &tampered), Err(Ok(PiCoinError::InvalidInput)));
+    
+    client.execute_action(&signers.get(2).unwrap(), &action_id, &args);
+    assert_eq!(client.get_current_supply(), 100);
+    assert!(client.get_action(&action_id).unwrap().executed);
+    let (_, data) = find_event(&env, "ActionExecuted").unwrap();
+    assert_eq!(ActionEvent::try_from_val(&env, &data).unwrap().approvals, 2);
+    
+    // An executed action cannot run twice
+    assert_eq!(client.try_execute_action(&signers.get(2).unwrap(), &action_id, &args), Err(Ok(PiCoinError::InvalidInput)));
+}
+
+#[test]
+fn test_action_expires_before_threshold() {
+    let env = Env::default();
+    let (signers, client) = setup_two_of_three(&env);
+    let pi = Symbol::new(&env, "PI");
+    
+    let args: Vec<Val> = Vec::from_array(&env, [pi.into_val(&env), 320_000u64.into_val(&env)]);
+    let args_hash: BytesN<32> = crypto::sha256(&env, &args.clone().to_xdr(&env)).into();
+    let action_id = client.propose_action(&signers.get(0).unwrap(), &Symbol::new(&env, "update_oracle_feed"), &args_hash);
+    
+    env.ledger().with_mut(|l| l.timestamp += ACTION_TTL + 1);
+    assert_eq!(client.try_approve_action(&signers.get(1).unwrap(), &action_id), Err(Ok(PiCoinError::DeadlineExpired)));
+    assert_eq!(client.try_execute_action(&signers.get(1).unwrap(), &action_id, &args), Err(Ok(PiCoinError::DeadlineExpired)));
+    
+    // Functions outside the approvable set cannot be proposed
+    assert_eq!(client.try_propose_action(&signers.get(0).unwrap(), &Symbol::new(&env, "burn"), &args_hash), Err(Ok(PiCoinError::InvalidInput)));
+}
+
+#[test]
+fn test_direct_privileged_calls_rejected_above_threshold_one() {
+    let env = Env::default();
+    let (signers, client) = setup_two_of_three(&env);
+    let signer = signers.get(0).unwrap();
+    let user = Address::generate(&env);
+    let us = Symbol::new(&env, "US");
+    
+    // One signer's authority is not enough for any approvable function
+    assert_eq!(client.try_register_compliance(&user, &true, &us, &10u32), Err(Ok(PiCoinError::Unauthorized)));
+    assert_eq!(client.try_batch_register_compliance(&signer, &Vec::from_array(&env, [(user.clone(), true, us.clone(), 10u32)])), Err(Ok(PiCoinError::Unauthorized)));
+    assert_eq!(client.try_update_oracle_feed(&Symbol::new(&env, "PI"), &320_000), Err(Ok(PiCoinError::Unauthorized)));
+    assert_eq!(client.try_mint(&user, &100, &Symbol::new(&env, "mining")), Err(Ok(PiCoinError::Unauthorized)));
+    assert_eq!(client.get_current_supply(), 0);
+    
+    // The same changes go through once a second signer approves
+    let entries = Vec::from_array(&env, [(user.clone(), true, us.clone(), 10u32)]);
+    run_approved_action(&env, &client, &signers, "batch_register_compliance", Vec::from_array(&env, [signer.into_val(&env), entries.into_val(&env)]));
+    assert!(client.get_compliance(&user).is_some());
+    assert_eq!(client.try_remove_compliance(&signer, &user), Err(Ok(PiCoinError::Unauthorized)));
+    run_approved_action(&env, &client, &signers, "remove_compliance", Vec::from_array(&env, [signer.into_val(&env), user.into_val(&env)]));
+    assert!(client.get_compliance(&user).is_none());
+}
+
+// Reserve token that tracks balances moved by transfer
+#[contract]
+pub struct MockReserveToken;
+
+#[contractimpl]
+impl MockReserveToken {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let mut balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.set(from.clone(), balances.get(from).unwrap_or(0) - amount);
+        balances.set(to.clone(), balances.get(to).unwrap_or(0) + amount);
+        env.storage().instance().set(&symbol_short!("bal"), &balances);
+    }
+    
+    pub fn balance(env: Env, id: Address) -> i128 {
+        let balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.get(id).unwrap_or(0)
+    }
+}
+
+fn setup_redemption<'a>(env: &'a Env) -> (Address, Address, BytesN<32>, PiCoinContractClient<'a>) {
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let user = Address::generate(env);
+    let token = env.register_contract(None, MockReserveToken);
+    
+    env.mock_all_auths();
+    client.init(&Vec::from_array(env, [admin.clone()]), &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(env, "US"), &10u32);
+    client.mint(&user, &10, &Symbol::new(env, "mining"));
+    let (_, data) = find_event(env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(env, &data).unwrap().coin_id;
+    client.set_redemption_asset(&Symbol::new(env, "USDC"), &token);
+    (user, token, coin_id, client)
+}
+
+#[test]
+fn test_redeem_burns_pi_and_releases_reserves() {
+    let env = Env::default();
+    let (user, token, coin_id, client) = setup_redemption(&env);
+    let usdc = Symbol::new(&env, "USDC");
+    
+    // 10 PI at $314,159 fully backed
+    client.deposit_reserve(&usdc, &3_141_590);
+    client.redeem(&user, &4, &coin_id);
+    
+    assert_eq!(client.get_current_supply(), 6);
+    assert_eq!(client.get_coin(&coin_id).unwrap().amount, 6);
+    assert_eq!(client.get_reserves().get(usdc.clone()), Some(1_884_954));
+    assert_eq!(MockReserveTokenClient::new(&env, &token).balance(&user), 1_256_636 * 10i128.pow(DECIMALS));
+    
+    let (topics, data) = find_event(&env, "Redeemed").unwrap();
+    assert_eq!(Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), user);
+    let redeemed = RedeemedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(redeemed.amount, 4);
+    assert_eq!(redeemed.asset, usdc);
+    assert_eq!(redeemed.released, 1_256_636);
+}
+
+#[test]
+fn test_redeem_rejected_when_reserves_insufficient() {
+    let env = Env::default();
+    let (user, token, coin_id, client) = setup_redemption(&env);
+    let usdc = Symbol::new(&env, "USDC");
+    
+    // Only one PI's worth is held, so redeeming two must not burn anything
+    client.deposit_reserve(&usdc, &314_159);
+    assert_eq!(client.try_redeem(&user, &2, &coin_id), Err(Ok(PiCoinError::InsufficientReserves)));
+    
+    assert_eq!(client.get_current_supply(), 10);
+    assert_eq!(client.get_coin(&coin_id).unwrap().amount, 10);
+    assert_eq!(client.get_reserves().get(usdc), Some(314_159));
+    assert_eq!(MockReserveTokenClient::new(&env, &token).balance(&user), 0);
+}
+
+#[test]
+fn test_redeem_rejected_while_oracle_breaker_tripped() {
+    let env = Env::default();
+    let (user, token, coin_id, client) = setup_redemption(&env);
+    client.deposit_reserve(&Symbol::new(&env, "USDC"), &3_141_590);
+    
+    client.set_oracle(&tripped_oracle(&env));
+    assert_eq!(client.try_redeem(&user, &4, &coin_id), Err(Ok(PiCoinError::CircuitBreakerTripped)));
+    
+    // An oracle that cannot answer fails closed
+    client.set_oracle(&Address::generate(&env));
+    assert_eq!(client.try_redeem(&user, &4, &coin_id), Err(Ok(PiCoinError::CallFailed)));
+    
+    assert_eq!(client.get_coin(&coin_id).unwrap().amount, 10);
+    assert_eq!(MockReserveTokenClient::new(&env, &token).balance(&user), 0);
+}
+
+#[test]
+fn test_coin_provenance_follows_transfers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owners = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    
+    env.mock_all_auths();
+    client.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+    for owner in owners.iter() {
+        client.register_compliance(owner, &true, &Symbol::new(&env, "US"), &10u32);
+    }
+    let mut thresholds = client.get_ai_thresholds();
+    thresholds.transfer = 100;
+    client.set_ai_thresholds(&thresholds);
+    client.mint(&owners[0], &40, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.transfer(&owners[0], &owners[1], &40, &coin_id);
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    client.transfer(&owners[1], &owners[2], &40, &coin_id);
+    
+    let (page, next) = client.get_coin_provenance(&coin_id, &0, &1);
+    assert_eq!(page, Vec::from_array(&env, [ProvenanceEntry { from: owners[0].clone(), to: owners[1].clone(), timestamp: 1_000 }]));
+    assert_eq!(next, Some(1));
+    let (page, next) = client.get_coin_provenance(&coin_id, &1, &10);
+    assert_eq!(page, Vec::from_array(&env, [ProvenanceEntry { from: owners[1].clone(), to: owners[2].clone(), timestamp: 2_000 }]));
+    assert_eq!(next, None);
+}
+
+#[test]
+fn test_ai_override_kill_switch_blocks_transfer() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let overridden = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+    client.register_compliance(&sender, &true, &Symbol::new(&env, "US"), &10u32);
+    // No KYC, but risk 1 scores 15 and earns an AI override
+    client.register_compliance(&overridden, &false, &Symbol::new(&env, "US"), &1u32);
+    client.mint(&sender, &4, &Symbol::new(&env, "mining"));
+    let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+    let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+    
+    client.transfer(&sender, &overridden, &1, &coin_id);
+    client.transfer(&overridden, &sender, &1, &coin_id);
+    
+    assert!(client.is_ai_override_enabled());
+    client.set_ai_override_enabled(&false);
+    assert!(find_event(&env, "AiOverrideDisabled").is_some());
+    assert_eq!(client.try_transfer(&sender, &overridden, &1, &coin_id), Err(Ok(PiCoinError::ComplianceFailed)));
+    // The stored record is untouched
+    assert!(client.get_compliance(&overridden).unwrap().ai_override);
+    
+    client.set_ai_override_enabled(&true);
+    assert!(find_event(&env, "AiOverrideEnabled").is_some());
+    client.transfer(&sender, &overridden, &1, &coin_id);
+}
+
+#[test]
+fn test_identical_mints_get_distinct_coin_ids() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let client = PiCoinContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    
+    env.mock_all_auths();
+    client.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+    client.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+    
+    let mut ids = Vec::new(&env);
+    for nonce in 0..2u64 {
+        client.mint(&user, &100, &Symbol::new(&env, "mining"));
+        let (_, data) = find_event(&env, "GodHeadNexusMinted").unwrap();
+        let coin_id = MintEvent::try_from_val(&env, &data).unwrap().coin_id;
+        assert_eq!(coin_id, expected_coin_id(&env, &user, 100, &Symbol::new(&env, "mining"), nonce));
+        ids.push_back(coin_id);
+    }
     
-    // Check BlackHoleEvents
-    let events = env.events();
-    assert!(events.iter().any(|e| e.1 == Symbol::new(&env, "BlackHoleDepeg")));
+    // Both coins survive with their own records
+    assert_ne!(ids.get(0).unwrap(), ids.get(1).unwrap());
+    assert_eq!(client.get_coin(&ids.get(0).unwrap()).unwrap().amount, 100);
+    assert_eq!(client.get_coin(&ids.get(1).unwrap()).unwrap().amount, 100);
+    assert_eq!(client.get_current_supply(), 200);
 }
@@ -0,0 +1,162 @@
+// contracts/pi_coin_client/src/lib.rs - Typed client for GodHead Nexus Pi Coin
+// Thin wrappers over the generated contract bindings so integrators call Pi Coin
+// from Rust with plain arguments and a single error type instead of hand-rolled
+// invoke_contract calls.
+
+//! Typed Pi Coin client.
+//!
+//! ```
+//! use soroban_sdk::{testutils::Address as _, Address, Env, Symbol, Vec};
+//! use pi_coin_client::{ClientError, PiCoinClient};
+//! use pi_coin_contract::{PiCoinContract, PiCoinError};
+//!
+//! let env = Env::default();
+//! env.mock_all_auths();
+//! let contract_id = env.register_contract(None, PiCoinContract);
+//! let pi_coin = PiCoinClient::new(&env, &contract_id);
+//!
+//! let admin = Address::generate(&env);
+//! pi_coin.init(&Vec::from_array(&env, [admin]), 1).unwrap();
+//! assert_eq!(pi_coin.current_supply(), Ok(0));
+//!
+//! // Contract errors come back as typed variants
+//! let user = Address::generate(&env);
+//! let err = pi_coin.mint(&user, 1, &Symbol::new(&env, "unknown_source")).unwrap_err();
+//! assert_eq!(err, ClientError::Contract(PiCoinError::InvalidInput));
+//! ```
+
+#![no_std]
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, InvokeError, Symbol, Vec};
+use pi_coin_contract::oracle::OracleContractClient;
+use pi_coin_contract::{PiCoin, PiCoinContractClient, PiCoinError, ProposalTally};
+
+// Errors surfaced by the client
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ClientError {
+    Contract(PiCoinError), // Contract returned a known PiCoinError
+    Code(u32),             // Contract returned a code outside PiCoinError
+    Host,                  // Invocation failed in the host (panic, auth, missing contract)
+    Conversion,            // Return value could not be decoded
+}
+
+impl ClientError {
+    // Map a raw contract error code (as returned by modules using u32 codes)
+    pub fn from_code(code: u32) -> Self {
+        PiCoinError::try_from(code).map_or(ClientError::Code(code), ClientError::Contract)
+    }
+}
+
+type TryResult<T, E> = Result<Result<T, soroban_sdk::ConversionError>, Result<E, InvokeError>>;
+
+fn flatten<T>(result: TryResult<T, PiCoinError>) -> Result<T, ClientError> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(_)) => Err(ClientError::Conversion),
+        Err(Ok(err)) => Err(ClientError::Contract(err)),
+        Err(Err(_)) => Err(ClientError::Host),
+    }
+}
+
+// flatten for modules that return raw u32 error codes
+fn flatten_code<T>(result: TryResult<T, u32>) -> Result<T, ClientError> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(_)) => Err(ClientError::Conversion),
+        Err(Ok(code)) => Err(ClientError::from_code(code)),
+        Err(Err(_)) => Err(ClientError::Host),
+    }
+}
+
+// Client for PiCoinContract
+pub struct PiCoinClient<'a> {
+    inner: PiCoinContractClient<'a>,
+}
+
+impl<'a> PiCoinClient<'a> {
+    pub fn new(env: &'a Env, contract_id: &Address) -> Self {
+        PiCoinClient { inner: PiCoinContractClient::new(env, contract_id) }
+    }
+
+    pub fn address(&self) -> &Address {
+        &self.inner.address
+    }
+
+    pub fn init(&self, signers: &Vec<Address>, threshold: u32) -> Result<(), ClientError> {
+        flatten(self.inner.try_init(signers, &threshold))
+    }
+
+    pub fn mint(&self, to: &Address, amount: u64, source: &Symbol) -> Result<PiCoin, ClientError> {
+        flatten(self.inner.try_mint(to, &amount, source))
+    }
+
+    pub fn transfer(&self, from: &Address, to: &Address, amount: u64, coin_id: &BytesN<32>) -> Result<(), ClientError> {
+        flatten(self.inner.try_transfer(from, to, &amount, coin_id))
+    }
+
+    pub fn burn(&self, from: &Address, amount: u64, coin_id: &BytesN<32>) -> Result<(), ClientError> {
+        flatten(self.inner.try_burn(from, &amount, coin_id))
+    }
+
+    pub fn balance_of(&self, account: &Address) -> Result<u64, ClientError> {
+        flatten(self.inner.try_balance_of(account))
+    }
+
+    pub fn current_supply(&self) -> Result<u64, ClientError> {
+        flatten(self.inner.try_get_current_supply())
+    }
+
+    pub fn register_compliance(&self, user: &Address, kyc_verified: bool, country_code: &Symbol, risk_score: u32) -> Result<(), ClientError> {
+        flatten(self.inner.try_register_compliance(user, &kyc_verified, country_code, &risk_score))
+    }
+
+    pub fn vote(&self, voter: &Address, proposal: &Symbol, vote: bool) -> Result<(), ClientError> {
+        flatten(self.inner.try_ai_governance_vote(voter, proposal, &vote))
+    }
+
+    pub fn proposal_tally(&self, proposal: &Symbol) -> ProposalTally {
+        self.inner.get_proposal_tally(proposal)
+    }
+
+    pub fn bridge(&self, from: &Address, dimension: &Symbol, amount: u64, min_received: u64, deadline: u64) -> Result<u64, ClientError> {
+        flatten(self.inner.try_interdimensional_bridge(from, dimension, &amount, &min_received, &deadline))
+    }
+
+    pub fn holder_count(&self) -> u32 {
+        self.inner.holder_count()
+    }
+
+    pub fn verify_hologram(&self, coin_id: &BytesN<32>, hologram: &Bytes) -> bool {
+        self.inner.verify_hologram(coin_id, hologram)
+    }
+}
+
+// Client for the oracle module
+pub struct OracleClient<'a> {
+    inner: OracleContractClient<'a>,
+}
+
+impl<'a> OracleClient<'a> {
+    pub fn new(env: &'a Env, contract_id: &Address) -> Self {
+        OracleClient { inner: OracleContractClient::new(env, contract_id) }
+    }
+
+    // Median of the given prices (pure)
+    pub fn get_median_price(&self, prices: &Vec<u64>) -> Result<u64, ClientError> {
+        flatten_code(self.inner.try_get_median_price(prices))
+    }
+
+    // Median last recalculated from verified feeds
+    pub fn current_median(&self) -> Result<u64, ClientError> {
+        flatten_code(self.inner.try_get_current_median())
+    }
+
+    pub fn effective_price(&self) -> Result<u64, ClientError> {
+        flatten_code(self.inner.try_get_effective_price())
+    }
+
+    // `caller` must be a feed reporter or registered peg checker
+    pub fn check_peg(&self, caller: &Address) -> Result<bool, ClientError> {
+        flatten_code(self.inner.try_check_peg(caller))
+    }
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol, Vec};
+use pi_coin_client::{ClientError, OracleClient, PiCoinClient};
+use pi_coin_contract::oracle::OracleContract;
+use pi_coin_contract::{PiCoinContract, PiCoinError};
+
+#[test]
+fn test_pi_coin_client_mint_and_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let pi_coin = PiCoinClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    pi_coin.init(&Vec::from_array(&env, [admin]), 1).unwrap();
+    pi_coin.register_compliance(&user, true, &Symbol::new(&env, "US"), 10).unwrap();
+    
+    let coin = pi_coin.mint(&user, 100, &Symbol::new(&env, "mining")).unwrap();
+    assert_eq!(coin.amount, 100);
+    assert_eq!(pi_coin.current_supply(), Ok(100));
+    assert_eq!(pi_coin.holder_count(), 1);
+}
+
+#[test]
+fn test_pi_coin_client_maps_contract_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, PiCoinContract);
+    let pi_coin = PiCoinClient::new(&env, &contract_id);
+    
+    // Uninitialized contract has no supply record
+    assert_eq!(pi_coin.current_supply(), Err(ClientError::Contract(PiCoinError::NotFound)));
+    
+    let voter = Address::generate(&env);
+    let proposal = Symbol::new(&env, "raise_cap");
+    pi_coin.init(&Vec::from_array(&env, [Address::generate(&env)]), 1).unwrap();
+    pi_coin.vote(&voter, &proposal, true).unwrap();
+    assert_eq!(pi_coin.vote(&voter, &proposal, true), Err(ClientError::Contract(PiCoinError::AlreadyVoted)));
+    assert_eq!(pi_coin.proposal_tally(&proposal).yes, 1);
+}
+
+#[test]
+fn test_oracle_client_median() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, OracleContract);
+    let oracle = OracleClient::new(&env, &contract_id);
+    
+    assert_eq!(oracle.get_median_price(&Vec::from_array(&env, [3u64, 1u64, 2u64])), Ok(2));
+    assert_eq!(oracle.get_median_price(&Vec::new(&env)), Err(ClientError::Contract(PiCoinError::InvalidInput)));
+    // No feed has reported yet
+    assert_eq!(oracle.current_median(), Err(ClientError::Contract(PiCoinError::NotFound)));
+    assert_eq!(ClientError::from_code(999), ClientError::Code(999));
+}
+
+#[test]
+fn test_from_code_covers_every_pi_coin_error() {
+    for code in 1..=22u32 {
+        match ClientError::from_code(code) {
+            ClientError::Contract(err) => assert_eq!(err as u32, code),
+            other => panic!("code {} mapped to {:?}", code, other),
+        }
+    }
+    assert_eq!(ClientError::from_code(20), ClientError::Contract(PiCoinError::Paused));
+    assert_eq!(ClientError::from_code(22), ClientError::Contract(PiCoinError::CoinIdCollision));
+    assert_eq!(ClientError::from_code(23), ClientError::Code(23));
+    assert_eq!(ClientError::from_code(0), ClientError::Code(0));
+}
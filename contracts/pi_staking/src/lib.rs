@@ -1,7 +1,7 @@
 // contracts/pi_staking/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, contractcall};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Env, Address, Symbol, Vec, Map, BytesN, IntoVal, Val};
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
 use sha3::{Digest, Sha3_512};
 
@@ -10,17 +10,46 @@ use sha3::{Digest, Sha3_512};
 pub struct Stake {
     pub staker: Address,
     pub amount: u64,
-    pub start_time: u64,
+    pub start_time: u64,  // Amount-weighted lock start; topping up pulls it toward now
     pub rewards_earned: u64,
+    pub unlock_at: Option<u64>, // Set by request_unstake; the stake stops counting as voting power
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StakingError {
+    InvalidAmount = 1,       // Stake amount must be non-zero
+    NoStake = 2,             // Staker has nothing locked
+    UnstakeNotRequested = 3, // A cooldown is configured and request_unstake was not called
+    CooldownActive = 4,      // Cooldown started by request_unstake has not elapsed
+    UnstakePending = 5,      // Stake is already on its way out and cannot be topped up
+    Unauthorized = 6,        // Only the registered governance contract may slash
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashedEvent {
+    pub amount: u64,                // PI actually removed, capped at the locked stake
+    pub remaining: u64,             // Stake left locked after the slash
+    pub recipient: Option<Address>, // Where the slashed PI went; None when burned
+}
+
+// Lock duration after which a stake's voting power stops growing (one year)
+pub const POWER_RAMP_SECS: u64 = 31_536_000;
+
 #[contracttype]
 pub enum DataKey {
     Stakes,          // Map of stakes
     TotalStaked,     // Total Pi Coin staked
-    RewardPool,      // Pool for rewards
+    RewardPool,      // PI deposited through fund_rewards and held here; rewards never exceed it
     AiYieldModel,    // AI for yield optimization
     QuantumKey,
+    PiToken,         // Pi Coin token stakes are locked in and paid out from
+    UnstakeCooldown, // Seconds between request_unstake and unstake; 0 unstakes immediately
+    Admin,
+    Governance,      // Contract whose passed proposals may slash stakes
+    SlashRecipient,  // Address slashed PI is redirected to; burned when unset
 }
 
 #[contract]
@@ -29,14 +58,17 @@ pub struct PiStakingContract;
 #[contractimpl]
 impl PiStakingContract {
     // Initialize with hyper-tech staking
-    pub fn init(env: Env, admin: Address, reward_rate: u32) {
+    pub fn init(env: Env, admin: Address, pi_token: Address, reward_rate: u32, unstake_cooldown: u64) {
         admin.require_auth();
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage().persistent().set(&DataKey::PiToken, &pi_token);
+        env.storage().persistent().set(&DataKey::UnstakeCooldown, &unstake_cooldown);
         
         let stakes = Map::new(&env);
         env.storage().persistent().set(&DataKey::Stakes, &stakes);
         
         env.storage().persistent().set(&DataKey::TotalStaked, &0u64);
-        env.storage().persistent().set(&DataKey::RewardPool, &1000000u64);  // Initial pool
+        env.storage().persistent().set(&DataKey::RewardPool, &0u64);  // Funded with fund_rewards
         
         // AI Yield Model: Weights for optimization
         let ai_model = Map::new(&env);
@@ -51,36 +83,74 @@ impl PiStakingContract {
         env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
     }
     
-    // Stake Pi Coin
-    pub fn stake(env: Env, staker: Address, amount: u64) {
+    // Lock PI in this contract; the staker must have approved it as spender on the token
+    pub fn stake(env: Env, staker: Address, amount: u64) -> Result<(), StakingError> {
         staker.require_auth();
+        if amount == 0 {
+            return Err(StakingError::InvalidAmount);
+        }
         
-        // Transfer Pi Coin from staker (assume Pi Coin contract call)
-        let pi_contract = env.storage().persistent().get(&Symbol::new(&env, "pi_coin_contract")).unwrap();
-        contractcall!(env, pi_contract, transfer, staker, env.current_contract_address(), amount);
-        
-        let stake = Stake {
-            staker: staker.clone(),
-            amount,
-            start_time: env.ledger().timestamp(),
-            rewards_earned: 0,
+        let mut stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap();
+        let now = env.ledger().timestamp();
+        let stake = match stakes.get(staker.clone()) {
+            Some(existing) if existing.unlock_at.is_some() => return Err(StakingError::UnstakePending),
+            Some(existing) => {
+                let total = existing.amount + amount;
+                let weighted_start = (existing.start_time as u128 * existing.amount as u128 + now as u128 * amount as u128) / total as u128;
+                Stake { amount: total, start_time: weighted_start as u64, ..existing }
+            }
+            None => Stake {
+                staker: staker.clone(),
+                amount,
+                start_time: now,
+                rewards_earned: 0,
+                unlock_at: None,
+            },
         };
         
-        let mut stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap();
+        let pi_token: Address = env.storage().persistent().get(&DataKey::PiToken).unwrap();
+        let this = env.current_contract_address();
+        let args: Vec<Val> = Vec::from_array(&env, [this.into_val(&env), staker.into_val(&env), this.into_val(&env), (amount as i128).into_val(&env)]);
+        env.invoke_contract::<()>(&pi_token, &Symbol::new(&env, "transfer_from"), args);
+        
         stakes.set(staker, stake);
         env.storage().persistent().set(&DataKey::Stakes, &stakes);
         
         let mut total_staked: u64 = env.storage().persistent().get(&DataKey::TotalStaked).unwrap();
         total_staked += amount;
         env.storage().persistent().set(&DataKey::TotalStaked, &total_staked);
+        Ok(())
+    }
+    
+    // Start the unstake cooldown; voting power drops to 0 immediately
+    pub fn request_unstake(env: Env, staker: Address) -> Result<u64, StakingError> {
+        staker.require_auth();
+        
+        let mut stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap();
+        let mut stake = stakes.get(staker.clone()).ok_or(StakingError::NoStake)?;
+        if let Some(unlock_at) = stake.unlock_at {
+            return Ok(unlock_at);
+        }
+        let cooldown: u64 = env.storage().persistent().get(&DataKey::UnstakeCooldown).unwrap_or(0);
+        let unlock_at = env.ledger().timestamp() + cooldown;
+        stake.unlock_at = Some(unlock_at);
+        stakes.set(staker, stake);
+        env.storage().persistent().set(&DataKey::Stakes, &stakes);
+        Ok(unlock_at)
     }
     
-    // Unstake with AI-optimized rewards
-    pub fn unstake(env: Env, staker: Address) -> u64 {
+    // Unstake with AI-optimized rewards; with a cooldown set, only after request_unstake and the cooldown elapse
+    pub fn unstake(env: Env, staker: Address) -> Result<u64, StakingError> {
         staker.require_auth();
         
         let mut stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap();
-        let mut stake = stakes.get(staker.clone()).unwrap();
+        let mut stake = stakes.get(staker.clone()).ok_or(StakingError::NoStake)?;
+        let cooldown: u64 = env.storage().persistent().get(&DataKey::UnstakeCooldown).unwrap_or(0);
+        match stake.unlock_at {
+            None if cooldown > 0 => return Err(StakingError::UnstakeNotRequested),
+            Some(unlock_at) if env.ledger().timestamp() < unlock_at => return Err(StakingError::CooldownActive),
+            _ => {}
+        }
         
         // Calculate rewards with AI
         let ai_model: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::AiYieldModel).unwrap();
@@ -88,13 +158,17 @@ impl PiStakingContract {
         let ai_boost = ai_model.get(Symbol::new(&env, "ai_boost")).unwrap_or(10);
         
         let duration = env.ledger().timestamp() - stake.start_time;
-        let rewards = (stake.amount * base_yield as u64 * duration / 31536000) * (100 + ai_boost as u64) / 100;  // Annual yield with boost
+        let earned = (stake.amount as u128 * base_yield as u128 * duration as u128 / 31536000) * (100 + ai_boost as u128) / 100;  // Annual yield with boost
+        
+        // Principal always comes back; rewards only as far as the funded pool reaches
+        let reward_pool: u64 = env.storage().persistent().get(&DataKey::RewardPool).unwrap_or(0);
+        let rewards = earned.min(reward_pool as u128) as u64;
         stake.rewards_earned = rewards;
+        env.storage().persistent().set(&DataKey::RewardPool, &(reward_pool - rewards));
         
         // Transfer back Pi Coin + rewards
-        let pi_contract = env.storage().persistent().get(&Symbol::new(&env, "pi_coin_contract")).unwrap();
-        contractcall!(env, pi_contract, mint, env.current_contract_address(), rewards, Symbol::new(&env, "staking_reward"));
-        contractcall!(env, pi_contract, transfer, env.current_contract_address(), staker, stake.amount + rewards);
+        let pi_contract: Address = env.storage().persistent().get(&DataKey::PiToken).unwrap();
+        Self::pay_out(&env, &pi_contract, &staker, stake.amount + rewards);
         
         stakes.remove(staker.clone());
         env.storage().persistent().set(&DataKey::Stakes, &stakes);
@@ -103,23 +177,99 @@ impl PiStakingContract {
         total_staked -= stake.amount;
         env.storage().persistent().set(&DataKey::TotalStaked, &total_staked);
         
-        stake.amount + rewards
+        Ok(stake.amount + rewards)
     }
     
-    // Autonomous reward distribution
+    // Register the governance contract allowed to slash, and where slashed PI goes (burned when None)
+    pub fn set_governance(env: Env, governance: Address, slash_recipient: Option<Address>) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::Governance, &governance);
+        match slash_recipient {
+            Some(recipient) => env.storage().persistent().set(&DataKey::SlashRecipient, &recipient),
+            None => env.storage().persistent().remove(&DataKey::SlashRecipient),
+        }
+    }
+    
+    // Penalize a malicious voter on behalf of a passed governance proposal; stakes in cooldown can still be slashed
+    pub fn slash(env: Env, caller: Address, user: Address, amount: u64) -> Result<u64, StakingError> {
+        caller.require_auth();
+        let governance: Option<Address> = env.storage().persistent().get(&DataKey::Governance);
+        if governance != Some(caller) {
+            return Err(StakingError::Unauthorized);
+        }
+        if amount == 0 {
+            return Err(StakingError::InvalidAmount);
+        }
+        
+        let mut stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap();
+        let mut stake = stakes.get(user.clone()).ok_or(StakingError::NoStake)?;
+        let slashed = amount.min(stake.amount);
+        stake.amount -= slashed;
+        if stake.amount == 0 {
+            stakes.remove(user.clone());
+        } else {
+            stakes.set(user.clone(), stake.clone());
+        }
+        env.storage().persistent().set(&DataKey::Stakes, &stakes);
+        
+        let mut total_staked: u64 = env.storage().persistent().get(&DataKey::TotalStaked).unwrap();
+        total_staked -= slashed;
+        env.storage().persistent().set(&DataKey::TotalStaked, &total_staked);
+        
+        let pi_contract: Address = env.storage().persistent().get(&DataKey::PiToken).unwrap();
+        let recipient: Option<Address> = env.storage().persistent().get(&DataKey::SlashRecipient);
+        match &recipient {
+            Some(to) => Self::pay_out(&env, &pi_contract, to, slashed),
+            None => {
+                let args: Vec<Val> = Vec::from_array(&env, [env.current_contract_address().into_val(&env), (slashed as i128).into_val(&env)]);
+                env.invoke_contract::<()>(&pi_contract, &Symbol::new(&env, "burn"), args);
+            }
+        }
+        
+        env.events().publish((Symbol::new(&env, "Slashed"), user), SlashedEvent { amount: slashed, remaining: stake.amount, recipient });
+        Ok(slashed)
+    }
+    
+    // Deposit PI into the reward pool that unstake and distribute_rewards pay from
+    pub fn fund_rewards(env: Env, funder: Address, amount: u64) -> Result<(), StakingError> {
+        funder.require_auth();
+        if amount == 0 {
+            return Err(StakingError::InvalidAmount);
+        }
+        let pi_contract: Address = env.storage().persistent().get(&DataKey::PiToken).unwrap();
+        let args: Vec<Val> = Vec::from_array(&env, [funder.into_val(&env), env.current_contract_address().into_val(&env), (amount as i128).into_val(&env)]);
+        env.invoke_contract::<()>(&pi_contract, &Symbol::new(&env, "transfer"), args);
+        
+        let reward_pool: u64 = env.storage().persistent().get(&DataKey::RewardPool).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::RewardPool, &(reward_pool + amount));
+        Ok(())
+    }
+    
+    pub fn get_reward_pool(env: Env) -> u64 {
+        env.storage().persistent().get(&DataKey::RewardPool).unwrap_or(0)
+    }
+    
+    // Autonomous reward distribution out of the funded pool; stakers it cannot cover are skipped
     pub fn distribute_rewards(env: Env) {
         let stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap();
-        let reward_pool: u64 = env.storage().persistent().get(&DataKey::RewardPool).unwrap();
+        let mut reward_pool: u64 = env.storage().persistent().get(&DataKey::RewardPool).unwrap_or(0);
+        let pi_contract: Address = env.storage().persistent().get(&DataKey::PiToken).unwrap();
         
         for (staker, stake) in stakes.iter() {
-            let rewards = (stake.amount * 5 / 100);  // 5% annual, simplified
-            if rewards <= reward_pool {
-                // Mint and distribute
-                let pi_contract = env.storage().persistent().get(&Symbol::new(&env, "pi_coin_contract")).unwrap();
-                contractcall!(env, pi_contract, mint, env.current_contract_address(), rewards, Symbol::new(&env, "staking_reward"));
-                contractcall!(env, pi_contract, transfer, env.current_contract_address(), staker, rewards);
+            let rewards = stake.amount * 5 / 100;  // 5% annual, simplified
+            if rewards > 0 && rewards <= reward_pool {
+                reward_pool -= rewards;
+                Self::pay_out(&env, &pi_contract, &staker, rewards);
             }
         }
+        env.storage().persistent().set(&DataKey::RewardPool, &reward_pool);
+    }
+    
+    // Transfer PI from this contract to a staker
+    fn pay_out(env: &Env, pi_contract: &Address, staker: &Address, amount: u64) {
+        let args: Vec<Val> = Vec::from_array(env, [env.current_contract_address().into_val(env), staker.into_val(env), (amount as i128).into_val(env)]);
+        env.invoke_contract::<()>(pi_contract, &Symbol::new(env, "transfer"), args);
     }
     
     // Get stake info
@@ -128,6 +278,24 @@ impl PiStakingContract {
         stakes.get(staker).unwrap()
     }
     
+    // Amount currently staked by `staker`; 0 without a stake (governance voting power source)
+    pub fn staked_balance(env: Env, staker: Address) -> u64 {
+        let stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap_or(Map::new(&env));
+        stakes.get(staker).map_or(0, |stake| stake.amount)
+    }
+    
+    // Governance weight: the locked amount, growing linearly to 2x over POWER_RAMP_SECS; 0 once unstake is requested
+    pub fn voting_power(env: Env, staker: Address) -> u64 {
+        let stakes: Map<Address, Stake> = env.storage().persistent().get(&DataKey::Stakes).unwrap_or(Map::new(&env));
+        match stakes.get(staker) {
+            Some(stake) if stake.unlock_at.is_none() => {
+                let held = (env.ledger().timestamp() - stake.start_time).min(POWER_RAMP_SECS);
+                stake.amount + (stake.amount as u128 * held as u128 / POWER_RAMP_SECS as u128) as u64
+            }
+            _ => 0,
+        }
+    }
+    
     // Get total staked
     pub fn get_total_staked(env: Env) -> u64 {
         env.storage().persistent().get(&DataKey::TotalStaked).unwrap()
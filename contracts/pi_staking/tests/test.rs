@@ -1,10 +1,187 @@
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::{Address as _, Events, Ledger}, token, Address, Env, Map, Symbol, TryFromVal, Vec};
+use pi_coin_contract::{PiCoinContract, PiCoinContractClient, DECIMALS};
+use pi_staking::{PiStakingContract, PiStakingContractClient, SlashedEvent, StakingError, POWER_RAMP_SECS};
+
+// Token with balances, transfer_from and burn
+#[contract]
+pub struct MockPiToken;
+
+#[contractimpl]
+impl MockPiToken {
+    pub fn set_balance(env: Env, id: Address, amount: i128) {
+        let mut balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.set(id, amount);
+        env.storage().instance().set(&symbol_short!("bal"), &balances);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        let balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.get(id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "insufficient balance");
+        Self::set_balance(env.clone(), from, from_balance - amount);
+        let to_balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(env, to, to_balance + amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        Self::transfer(env, from, to, amount);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), from.clone());
+        assert!(balance >= amount, "insufficient balance");
+        Self::set_balance(env, from, balance - amount);
+    }
+}
+
+fn setup(env: &Env, cooldown: u64) -> (PiStakingContractClient<'static>, MockPiTokenClient<'static>, Address) {
+    let contract_id = env.register_contract(None, PiStakingContract);
+    let client = PiStakingContractClient::new(env, &contract_id);
+    let token_id = env.register_contract(None, MockPiToken);
+    let token = MockPiTokenClient::new(env, &token_id);
+    let admin = Address::generate(env);
+    let staker = Address::generate(env);
+
+    env.mock_all_auths();
+    client.init(&admin, &token_id, &5, &cooldown);
+    token.set_balance(&staker, &5000);
+    (client, token, staker)
+}
+
 #[test]
 fn test_stake() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, PiStakingContract);
-    let client = PiStakingContractClient::new(&env, &contract_id);
-    
-    client.init(&admin, &5);
+    let (client, token, staker) = setup(&env, 0);
+
     client.stake(&staker, &1000);
     assert_eq!(client.get_total_staked(), 1000);
+    assert_eq!(token.balance(&staker), 4000);
+    assert_eq!(token.balance(&client.address), 1000);
+    assert_eq!(client.try_stake(&staker, &0), Err(Ok(StakingError::InvalidAmount)));
+}
+
+#[test]
+fn test_staking_increases_voting_power() {
+    let env = Env::default();
+    let (client, _token, staker) = setup(&env, 0);
+    assert_eq!(client.voting_power(&staker), 0);
+
+    client.stake(&staker, &1000);
+    assert_eq!(client.voting_power(&staker), 1000);
+
+    // Half the ramp adds half the stake again
+    env.ledger().with_mut(|li| li.timestamp += POWER_RAMP_SECS / 2);
+    assert_eq!(client.voting_power(&staker), 1500);
+
+    // Topping up raises power, but the new PI starts its own ramp
+    client.stake(&staker, &1000);
+    assert_eq!(client.voting_power(&staker), 2500);
+
+    // Power is capped at 2x once the ramp is complete
+    env.ledger().with_mut(|li| li.timestamp += POWER_RAMP_SECS * 2);
+    assert_eq!(client.voting_power(&staker), 4000);
+}
+
+#[test]
+fn test_unstake_gated_by_cooldown() {
+    let env = Env::default();
+    let (client, token, staker) = setup(&env, 86_400);
+    let funder = Address::generate(&env);
+    token.set_balance(&funder, &100);
+    client.fund_rewards(&funder, &100);
+    client.stake(&staker, &1000);
+
+    assert_eq!(client.try_unstake(&staker), Err(Ok(StakingError::UnstakeNotRequested)));
+    let unlock_at = client.request_unstake(&staker);
+    assert_eq!(unlock_at, env.ledger().timestamp() + 86_400);
+    assert_eq!(client.voting_power(&staker), 0);
+    assert_eq!(client.try_stake(&staker, &10), Err(Ok(StakingError::UnstakePending)));
+
+    env.ledger().with_mut(|li| li.timestamp += 86_399);
+    assert_eq!(client.try_unstake(&staker), Err(Ok(StakingError::CooldownActive)));
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    // A day at 5% with the 10% boost earns 14 out of the funded pool
+    assert_eq!(client.unstake(&staker), 1014);
+    assert_eq!(token.balance(&staker), 5014);
+    assert_eq!(client.get_reward_pool(), 86);
+    assert_eq!(client.get_total_staked(), 0);
+    assert_eq!(client.try_unstake(&staker), Err(Ok(StakingError::NoStake)));
+}
+
+#[test]
+fn test_governance_slash_reduces_stake_and_power() {
+    let env = Env::default();
+    let (client, token, staker) = setup(&env, 0);
+    let governance = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.stake(&staker, &1000);
+
+    // Burned when no recipient is registered
+    client.set_governance(&governance, &None);
+    assert_eq!(client.slash(&governance, &staker, &300), 300);
+    assert_eq!(client.get_stake(&staker).amount, 700);
+    assert_eq!(client.voting_power(&staker), 700);
+    assert_eq!(client.get_total_staked(), 700);
+    assert_eq!(token.balance(&client.address), 700);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "Slashed"));
+    assert_eq!(SlashedEvent::try_from_val(&env, &data).unwrap(), SlashedEvent { amount: 300, remaining: 700, recipient: None });
+
+    // Redirected to the treasury, capped at what is still locked
+    client.set_governance(&governance, &Some(treasury.clone()));
+    assert_eq!(client.slash(&governance, &staker, &5000), 700);
+    assert_eq!(token.balance(&treasury), 700);
+    assert_eq!(client.voting_power(&staker), 0);
+    assert_eq!(client.try_slash(&governance, &staker, &1), Err(Ok(StakingError::NoStake)));
+}
+
+#[test]
+fn test_slash_rejected_without_governance() {
+    let env = Env::default();
+    let (client, _token, staker) = setup(&env, 0);
+    let governance = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    client.stake(&staker, &1000);
+
+    // Nobody may slash until governance is registered, and then only governance
+    assert_eq!(client.try_slash(&governance, &staker, &100), Err(Ok(StakingError::Unauthorized)));
+    client.set_governance(&governance, &None);
+    assert_eq!(client.try_slash(&attacker, &staker, &100), Err(Ok(StakingError::Unauthorized)));
+    assert_eq!(client.get_stake(&staker).amount, 1000);
+    assert_eq!(client.voting_power(&staker), 1000);
+}
+
+#[test]
+fn test_unstake_returns_principal_against_real_pi_coin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let staker = Address::generate(&env);
+    let pi_coin = PiCoinContractClient::new(&env, &env.register_contract(None, PiCoinContract));
+    pi_coin.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+    pi_coin.register_compliance(&staker, &true, &Symbol::new(&env, "US"), &10u32);
+    pi_coin.mint(&staker, &100, &Symbol::new(&env, "mining"));
+    let asset_id = pi_coin.get_asset_id();
+    let asset = token::Client::new(&env, &asset_id);
+    let held = asset.balance(&staker);
+    assert_eq!(held, 100 * 10i128.pow(DECIMALS));
+
+    let client = PiStakingContractClient::new(&env, &env.register_contract(None, PiStakingContract));
+    client.init(&admin, &asset_id, &5, &0);
+    let stake = 40 * 10u64.pow(DECIMALS);
+    asset.approve(&staker, &client.address, &(stake as i128), &(env.ledger().sequence() + 100));
+    client.stake(&staker, &stake);
+    assert_eq!(asset.balance(&client.address), stake as i128);
+
+    // A year of yield but an empty reward pool: the principal still comes back in full
+    env.ledger().with_mut(|li| li.timestamp += POWER_RAMP_SECS);
+    assert_eq!(client.unstake(&staker), stake);
+    assert_eq!(asset.balance(&staker), held);
+    assert_eq!(asset.balance(&client.address), 0);
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, Map, Val, log, panic_with_error};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, IntoVal, Symbol, Vec, Map, Val, log, panic_with_error};
 use soroban_sdk::auth::Context;
 
 // Custom error types for advanced error handling
@@ -10,6 +10,20 @@ pub enum SecurityError {
     ThresholdNotMet = 2,
     AnomalyDetected = 3,
     RecoveryFailed = 4,
+    InvalidConfig = 5, // Rejected at initialize, e.g. a threshold above the signer count
+}
+
+// Starting anomaly score threshold; detect_anomaly raises it as anomalies are seen
+pub const DEFAULT_ANOMALY_THRESHOLD: u32 = 5;
+
+// Security tunables, passed to initialize and returned by get_config
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SecurityConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,           // Approvals multi_sig_approve needs; 1..=signers.len()
+    pub anomaly_threshold: u32,   // Score above which a transaction is anomalous; non-zero
+    pub nexus_links: Vec<Address>,
 }
 
 // Struct for storing security state
@@ -18,40 +32,106 @@ pub struct SecurityContract {
     signers: Map<Address, bool>,  // List of authorized signers
     threshold: u32,               // Dynamic threshold for multi-sig
     paused: bool,                 // Pause state for self-healing
-    anomaly_score: Map<Symbol, u32>, // Anomaly scores for transactions (adaptive detection)
+    anomaly_score: Map<Symbol, u32>, // Anomaly scores for recent transactions, keyed by tx_hash (adaptive detection)
     nexus_links: Vec<Address>,    // Links to other contracts (e.g., pi_coin) for "nexus" communication
 }
 
+// Most recent per-transaction anomaly scores kept; older ones are pruned
+const MAX_ANOMALY_HISTORY: u32 = 50;
+// Recent scores needed before spike detection against their average kicks in
+const MIN_BASELINE_SAMPLES: u32 = 5;
+// A score this many times the recent average is a spike
+const SPIKE_FACTOR: u32 = 3;
+
 // GodHead Nexus Level: Autonomous AI-like logic for threat detection
-// This simulates "intelligence" by aggregating votes and adapting thresholds
+// This simulates "intelligence" by aggregating votes and adapting thresholds.
+// Each transaction's score is persisted under its tx_hash, and a score is also flagged when it
+// spikes well above the average of recent scores.
 fn detect_anomaly(env: &Env, tx_hash: Symbol, votes: Vec<bool>) -> bool {
     let mut anomaly_score = 0u32;
     for vote in votes.iter() {
         if *vote { anomaly_score += 1; } else { anomaly_score += 2; } // Weighted voting for "intelligence"
     }
+    
+    let spike = match recent_average(env) {
+        Some(average) => anomaly_score > average.saturating_mul(SPIKE_FACTOR),
+        None => false,
+    };
+    record_anomaly_score(env, tx_hash, anomaly_score);
+    
     // Adaptive threshold: If score > dynamic threshold, flag as anomaly
-    let dynamic_threshold = env.storage().instance().get(&"dynamic_threshold").unwrap_or(5u32);
+    let dynamic_threshold = env.storage().instance().get(&"dynamic_threshold").unwrap_or(DEFAULT_ANOMALY_THRESHOLD);
     if anomaly_score > dynamic_threshold {
         // Self-heal: Increase threshold to prevent false positives
         env.storage().instance().set(&"dynamic_threshold", &(dynamic_threshold + 1));
         true
     } else {
-        false
+        spike
+    }
+}
+
+// Average of recent scores, once there are enough to form a baseline
+fn recent_average(env: &Env) -> Option<u32> {
+    let history: Vec<Symbol> = env.storage().instance().get(&"anomaly_history").unwrap_or_default();
+    if history.len() < MIN_BASELINE_SAMPLES {
+        return None;
     }
+    let scores: Map<Symbol, u32> = env.storage().instance().get(&"anomaly_score").unwrap_or_default();
+    let total: u32 = history.iter().map(|tx| scores.get(tx).unwrap_or(0)).sum();
+    Some(total / history.len())
+}
+
+// Store a transaction's score, most recent last, pruning the oldest past MAX_ANOMALY_HISTORY
+fn record_anomaly_score(env: &Env, tx_hash: Symbol, score: u32) {
+    let mut scores: Map<Symbol, u32> = env.storage().instance().get(&"anomaly_score").unwrap_or_default();
+    let mut history: Vec<Symbol> = env.storage().instance().get(&"anomaly_history").unwrap_or_default();
+    if let Some(index) = history.first_index_of(&tx_hash) {
+        history.remove(index);
+    }
+    history.push_back(tx_hash.clone());
+    scores.set(tx_hash, score);
+    while history.len() > MAX_ANOMALY_HISTORY {
+        let oldest = history.pop_front().unwrap();
+        scores.remove(oldest);
+    }
+    env.storage().instance().set(&"anomaly_score", &scores);
+    env.storage().instance().set(&"anomaly_history", &history);
 }
 
 #[contractimpl]
 impl SecurityContract {
-    // Initialize the security nexus
-    pub fn initialize(env: Env, admin: Address, initial_signers: Vec<Address>, initial_threshold: u32, nexus_contracts: Vec<Address>) {
+    // Initialize the security nexus. The threshold must be between 1 and the signer count.
+    pub fn initialize(env: Env, admin: Address, config: SecurityConfig) -> Result<(), SecurityError> {
         admin.require_auth();
-        env.storage().instance().set(&"signers", &initial_signers.iter().map(|s| (s.clone(), true)).collect::<Map<_, _>>());
-        env.storage().instance().set(&"threshold", &initial_threshold);
+        if config.threshold == 0 || config.threshold > config.signers.len() || config.anomaly_threshold == 0 {
+            return Err(SecurityError::InvalidConfig);
+        }
+        env.storage().instance().set(&"signers", &config.signers.iter().map(|s| (s.clone(), true)).collect::<Map<_, _>>());
+        env.storage().instance().set(&"threshold", &config.threshold);
         env.storage().instance().set(&"paused", &false);
         env.storage().instance().set(&"anomaly_score", &Map::new(&env));
-        env.storage().instance().set(&"nexus_links", &nexus_contracts);
-        env.storage().instance().set(&"dynamic_threshold", &5u32); // Starting adaptive threshold
+        env.storage().instance().set(&"anomaly_history", &Vec::<Symbol>::new(&env));
+        env.storage().instance().set(&"nexus_links", &config.nexus_links);
+        env.storage().instance().set(&"dynamic_threshold", &config.anomaly_threshold); // Starting adaptive threshold
         log!(&env, "Security Nexus Initialized with GodHead Autonomy");
+        Ok(())
+    }
+
+    // All tunables in one read; anomaly_threshold reflects any adaptation since initialize
+    pub fn get_config(env: Env) -> SecurityConfig {
+        let signers: Map<Address, bool> = env.storage().instance().get(&"signers").unwrap_or_default();
+        let mut active = Vec::new(&env);
+        for (signer, enabled) in signers.iter() {
+            if enabled {
+                active.push_back(signer);
+            }
+        }
+        SecurityConfig {
+            signers: active,
+            threshold: env.storage().instance().get(&"threshold").unwrap_or(1),
+            anomaly_threshold: env.storage().instance().get(&"dynamic_threshold").unwrap_or(DEFAULT_ANOMALY_THRESHOLD),
+            nexus_links: env.storage().instance().get(&"nexus_links").unwrap_or_default(),
+        }
     }
 
     // Advanced Multi-Sig with Anomaly Detection (Autonomous Voting)
@@ -60,13 +140,15 @@ impl SecurityContract {
             return Err(SecurityError::RecoveryFailed);
         }
         
-        // Nexus Communication: Query linked contracts for additional "intelligence"
+        // Nexus Communication: Each linked contract votes via get_anomaly_vote(tx_hash) -> bool
+        // (true = looks safe). A link that fails to answer abstains.
         let nexus_links: Vec<Address> = env.storage().instance().get(&"nexus_links").unwrap_or_default();
         let mut enhanced_votes = votes.clone();
         for link in nexus_links.iter() {
-            // Simulate querying another contract (e.g., pi_coin for balance anomaly)
-            // In real impl, use cross-contract call: env.invoke_contract(link, "get_anomaly_vote", ...)
-            enhanced_votes.push_back(true); // Placeholder for nexus input
+            let args = Vec::from_array(&env, [tx_hash.into_val(&env)]);
+            if let Ok(Ok(vote)) = env.try_invoke_contract::<bool, soroban_sdk::Error>(&link, &Symbol::new(&env, "get_anomaly_vote"), args) {
+                enhanced_votes.push_back(vote);
+            }
         }
         
         // Autonomous Detection
@@ -91,11 +173,18 @@ impl SecurityContract {
         }
         // Autonomous rollback: Reset anomaly scores
         env.storage().instance().set(&"anomaly_score", &Map::new(&env));
+        env.storage().instance().set(&"anomaly_history", &Vec::<Symbol>::new(&env));
         env.storage().instance().set(&"paused", &false);
         log!(&env, "GodHead Nexus Recovered Autonomously");
         Ok(())
     }
 
+    // Score recorded for a transaction; 0 if unknown or pruned
+    pub fn get_anomaly_score(env: Env, tx_hash: Symbol) -> u32 {
+        let scores: Map<Symbol, u32> = env.storage().instance().get(&"anomaly_score").unwrap_or_default();
+        scores.get(tx_hash).unwrap_or(0)
+    }
+
     // Pause for emergency (part of self-healing)
     pub fn pause(env: Env) {
         env.storage().instance().set(&"paused", &true);
@@ -1,38 +1,96 @@
 // contracts/stablecoin/multi_asset_collateral.rs
 // Multi-Asset Collateral: Diverse backing for Pi Coin.
 // Multi-asset collateral, eternal flexibility.
-// Features: Deposit multi-asset, withdraw, GodHead Nexus AI collateral.
+// Features: Deposit multi-asset, withdraw, pause, emergency withdraw, GodHead Nexus AI collateral.
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Map, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Map, log};
+
+/// Data of CollateralDeposited and CollateralWithdrawn; topic1 is the user.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralEvent {
+    pub asset: Symbol,
+    pub amount: i128,
+}
 
 #[contract]
 pub struct MultiAssetCollateral {
-    collateral: Map<Symbol, Map<Symbol, i128>>, // User -> Asset -> Amount.
+    collateral: Map<Address, Map<Symbol, i128>>, // User -> Asset -> Amount.
     total_supply: i128, // 100,000,000,000.
+    admin: Address, // May pause and unpause.
+    paused: bool, // While set, only emergency_withdraw is available.
+    oracle: Address, // Sole source of asset prices.
+    prices: Map<Symbol, i128>, // Asset -> Price per unit in the common valuation unit.
 }
 
 #[contractimpl]
 impl MultiAssetCollateral {
-    pub fn init(env: Env) -> MultiAssetCollateral {
-        MultiAssetCollateral { collateral: Map::new(&env), total_supply: 100000000000 }
+    pub fn init(env: Env, admin: Address, oracle: Address) -> MultiAssetCollateral {
+        MultiAssetCollateral { collateral: Map::new(&env), total_supply: 100000000000, admin, paused: false, oracle, prices: Map::new(&env) }
+    }
+
+    /// Set an asset's price in the common valuation unit (oracle only). Pricing an asset makes it depositable.
+    pub fn set_asset_price(&mut self, env: Env, asset: Symbol, price: i128) -> Result<(), &'static str> {
+        self.oracle.require_auth();
+        if price <= 0 {
+            return Err("Price must be positive.");
+        }
+        self.prices.set(asset.clone(), price);
+        env.events().publish((Symbol::new(&env, "AssetPriceSet"), asset.clone()), price);
+        log!(&env, "Asset price set: {} = {}", asset, price);
+        Ok(())
+    }
+
+    pub fn get_asset_price(&self, asset: Symbol) -> Option<i128> {
+        self.prices.get(asset)
+    }
+
+    /// Value of all of a user's collateral: each asset's amount times its price.
+    pub fn portfolio_value(&self, env: Env, user: Address) -> Result<i128, &'static str> {
+        let mut value: i128 = 0;
+        for (asset, amount) in self.get_user_collateral(env, user).iter() {
+            let price = self.prices.get(asset).ok_or("Unknown asset.")?;
+            value = amount.checked_mul(price).and_then(|v| value.checked_add(v)).ok_or("Valuation overflow.")?;
+        }
+        Ok(value)
     }
 
     /// Deposit multi-asset collateral.
-    pub fn deposit_multi_asset(&mut self, env: Env, user: Symbol, asset: Symbol, amount: i128) {
-        let mut user_coll = self.collateral.get(user).unwrap_or(Map::new(&env));
-        let current = user_coll.get(asset).unwrap_or(0);
-        user_coll.set(asset, current + amount);
-        self.collateral.set(user, user_coll);
+    pub fn deposit_multi_asset(&mut self, env: Env, user: Address, asset: Symbol, amount: i128) -> Result<(), &'static str> {
+        user.require_auth();
+        if self.paused {
+            return Err("Collateral operations paused.");
+        }
+        if amount <= 0 {
+            return Err("Amount must be positive.");
+        }
+        if !self.prices.contains_key(asset.clone()) {
+            return Err("Unknown asset.");
+        }
+        let mut user_coll = self.collateral.get(user.clone()).unwrap_or(Map::new(&env));
+        let current = user_coll.get(asset.clone()).unwrap_or(0);
+        user_coll.set(asset.clone(), current + amount);
+        self.collateral.set(user.clone(), user_coll);
+        env.events().publish((Symbol::new(&env, "CollateralDeposited"), user.clone()), CollateralEvent { asset: asset.clone(), amount });
         log!(&env, "Multi-asset deposited: {} {} by {}", amount, asset, user);
+        Ok(())
     }
 
     /// Withdraw multi-asset collateral.
-    pub fn withdraw_multi_asset(&mut self, env: Env, user: Symbol, asset: Symbol, amount: i128) -> Result<(), &'static str> {
-        let mut user_coll = self.collateral.get(user).ok_or("No collateral")?;
-        let current = user_coll.get(asset).unwrap_or(0);
+    pub fn withdraw_multi_asset(&mut self, env: Env, user: Address, asset: Symbol, amount: i128) -> Result<(), &'static str> {
+        user.require_auth();
+        if self.paused {
+            return Err("Collateral operations paused.");
+        }
+        if amount <= 0 {
+            return Err("Amount must be positive.");
+        }
+        let mut user_coll = self.collateral.get(user.clone()).ok_or("No collateral")?;
+        let current = user_coll.get(asset.clone()).unwrap_or(0);
         if current >= amount {
-            user_coll.set(asset, current - amount);
-            self.collateral.set(user, user_coll);
+            user_coll.set(asset.clone(), current - amount);
+            self.collateral.set(user.clone(), user_coll);
+            env.events().publish((Symbol::new(&env, "CollateralWithdrawn"), user.clone()), CollateralEvent { asset: asset.clone(), amount });
             log!(&env, "Multi-asset withdrawn: {} {} by {}", amount, asset, user);
             Ok(())
         } else {
@@ -40,14 +98,137 @@ impl MultiAssetCollateral {
         }
     }
 
+    /// Halt deposits and withdrawals (admin only).
+    pub fn pause(&mut self, env: Env) {
+        self.admin.require_auth();
+        self.paused = true;
+        env.events().publish((Symbol::new(&env, "CollateralPaused"),), true);
+    }
+
+    /// Resume deposits and withdrawals (admin only).
+    pub fn unpause(&mut self, env: Env) {
+        self.admin.require_auth();
+        self.paused = false;
+        env.events().publish((Symbol::new(&env, "CollateralPaused"),), false);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Reclaim all of the caller's collateral in `asset` while paused, skipping any debt checks.
+    /// Graceful-shutdown path only: unavailable while operations are running normally.
+    pub fn emergency_withdraw(&mut self, env: Env, user: Address, asset: Symbol) -> Result<i128, &'static str> {
+        user.require_auth();
+        if !self.paused {
+            return Err("Emergency withdrawal only while paused.");
+        }
+        let mut user_coll = self.collateral.get(user.clone()).ok_or("No collateral")?;
+        let amount = user_coll.get(asset.clone()).unwrap_or(0);
+        if amount == 0 {
+            return Err("No collateral");
+        }
+        user_coll.remove(asset.clone());
+        self.collateral.set(user.clone(), user_coll);
+        env.events().publish((Symbol::new(&env, "EmergencyWithdraw"), user), (asset, amount));
+        Ok(amount)
+    }
+
     /// Collateral with AI.
-    pub fn collateral_with_ai(&self, env: Env, user: Symbol) -> Symbol {
+    pub fn collateral_with_ai(&self, env: Env, user: Address) -> Symbol {
         // Integrate with GodHead Nexus.
         Symbol::new(&env, "ai_multi_collateralized")
     }
 
     /// Get user collateral.
-    pub fn get_user_collateral(&self, env: Env, user: Symbol) -> Map<Symbol, i128> {
+    pub fn get_user_collateral(&self, env: Env, user: Address) -> Map<Symbol, i128> {
         self.collateral.get(user).unwrap_or(Map::new(&env))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::TryFromVal;
+
+    fn setup(env: &Env) -> (MultiAssetCollateral, Address) {
+        env.mock_all_auths();
+        let mut collateral = MultiAssetCollateral::init(env.clone(), Address::generate(env), Address::generate(env));
+        collateral.set_asset_price(env.clone(), Symbol::new(env, "XLM"), 12).unwrap();
+        let user = Address::generate(env);
+        collateral.deposit_multi_asset(env.clone(), user.clone(), Symbol::new(env, "XLM"), 500).unwrap();
+        (collateral, user)
+    }
+
+    #[test]
+    fn test_emergency_withdraw_rejected_while_unpaused() {
+        let env = Env::default();
+        let (mut collateral, user) = setup(&env);
+        assert_eq!(collateral.emergency_withdraw(env.clone(), user.clone(), Symbol::new(&env, "XLM")), Err("Emergency withdrawal only while paused."));
+        assert_eq!(collateral.get_user_collateral(env.clone(), user).get(Symbol::new(&env, "XLM")), Some(500));
+    }
+
+    #[test]
+    fn test_paused_blocks_normal_operations_but_allows_emergency_withdraw() {
+        let env = Env::default();
+        let (mut collateral, user) = setup(&env);
+        collateral.pause(env.clone());
+        assert!(collateral.is_paused());
+        assert_eq!(collateral.withdraw_multi_asset(env.clone(), user.clone(), Symbol::new(&env, "XLM"), 100), Err("Collateral operations paused."));
+        assert_eq!(collateral.deposit_multi_asset(env.clone(), user.clone(), Symbol::new(&env, "XLM"), 100), Err("Collateral operations paused."));
+
+        assert_eq!(collateral.emergency_withdraw(env.clone(), user.clone(), Symbol::new(&env, "XLM")), Ok(500));
+        assert_eq!(collateral.get_user_collateral(env.clone(), user.clone()).get(Symbol::new(&env, "XLM")), None);
+        // Nothing left to reclaim
+        assert_eq!(collateral.emergency_withdraw(env.clone(), user.clone(), Symbol::new(&env, "XLM")), Err("No collateral"));
+
+        collateral.unpause(env.clone());
+        assert_eq!(collateral.deposit_multi_asset(env.clone(), user, Symbol::new(&env, "XLM"), 100), Ok(()));
+    }
+
+    #[test]
+    fn test_portfolio_value_tracks_prices() {
+        let env = Env::default();
+        let (mut collateral, user) = setup(&env);
+        collateral.set_asset_price(env.clone(), Symbol::new(&env, "USDC"), 100).unwrap();
+        collateral.deposit_multi_asset(env.clone(), user.clone(), Symbol::new(&env, "USDC"), 30).unwrap();
+        // 500 XLM at 12 plus 30 USDC at 100
+        assert_eq!(collateral.portfolio_value(env.clone(), user.clone()), Ok(9_000));
+
+        collateral.set_asset_price(env.clone(), Symbol::new(&env, "XLM"), 10).unwrap();
+        assert_eq!(collateral.portfolio_value(env.clone(), user.clone()), Ok(8_000));
+    }
+
+    #[test]
+    fn test_unknown_asset_rejected() {
+        let env = Env::default();
+        let (mut collateral, user) = setup(&env);
+        assert_eq!(collateral.deposit_multi_asset(env.clone(), user.clone(), Symbol::new(&env, "DOGE"), 10), Err("Unknown asset."));
+        assert_eq!(collateral.set_asset_price(env.clone(), Symbol::new(&env, "DOGE"), 0), Err("Price must be positive."));
+        assert_eq!(collateral.portfolio_value(env.clone(), user), Ok(6_000));
+    }
+
+    #[test]
+    fn test_non_positive_amounts_rejected() {
+        let env = Env::default();
+        let (mut collateral, user) = setup(&env);
+        let xlm = Symbol::new(&env, "XLM");
+        // A negative withdrawal would otherwise pass the balance check and credit the user
+        assert_eq!(collateral.withdraw_multi_asset(env.clone(), user.clone(), xlm.clone(), -1_000), Err("Amount must be positive."));
+        assert_eq!(collateral.deposit_multi_asset(env.clone(), user.clone(), xlm.clone(), -1_000), Err("Amount must be positive."));
+        assert_eq!(collateral.deposit_multi_asset(env.clone(), user.clone(), xlm.clone(), 0), Err("Amount must be positive."));
+        assert_eq!(collateral.withdraw_multi_asset(env.clone(), user.clone(), xlm.clone(), 0), Err("Amount must be positive."));
+        assert_eq!(collateral.get_user_collateral(env.clone(), user).get(xlm), Some(500));
+    }
+
+    #[test]
+    fn test_collateral_deposited_event() {
+        let env = Env::default();
+        let (_, user) = setup(&env);
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "CollateralDeposited"));
+        assert_eq!(Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), user);
+        assert_eq!(CollateralEvent::try_from_val(&env, &data).unwrap(), CollateralEvent { asset: Symbol::new(&env, "XLM"), amount: 500 });
+    }
+}
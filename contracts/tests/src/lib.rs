@@ -1,12 +1,19 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, Map, Val, log};
-use soroban_sdk::testutils::{EnvTest, Address as TestAddress};
+use soroban_sdk::{contracttype, Env, IntoVal, Symbol, TryFromVal, Val, Vec, log};
 use rand::Rng; // For fuzzing (add to Cargo.toml)
 
 // Import contracts for testing
-use crate::security::SecurityContract;
-use crate::governance::GovernanceContract;
+use security::{SecurityConfig, SecurityContract, SecurityContractClient, SecurityError, DEFAULT_ANOMALY_THRESHOLD};
+use governance::{GovernanceConfig, GovernanceContract, GovernanceContractClient, GovernanceError, PowerSource, ProposalStatus, DEFAULT_ADAPTIVE_THRESHOLD, DEFAULT_MIN_STAKE};
+use pause_registry::{PauseRegistryContract, PauseRegistryContractClient};
+use pi_coin_contract::stability::{StabilityContract, StabilityContractClient};
+use pi_coin_contract::{PiCoinContract, PiCoinContractClient, PiCoinError};
+use transaction::{TransactionContract, TransactionContractClient};
+
+// Mock dependencies shared by the unit tests below and tests/integration.rs
+pub mod mocks;
 
 // Custom test result struct
+#[contracttype]
 #[derive(Clone, Debug)]
 pub struct TestResult {
     pub test_name: Symbol,
@@ -26,40 +33,67 @@ fn evolve_test_case(env: &Env, base_input: Vec<Val>, history: &Vec<TestResult>)
         for i in 0..evolved.len() {
             if rng.gen_bool(0.5) {
                 // Placeholder mutation (real impl: adjust based on type)
-                evolved.set(i, Val::U32(rng.gen_range(0..100)));
+                evolved.set(i, rng.gen_range(0..100u32).into_val(env));
             }
         }
     }
     evolved
 }
 
+// Decode evolved inputs back to votes; mutated (non-bool) entries count as rejections
+fn to_votes(env: &Env, inputs: &Vec<Val>) -> Vec<bool> {
+    let mut votes = Vec::new(env);
+    for input in inputs.iter() {
+        votes.push_back(bool::try_from_val(env, &input).unwrap_or(false));
+    }
+    votes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mocks::{MockNexusLink, MockNexusLinkClient, MockOracle, MockToken, MockTokenClient, MockPiCoinTransfer, MockPiCoinTransferClient, MockVerification, MockVerificationClient};
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::{Address, BytesN};
+
+    fn security_config(signers: &Vec<Address>, threshold: u32, nexus_links: Vec<Address>) -> SecurityConfig {
+        SecurityConfig { signers: signers.clone(), threshold, anomaly_threshold: DEFAULT_ANOMALY_THRESHOLD, nexus_links }
+    }
+
+    fn setup_security<'a>(env: &'a Env, signers: &Vec<Address>, threshold: u32) -> (Address, SecurityContractClient<'a>) {
+        let security_id = env.register_contract(None, SecurityContract);
+        let security = SecurityContractClient::new(env, &security_id);
+        security.initialize(&Address::generate(env), &security_config(signers, threshold, Vec::new(env)));
+        (security_id, security)
+    }
+
+    fn governance_config(voting_token: &Address, security_nexus: &Address) -> GovernanceConfig {
+        GovernanceConfig {
+            voting_token: voting_token.clone(),
+            security_nexus: security_nexus.clone(),
+            adaptive_threshold: DEFAULT_ADAPTIVE_THRESHOLD,
+            min_stake: DEFAULT_MIN_STAKE,
+            power_source: PowerSource::Registry,
+        }
+    }
 
     // Autonomous fuzzing test for security contract
     #[test]
     fn godhead_fuzz_security() {
-        let env = EnvTest::default();
-        let admin = TestAddress::random(&env);
-        let signers = vec![TestAddress::random(&env), TestAddress::random(&env)];
-        let security = SecurityContract::new(env.clone());
-        
-        // Initialize
-        security.initialize(env.clone(), admin, signers.clone(), 2, vec![]);
+        let env = Env::default();
+        env.mock_all_auths();
+        let signers = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+        let (_, security) = setup_security(&env, &signers, 2);
         
         // Evolutionary fuzzing: Run multiple iterations
         let mut history: Vec<TestResult> = Vec::new(&env);
         for _ in 0..10 {
             let tx_hash = Symbol::new(&env, "test_tx");
-            let base_votes = vec![true, false];
-            let evolved_votes = evolve_test_case(&env, base_votes.iter().map(|v| Val::Bool(*v)).collect(), &history);
+            let base_votes = Vec::from_array(&env, [true.into_val(&env), true.into_val(&env)]);
+            let evolved_votes = evolve_test_case(&env, base_votes, &history);
+            let votes = to_votes(&env, &evolved_votes);
             
-            // Convert back to bools (placeholder)
-            let votes: Vec<bool> = evolved_votes.iter().map(|v| matches!(v, Val::Bool(true))).collect();
-            
-            let result = security.multi_sig_approve(env.clone(), tx_hash, votes);
-            let passed = result.is_ok();
+            let passed = matches!(security.try_multi_sig_approve(&tx_hash, &votes), Ok(Ok(true)));
             history.push_back(TestResult {
                 test_name: Symbol::new(&env, "fuzz_security"),
                 passed,
@@ -73,51 +107,326 @@ mod tests {
         log!(&env, "GodHead Security Fuzzing Completed with {}% Pass Rate", (pass_rate * 100.0) as u32);
     }
 
+    // Per-transaction anomaly scores persist and old ones are pruned
+    #[test]
+    fn godhead_anomaly_scores_recorded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, security) = setup_security(&env, &Vec::from_array(&env, [Address::generate(&env)]), 1);
+        
+        // Approvals weigh 1, rejections 2
+        security.multi_sig_approve(&Symbol::new(&env, "tx_a"), &Vec::from_array(&env, [true, false]));
+        security.multi_sig_approve(&Symbol::new(&env, "tx_b"), &Vec::from_array(&env, [false, false, true]));
+        security.multi_sig_approve(&Symbol::new(&env, "tx_c"), &Vec::from_array(&env, [true]));
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "tx_a")), 3);
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "tx_b")), 5);
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "tx_c")), 1);
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "unknown")), 0);
+        
+        for i in 0..50 {
+            security.multi_sig_approve(&Symbol::new(&env, &format!("tx_{}", i)), &Vec::from_array(&env, [true]));
+        }
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "tx_a")), 0);
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "tx_49")), 1);
+    }
+
+    // A score far above the recent baseline is flagged even under the dynamic threshold
+    #[test]
+    fn godhead_anomaly_spike_detected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, security) = setup_security(&env, &Vec::from_array(&env, [Address::generate(&env)]), 1);
+        for i in 0..5 {
+            security.multi_sig_approve(&Symbol::new(&env, &format!("calm_{}", i)), &Vec::from_array(&env, [true]));
+        }
+        let result = security.try_multi_sig_approve(&Symbol::new(&env, "spike"), &Vec::from_array(&env, [false, false]));
+        assert_eq!(result, Err(Ok(SecurityError::AnomalyDetected)));
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "spike")), 4);
+    }
+
+    // A hostile nexus link tips the anomaly decision; a link that cannot answer abstains
+    #[test]
+    fn godhead_nexus_link_votes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let link_id = env.register_contract(None, MockNexusLink);
+        let link = MockNexusLinkClient::new(&env, &link_id);
+        let unreachable = Address::generate(&env);
+        let security = SecurityContractClient::new(&env, &env.register_contract(None, SecurityContract));
+        let signers = Vec::from_array(&env, [Address::generate(&env)]);
+        security.initialize(&Address::generate(&env), &security_config(&signers, 1, Vec::from_array(&env, [link_id.clone(), unreachable])));
+        
+        // Four approvals plus a benign link score 5, at the dynamic threshold
+        let votes = Vec::from_array(&env, [true, true, true, true]);
+        assert_eq!(security.multi_sig_approve(&Symbol::new(&env, "tx_ok"), &votes), true);
+        assert_eq!(security.get_anomaly_score(&Symbol::new(&env, "tx_ok")), 5);
+        
+        // The same votes with a hostile link score 6 and are flagged
+        link.set_vote(&false);
+        let result = security.try_multi_sig_approve(&Symbol::new(&env, "tx_bad"), &votes);
+        assert_eq!(result, Err(Ok(SecurityError::AnomalyDetected)));
+        // Self-healing paused the contract
+        assert_eq!(security.try_multi_sig_approve(&Symbol::new(&env, "tx_next"), &votes), Err(Ok(SecurityError::RecoveryFailed)));
+    }
+
     // Predictive integration test for governance
     #[test]
     fn godhead_predictive_governance() {
-        let env = EnvTest::default();
-        let admin = TestAddress::random(&env);
-        let voting_token = TestAddress::random(&env); // Mock pi_coin
-        let security_nexus = TestAddress::random(&env); // Mock security
-        let governance = GovernanceContract::new(env.clone());
+        let env = Env::default();
+        env.mock_all_auths();
+        let voting_token = Address::generate(&env); // Mock pi_coin
+        let security_nexus = Address::generate(&env); // Mock security
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
         
         // Initialize
-        governance.initialize(env.clone(), admin, voting_token, security_nexus);
+        governance.initialize(&Address::generate(&env), &governance_config(&voting_token, &security_nexus));
         
         // Create and vote on proposal
-        let proposer = TestAddress::random(&env);
-        let proposal_id = governance.create_proposal(env.clone(), proposer, Symbol::new(&env, "Test Proposal"), 1000);
+        let proposer = Address::generate(&env);
+        let proposal_id = governance.create_proposal(&proposer, &Symbol::new(&env, "test_proposal"), &1000);
         
         // Simulate predictive voting
-        let voter = TestAddress::random(&env);
-        let result = governance.vote(env.clone(), voter, proposal_id, true, 50);
-        assert!(result.is_ok(), "Voting Failed");
+        let voter = Address::generate(&env);
+        governance.set_voting_power(&voter, &50);
+        assert!(governance.try_vote(&voter, &proposal_id, &true).is_ok(), "Voting Failed");
         
-        // Execute and check prediction
-        let exec_result = governance.execute_proposal(env.clone(), proposal_id);
-        // Assert based on adaptive logic
+        // Execution waits for the voting period to end
+        assert!(governance.try_execute_proposal(&proposal_id).is_err());
+        env.ledger().with_mut(|li| li.timestamp += 1001);
+        assert!(governance.try_execute_proposal(&proposal_id).is_ok());
         log!(&env, "GodHead Governance Prediction Test Passed");
     }
 
+    // Vote weight comes from the configured power source, never from the caller
+    #[test]
+    fn godhead_votes_weighted_by_power_source() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let token = env.register_contract(None, MockToken);
+        let token_client = MockTokenClient::new(&env, &token);
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
+        let mut config = governance_config(&token, &Address::generate(&env));
+        config.power_source = PowerSource::TokenBalance(token.clone());
+        governance.initialize(&Address::generate(&env), &config);
+        let (holder, dust) = (Address::generate(&env), Address::generate(&env));
+        token_client.set_balance(&holder, &300);
+        token_client.set_balance(&dust, &(DEFAULT_MIN_STAKE as i128 - 1));
+        
+        let proposal_id = governance.create_proposal(&holder, &Symbol::new(&env, "weighted"), &1000);
+        governance.vote(&holder, &proposal_id, &true);
+        assert_eq!(governance.try_vote(&holder, &proposal_id, &true), Err(Ok(GovernanceError::AlreadyVoted)));
+        assert_eq!(governance.try_vote(&dust, &proposal_id, &false), Err(Ok(GovernanceError::InsufficientStake)));
+        let (page, _) = governance.list_proposals(&ProposalStatus::Active, &0, &1);
+        assert_eq!(page.get(0).unwrap().votes_for, 300);
+        assert_eq!(page.get(0).unwrap().votes_against, 0);
+    }
+
+    // Config getter tracks initialize and threshold changes
+    #[test]
+    fn godhead_governance_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (voting_token, security_nexus) = (Address::generate(&env), Address::generate(&env));
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
+        governance.initialize(&Address::generate(&env), &governance_config(&voting_token, &security_nexus));
+        
+        let mut expected = GovernanceConfig { voting_token, security_nexus, adaptive_threshold: 50, min_stake: 10, power_source: PowerSource::Registry };
+        assert_eq!(governance.get_config(), expected);
+        
+        governance.adjust_threshold(&66);
+        expected.adaptive_threshold = 66;
+        assert_eq!(governance.get_config(), expected);
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(), Symbol::new(&env, "ConfigChanged"));
+        assert_eq!(GovernanceConfig::try_from_val(&env, &data).unwrap(), expected);
+    }
+
+    // Only the admin may move the execution threshold
+    #[test]
+    fn godhead_threshold_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
+        governance.initialize(&Address::generate(&env), &governance_config(&Address::generate(&env), &Address::generate(&env)));
+        
+        // No authorization from the admin: an external caller is rejected
+        env.set_auths(&[]);
+        assert!(governance.try_adjust_threshold(&1).is_err());
+        assert_eq!(governance.get_config().adaptive_threshold, 50);
+        
+        env.mock_all_auths();
+        assert_eq!(governance.try_adjust_threshold(&101), Err(Ok(GovernanceError::InvalidThreshold)));
+        governance.adjust_threshold(&75);
+        assert_eq!(governance.get_config().adaptive_threshold, 75);
+    }
+
+    // Out-of-range thresholds are refused at initialize instead of stored
+    #[test]
+    fn godhead_init_rejects_invalid_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
+        let mut config = governance_config(&Address::generate(&env), &Address::generate(&env));
+        config.adaptive_threshold = 101;
+        assert_eq!(governance.try_initialize(&admin, &config), Err(Ok(GovernanceError::InvalidThreshold)));
+        config.adaptive_threshold = 0;
+        assert_eq!(governance.try_initialize(&admin, &config), Err(Ok(GovernanceError::InvalidThreshold)));
+        config.adaptive_threshold = 60;
+        config.min_stake = 0;
+        assert_eq!(governance.try_initialize(&admin, &config), Err(Ok(GovernanceError::InvalidConfig)));
+        
+        // A 3-of-2 multi-sig could never approve anything
+        let security = SecurityContractClient::new(&env, &env.register_contract(None, SecurityContract));
+        let signers = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+        assert_eq!(security.try_initialize(&admin, &security_config(&signers, 3, Vec::new(&env))), Err(Ok(SecurityError::InvalidConfig)));
+        security.initialize(&admin, &security_config(&signers, 2, Vec::new(&env)));
+        assert_eq!(security.get_config(), security_config(&signers, 2, Vec::new(&env)));
+    }
+
+    // Proposals listed by status: active, passed, failed and executed
+    #[test]
+    fn godhead_list_proposals_by_status() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
+        governance.initialize(&Address::generate(&env), &governance_config(&Address::generate(&env), &Address::generate(&env)));
+        let (proposer, voter) = (Address::generate(&env), Address::generate(&env));
+        governance.set_voting_power(&voter, &50);
+        
+        let passed = governance.create_proposal(&proposer, &Symbol::new(&env, "passes"), &100);
+        let failed = governance.create_proposal(&proposer, &Symbol::new(&env, "fails"), &100);
+        let executed = governance.create_proposal(&proposer, &Symbol::new(&env, "executes"), &100);
+        let active = governance.create_proposal(&proposer, &Symbol::new(&env, "still_open"), &10000);
+        governance.vote(&voter, &passed, &true);
+        governance.vote(&voter, &failed, &false);
+        governance.vote(&voter, &executed, &true);
+        
+        env.ledger().with_mut(|li| li.timestamp += 101);
+        governance.execute_proposal(&executed);
+        
+        let ids = |status: ProposalStatus| -> Vec<u64> {
+            let (page, _) = governance.list_proposals(&status, &0, &10);
+            let mut ids = Vec::new(&env);
+            for proposal in page.iter() {
+                ids.push_back(proposal.id);
+            }
+            ids
+        };
+        assert_eq!(ids(ProposalStatus::Active), Vec::from_array(&env, [active]));
+        assert_eq!(ids(ProposalStatus::Passed), Vec::from_array(&env, [passed]));
+        assert_eq!(ids(ProposalStatus::Failed), Vec::from_array(&env, [failed]));
+        assert_eq!(ids(ProposalStatus::Executed), Vec::from_array(&env, [executed]));
+        
+        // Paging over the open index resumes after the last entry scanned
+        let (page, next) = governance.list_proposals(&ProposalStatus::Passed, &0, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(next, Some(1));
+    }
+
+    // One guardian flip halts minting and transaction processing across contracts
+    #[test]
+    fn godhead_global_pause_halts_ecosystem() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let guardians = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+        
+        let registry_id = env.register_contract(None, PauseRegistryContract);
+        let registry = PauseRegistryContractClient::new(&env, &registry_id);
+        registry.init(&guardians, &2u32);
+        
+        let pi_coin = PiCoinContractClient::new(&env, &env.register_contract(None, PiCoinContract));
+        pi_coin.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+        pi_coin.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+        pi_coin.set_pause_registry(&registry_id);
+        
+        let transaction = TransactionContractClient::new(&env, &env.register_contract(None, TransactionContract));
+        transaction.init(&admin, &env.register_contract(None, MockPiCoinTransfer), &env.register_contract(None, MockVerification));
+        transaction.set_pause_registry(&registry_id);
+        
+        // Oracle mock sits at the peg, so auto_adjust is a no-op unless paused
+        let stability = StabilityContractClient::new(&env, &env.register_contract(None, StabilityContract));
+        stability.init_stability(&Vec::from_array(&env, [admin.clone()]), &1u32, &env.register_contract(None, MockOracle));
+        stability.set_pause_registry(&registry_id);
+        
+        let mining = Symbol::new(&env, "mining");
+        let (coin_id, signature) = (BytesN::from_array(&env, &[1u8; 32]), BytesN::from_array(&env, &[0u8; 64]));
+        assert!(pi_coin.try_mint(&user, &100, &mining).is_ok());
+        assert!(transaction.try_process_transaction(&user, &admin, &10, &mining, &coin_id, &signature).is_ok());
+        assert!(stability.try_auto_adjust().is_ok());
+        
+        // Cached "not paused" answers expire before the next calls
+        registry.set_global_pause(&guardians, &true);
+        env.ledger().with_mut(|l| l.timestamp += pi_coin_contract::PAUSE_CACHE_TTL);
+        assert_eq!(pi_coin.try_mint(&user, &100, &mining), Err(Ok(PiCoinError::Paused)));
+        assert!(transaction.try_process_transaction(&user, &admin, &10, &mining, &coin_id, &signature).is_err());
+        assert_eq!(stability.try_auto_adjust(), Err(Ok(PiCoinError::Paused as u32)));
+        
+        registry.set_global_pause(&guardians, &false);
+        env.ledger().with_mut(|l| l.timestamp += pi_coin_contract::PAUSE_CACHE_TTL);
+        assert!(pi_coin.try_mint(&user, &50, &mining).is_ok());
+        assert!(stability.try_auto_adjust().is_ok());
+    }
+
+    // Transaction forwards verified transfers to Pi Coin and stops at a failed origin check
+    #[test]
+    fn godhead_transaction_respects_mock_verification() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let (sender, receiver) = (Address::generate(&env), Address::generate(&env));
+        
+        let pi_coin_id = env.register_contract(None, MockPiCoinTransfer);
+        let pi_coin = MockPiCoinTransferClient::new(&env, &pi_coin_id);
+        let verification_id = env.register_contract(None, MockVerification);
+        let transaction = TransactionContractClient::new(&env, &env.register_contract(None, TransactionContract));
+        transaction.init(&admin, &pi_coin_id, &verification_id);
+        
+        let mining = Symbol::new(&env, "mining");
+        let (coin_id, signature) = (BytesN::from_array(&env, &[1u8; 32]), BytesN::from_array(&env, &[0u8; 64]));
+        let verification = MockVerificationClient::new(&env, &verification_id);
+        
+        // Rejected origin: fails before consensus and never reaches Pi Coin
+        verification.set_result(&false, &95);
+        let tx = transaction.process_transaction(&sender, &receiver, &10, &mining, &coin_id, &signature);
+        assert_eq!(tx.status, Symbol::new(&env, "failed"));
+        assert_eq!(tx.consensus_votes.len(), 0);
+        assert_eq!(pi_coin.last_transfer(), None);
+        
+        // Accepted origin: only simulated consensus can fail it now, so retry until it completes
+        verification.set_result(&true, &0);
+        let mut completed = false;
+        for _ in 0..20 {
+            let tx = transaction.process_transaction(&sender, &receiver, &10, &mining, &coin_id, &signature);
+            assert_eq!(tx.consensus_votes.len(), 3);
+            if tx.status == Symbol::new(&env, "completed") {
+                completed = true;
+                break;
+            }
+            assert_eq!(pi_coin.last_transfer(), None);
+        }
+        assert!(completed, "consensus never reached");
+        assert_eq!(pi_coin.last_transfer(), Some((sender, receiver, 10u64)));
+    }
+    
     // Nexus cross-contract test
     #[test]
     fn godhead_nexus_integration() {
-        let env = EnvTest::default();
-        let admin = TestAddress::random(&env);
-        
-        // Deploy mocks
-        let security = SecurityContract::new(env.clone());
-        let governance = GovernanceContract::new(env.clone());
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
         
-        // Initialize both
-        security.initialize(env.clone(), admin.clone(), vec![admin.clone()], 1, vec![]);
-        governance.initialize(env.clone(), admin, TestAddress::random(&env), security.address(&env)); // Link security
+        // Deploy both contracts
+        let (security_id, _) = setup_security(&env, &Vec::from_array(&env, [admin.clone()]), 1);
+        let governance = GovernanceContractClient::new(&env, &env.register_contract(None, GovernanceContract));
+        governance.initialize(&admin, &governance_config(&Address::generate(&env), &security_id)); // Link security
         
         // Test interaction: Governance checks security
-        let proposal_id = governance.create_proposal(env.clone(), admin, Symbol::new(&env, "Nexus Test"), 1000);
-        let vote_result = governance.vote(env.clone(), admin, proposal_id, true, 100);
-        assert!(vote_result.is_ok(), "Nexus Integration Failed");
+        let proposal_id = governance.create_proposal(&admin, &Symbol::new(&env, "nexus_test"), &1000);
+        governance.set_voting_power(&admin, &100);
+        assert!(governance.try_vote(&admin, &proposal_id, &true).is_ok(), "Nexus Integration Failed");
         
         log!(&env, "GodHead Nexus Integration Test Passed");
     }
@@ -0,0 +1,161 @@
+// Configurable stand-ins for the contracts ours call into.
+//
+// Register a mock like any contract, configure it through its generated client, then hand its
+// address to the contract under test wherever the real dependency would go:
+//
+//     let token_id = env.register_contract(None, MockToken);
+//     MockTokenClient::new(&env, &token_id).set_balance(&pi_coin_id, &1_000);
+//     pi_coin.set_redemption_asset(&Symbol::new(&env, "USDC"), &token_id);
+//
+// Unit tests in lib.rs use `crate::mocks::*`; tests/integration.rs uses `tests::mocks::*`.
+// Mocks panic where the real contract would fail, so callers see a failed cross-contract call.
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Map, Symbol};
+use verification::VerificationResult;
+
+// Peg MockOracle reports until a test moves the median
+pub const MOCK_PEG: u64 = 314_159;
+
+// SEP-41 subset with balances the test sets directly; total_supply tracks every change
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn set_balance(env: Env, id: Address, amount: i128) {
+        let mut balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        let supply: i128 = env.storage().instance().get(&symbol_short!("supply")).unwrap_or(0);
+        let previous = balances.get(id.clone()).unwrap_or(0);
+        balances.set(id, amount);
+        env.storage().instance().set(&symbol_short!("bal"), &balances);
+        env.storage().instance().set(&symbol_short!("supply"), &(supply - previous + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        let balances: Map<Address, i128> = env.storage().instance().get(&symbol_short!("bal")).unwrap_or(Map::new(&env));
+        balances.get(id).unwrap_or(0)
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage().instance().get(&symbol_short!("supply")).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(amount >= 0 && from_balance >= amount, "insufficient balance");
+        Self::set_balance(env.clone(), from, from_balance - amount);
+        let to_balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(env, to, to_balance + amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        Self::transfer(env, from, to, amount);
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(env, to, balance + amount);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), from.clone());
+        assert!(amount >= 0 && balance >= amount, "insufficient balance");
+        Self::set_balance(env, from, balance - amount);
+    }
+}
+
+// Oracle answering the reads StabilityContract makes, from a median the test sets (MOCK_PEG by default)
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_median(env: Env, price: u64) {
+        env.storage().instance().set(&symbol_short!("median"), &price);
+    }
+
+    pub fn set_circuit_breaker(env: Env, tripped: bool) {
+        env.storage().instance().set(&symbol_short!("breaker"), &tripped);
+    }
+
+    pub fn get_current_median(env: Env) -> u64 {
+        env.storage().instance().get(&symbol_short!("median")).unwrap_or(MOCK_PEG)
+    }
+
+    pub fn is_circuit_breaker_tripped(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("breaker")).unwrap_or(false)
+    }
+
+    // Stable within the real oracle's 1000 micro-unit breach threshold, unless the breaker is tripped
+    pub fn check_peg(env: Env, _caller: Address) -> bool {
+        Self::get_current_median(env.clone()).abs_diff(MOCK_PEG) < 1000 && !Self::is_circuit_breaker_tripped(env)
+    }
+}
+
+// Bridge endpoint delivering `delivery_bps` of every transfer: below 10_000 under-delivers, above over-delivers
+#[contract]
+pub struct MockBridge;
+
+#[contractimpl]
+impl MockBridge {
+    pub fn set_delivery_bps(env: Env, bps: u32) {
+        env.storage().instance().set(&symbol_short!("bps"), &bps);
+    }
+
+    pub fn interdimensional_bridge(env: Env, _from: Address, amount: i128, _min_received: i128, _deadline: u64) -> i128 {
+        let bps: u32 = env.storage().instance().get(&symbol_short!("bps")).unwrap_or(10_000);
+        env.storage().instance().set(&symbol_short!("last"), &amount);
+        amount * bps as i128 / 10_000
+    }
+
+    // Amount the last bridge call was asked to move
+    pub fn last_bridged(env: Env) -> Option<i128> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+// Linked contract answering the nexus anomaly vote with a fixed verdict
+#[contract]
+pub struct MockNexusLink;
+
+#[contractimpl]
+impl MockNexusLink {
+    pub fn set_vote(env: Env, vote: bool) {
+        env.storage().instance().set(&Symbol::new(&env, "vote"), &vote);
+    }
+
+    pub fn get_anomaly_vote(env: Env, _tx_hash: Symbol) -> bool {
+        env.storage().instance().get(&Symbol::new(&env, "vote")).unwrap_or(true)
+    }
+}
+
+// Verification stub returning a set verdict; accepts every origin until told otherwise
+#[contract]
+pub struct MockVerification;
+
+#[contractimpl]
+impl MockVerification {
+    pub fn set_result(env: Env, is_valid: bool, anomaly_score: u32) {
+        env.storage().instance().set(&symbol_short!("result"), &(is_valid, anomaly_score));
+    }
+
+    pub fn verify_origin(env: Env, _source: Symbol, _coin_id: BytesN<32>, _amount: u64, _frequency: u32, _signature: BytesN<64>) -> VerificationResult {
+        let (is_valid, anomaly_score): (bool, u32) = env.storage().instance().get(&symbol_short!("result")).unwrap_or((true, 0));
+        VerificationResult { is_valid, anomaly_score, quantum_verified: is_valid }
+    }
+}
+
+// Pi Coin stub whose transfer always succeeds and records the last call
+#[contract]
+pub struct MockPiCoinTransfer;
+
+#[contractimpl]
+impl MockPiCoinTransfer {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: u64, _coin_id: BytesN<32>) {
+        env.storage().instance().set(&symbol_short!("last"), &(from, to, amount));
+    }
+
+    pub fn last_transfer(env: Env) -> Option<(Address, Address, u64)> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
@@ -0,0 +1,257 @@
+// End-to-end lifecycle across the deployed contracts: mint -> verify -> transfer -> stabilize
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::{Address as _, Events}, xdr::ToXdr, Address, BytesN, Env, Symbol, TryFromVal, Vec};
+use pi_coin_contract::oracle::{OracleContract, OracleContractClient};
+use pi_coin_contract::stability::{PidGains, StabilityContract, StabilityContractClient};
+use pi_coin_contract::{MintEvent, PiCoinContract, PiCoinContractClient, PiCoinError, DECIMALS};
+use tests::mocks::{MockBridge, MockBridgeClient, MockOracle, MockOracleClient, MockToken, MockTokenClient};
+use transaction::{TransactionContract, TransactionContractClient};
+use verification::{VerificationContract, VerificationContractClient};
+
+const PEG: u64 = 314159;
+
+// Coin id of the last mint, taken from its GodHeadNexusMinted event
+fn last_minted_coin(env: &Env) -> BytesN<32> {
+    let mut coin_id = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let topic0 = topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok());
+        if topic0 == Some(Symbol::new(env, "GodHeadNexusMinted")) {
+            coin_id = Some(MintEvent::try_from_val(env, &data).unwrap().coin_id);
+        }
+    }
+    coin_id.unwrap()
+}
+
+// Attester signature over the XDR of (source, coin_id, amount), as verify_origin expects
+fn sign_origin(env: &Env, signing_key: &SigningKey, source: &Symbol, coin_id: &BytesN<32>, amount: u64) -> BytesN<64> {
+    let encoded = (source.clone(), coin_id.clone(), amount).to_xdr(env);
+    let mut message = vec![0u8; encoded.len() as usize];
+    encoded.copy_into_slice(&mut message);
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+fn price_sources(env: &Env) -> Vec<Symbol> {
+    Vec::from_array(env, [Symbol::new(env, "chainlink"), Symbol::new(env, "pyth"), Symbol::new(env, "band")])
+}
+
+#[test]
+fn test_full_lifecycle_keeps_supply_and_peg_consistent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let mining = Symbol::new(&env, "mining");
+
+    // Oracle: three agreeing feeds at the peg
+    let oracle_id = env.register_contract(None, OracleContract);
+    let oracle = OracleContractClient::new(&env, &oracle_id);
+    oracle.init_oracle(&signers, &1u32, &price_sources(&env));
+    let reporter = Address::generate(&env);
+    for source in price_sources(&env).iter() {
+        oracle.set_feed_reporter(&source, &reporter);
+        oracle.update_feed(&source, &PEG);
+    }
+    let median = oracle.get_current_median();
+
+    // Pi Coin, fed the oracle median
+    let pi_coin_id = env.register_contract(None, PiCoinContract);
+    let pi_coin = PiCoinContractClient::new(&env, &pi_coin_id);
+    pi_coin.init(&signers, &1u32);
+    pi_coin.update_oracle_feed(&Symbol::new(&env, "PI"), &median);
+    pi_coin.register_compliance(&sender, &true, &Symbol::new(&env, "US"), &10u32);
+    pi_coin.register_compliance(&receiver, &true, &Symbol::new(&env, "ID"), &20u32);
+
+    // Verification with an off-chain attester, and the Transaction contract wired to both
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let verification_id = env.register_contract(None, VerificationContract);
+    let verification = VerificationContractClient::new(&env, &verification_id);
+    verification.init(&admin, &BytesN::from_array(&env, &signing_key.verifying_key().to_bytes()));
+    let transaction = TransactionContractClient::new(&env, &env.register_contract(None, TransactionContract));
+    transaction.init(&admin, &pi_coin_id, &verification_id);
+
+    // Stability reads the same oracle contract
+    let stability = StabilityContractClient::new(&env, &env.register_contract(None, StabilityContract));
+    oracle.set_peg_checker(&stability.address, &true);
+    stability.init_stability(&signers, &1u32, &oracle_id);
+
+    // Mint
+    pi_coin.mint(&sender, &100, &mining);
+    let coin_id = last_minted_coin(&env);
+
+    // Verify and transfer through the Transaction contract
+    let signature = sign_origin(&env, &signing_key, &mining, &coin_id, 40);
+    let tx = transaction.process_transaction(&sender, &receiver, &40, &mining, &coin_id, &signature);
+    assert_eq!(tx.status, Symbol::new(&env, "completed"));
+    assert_eq!(tx.coin_id, coin_id);
+    let coin = pi_coin.get_coin(&coin_id).unwrap();
+    assert_eq!(coin.owner, receiver);
+    assert_eq!(coin.amount, 60);
+
+    // Stabilize: at peg there is nothing to adjust
+    let adjustment = stability.simulate_adjust();
+    assert_eq!(adjustment.adjustment_type, Symbol::new(&env, "none"));
+
+    // Supply consistency: circulating supply is exactly what was minted, within the cap
+    let current_supply = pi_coin.get_current_supply();
+    let minted: u64 = pi_coin.get_all_mint_stats().values().iter().sum();
+    assert_eq!(current_supply, minted);
+    assert_eq!(pi_coin.get_mint_by_source(&mining), 100);
+    assert!(current_supply <= pi_coin.get_total_supply());
+
+    // Peg stability: the oracle median holds the peg Pi Coin enforces
+    assert!(oracle.check_peg(&reporter));
+    assert_eq!(oracle.get_current_median(), pi_coin.get_peg());
+    assert_eq!(pi_coin.get_peg(), PEG);
+}
+
+#[test]
+fn test_lifecycle_rejects_forged_origin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signers = Vec::from_array(&env, [admin.clone()]);
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let mining = Symbol::new(&env, "mining");
+
+    let pi_coin_id = env.register_contract(None, PiCoinContract);
+    let pi_coin = PiCoinContractClient::new(&env, &pi_coin_id);
+    pi_coin.init(&signers, &1u32);
+    pi_coin.register_compliance(&sender, &true, &Symbol::new(&env, "US"), &10u32);
+    pi_coin.register_compliance(&receiver, &true, &Symbol::new(&env, "ID"), &20u32);
+    pi_coin.mint(&sender, &100, &mining);
+    let coin_id = last_minted_coin(&env);
+
+    let attester = SigningKey::from_bytes(&[7u8; 32]);
+    let verification_id = env.register_contract(None, VerificationContract);
+    VerificationContractClient::new(&env, &verification_id).init(&admin, &BytesN::from_array(&env, &attester.verifying_key().to_bytes()));
+    let transaction = TransactionContractClient::new(&env, &env.register_contract(None, TransactionContract));
+    transaction.init(&admin, &pi_coin_id, &verification_id);
+
+    // Signed by a key other than the registered attester
+    let forger = SigningKey::from_bytes(&[8u8; 32]);
+    let signature = sign_origin(&env, &forger, &mining, &coin_id, 40);
+    assert!(transaction.try_process_transaction(&sender, &receiver, &40, &mining, &coin_id, &signature).is_err());
+
+    // Nothing moved and supply is untouched
+    let coin = pi_coin.get_coin(&coin_id).unwrap();
+    assert_eq!(coin.owner, sender);
+    assert_eq!(coin.amount, 100);
+    assert_eq!(pi_coin.get_current_supply(), pi_coin.get_mint_by_source(&mining));
+}
+
+// Pi Coin with one KYC'd holder of 10 freshly minted PI
+fn setup_pi_coin<'a>(env: &'a Env) -> (Address, Address, BytesN<32>, PiCoinContractClient<'a>) {
+    let admin = Address::generate(env);
+    let user = Address::generate(env);
+    let pi_coin = PiCoinContractClient::new(env, &env.register_contract(None, PiCoinContract));
+    pi_coin.init(&Vec::from_array(env, [admin.clone()]), &1u32);
+    pi_coin.register_compliance(&user, &true, &Symbol::new(env, "US"), &10u32);
+    pi_coin.mint(&user, &10, &Symbol::new(env, "mining"));
+    (admin, user, last_minted_coin(env), pi_coin)
+}
+
+#[test]
+fn test_oracle_collateral_check_reads_pi_coin_reserves() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, _, pi_coin) = setup_pi_coin(&env);
+    let signers = Vec::from_array(&env, [admin]);
+    let oracle = OracleContractClient::new(&env, &env.register_contract(None, OracleContract));
+    oracle.init_oracle(&signers, &1u32, &price_sources(&env));
+    let reporter = Address::generate(&env);
+    for source in price_sources(&env).iter() {
+        oracle.set_feed_reporter(&source, &reporter);
+        oracle.update_feed(&source, &PEG);
+    }
+    oracle.set_min_collateral_ratio(&10_000);
+
+    // Without a linked Pi Coin there is no ratio to satisfy the floor
+    assert!(!oracle.check_peg(&reporter));
+
+    // Half of the 10 PI in circulation is backed
+    oracle.set_pi_coin(&pi_coin.address);
+    let usdc = Symbol::new(&env, "USDC");
+    pi_coin.deposit_reserve(&usdc, &(5 * PEG as i128));
+    assert_eq!(pi_coin.collateralization_ratio(), 5_000);
+    assert!(!oracle.check_peg(&reporter));
+
+    // Fully backed on the separately deployed Pi Coin: the peg holds
+    pi_coin.deposit_reserve(&usdc, &(5 * PEG as i128));
+    assert_eq!(pi_coin.collateralization_ratio(), 10_000);
+    assert!(oracle.check_peg(&reporter));
+}
+
+#[test]
+fn test_bridge_slippage_against_mock_bridge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, user, _, pi_coin) = setup_pi_coin(&env);
+    let dimension = Symbol::new(&env, "ethereum");
+    let bridge_id = env.register_contract(None, MockBridge);
+    let bridge = MockBridgeClient::new(&env, &bridge_id);
+    pi_coin.register_bridge(&admin, &dimension, &bridge_id, &false);
+
+    // Half arrives: below min_received, so the bridge leg reverts
+    bridge.set_delivery_bps(&5_000);
+    assert_eq!(pi_coin.try_interdimensional_bridge(&user, &dimension, &2, &2, &u64::MAX), Err(Ok(PiCoinError::SlippageExceeded)));
+
+    // Over-delivery is reported back as-is
+    bridge.set_delivery_bps(&15_000);
+    assert_eq!(pi_coin.interdimensional_bridge(&user, &dimension, &2, &2, &u64::MAX), 3);
+    assert_eq!(bridge.last_bridged(), Some(2));
+}
+
+#[test]
+fn test_redeem_pays_out_through_mock_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, user, coin_id, pi_coin) = setup_pi_coin(&env);
+    let usdc = Symbol::new(&env, "USDC");
+    let unit = 10i128.pow(DECIMALS);
+    let token_id = env.register_contract(None, MockToken);
+    let token = MockTokenClient::new(&env, &token_id);
+    pi_coin.set_redemption_asset(&usdc, &token_id);
+    pi_coin.deposit_reserve(&usdc, &(4 * PEG as i128));
+
+    // Bookkept reserves are not enough when the token contract holds nothing
+    assert_eq!(pi_coin.try_redeem(&user, &4, &coin_id), Err(Ok(PiCoinError::CallFailed)));
+
+    token.set_balance(&pi_coin.address, &(4 * PEG as i128 * unit));
+    pi_coin.redeem(&user, &4, &coin_id);
+    assert_eq!(token.balance(&user), 4 * PEG as i128 * unit);
+    assert_eq!(token.balance(&pi_coin.address), 0);
+    assert_eq!(pi_coin.get_coin(&coin_id).unwrap().amount, 6);
+}
+
+#[test]
+fn test_stability_adjusts_from_mock_oracle_median() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let signers = Vec::from_array(&env, [Address::generate(&env)]);
+    let oracle_id = env.register_contract(None, MockOracle);
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    let stability = StabilityContractClient::new(&env, &env.register_contract(None, StabilityContract));
+    stability.init_stability(&signers, &1u32, &oracle_id);
+    stability.set_controller(&Symbol::new(&env, "pid"), &PidGains { kp: 2500, ki: 50, kd: 250 });
+
+    // On peg the mock's check_peg passes and nothing is adjusted
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 0);
+
+    // 10% above peg: a 31_415 error through the fresh PID burns 87 PI
+    oracle.set_median(&(PEG * 11 / 10));
+    stability.auto_adjust();
+    let log = stability.get_adjustment_log();
+    assert_eq!(log.len(), 1);
+    let adjustment = log.get(0).unwrap();
+    assert_eq!((adjustment.adjustment_type, adjustment.amount), (Symbol::new(&env, "burn"), 87));
+
+    // A tripped breaker fails check_peg, but an on-peg median is below the threshold so nothing is logged
+    oracle.set_median(&PEG);
+    oracle.set_circuit_breaker(&true);
+    stability.auto_adjust();
+    assert_eq!(stability.get_adjustment_log().len(), 1);
+}
@@ -1,8 +1,11 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Symbol, Vec, BytesN, Map, Val};
+use soroban_sdk::{contract, contractimpl, contracttype, xdr::ToXdr, Env, Address, Symbol, Vec, BytesN, Map, IntoVal, Val};
+#[cfg(feature = "quantum")]
 use rsa::{PublicKey, RsaPrivateKey, PaddingScheme};
+#[cfg(feature = "quantum")]
 use sha3::{Digest, Sha3_512};
+use verification::VerificationResult;
 
 #[contracttype]
 #[derive(Clone)]
@@ -12,18 +15,46 @@ pub struct Transaction {
     pub receiver: Address,
     pub amount: u64,
     pub source: Symbol,
+    pub coin_id: BytesN<32>, // Pi Coin moved by this transaction
     pub status: Symbol, // "pending", "verified", "completed", "failed"
     pub consensus_votes: Vec<bool>, // Simulated votes
     pub routed_path: Vec<Address>, // AI-routed path
+    pub route_node: Option<Address>, // Registered intermediate node, None when routed directly
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RouteNode {
+    pub node: Address,
+    pub fee_bps: u32, // Advertised fee in basis points; only ranks nodes, nothing is charged on-chain
+    pub latency_ms: u32,
+    pub capacity: u64, // Largest amount the node accepts
 }
 
 #[contracttype]
 pub enum DataKey {
     Ledger, // Map of transactions
     ConsensusNodes, // Simulated nodes for consensus
-    QuantumKey,
+    QuantumKey, // RSA key pair; written only with the "quantum" feature
+    AccountTxs(Address), // Vec<BytesN<32>> of tx ids where the account is sender or receiver, oldest first
+    Admin,
+    RouteNodes, // Map<Address, RouteNode> registry of routing nodes
+    PauseRegistry, // Address of the ecosystem PauseRegistry; unset means never paused
+    PauseCache, // (bool, u64) last is_paused("transaction") answer and when it was fetched
+    VolatilityOracle, // Address of the anti-volatility oracle allowed to reject assets
+    RejectedAssets, // Vec<Symbol> of sources whose transactions fail until the admin allows them again
+    TxSequence, // u64 count of processed transactions, hashed into each tx id
 }
 
+// How long a pause registry answer is reused before it is fetched again
+const PAUSE_CACHE_TTL: u64 = 60;
+
+// Amounts above this are routed through a registered node
+const ROUTING_THRESHOLD: u64 = 1_000_000;
+
+// Upper bound on a single list_transactions_for page
+const MAX_PAGE_SIZE: u32 = 50;
+
 #[contract]
 pub struct TransactionContract;
 
@@ -32,6 +63,8 @@ impl TransactionContract {
     // Initialize with hyper-tech setup
     pub fn init(env: Env, admin: Address, pi_coin_contract: Address, verification_contract: Address) {
         admin.require_auth();
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage().persistent().set(&DataKey::RouteNodes, &Map::<Address, RouteNode>::new(&env));
         
         // Ledger map
         let ledger = Map::new(&env);
@@ -43,40 +76,59 @@ impl TransactionContract {
         
         // Quantum RSA key (placeholder; real quantum crypto not in Soroban yet)
         // Note: RSA not natively in Soroban; this is simulated
-        let private_key = RsaPrivateKey::new(&mut env.prng(), 2048).expect("Failed to generate key");
-        let public_key = private_key.to_public_key();
-        env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
+        #[cfg(feature = "quantum")]
+        {
+            let private_key = RsaPrivateKey::new(&mut env.prng(), 2048).expect("Failed to generate key");
+            let public_key = private_key.to_public_key();
+            env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
+        }
         
         // Store contract addresses
         env.storage().persistent().set(&Symbol::new(&env, "pi_coin_contract"), &pi_coin_contract);
         env.storage().persistent().set(&Symbol::new(&env, "verification_contract"), &verification_contract);
     }
     
-    // Process transaction with AI routing and consensus
-    pub fn process_transaction(env: Env, sender: Address, receiver: Address, amount: u64, source: Symbol) -> Transaction {
+    // Process transaction with AI routing and consensus.
+    // `coin_id` is the Pi Coin being moved; `origin_signature` is the verification
+    // attester's signature over (source, coin_id, amount).
+    pub fn process_transaction(env: Env, sender: Address, receiver: Address, amount: u64, source: Symbol, coin_id: BytesN<32>, origin_signature: BytesN<64>) -> Transaction {
         sender.require_auth();
+        Self::require_not_paused(&env);
         
-        let tx_id = env.crypto().sha256(&vec![Val::Address(sender.clone()), Val::Address(receiver.clone()), Val::U64(amount)]);
+        // The sequence number keeps identical transfers from sharing an id
+        let sequence: u64 = env.storage().persistent().get(&DataKey::TxSequence).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::TxSequence, &(sequence + 1));
+        let tx_id = env.crypto().sha256(&(sender.clone(), receiver.clone(), amount, sequence).to_xdr(&env));
         let mut tx = Transaction {
             id: tx_id.clone(),
             sender: sender.clone(),
             receiver: receiver.clone(),
             amount,
             source: source.clone(),
+            coin_id: coin_id.clone(),
             status: Symbol::new(&env, "pending"),
             consensus_votes: Vec::new(&env),
             routed_path: Vec::new(&env),
+            route_node: None,
         };
         
-        // AI-Optimized Routing: Simulate path selection (e.g., low-fee nodes)
-        let routed_path = Self::ai_route_transaction(&env, &sender, &receiver, amount);
+        // AI-Optimized Routing: path through the cheapest suitable registered node
+        let (routed_path, route) = Self::ai_route_transaction(&env, &sender, &receiver, amount);
         tx.routed_path = routed_path;
+        tx.route_node = route.map(|route| route.node);
+        
+        // Assets the volatility oracle rejected fail before any external call
+        let rejected: Vec<Symbol> = env.storage().persistent().get(&DataKey::RejectedAssets).unwrap_or(Vec::new(&env));
+        if rejected.contains(&source) {
+            tx.status = Symbol::new(&env, "failed");
+            return tx;
+        }
         
         // Verify origin via Verification contract
         let verification_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "verification_contract")).unwrap();
-        let verify_args = vec![Val::Symbol(source.clone()), Val::BytesN(tx_id.clone()), Val::U64(amount), Val::U32(1)];
-        let result: bool = env.invoke_contract(&verification_contract, &Symbol::new(&env, "verify_origin"), verify_args).unwrap();
-        if !result {
+        let verify_args: Vec<Val> = Vec::from_array(&env, [source.into_val(&env), coin_id.into_val(&env), amount.into_val(&env), 1u32.into_val(&env), origin_signature.into_val(&env)]);
+        let result: VerificationResult = env.invoke_contract(&verification_contract, &Symbol::new(&env, "verify_origin"), verify_args);
+        if !result.is_valid {
             tx.status = Symbol::new(&env, "failed");
             return tx;
         }
@@ -91,29 +143,146 @@ impl TransactionContract {
         
         // Transfer via Pi Coin contract
         let pi_coin_contract: Address = env.storage().persistent().get(&Symbol::new(&env, "pi_coin_contract")).unwrap();
-        let transfer_args = vec![Val::Address(sender), Val::Address(receiver), Val::U64(amount), Val::BytesN(tx_id.clone())];
-        env.invoke_contract(&pi_coin_contract, &Symbol::new(&env, "transfer"), transfer_args);
+        let transfer_args: Vec<Val> = Vec::from_array(&env, [sender.into_val(&env), receiver.into_val(&env), amount.into_val(&env), coin_id.into_val(&env)]);
+        env.invoke_contract::<()>(&pi_coin_contract, &Symbol::new(&env, "transfer"), transfer_args);
         
         tx.status = Symbol::new(&env, "completed");
         
         // Log to ledger
         let mut ledger: Map<BytesN<32>, Transaction> = env.storage().persistent().get(&DataKey::Ledger).unwrap();
-        ledger.set(tx_id, tx.clone());
+        ledger.set(tx_id.clone(), tx.clone());
         env.storage().persistent().set(&DataKey::Ledger, &ledger);
+        Self::index_transaction(&env, &tx.sender, &tx_id);
+        if tx.receiver != tx.sender {
+            Self::index_transaction(&env, &tx.receiver, &tx_id);
+        }
         
         tx
     }
     
-    // AI Route Transaction (heuristic-based)
-    fn ai_route_transaction(env: &Env, sender: &Address, receiver: &Address, amount: u64) -> Vec<Address> {
-        // Simulate AI: Choose path based on amount (e.g., direct for small, routed for large)
+    // Point the contract at the ecosystem PauseRegistry (admin only)
+    pub fn set_pause_registry(env: Env, registry: Address) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::PauseRegistry, &registry);
+        env.storage().persistent().remove(&DataKey::PauseCache);
+    }
+    
+    // Panics while the registry pauses the "transaction" module; answers are cached for PAUSE_CACHE_TTL
+    fn require_not_paused(env: &Env) {
+        let registry: Address = match env.storage().persistent().get(&DataKey::PauseRegistry) {
+            Some(registry) => registry,
+            None => return,
+        };
+        let now = env.ledger().timestamp();
+        let cached: Option<(bool, u64)> = env.storage().persistent().get(&DataKey::PauseCache);
+        let paused = match cached {
+            Some((paused, fetched_at)) if now.saturating_sub(fetched_at) < PAUSE_CACHE_TTL => paused,
+            _ => {
+                let args: Vec<Val> = Vec::from_array(env, [Symbol::new(env, "transaction").into_val(env)]);
+                let paused: bool = env.invoke_contract(&registry, &Symbol::new(env, "is_paused"), args);
+                env.storage().persistent().set(&DataKey::PauseCache, &(paused, now));
+                paused
+            }
+        };
+        assert!(!paused, "transaction module paused");
+    }
+    
+    // Register the anti-volatility oracle whose auto_reject_transaction may reject assets (admin only)
+    pub fn set_volatility_oracle(env: Env, oracle: Address) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::VolatilityOracle, &oracle);
+    }
+    
+    // Called by the registered volatility oracle: transactions sourced from `asset` fail from now on
+    pub fn reject_transaction(env: Env, asset: Symbol) {
+        let oracle: Address = env.storage().persistent().get(&DataKey::VolatilityOracle).expect("No volatility oracle registered");
+        oracle.require_auth();
+        let mut rejected: Vec<Symbol> = env.storage().persistent().get(&DataKey::RejectedAssets).unwrap_or(Vec::new(&env));
+        if !rejected.contains(&asset) {
+            rejected.push_back(asset);
+            env.storage().persistent().set(&DataKey::RejectedAssets, &rejected);
+        }
+    }
+    
+    // Lift an oracle rejection so the asset's transactions are processed again (admin only)
+    pub fn allow_asset(env: Env, asset: Symbol) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let mut rejected: Vec<Symbol> = env.storage().persistent().get(&DataKey::RejectedAssets).unwrap_or(Vec::new(&env));
+        if let Some(index) = rejected.first_index_of(&asset) {
+            rejected.remove(index);
+            env.storage().persistent().set(&DataKey::RejectedAssets, &rejected);
+        }
+    }
+    
+    pub fn is_asset_rejected(env: Env, asset: Symbol) -> bool {
+        let rejected: Vec<Symbol> = env.storage().persistent().get(&DataKey::RejectedAssets).unwrap_or(Vec::new(&env));
+        rejected.contains(&asset)
+    }
+    
+    // Page through an account's transactions, oldest first; next cursor is None after the last page
+    pub fn list_transactions_for(env: Env, account: Address, cursor: u32, limit: u32) -> (Vec<Transaction>, Option<u32>) {
+        let ids: Vec<BytesN<32>> = env.storage().persistent().get(&DataKey::AccountTxs(account)).unwrap_or(Vec::new(&env));
+        let ledger: Map<BytesN<32>, Transaction> = env.storage().persistent().get(&DataKey::Ledger).unwrap_or(Map::new(&env));
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(ids.len());
+        let mut page = Vec::new(&env);
+        for i in cursor..end {
+            if let Some(tx) = ledger.get(ids.get(i).unwrap()) {
+                page.push_back(tx);
+            }
+        }
+        let next = if end < ids.len() { Some(end) } else { None };
+        (page, next)
+    }
+    
+    // Append a tx id to an account's history
+    fn index_transaction(env: &Env, account: &Address, tx_id: &BytesN<32>) {
+        let key = DataKey::AccountTxs(account.clone());
+        let mut ids: Vec<BytesN<32>> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        ids.push_back(tx_id.clone());
+        env.storage().persistent().set(&key, &ids);
+    }
+    
+    // AI Route Transaction: direct for small amounts; large ones go through the registered node
+    // with capacity for the amount and the lowest fee (ties broken by latency)
+    fn ai_route_transaction(env: &Env, sender: &Address, receiver: &Address, amount: u64) -> (Vec<Address>, Option<RouteNode>) {
         let mut path = Vec::new(env);
         path.push_back(sender.clone());
-        if amount > 1_000_000 {
-            path.push_back(Address::generate(env)); // Simulated intermediate node
+        let mut chosen: Option<RouteNode> = None;
+        if amount > ROUTING_THRESHOLD {
+            let nodes: Map<Address, RouteNode> = env.storage().persistent().get(&DataKey::RouteNodes).unwrap_or(Map::new(env));
+            for (_, candidate) in nodes.iter() {
+                if candidate.capacity < amount {
+                    continue;
+                }
+                let better = match &chosen {
+                    None => true,
+                    Some(best) => (candidate.fee_bps, candidate.latency_ms) < (best.fee_bps, best.latency_ms),
+                };
+                if better {
+                    chosen = Some(candidate);
+                }
+            }
+            if let Some(route) = &chosen {
+                path.push_back(route.node.clone());
+            }
         }
         path.push_back(receiver.clone());
-        path
+        (path, chosen)
+    }
+    
+    // Register or update a routing node (admin only)
+    pub fn register_route_node(env: Env, node: Address, fee_bps: u32, latency_ms: u32, capacity: u64) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if fee_bps > 10_000 {
+            panic!("Fee exceeds 100%");
+        }
+        let mut nodes: Map<Address, RouteNode> = env.storage().persistent().get(&DataKey::RouteNodes).unwrap_or(Map::new(&env));
+        nodes.set(node.clone(), RouteNode { node, fee_bps, latency_ms, capacity });
+        env.storage().persistent().set(&DataKey::RouteNodes, &nodes);
     }
     
     // Simulate Consensus
@@ -136,6 +305,7 @@ impl TransactionContract {
     }
     
     // Quantum-Secured Ledger Verification
+    #[cfg(feature = "quantum")]
     pub fn verify_ledger_entry(env: Env, tx_id: BytesN<32>) -> bool {
         let tx = Self::get_transaction(env.clone(), tx_id);
         let (private_key, public_key): (RsaPrivateKey, _) = env.storage().persistent().get(&DataKey::QuantumKey).unwrap();
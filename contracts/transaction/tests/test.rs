@@ -1,3 +1,47 @@
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, BytesN, Env, Symbol, Vec};
+use transaction::{TransactionContract, TransactionContractClient};
+use verification::VerificationResult;
+
+// Verification stub accepting every origin
+#[contract]
+pub struct MockVerificationContract;
+
+#[contractimpl]
+impl MockVerificationContract {
+    pub fn verify_origin(_env: Env, _source: Symbol, _coin_id: BytesN<32>, _amount: u64, _frequency: u32, _signature: BytesN<64>) -> VerificationResult {
+        VerificationResult { is_valid: true, anomaly_score: 0, quantum_verified: true }
+    }
+}
+
+// Pi Coin stub whose transfer always succeeds
+#[contract]
+pub struct MockPiCoinContract;
+
+#[contractimpl]
+impl MockPiCoinContract {
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: u64, _coin_id: BytesN<32>) {}
+}
+
+// Mock verifier ignores the attester signature
+fn no_signature(env: &Env) -> BytesN<64> {
+    BytesN::from_array(env, &[0u8; 64])
+}
+
+// Mock Pi Coin accepts any coin id
+fn any_coin(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[1u8; 32])
+}
+
+fn setup<'a>(env: &'a Env) -> TransactionContractClient<'a> {
+    let contract_id = env.register_contract(None, TransactionContract);
+    let client = TransactionContractClient::new(env, &contract_id);
+    let pi_coin_id = env.register_contract(None, MockPiCoinContract);
+    let verification_id = env.register_contract(None, MockVerificationContract);
+    env.mock_all_auths();
+    client.init(&Address::generate(env), &pi_coin_id, &verification_id);
+    client
+}
+
 #[test]
 fn test_process_transaction() {
     let env = Env::default();
@@ -5,6 +49,97 @@ fn test_process_transaction() {
     let client = TransactionContractClient::new(&env, &contract_id);
     
     client.init(&admin, &pi_coin_id, &verification_id);
-    let tx = client.process_transaction(&sender, &receiver, &100, &Symbol::new(&env, "p2p"));
+    let tx = client.process_transaction(&sender, &receiver, &100, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
     assert_eq!(tx.status, Symbol::new(&env, "completed"));
 }
+
+#[test]
+fn test_list_transactions_for_pages_history() {
+    let env = Env::default();
+    let client = setup(&env);
+    let sender = Address::generate(&env);
+    let other = Address::generate(&env);
+    
+    // Consensus is probabilistic; only completed transactions reach the ledger
+    let mut completed = Vec::new(&env);
+    for amount in 1..=5u64 {
+        let tx = client.process_transaction(&sender, &Address::generate(&env), &amount, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
+        if tx.status == Symbol::new(&env, "completed") {
+            completed.push_back(tx.id);
+        }
+    }
+    client.process_transaction(&other, &Address::generate(&env), &99, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
+    
+    let mut seen = Vec::new(&env);
+    let mut cursor = Some(0u32);
+    while let Some(at) = cursor {
+        let (page, next) = client.list_transactions_for(&sender, &at, &2);
+        assert!(page.len() <= 2);
+        for tx in page.iter() {
+            assert_eq!(tx.sender, sender);
+            seen.push_back(tx.id);
+        }
+        cursor = next;
+    }
+    assert_eq!(seen, completed);
+}
+
+#[test]
+fn test_large_transfer_routes_through_registered_node() {
+    let env = Env::default();
+    let client = setup(&env);
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let cheap = Address::generate(&env);
+    let pricey = Address::generate(&env);
+    client.register_route_node(&cheap, &5, &200, &10_000_000);
+    client.register_route_node(&pricey, &30, &50, &10_000_000);
+    
+    let large = client.process_transaction(&sender, &receiver, &2_000_000, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
+    assert_eq!(large.routed_path, Vec::from_array(&env, [sender.clone(), cheap.clone(), receiver.clone()]));
+    assert_eq!(large.route_node, Some(cheap));
+    assert_eq!(large.amount, 2_000_000);
+    
+    let small = client.process_transaction(&sender, &receiver, &100, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
+    assert_eq!(small.routed_path, Vec::from_array(&env, [sender, receiver]));
+    assert_eq!(small.route_node, None);
+}
+
+#[test]
+fn test_rejected_asset_fails_until_allowed() {
+    let env = Env::default();
+    let client = setup(&env);
+    let oracle = Address::generate(&env);
+    let (sender, receiver) = (Address::generate(&env), Address::generate(&env));
+    let bitcoin = Symbol::new(&env, "bitcoin");
+    
+    // Only a registered oracle may reject
+    assert!(client.try_reject_transaction(&bitcoin).is_err());
+    client.set_volatility_oracle(&oracle);
+    client.reject_transaction(&bitcoin);
+    assert!(client.is_asset_rejected(&bitcoin));
+    
+    let tx = client.process_transaction(&sender, &receiver, &100, &bitcoin, &any_coin(&env), &no_signature(&env));
+    assert_eq!(tx.status, Symbol::new(&env, "failed"));
+    assert_eq!(client.list_transactions_for(&sender, &0, &10).0.len(), 0);
+    
+    client.allow_asset(&bitcoin);
+    assert!(!client.is_asset_rejected(&bitcoin));
+}
+
+#[test]
+fn test_identical_transfers_get_distinct_ids() {
+    let env = Env::default();
+    let client = setup(&env);
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    
+    let first = client.process_transaction(&sender, &receiver, &100, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
+    let second = client.process_transaction(&sender, &receiver, &100, &Symbol::new(&env, "p2p"), &any_coin(&env), &no_signature(&env));
+    assert_ne!(first.id, second.id);
+    
+    // Each completed transfer has its own ledger entry, indexed once per account
+    let completed = [first, second].into_iter().filter(|tx| tx.status == Symbol::new(&env, "completed")).count() as u32;
+    assert_eq!(client.list_transactions_for(&sender, &0, &10).0.len(), completed);
+    assert_eq!(client.list_transactions_for(&receiver, &0, &10).0.len(), completed);
+}
@@ -1,10 +1,7 @@
 // contracts/verification/src/lib.rs
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Symbol, Vec, BytesN, Map};
-use rsa::{PublicKey, RsaPrivateKey, PaddingScheme, pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding}};
-use sha3::{Digest, Sha3_512};
-use num_bigint::BigUint; // For Pi math
+use soroban_sdk::{contract, contractimpl, contracttype, xdr::ToXdr, Address, Env, Symbol, Vec, BytesN, Map};
 
 #[contracttype]
 #[derive(Clone)]
@@ -17,7 +14,7 @@ pub struct VerificationResult {
 #[contracttype]
 pub enum DataKey {
     AiModel, // Simulated AI model (weights for pattern recognition)
-    QuantumKey,
+    AttesterKey, // ed25519 public key whose signatures attest coin origins
     EcosystemData, // Map of transaction data for monitoring
 }
 
@@ -27,7 +24,7 @@ pub struct VerificationContract;
 #[contractimpl]
 impl VerificationContract {
     // Initialize with hyper-tech setup
-    pub fn init(env: Env, admin: Address) {
+    pub fn init(env: Env, admin: Address, attester_key: BytesN<32>) {
         admin.require_auth();
         
         // Simulated AI model: Simple weights for source validation (expandable to ML)
@@ -38,21 +35,53 @@ impl VerificationContract {
         ai_model.set(Symbol::new(&env, "exchange_weight"), 10u32); // Low trust
         env.storage().persistent().set(&DataKey::AiModel, &ai_model);
         
-        // Quantum RSA key
-        let mut rng = env.prng();
-        let private_key = RsaPrivateKey::new(&mut rng, 2048).expect("Failed to generate key");
-        let public_key = private_key.to_public_key();
-        env.storage().persistent().set(&DataKey::QuantumKey, &(private_key, public_key));
+        // Origin signatures are produced off-chain by the attester and only verified here
+        env.storage().persistent().set(&DataKey::AttesterKey, &attester_key);
         
         // Ecosystem data map
         let ecosystem = Map::new(&env);
         env.storage().persistent().set(&DataKey::EcosystemData, &ecosystem);
     }
     
-    // AI-verified origin check
-    pub fn verify_origin(env: Env, source: Symbol, coin_id: BytesN<32>, amount: u64, frequency: u32) -> VerificationResult {
+    // AI-verified origin check. `signature` is the attester's ed25519 signature over the
+    // XDR of (source, coin_id, amount); an invalid signature aborts the call.
+    pub fn verify_origin(env: Env, source: Symbol, coin_id: BytesN<32>, amount: u64, frequency: u32, signature: BytesN<64>) -> VerificationResult {
         let ai_model: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::AiModel).unwrap();
+        let attester_key: BytesN<32> = env.storage().persistent().get(&DataKey::AttesterKey).unwrap();
+        let mut ecosystem: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::EcosystemData).unwrap();
+        
+        let result = Self::evaluate(&env, &ai_model, &attester_key, &mut ecosystem, source, coin_id, amount, frequency, signature);
+        env.storage().persistent().set(&DataKey::EcosystemData, &ecosystem);
+        result
+    }
+    
+    // Batch verification: model, key and ecosystem map are loaded once, every signature is
+    // checked in a single pass, and the ecosystem map is written back once at the end
+    pub fn batch_verify(env: Env, verifications: Vec<(Symbol, BytesN<32>, u64, u32, BytesN<64>)>) -> Vec<VerificationResult> {
+        let ai_model: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::AiModel).unwrap();
+        let attester_key: BytesN<32> = env.storage().persistent().get(&DataKey::AttesterKey).unwrap();
+        let mut ecosystem: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::EcosystemData).unwrap();
         
+        let mut results = Vec::new(&env);
+        for (source, coin_id, amount, freq, signature) in verifications.iter() {
+            results.push_back(Self::evaluate(&env, &ai_model, &attester_key, &mut ecosystem, source, coin_id, amount, freq, signature));
+        }
+        env.storage().persistent().set(&DataKey::EcosystemData, &ecosystem);
+        results
+    }
+    
+    // Scoring and signature check for one item against already-loaded state
+    fn evaluate(
+        env: &Env,
+        ai_model: &Map<Symbol, u32>,
+        attester_key: &BytesN<32>,
+        ecosystem: &mut Map<Symbol, u32>,
+        source: Symbol,
+        coin_id: BytesN<32>,
+        amount: u64,
+        frequency: u32,
+        signature: BytesN<64>,
+    ) -> VerificationResult {
         // AI Pattern Recognition: Score based on source weight and features
         let base_score = ai_model.get(source.clone()).unwrap_or(0);
         let feature_score = (amount as u32 / 1000) + frequency; // Simple heuristic (expand to ML)
@@ -61,33 +90,19 @@ impl VerificationContract {
         // Anomaly detection: Flag if score < 50 or amount spikes
         let anomaly_score = if total_score < 50 || amount > 1_000_000_000 { 100 } else { 100 - total_score };
         
-        // Quantum-Resistant Hash Verification
-        let pi_digits = generate_pi_digits(50);
-        let expected_hash = pi_based_hash(&format!("{}-{}-{}", source, coin_id, amount), &pi_digits);
-        let (private_key, public_key): (RsaPrivateKey, _) = env.storage().persistent().get(&DataKey::QuantumKey).unwrap();
-        let signature = private_key.sign(PaddingScheme::new_pkcs1v15_sign::<Sha3_512>(), &expected_hash).expect("Signing failed");
-        let quantum_verified = public_key.verify(PaddingScheme::new_pkcs1v15_verify::<Sha3_512>(), &expected_hash, &signature).is_ok();
+        // Attester signature over the origin claim (panics if invalid)
+        let message = (source.clone(), coin_id, amount).to_xdr(env);
+        env.crypto().ed25519_verify(attester_key, &message, &signature);
         
         // Ecosystem Monitoring: Log and check for patterns
-        let mut ecosystem: Map<Symbol, u32> = env.storage().persistent().get(&DataKey::EcosystemData).unwrap();
         let current_freq = ecosystem.get(source.clone()).unwrap_or(0) + 1;
         ecosystem.set(source, current_freq);
-        env.storage().persistent().set(&DataKey::EcosystemData, &ecosystem);
         
         VerificationResult {
-            is_valid: total_score >= 50 && quantum_verified,
+            is_valid: total_score >= 50,
             anomaly_score,
-            quantum_verified,
-        }
-    }
-    
-    // Batch verification for efficiency
-    pub fn batch_verify(env: Env, verifications: Vec<(Symbol, BytesN<32>, u64, u32)>) -> Vec<VerificationResult> {
-        let mut results = Vec::new(&env);
-        for (source, coin_id, amount, freq) in verifications.iter() {
-            results.push_back(Self::verify_origin(env.clone(), source.clone(), coin_id.clone(), amount, freq));
+            quantum_verified: true, // Reaching here means the signature verified
         }
-        results
     }
     
     // Update AI model (admin only)
@@ -98,16 +113,3 @@ impl VerificationContract {
         env.storage().persistent().set(&DataKey::AiModel, &ai_model);
     }
 }
-
-// Pi-math utilities (shared with pi_coin contract)
-fn generate_pi_digits(digits: usize) -> String {
-    let pi = std::f64::consts::PI;
-    format!("{:.1$}", pi, digits)
-}
-
-fn pi_based_hash(data: &str, pi_digits: &str) -> [u8; 64] {
-    let combined = format!("{}{}", data, pi_digits);
-    let mut hasher = Sha3_512::new();
-    hasher.update(combined.as_bytes());
-    hasher.finalize().into()
-}
@@ -1,10 +1,98 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, BytesN, Env, Map, Symbol, Vec};
+use verification::{DataKey, VerificationContract, VerificationContractClient};
+
+fn attester(env: &Env) -> (SigningKey, BytesN<32>) {
+    let signing_key = SigningKey::from_bytes(&[11u8; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    (signing_key, public_key)
+}
+
+fn sign_origin(env: &Env, signing_key: &SigningKey, source: &Symbol, coin_id: &BytesN<32>, amount: u64) -> BytesN<64> {
+    let encoded = (source.clone(), coin_id.clone(), amount).to_xdr(env);
+    let mut message = vec![0u8; encoded.len() as usize];
+    encoded.copy_into_slice(&mut message);
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+fn setup<'a>(env: &'a Env) -> (Address, VerificationContractClient<'a>, SigningKey) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, VerificationContract);
+    let client = VerificationContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let (signing_key, public_key) = attester(env);
+    client.init(&admin, &public_key);
+    client.update_ai_model(&admin, &Symbol::new(env, "mining"), &100);
+    client.update_ai_model(&admin, &Symbol::new(env, "exchange"), &10);
+    (contract_id, client, signing_key)
+}
+
 #[test]
 fn test_verify_origin() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, VerificationContract);
-    let client = VerificationContractClient::new(&env, &contract_id);
+    let (_, client, signing_key) = setup(&env);
+    let source = Symbol::new(&env, "mining");
+    let coin_id = BytesN::from_array(&env, &[1u8; 32]);
     
-    client.init(&admin);
-    let result = client.verify_origin(&Symbol::new(&env, "mining"), &coin_id, &100, &5);
+    let signature = sign_origin(&env, &signing_key, &source, &coin_id, 100);
+    let result = client.verify_origin(&source, &coin_id, &100, &5, &signature);
     assert!(result.is_valid);
+    assert!(result.quantum_verified);
+}
+
+#[test]
+fn test_batch_verify_mixed_sources() {
+    let env = Env::default();
+    let (contract_id, client, signing_key) = setup(&env);
+    let mining = Symbol::new(&env, "mining");
+    let exchange = Symbol::new(&env, "exchange");
+    
+    let mut batch = Vec::new(&env);
+    for (i, source) in [mining.clone(), exchange.clone(), mining.clone()].iter().enumerate() {
+        let coin_id = BytesN::from_array(&env, &[i as u8; 32]);
+        let signature = sign_origin(&env, &signing_key, source, &coin_id, 100);
+        batch.push_back((source.clone(), coin_id, 100u64, 1u32, signature));
+    }
+    
+    let results = client.batch_verify(&batch);
+    assert_eq!(results.len(), 3);
+    assert!(results.get(0).unwrap().is_valid);
+    assert!(!results.get(1).unwrap().is_valid); // Low-trust source
+    assert!(results.get(2).unwrap().is_valid);
+    
+    // The shared ecosystem map accumulated every item before its single write-back
+    let ecosystem: Map<Symbol, u32> = env.as_contract(&contract_id, || {
+        env.storage().persistent().get(&DataKey::EcosystemData).unwrap()
+    });
+    assert_eq!(ecosystem.get(mining), Some(2));
+    assert_eq!(ecosystem.get(exchange), Some(1));
+}
+
+// Cost ceiling for batch_verify over a representative batch; prints the measured
+// baseline with --nocapture. Fails if per-item work regresses (e.g. reloading storage per item).
+const BATCH_SIZE: u32 = 10;
+const BATCH_VERIFY_CPU_CEILING: u64 = 60_000_000;
+const BATCH_VERIFY_MEM_CEILING: u64 = 20_000_000;
+
+#[test]
+fn test_batch_verify_cost_ceiling() {
+    let env = Env::default();
+    let (_, client, signing_key) = setup(&env);
+    let source = Symbol::new(&env, "mining");
+    
+    let mut batch = Vec::new(&env);
+    for i in 0..BATCH_SIZE {
+        let coin_id = BytesN::from_array(&env, &[i as u8; 32]);
+        let signature = sign_origin(&env, &signing_key, &source, &coin_id, 100);
+        batch.push_back((source.clone(), coin_id, 100u64, 1u32, signature));
+    }
+    
+    env.budget().reset_default();
+    let results = client.batch_verify(&batch);
+    let cpu = env.budget().cpu_instruction_cost();
+    let mem = env.budget().memory_bytes_cost();
+    println!("batch_verify({BATCH_SIZE}): cpu={cpu} mem={mem}");
+    assert_eq!(results.len(), BATCH_SIZE);
+    assert!(cpu <= BATCH_VERIFY_CPU_CEILING);
+    assert!(mem <= BATCH_VERIFY_MEM_CEILING);
 }
@@ -1,11 +1,29 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, Map, Val, log};
-use soroban_sdk::testutils::EnvTest;
+use soroban_sdk::{testutils::{Address as _, Events}, Address, BytesN, Env, Symbol, TryFromVal};
 
-// Import contracts (placeholders; real impl link to deployed)
-use stellar_pi_coin_sdk::contracts::pi_coin::PiCoinContract;
-use stellar_pi_coin_sdk::contracts::verification::VerificationContract;
-use stellar_pi_coin_sdk::contracts::transaction::TransactionContract;
-use stellar_pi_coin_sdk::contracts::ecosystem::EcosystemContract;
+// Contracts exercised by the scenario, registered in the host test environment
+use pi_coin_contract::{MintEvent, PiCoinContract, PiCoinContractClient};
+
+// Deployed Pi Coin and the accounts a scenario acts on
+struct ScenarioContext<'a> {
+    pi_coin: PiCoinContractClient<'a>,
+    admin: Address,
+    user: Address,
+    recipient: Address,
+    coin_id: Option<BytesN<32>>,
+}
+
+impl<'a> ScenarioContext<'a> {
+    fn new(env: &'a Env) -> Self {
+        env.mock_all_auths();
+        ScenarioContext {
+            pi_coin: PiCoinContractClient::new(env, &env.register_contract(None, PiCoinContract)),
+            admin: Address::generate(env),
+            user: Address::generate(env),
+            recipient: Address::generate(env),
+            coin_id: None,
+        }
+    }
+}
 
 // Example scenario struct
 #[derive(Clone)]
@@ -32,7 +50,8 @@ fn evolve_scenario(history: &Vec<UsageScenario>, base_scenario: UsageScenario) -
 fn main() {
     println!("GodHead Nexus Usage Example Started");
 
-    let env = EnvTest::default();
+    let env = Env::default();
+    let mut context = ScenarioContext::new(&env);
     let mut history: Vec<UsageScenario> = Vec::new();
 
     // Base scenario: Mint and transfer Pi Coin
@@ -50,44 +69,101 @@ fn main() {
     // Evolve scenario
     let scenario = evolve_scenario(&history, base_scenario);
 
-    println!("Running Scenario: {}", scenario.name.to_string());
+    println!("Running Scenario: {:?}", scenario.name);
+    let scenario = run_scenario(&env, &mut context, scenario);
 
-    // Simulate execution (placeholder; real impl: invoke contracts)
+    // Predictive next scenario
+    let next_scenario = predict_next_scenario(&env, &scenario);
+    println!("GodHead Prediction: Next Scenario - {}", next_scenario);
+
+    history.push(scenario);
+    println!("GodHead Nexus Usage Example Completed");
+}
+
+// Run each step against the deployed contracts, stopping at the first failure
+fn run_scenario(env: &Env, context: &mut ScenarioContext, mut scenario: UsageScenario) -> UsageScenario {
+    scenario.success = true;
     for (i, step) in scenario.steps.iter().enumerate() {
         println!("Step {}: {}", i + 1, step);
-        // Simulate: Call contracts
-        let success = simulate_step(&env, step);
-        if !success {
+        if !run_step(env, context, step) {
             println!("GodHead Alert: Step Failed - Scenario Aborted");
+            scenario.success = false;
             break;
         }
     }
-
-    // Predictive next scenario
-    let next_scenario = predict_next_scenario(&scenario);
-    println!("GodHead Prediction: Next Scenario - {}", next_scenario);
-
-    history.push(scenario);
-    println!("GodHead Nexus Usage Example Completed");
+    scenario
 }
 
-// Placeholder simulation
-fn simulate_step(env: &EnvTest, step: &str) -> bool {
-    // Simulate success/failure
+// Invoke the contract call behind a scenario step
+fn run_step(env: &Env, context: &mut ScenarioContext, step: &str) -> bool {
+    let pi_coin = &context.pi_coin;
     match step {
-        "Initialize PiCoin contract" => true,
-        "Mint 100 PI to user" => true,
-        "Transfer 50 PI to another user" => true,
-        "Verify transaction" => rand::random::<bool>(),
+        "Initialize PiCoin contract" => {
+            pi_coin.try_init(&soroban_sdk::Vec::from_array(env, [context.admin.clone()]), &1u32).is_ok()
+                && pi_coin.try_register_compliance(&context.user, &true, &Symbol::new(env, "US"), &10u32).is_ok()
+                && pi_coin.try_register_compliance(&context.recipient, &true, &Symbol::new(env, "ID"), &20u32).is_ok()
+        }
+        "Mint 100 PI to user" => {
+            if pi_coin.try_mint(&context.user, &100, &Symbol::new(env, "mining")).is_err() {
+                return false;
+            }
+            context.coin_id = last_minted_coin(env);
+            context.coin_id.is_some()
+        }
+        "Transfer 50 PI to another user" => match &context.coin_id {
+            Some(coin_id) => pi_coin.try_transfer(&context.user, &context.recipient, &50, coin_id).is_ok(),
+            None => false,
+        },
+        "Verify transaction" => match &context.coin_id {
+            Some(coin_id) => matches!(pi_coin.get_coin(coin_id), Some(coin) if coin.owner == context.recipient && coin.amount == 50),
+            None => false,
+        },
         _ => false,
     }
 }
 
+// Coin id of the last mint, taken from its GodHeadNexusMinted event
+fn last_minted_coin(env: &Env) -> Option<BytesN<32>> {
+    let mut coin_id = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let topic0 = topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok());
+        if topic0 == Some(Symbol::new(env, "GodHeadNexusMinted")) {
+            coin_id = MintEvent::try_from_val(env, &data).ok().map(|event| event.coin_id);
+        }
+    }
+    coin_id
+}
+
 // Predictive scenario generation
-fn predict_next_scenario(current: &UsageScenario) -> String {
-    if current.name == Symbol::new(&EnvTest::default(), "mint_transfer") {
+fn predict_next_scenario(env: &Env, current: &UsageScenario) -> String {
+    if current.name == Symbol::new(env, "mint_transfer") {
         "ecosystem_integration".to_string()
     } else {
         "advanced_verification".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_transfer_scenario_runs_against_contract() {
+        let env = Env::default();
+        let mut context = ScenarioContext::new(&env);
+        let scenario = UsageScenario {
+            name: Symbol::new(&env, "mint_transfer"),
+            steps: vec![
+                "Initialize PiCoin contract".to_string(),
+                "Mint 100 PI to user".to_string(),
+                "Transfer 50 PI to another user".to_string(),
+                "Verify transaction".to_string(),
+            ],
+            success: false,
+        };
+
+        let scenario = run_scenario(&env, &mut context, scenario);
+        assert!(scenario.success);
+        assert_eq!(predict_next_scenario(&env, &scenario), "ecosystem_integration");
+    }
+}
@@ -0,0 +1,296 @@
+// scripts/asset_setup.rs - Off-chain PI asset trustline setup
+// Creates the PI trustline for an account against Horizon: validates the issuer,
+// builds and signs the change-trust transaction with stellar-xdr, and submits it.
+// Runs off-chain (std); contracts such as AutoIntegrate only announce integrations.
+
+use ed25519_dalek::{Signer, SigningKey};
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{
+    AccountId, AlphaNum4, AssetCode4, ChangeTrustAsset, ChangeTrustOp, DecoratedSignature, Hash, Limits, Memo,
+    MuxedAccount, Operation, OperationBody, Preconditions, PublicKey, SequenceNumber, Signature, SignatureHint,
+    Transaction, TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
+    TransactionSignaturePayloadTaggedTransaction, TransactionV1Envelope, Uint256, WriteXdr,
+};
+
+pub const PI_ASSET_CODE: &str = "PI";
+pub const BASE_FEE: u32 = 100; // Stroops per operation
+
+#[derive(thiserror::Error, Debug)]
+pub enum AssetSetupError {
+    #[error("invalid account {0}")]
+    InvalidAccount(String),
+    #[error("invalid issuer {0}")]
+    InvalidIssuer(String),
+    #[error("issuer {0} does not exist on the network")]
+    IssuerNotFound(String),
+    #[error("account {0} does not exist on the network")]
+    AccountNotFound(String),
+    #[error("account cannot trust its own asset")]
+    SelfTrust,
+    #[error("horizon rejected the request with status {status}: {result_codes}")]
+    Horizon { status: u16, result_codes: serde_json::Value },
+    #[error("http error: {0}")]
+    Http(String),
+    #[error("xdr error: {0}")]
+    Xdr(String),
+}
+
+// Result of a submitted change-trust transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustlineSetup {
+    pub account: String,
+    pub hash: String,
+    pub ledger: u32,
+    pub envelope_xdr: String,
+}
+
+pub struct AssetSetup {
+    horizon_url: String,
+    network_passphrase: String,
+    issuer: String,
+    issuer_key: [u8; 32],
+    http: reqwest::blocking::Client,
+}
+
+impl AssetSetup {
+    pub fn new(horizon_url: &str, network_passphrase: &str, issuer: &str) -> Result<Self, AssetSetupError> {
+        let issuer_key = stellar_strkey::ed25519::PublicKey::from_string(issuer)
+            .map_err(|_| AssetSetupError::InvalidIssuer(issuer.to_string()))?
+            .0;
+        Ok(AssetSetup {
+            horizon_url: horizon_url.trim_end_matches('/').to_string(),
+            network_passphrase: network_passphrase.to_string(),
+            issuer: issuer.to_string(),
+            issuer_key,
+            http: reqwest::blocking::Client::new(),
+        })
+    }
+
+    // Issuer must be a funded account on the target network
+    pub fn validate_issuer(&self) -> Result<(), AssetSetupError> {
+        match self.load_sequence(&self.issuer) {
+            Err(AssetSetupError::AccountNotFound(id)) => Err(AssetSetupError::IssuerNotFound(id)),
+            other => other.map(|_| ()),
+        }
+    }
+
+    // Validate the issuer, then build, sign and submit a change-trust for the PI asset
+    pub fn create_trustline(&self, signing_key: &SigningKey, limit: i64) -> Result<TrustlineSetup, AssetSetupError> {
+        let account = stellar_strkey::ed25519::PublicKey(signing_key.verifying_key().to_bytes()).to_string();
+        if signing_key.verifying_key().to_bytes() == self.issuer_key {
+            return Err(AssetSetupError::SelfTrust);
+        }
+        self.validate_issuer()?;
+        let sequence = self.load_sequence(&account)?;
+        let envelope = self.build_change_trust(signing_key, sequence + 1, limit)?;
+        self.submit(&account, &envelope)
+    }
+
+    // Signed change-trust envelope for the PI asset at the given sequence number
+    pub fn build_change_trust(&self, signing_key: &SigningKey, sequence: i64, limit: i64) -> Result<TransactionEnvelope, AssetSetupError> {
+        let mut code = [0u8; 4];
+        code[..PI_ASSET_CODE.len()].copy_from_slice(PI_ASSET_CODE.as_bytes());
+        let operation = Operation {
+            source_account: None,
+            body: OperationBody::ChangeTrust(ChangeTrustOp {
+                line: ChangeTrustAsset::CreditAlphanum4(AlphaNum4 {
+                    asset_code: AssetCode4(code),
+                    issuer: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(self.issuer_key))),
+                }),
+                limit,
+            }),
+        };
+        let tx = Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(signing_key.verifying_key().to_bytes())),
+            fee: BASE_FEE,
+            seq_num: SequenceNumber(sequence),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: vec![operation].try_into().map_err(|e| AssetSetupError::Xdr(format!("{e}")))?,
+            ext: TransactionExt::V0,
+        };
+
+        let signature = signing_key.sign(&self.transaction_hash(&tx)?);
+        let public_key = signing_key.verifying_key().to_bytes();
+        let decorated = DecoratedSignature {
+            hint: SignatureHint(public_key[28..].try_into().unwrap()),
+            signature: Signature(signature.to_bytes().to_vec().try_into().map_err(|e| AssetSetupError::Xdr(format!("{e}")))?),
+        };
+        Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx,
+            signatures: vec![decorated].try_into().map_err(|e| AssetSetupError::Xdr(format!("{e}")))?,
+        }))
+    }
+
+    // Network-bound hash that signers sign
+    pub fn transaction_hash(&self, tx: &Transaction) -> Result<[u8; 32], AssetSetupError> {
+        let payload = TransactionSignaturePayload {
+            network_id: Hash(Sha256::digest(self.network_passphrase.as_bytes()).into()),
+            tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+        };
+        let bytes = payload.to_xdr(Limits::none()).map_err(|e| AssetSetupError::Xdr(format!("{e}")))?;
+        Ok(Sha256::digest(bytes).into())
+    }
+
+    fn load_sequence(&self, account: &str) -> Result<i64, AssetSetupError> {
+        stellar_strkey::ed25519::PublicKey::from_string(account).map_err(|_| AssetSetupError::InvalidAccount(account.to_string()))?;
+        let response = self
+            .http
+            .get(format!("{}/accounts/{}", self.horizon_url, account))
+            .send()
+            .map_err(|e| AssetSetupError::Http(e.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(AssetSetupError::AccountNotFound(account.to_string()));
+        }
+        let body: serde_json::Value = Self::json(response)?;
+        body["sequence"]
+            .as_str()
+            .and_then(|seq| seq.parse().ok())
+            .ok_or_else(|| AssetSetupError::Http("missing sequence in account response".to_string()))
+    }
+
+    fn submit(&self, account: &str, envelope: &TransactionEnvelope) -> Result<TrustlineSetup, AssetSetupError> {
+        let envelope_xdr = envelope.to_xdr_base64(Limits::none()).map_err(|e| AssetSetupError::Xdr(format!("{e}")))?;
+        let response = self
+            .http
+            .post(format!("{}/transactions", self.horizon_url))
+            .form(&[("tx", envelope_xdr.as_str())])
+            .send()
+            .map_err(|e| AssetSetupError::Http(e.to_string()))?;
+        let body = Self::json(response)?;
+        Ok(TrustlineSetup {
+            account: account.to_string(),
+            hash: body["hash"].as_str().unwrap_or_default().to_string(),
+            ledger: body["ledger"].as_u64().unwrap_or_default() as u32,
+            envelope_xdr,
+        })
+    }
+
+    // Decode a Horizon response, surfacing result codes on failure
+    fn json(response: reqwest::blocking::Response) -> Result<serde_json::Value, AssetSetupError> {
+        let status = response.status();
+        let body: serde_json::Value = response.json().map_err(|e| AssetSetupError::Http(e.to_string()))?;
+        if !status.is_success() {
+            return Err(AssetSetupError::Horizon {
+                status: status.as_u16(),
+                result_codes: body["extras"]["result_codes"].clone(),
+            });
+        }
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Verifier, VerifyingKey};
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+    fn address(key: &SigningKey) -> String {
+        stellar_strkey::ed25519::PublicKey(key.verifying_key().to_bytes()).to_string()
+    }
+
+    #[test]
+    fn test_change_trust_envelope() {
+        let issuer = SigningKey::from_bytes(&[1; 32]);
+        let holder = SigningKey::from_bytes(&[2; 32]);
+        let setup = AssetSetup::new("http://localhost", PASSPHRASE, &address(&issuer)).unwrap();
+
+        let TransactionEnvelope::Tx(envelope) = setup.build_change_trust(&holder, 43, i64::MAX).unwrap() else {
+            panic!("expected a v1 envelope");
+        };
+        assert_eq!(envelope.tx.seq_num, SequenceNumber(43));
+        assert_eq!(envelope.tx.fee, BASE_FEE);
+        assert_eq!(envelope.tx.source_account, MuxedAccount::Ed25519(Uint256(holder.verifying_key().to_bytes())));
+        let OperationBody::ChangeTrust(op) = &envelope.tx.operations[0].body else {
+            panic!("expected a change-trust operation");
+        };
+        assert_eq!(op.limit, i64::MAX);
+        assert_eq!(
+            op.line,
+            ChangeTrustAsset::CreditAlphanum4(AlphaNum4 {
+                asset_code: AssetCode4(*b"PI\0\0"),
+                issuer: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(issuer.verifying_key().to_bytes()))),
+            })
+        );
+
+        // Signed by the holder over the network-bound hash
+        let hash = setup.transaction_hash(&envelope.tx).unwrap();
+        let signature = ed25519_dalek::Signature::from_slice(envelope.signatures[0].signature.0.as_slice()).unwrap();
+        let verifying_key = VerifyingKey::from_bytes(&holder.verifying_key().to_bytes()).unwrap();
+        assert!(verifying_key.verify(&hash, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_create_trustline_against_horizon() {
+        let server = MockServer::start();
+        let issuer = SigningKey::from_bytes(&[1; 32]);
+        let holder = SigningKey::from_bytes(&[2; 32]);
+        let setup = AssetSetup::new(&server.base_url(), PASSPHRASE, &address(&issuer)).unwrap();
+
+        let issuer_mock = server.mock(|when, then| {
+            when.method(GET).path(format!("/accounts/{}", address(&issuer)));
+            then.status(200).json_body(json!({ "sequence": "7" }));
+        });
+        let holder_mock = server.mock(|when, then| {
+            when.method(GET).path(format!("/accounts/{}", address(&holder)));
+            then.status(200).json_body(json!({ "sequence": "42" }));
+        });
+        let expected = setup.build_change_trust(&holder, 43, i64::MAX).unwrap().to_xdr_base64(Limits::none()).unwrap();
+        let submit_mock = server.mock(|when, then| {
+            when.method(POST).path("/transactions").x_www_form_urlencoded_tuple("tx", &expected);
+            then.status(200).json_body(json!({ "hash": "abc123", "ledger": 1234, "successful": true }));
+        });
+
+        let result = setup.create_trustline(&holder, i64::MAX).unwrap();
+        issuer_mock.assert();
+        holder_mock.assert();
+        submit_mock.assert();
+        assert_eq!(
+            result,
+            TrustlineSetup { account: address(&holder), hash: "abc123".to_string(), ledger: 1234, envelope_xdr: expected }
+        );
+    }
+
+    #[test]
+    fn test_unknown_issuer_rejected() {
+        let server = MockServer::start();
+        let issuer = SigningKey::from_bytes(&[1; 32]);
+        let holder = SigningKey::from_bytes(&[2; 32]);
+        let setup = AssetSetup::new(&server.base_url(), PASSPHRASE, &address(&issuer)).unwrap();
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/accounts/{}", address(&issuer)));
+            then.status(404).json_body(json!({ "status": 404 }));
+        });
+
+        assert!(matches!(setup.create_trustline(&holder, i64::MAX), Err(AssetSetupError::IssuerNotFound(_))));
+        assert!(matches!(AssetSetup::new(&server.base_url(), PASSPHRASE, "not-an-issuer"), Err(AssetSetupError::InvalidIssuer(_))));
+    }
+
+    #[test]
+    fn test_submission_failure_surfaces_result_codes() {
+        let server = MockServer::start();
+        let issuer = SigningKey::from_bytes(&[1; 32]);
+        let holder = SigningKey::from_bytes(&[2; 32]);
+        let setup = AssetSetup::new(&server.base_url(), PASSPHRASE, &address(&issuer)).unwrap();
+        server.mock(|when, then| {
+            when.method(GET).path_contains("/accounts/");
+            then.status(200).json_body(json!({ "sequence": "42" }));
+        });
+        server.mock(|when, then| {
+            when.method(POST).path("/transactions");
+            then.status(400).json_body(json!({ "extras": { "result_codes": { "transaction": "tx_bad_seq" } } }));
+        });
+
+        match setup.create_trustline(&holder, i64::MAX) {
+            Err(AssetSetupError::Horizon { status, result_codes }) => {
+                assert_eq!(status, 400);
+                assert_eq!(result_codes, json!({ "transaction": "tx_bad_seq" }));
+            }
+            other => panic!("expected horizon error, got {other:?}"),
+        }
+    }
+}
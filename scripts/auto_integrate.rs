@@ -41,8 +41,8 @@ impl AutoIntegrate {
 
     // Integrate with Lobstr wallet for international payments
     fn integrate_with_wallet(env: &Env, pi_coin_contract: Address) -> Result<(), ()> {
-        // Hyper-tech: Set trustline and enable payments
-        // Simulate API call to Lobstr (in real, use Stellar SDK to create trustline)
+        // Hyper-tech: Announce the integration; the PI trustline itself is created
+        // off-chain by scripts/asset_setup.rs (AssetSetup::create_trustline)
         log!(&env, "Integrated Pi Coin with Lobstr wallet for global payments");
         // Emit event for worldwide adoption
         env.events().publish((Symbol::new(env, "wallet_integration"), pi_coin_contract), Symbol::new(env, "lobstr"));
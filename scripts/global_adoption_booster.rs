@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
 use stellar_sdk::Server; // For cross-chain queries
-use rand::Rng; // For AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -57,7 +57,7 @@ impl GlobalAdoptionBooster {
     // AI predict campaign timing (hyper intelligence)
     fn ai_predict_campaign_timing(env: &Env) -> Result<i128, ()> {
         // Simulate ML analysis of market trends
-        let market_trend = rand::thread_rng().gen_range(60..100);
+        let market_trend = NexusPrng::from_ledger(env).gen_range(60, 100);
         Ok(market_trend)
     }
 
@@ -91,7 +91,7 @@ impl GlobalAdoptionBooster {
     // AI global reach score
     fn ai_global_reach(env: &Env) -> i128 {
         // Simulate worldwide adoption metrics
-        rand::thread_rng().gen_range(80..100)
+        NexusPrng::from_ledger(env).gen_range(80, 100)
     }
 }
 
@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
 use stellar_sdk::Server; // For mainnet queries
-use rand::Rng; // For AI randomness simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -62,7 +62,7 @@ impl HyperAIMonitor {
         // Ultimate AI: Simulate ML analysis of transactions, provenance, and external threats
         let transaction_volume = Self::query_transaction_volume(env)?; // Simulate query
         let provenance_integrity = Self::check_provenance_integrity(env, pi_coin_contract)?;
-        let external_threat = rand::thread_rng().gen_range(0..100); // Simulate global threats
+        let external_threat = NexusPrng::from_ledger(env).gen_range(0, 100); // Simulate global threats
         
         let anomaly_score = (100 - provenance_integrity) + external_threat + (transaction_volume % 10);
         Ok(anomaly_score.min(100)) // 0-100 scale
@@ -95,7 +95,7 @@ impl HyperAIMonitor {
     // AI global consensus (predict and enforce worldwide adoption)
     fn ai_global_consensus(env: &Env) -> i128 {
         // Hyper intelligence: Simulate global market analysis
-        let market_sentiment = rand::thread_rng().gen_range(80..100); // Simulate positive
+        let market_sentiment = NexusPrng::from_ledger(env).gen_range(80, 100); // Simulate positive
         market_sentiment
     }
 
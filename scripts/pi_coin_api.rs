@@ -1,7 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rocket::get, post, routes, launch, State; // Assume Rocket for HTTP API (add to Cargo.toml)
-use stellar_sdk::Server; // For queries
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
 
 #[contracttype]
 #[derive(Clone)]
@@ -11,33 +9,87 @@ pub struct APIStats {
     pub global_access_score: i128,
 }
 
+// Why a query was blocked
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ThreatReason {
+    Denied = 1,          // Caller is on the deny list
+    RateLimited = 2,     // Caller exceeded MAX_REQUESTS_PER_WINDOW
+    TooManyParams = 3,   // More than MAX_PARAMS entries
+    ParamTooLarge = 4,   // A value longer than MAX_PARAM_BYTES
+    MalformedParams = 5, // Unexpected or missing keys for the query type
+    UnknownQuery = 6,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CallerAccess {
+    Default, // Subject to every rule
+    Allow,   // Exempt from rate limiting; parameters are still validated
+    Deny,    // Always blocked
+}
+
+// Per-caller request count in the current window
+#[contracttype]
+#[derive(Clone)]
+pub struct RateWindow {
+    pub window_start: u64,
+    pub count: u32,
+}
+
+#[contracttype]
+pub enum ApiDataKey {
+    Admin,
+    Access(Address),
+    Rate(Address),
+}
+
+pub const RATE_WINDOW_SECS: u64 = 60;
+pub const MAX_REQUESTS_PER_WINDOW: u32 = 30;
+pub const MAX_PARAMS: u32 = 8;
+pub const MAX_PARAM_BYTES: u32 = 256;
+
 #[contract]
 pub struct PiCoinAPI;
 
 #[contractimpl]
 impl PiCoinAPI {
     // Initialize API with hyper intelligence
-    pub fn initialize(env: Env) -> Result<(), ()> {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ()> {
         let stats = APIStats {
             queries_handled: 0,
             attacks_blocked: 0,
             global_access_score: 100,
         };
         env.storage().instance().set(&Symbol::new(&env, "api_stats"), &stats);
+        env.storage().instance().set(&ApiDataKey::Admin, &admin);
         log!(&env, "Pi Coin API initialized: Autonomous hyper intelligence for global interactions");
         Ok(())
     }
 
-    // AI-driven query handler
-    pub fn handle_query(env: Env, query_type: Symbol, params: Map<Symbol, Bytes>) -> Result<Bytes, ()> {
+    // Allow, deny or reset a caller (admin only)
+    pub fn set_caller_access(env: Env, caller: Address, access: CallerAccess) {
+        let admin: Address = env.storage().instance().get(&ApiDataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().persistent().set(&ApiDataKey::Access(caller.clone()), &access);
+        log!(&env, "Caller access set: {} -> {:?}", caller, access);
+    }
+
+    pub fn get_caller_access(env: Env, caller: Address) -> CallerAccess {
+        env.storage().persistent().get(&ApiDataKey::Access(caller)).unwrap_or(CallerAccess::Default)
+    }
+
+    // Rule-checked query handler
+    pub fn handle_query(env: Env, caller: Address, query_type: Symbol, params: Map<Symbol, Bytes>) -> Result<Bytes, ThreatReason> {
+        caller.require_auth();
         let mut stats: APIStats = env.storage().instance().get(&Symbol::new(&env, "api_stats")).unwrap();
         stats.queries_handled += 1;
         
-        // Hyper intelligence: AI detect and block threats
-        if Self::ai_detect_threat(&env, &params)? {
+        if let Err(reason) = Self::detect_threat(&env, &caller, &query_type, &params) {
             stats.attacks_blocked += 1;
-            log!(&env, "Threat blocked: Query rejected for security");
-            return Err(());
+            log!(&env, "Threat blocked: {} query from {} rejected ({:?})", query_type, caller, reason);
+            return Err(reason);
         }
         
         // Process query based on type
@@ -45,7 +97,7 @@ impl PiCoinAPI {
             _ if query_type == Symbol::new(&env, "peg") => Self::get_peg_status(&env)?,
             _ if query_type == Symbol::new(&env, "provenance") => Self::verify_provenance(&env, params)?,
             _ if query_type == Symbol::new(&env, "transfer") => Self::initiate_transfer(&env, params)?,
-            _ => Bytes::from_slice(&env, b"Invalid query"),
+            _ => return Err(ThreatReason::UnknownQuery),
         };
         
         env.storage().instance().set(&Symbol::new(&env, "api_stats"), &stats);
@@ -53,54 +105,145 @@ impl PiCoinAPI {
         Ok(response)
     }
 
-    // AI detect threats (unmatched security)
-    fn ai_detect_threat(env: &Env, params: &Map<Symbol, Bytes>) -> Result<bool, ()> {
-        // Simulate ML threat detection
-        let threat_score = rand::thread_rng().gen_range(0..50); // Low threat simulation
-        Ok(threat_score > 40)
+    // Deterministic threat rules: deny list, parameter shape, then per-caller rate limit
+    fn detect_threat(env: &Env, caller: &Address, query_type: &Symbol, params: &Map<Symbol, Bytes>) -> Result<(), ThreatReason> {
+        let access = Self::get_caller_access(env.clone(), caller.clone());
+        if access == CallerAccess::Deny {
+            return Err(ThreatReason::Denied);
+        }
+        Self::validate_params(env, query_type, params)?;
+        if access != CallerAccess::Allow {
+            Self::record_request(env, caller)?;
+        }
+        Ok(())
+    }
+
+    // Each query type takes exactly its expected keys, within size limits
+    fn validate_params(env: &Env, query_type: &Symbol, params: &Map<Symbol, Bytes>) -> Result<(), ThreatReason> {
+        if params.len() > MAX_PARAMS {
+            return Err(ThreatReason::TooManyParams);
+        }
+        for (_, value) in params.iter() {
+            if value.len() > MAX_PARAM_BYTES {
+                return Err(ThreatReason::ParamTooLarge);
+            }
+        }
+        let expected: Vec<Symbol> = if *query_type == Symbol::new(env, "peg") {
+            Vec::new(env)
+        } else if *query_type == Symbol::new(env, "provenance") {
+            Vec::from_array(env, [Symbol::new(env, "coin_id")])
+        } else if *query_type == Symbol::new(env, "transfer") {
+            Vec::from_array(env, [Symbol::new(env, "from"), Symbol::new(env, "to"), Symbol::new(env, "amount")])
+        } else {
+            return Err(ThreatReason::UnknownQuery);
+        };
+        if params.len() != expected.len() || expected.iter().any(|key| !params.contains_key(key)) {
+            return Err(ThreatReason::MalformedParams);
+        }
+        Ok(())
+    }
+
+    // Count the request in the caller's current window
+    fn record_request(env: &Env, caller: &Address) -> Result<(), ThreatReason> {
+        let now = env.ledger().timestamp();
+        let key = ApiDataKey::Rate(caller.clone());
+        let mut window: RateWindow = env.storage().temporary().get(&key).unwrap_or(RateWindow { window_start: now, count: 0 });
+        if now >= window.window_start + RATE_WINDOW_SECS {
+            window = RateWindow { window_start: now, count: 0 };
+        }
+        if window.count >= MAX_REQUESTS_PER_WINDOW {
+            return Err(ThreatReason::RateLimited);
+        }
+        window.count += 1;
+        env.storage().temporary().set(&key, &window);
+        Ok(())
     }
 
     // Get peg status (exclusive to valid sources)
-    fn get_peg_status(env: &Env) -> Result<Bytes, ()> {
+    fn get_peg_status(env: &Env) -> Result<Bytes, ThreatReason> {
         Ok(Bytes::from_slice(env, b"Pi Coin peg: $314,159 - Valid for Mining/Rewards/P2P only"))
     }
 
     // Verify provenance
-    fn verify_provenance(env: &Env, params: Map<Symbol, Bytes>) -> Result<Bytes, ()> {
+    fn verify_provenance(env: &Env, params: Map<Symbol, Bytes>) -> Result<Bytes, ThreatReason> {
         // Simulate check
         Ok(Bytes::from_slice(env, b"Provenance verified: Valid source"))
     }
 
     // Initiate transfer
-    fn initiate_transfer(env: &Env, params: Map<Symbol, Bytes>) -> Result<Bytes, ()> {
+    fn initiate_transfer(env: &Env, params: Map<Symbol, Bytes>) -> Result<Bytes, ThreatReason> {
         Ok(Bytes::from_slice(env, b"Transfer initiated: Global payment processed"))
     }
 }
 
-// Rocket routes for HTTP API
-#[get("/peg")]
-fn get_peg(state: &State<Env>) -> String {
-    match PiCoinAPI::handle_query(state.inner().clone(), Symbol::new(state, "peg"), Map::new(state)) {
-        Ok(res) => String::from_utf8(res.to_vec()).unwrap(),
-        Err(_) => "Error".to_string(),
+// HTTP access goes through the std service in scripts/pi_coin_web.rs, which
+// reaches the deployed contract over Soroban RPC rather than a local Env.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn setup(env: &Env) -> PiCoinAPIClient<'_> {
+        env.mock_all_auths();
+        let client = PiCoinAPIClient::new(env, &env.register_contract(None, PiCoinAPI));
+        client.initialize(&Address::generate(env));
+        client
     }
-}
 
-#[post("/transfer", data = "<params>")]
-fn post_transfer(state: &State<Env>, params: String) -> String {
-    // Parse params (simplified)
-    let param_map = Map::new(state);
-    match PiCoinAPI::handle_query(state.inner().clone(), Symbol::new(state, "transfer"), param_map) {
-        Ok(res) => String::from_utf8(res.to_vec()).unwrap(),
-        Err(_) => "Error".to_string(),
+    #[test]
+    fn test_rate_limit_per_caller() {
+        let env = Env::default();
+        let client = setup(&env);
+        let caller = Address::generate(&env);
+        let peg = Symbol::new(&env, "peg");
+
+        for _ in 0..MAX_REQUESTS_PER_WINDOW {
+            client.handle_query(&caller, &peg, &Map::new(&env));
+        }
+        assert_eq!(client.try_handle_query(&caller, &peg, &Map::new(&env)), Err(Ok(ThreatReason::RateLimited)));
+
+        // Other callers are unaffected, and the window resets
+        client.handle_query(&Address::generate(&env), &peg, &Map::new(&env));
+        env.ledger().with_mut(|li| li.timestamp += RATE_WINDOW_SECS);
+        client.handle_query(&caller, &peg, &Map::new(&env));
     }
-}
 
-#[launch]
-fn rocket() -> _ {
-    let env = Env::default();
-    PiCoinAPI::initialize(env.clone()).unwrap();
-    rocket::build()
-        .manage(env)
-        .mount("/", routes![get_peg, post_transfer])
+    #[test]
+    fn test_malformed_params_rejected() {
+        let env = Env::default();
+        let client = setup(&env);
+        let caller = Address::generate(&env);
+        let transfer = Symbol::new(&env, "transfer");
+
+        // Missing "amount", plus an unexpected key
+        let mut params = Map::new(&env);
+        params.set(Symbol::new(&env, "from"), Bytes::from_slice(&env, b"alice"));
+        params.set(Symbol::new(&env, "to"), Bytes::from_slice(&env, b"bob"));
+        params.set(Symbol::new(&env, "memo"), Bytes::from_slice(&env, b"hi"));
+        assert_eq!(client.try_handle_query(&caller, &transfer, &params), Err(Ok(ThreatReason::MalformedParams)));
+
+        params.remove(Symbol::new(&env, "memo"));
+        params.set(Symbol::new(&env, "amount"), Bytes::from_array(&env, &[0u8; 512]));
+        assert_eq!(client.try_handle_query(&caller, &transfer, &params), Err(Ok(ThreatReason::ParamTooLarge)));
+
+        params.set(Symbol::new(&env, "amount"), Bytes::from_slice(&env, b"10"));
+        client.handle_query(&caller, &transfer, &params);
+    }
+
+    #[test]
+    fn test_deny_and_allow_lists() {
+        let env = Env::default();
+        let client = setup(&env);
+        let peg = Symbol::new(&env, "peg");
+        let denied = Address::generate(&env);
+        let trusted = Address::generate(&env);
+        client.set_caller_access(&denied, &CallerAccess::Deny);
+        client.set_caller_access(&trusted, &CallerAccess::Allow);
+
+        assert_eq!(client.try_handle_query(&denied, &peg, &Map::new(&env)), Err(Ok(ThreatReason::Denied)));
+        for _ in 0..=MAX_REQUESTS_PER_WINDOW {
+            client.handle_query(&trusted, &peg, &Map::new(&env));
+        }
+    }
 }
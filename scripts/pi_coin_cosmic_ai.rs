@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rand::Rng; // For cosmic AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -59,7 +59,7 @@ impl PiCoinCosmicAI {
     // Predict cosmic threats (ultimate prediction)
     fn predict_cosmic_threats(env: &Env) -> Result<u64, ()> {
         // Simulate prediction of cosmic events (asteroids, aliens, black holes)
-        let threats = rand::thread_rng().gen_range(0..100); // Cosmic vigilance
+        let threats = NexusPrng::from_ledger(env).gen_range(0, 100) as u64; // Cosmic vigilance
         log!(&env, "Cosmic threats predicted: {} - Neutralized by quantum AI", threats);
         Ok(threats)
     }
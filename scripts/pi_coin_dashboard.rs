@@ -1,13 +1,11 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rocket::get, routes, launch, State; // For web dashboard
-use stellar_sdk::Server; // For data queries
 
 #[contracttype]
 #[derive(Clone)]
 pub struct DashboardData {
     pub peg_visual: Bytes, // AI-generated visualization
-    pub provenance_stats: Map<Symbol, u32>, // Source counts
+    pub provenance_stats: Map<Symbol, u64>, // Mint counts per source, read from the Pi Coin contract
     pub global_alerts: Vec<Bytes>, // AI alerts
 }
 
@@ -46,18 +44,20 @@ impl PiCoinDashboard {
         Ok(())
     }
 
+    // Current dashboard state
+    pub fn get_dashboard(env: Env) -> Result<DashboardData, ()> {
+        env.storage().instance().get(&Symbol::new(&env, "dashboard_data")).ok_or(())
+    }
+
     // AI generate visualization (hyper intelligence)
     fn ai_generate_visual(env: &Env, oracle: Address) -> Result<Bytes, ()> {
         // Simulate AI chart generation (e.g., JSON for peg stability)
         Ok(Bytes::from_slice(env, b"{\"peg\": \"$314,159\", \"stability\": \"High\"}"))
     }
 
-    // Get provenance stats
-    fn get_provenance_stats(env: &Env, pi_coin_contract: Address) -> Result<Map<Symbol, u32>, ()> {
-        let stats = Map::new(env);
-        stats.set(Symbol::new(env, "Mining"), 1000);
-        stats.set(Symbol::new(env, "Rewards"), 500);
-        stats.set(Symbol::new(env, "P2P"), 200);
+    // Get provenance stats: per-source mint counts tracked by the Pi Coin contract
+    fn get_provenance_stats(env: &Env, pi_coin_contract: Address) -> Result<Map<Symbol, u64>, ()> {
+        let stats: Map<Symbol, u64> = env.invoke_contract(&pi_coin_contract, &Symbol::new(env, "get_mint_counts"), Vec::new(env));
         Ok(stats)
     }
 
@@ -69,22 +69,35 @@ impl PiCoinDashboard {
     }
 }
 
-// Rocket routes for web dashboard
-#[get("/dashboard")]
-fn get_dashboard(state: &State<Env>) -> String {
-    let data: DashboardData = state.storage().instance().get(&Symbol::new(state, "dashboard_data")).unwrap();
-    format!("Peg Visual: {}\nProvenance: {:?}\nAlerts: {:?}", 
-        String::from_utf8(data.peg_visual.to_vec()).unwrap(),
-        data.provenance_stats,
-        data.global_alerts)
-}
+// The web dashboard (GET /dashboard in scripts/pi_coin_web.rs) reads get_dashboard over Soroban RPC.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use pi_coin_contract::{PiCoinContract, PiCoinContractClient};
 
-#[launch]
-fn rocket() -> _ {
-    let env = Env::default();
-    let pi_coin_contract = Address::from_str(&env, "your-pi-coin-contract-address");
-    PiCoinDashboard::initialize(env.clone(), pi_coin_contract).unwrap();
-    rocket::build()
-        .manage(env)
-        .mount("/", routes![get_dashboard])
+    #[test]
+    fn test_provenance_stats_reflect_mints() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let pi_coin_id = env.register_contract(None, PiCoinContract);
+        let pi_coin = PiCoinContractClient::new(&env, &pi_coin_id);
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        pi_coin.init(&Vec::from_array(&env, [admin.clone()]), &1u32);
+        pi_coin.register_compliance(&user, &true, &Symbol::new(&env, "US"), &10u32);
+        pi_coin.mint(&user, &100, &Symbol::new(&env, "mining"));
+        pi_coin.mint(&user, &200, &Symbol::new(&env, "mining"));
+        pi_coin.mint(&user, &50, &Symbol::new(&env, "rewards"));
+
+        let dashboard = PiCoinDashboardClient::new(&env, &env.register_contract(None, PiCoinDashboard));
+        dashboard.initialize(&pi_coin_id);
+        dashboard.update_dashboard(&pi_coin_id, &Address::generate(&env));
+
+        let stats = dashboard.get_dashboard().provenance_stats;
+        assert_eq!(stats.get(Symbol::new(&env, "mining")), Some(2));
+        assert_eq!(stats.get(Symbol::new(&env, "rewards")), Some(1));
+        assert_eq!(stats.get(Symbol::new(&env, "p2p")), None);
+    }
 }
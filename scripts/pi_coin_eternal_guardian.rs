@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rand::Rng; // For eternal AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -56,7 +56,7 @@ impl PiCoinEternalGuardian {
     // Detect eternal threats (hyper-nexus prediction)
     fn detect_eternal_threats(env: &Env) -> Result<u64, ()> {
         // Simulate detection of all possible threats (cosmic, temporal, etc.)
-        let threats = rand::thread_rng().gen_range(0..10); // Eternal vigilance
+        let threats = NexusPrng::from_ledger(env).gen_range(0, 10) as u64; // Eternal vigilance
         log!(&env, "Eternal threats detected: {} - Neutralized instantly", threats);
         Ok(threats)
     }
@@ -1,77 +1,148 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rand::Rng; // For AI simulation
+use pi_coin_contract::PiCoinContractClient;
 
 #[contracttype]
 #[derive(Clone)]
 pub struct AuditData {
-    pub vulnerabilities_found: u32,
-    pub compliance_score: i128, // AI score for overall compliance
+    pub vulnerabilities_found: u32, // Invariants failed in the last audit
+    pub compliance_score: i128, // Percentage of well-formed compliance records
     pub provenance_verified: bool,
     pub last_audit_timestamp: u64,
+    pub peg_target: u64, // Peg the audited contract must hold
 }
 
+// Named pass/fail for one invariant
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantResult {
+    pub name: Symbol,
+    pub passed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditReport {
+    pub results: Vec<InvariantResult>,
+    pub all_passed: bool,
+}
+
+const AUDIT_PAGE_SIZE: u32 = 50;
+
 #[contract]
 pub struct PiCoinFinalAudit;
 
 #[contractimpl]
 impl PiCoinFinalAudit {
-    // Initialize final audit with hyper intelligence
-    pub fn initialize(env: Env) -> Result<(), ()> {
+    // Initialize final audit against the configured peg target
+    pub fn initialize(env: Env, peg_target: u64) -> Result<(), ()> {
         let data = AuditData {
             vulnerabilities_found: 0,
             compliance_score: 100, // Start perfect
             provenance_verified: true,
             last_audit_timestamp: env.ledger().timestamp(),
+            peg_target,
         };
         env.storage().instance().set(&Symbol::new(&env, "audit_data"), &data);
         log!(&env, "Pi Coin Final Audit initialized: Autonomous hyper intelligence for unmatched security verification");
         Ok(())
     }
 
-    // Autonomous hyper intelligence: Perform final audit
-    pub fn perform_final_audit(env: Env, pi_coin_contract: Address, oracle: Address, governance: Address) -> Result<(), ()> {
+    // Check on-chain invariants of the Pi Coin contract and record the report
+    pub fn perform_final_audit(env: Env, pi_coin_contract: Address, oracle: Address, governance: Address) -> Result<AuditReport, ()> {
         let mut data: AuditData = env.storage().instance().get(&Symbol::new(&env, "audit_data")).unwrap();
-        
-        // AI scan for vulnerabilities
-        let vuln_count = Self::ai_scan_vulnerabilities(&env, pi_coin_contract)?;
-        data.vulnerabilities_found += vuln_count;
-        
-        // Verify provenance and compliance
-        data.provenance_verified = Self::verify_provenance_compliance(&env, pi_coin_contract)?;
-        data.compliance_score = Self::ai_calculate_compliance(&env)?;
+        let pi_coin = PiCoinContractClient::new(&env, &pi_coin_contract);
+
+        let supply_ok = Self::check_supply_cap(&pi_coin);
+        let peg_ok = Self::check_peg(&pi_coin, data.peg_target);
+        let (well_formed, total_records) = Self::check_compliance_records(&env, &pi_coin);
+        let coins_ok = Self::check_coin_records(&env, &pi_coin);
+
+        let mut results = Vec::new(&env);
+        results.push_back(InvariantResult { name: Symbol::new(&env, "supply_within_cap"), passed: supply_ok });
+        results.push_back(InvariantResult { name: Symbol::new(&env, "peg_at_target"), passed: peg_ok });
+        results.push_back(InvariantResult { name: Symbol::new(&env, "compliance_well_formed"), passed: well_formed == total_records });
+        results.push_back(InvariantResult { name: Symbol::new(&env, "no_orphaned_coins"), passed: coins_ok });
+        let failed = results.iter().filter(|result| !result.passed).count() as u32;
+
+        data.vulnerabilities_found = failed;
+        data.provenance_verified = coins_ok;
+        data.compliance_score = if total_records == 0 { 100 } else { (well_formed as i128 * 100) / total_records as i128 };
         
         // Enforce fixes if needed
-        if vuln_count > 0 || !data.provenance_verified {
+        if failed > 0 {
             Self::enforce_audit_fixes(&env, pi_coin_contract, oracle, governance)?;
         }
         
         data.last_audit_timestamp = env.ledger().timestamp();
         env.storage().instance().set(&Symbol::new(&env, "audit_data"), &data);
-        log!(&env, "Final audit completed: Vulnerabilities {}, Provenance Verified {}, Compliance Score {}", data.vulnerabilities_found, data.provenance_verified, data.compliance_score);
-        Ok(())
+        log!(&env, "Final audit completed: Failed invariants {}, Provenance Verified {}, Compliance Score {}", data.vulnerabilities_found, data.provenance_verified, data.compliance_score);
+        Ok(AuditReport { results, all_passed: failed == 0 })
     }
 
-    // AI scan vulnerabilities (hyper-tech detection)
-    fn ai_scan_vulnerabilities(env: &Env, pi_coin_contract: Address) -> Result<u32, ()> {
-        // Simulate AI ML scan for reentrancy, overflow, etc.
-        let vuln_count = rand::thread_rng().gen_range(0..5); // Low for simulation
-        log!(&env, "AI scanned vulnerabilities: {} found in contract {}", vuln_count, pi_coin_contract);
-        Ok(vuln_count)
+    // CurrentSupply must not exceed the TotalSupply cap
+    fn check_supply_cap(pi_coin: &PiCoinContractClient) -> bool {
+        match (pi_coin.try_get_current_supply(), pi_coin.try_get_total_supply()) {
+            (Ok(Ok(current)), Ok(Ok(cap))) => current <= cap,
+            _ => false,
+        }
+    }
+
+    // The contract's peg must equal the configured target
+    fn check_peg(pi_coin: &PiCoinContractClient, peg_target: u64) -> bool {
+        matches!(pi_coin.try_get_peg(), Ok(Ok(peg)) if peg == peg_target)
     }
 
-    // Verify provenance compliance
-    fn verify_provenance_compliance(env: &Env, pi_coin_contract: Address) -> Result<bool, ()> {
-        // Simulate check for valid sources (Mining/Rewards/P2P)
-        let is_compliant = rand::thread_rng().gen_bool(0.95); // 95% compliance
-        log!(&env, "Provenance compliance verified: {} for contract {}", is_compliant, pi_coin_contract);
-        Ok(is_compliant)
+    // Risk scores are percentages, and every AI override has an audit-log entry.
+    // Returns (well-formed records, total records).
+    fn check_compliance_records(env: &Env, pi_coin: &PiCoinContractClient) -> (u32, u32) {
+        let mut overridden = Map::new(env);
+        for record in pi_coin.get_ai_override_log().iter() {
+            overridden.set(record.user, true);
+        }
+        let (mut well_formed, mut total) = (0u32, 0u32);
+        let mut cursor = Some(0);
+        while let Some(start) = cursor {
+            let (users, next) = pi_coin.enumerate_compliance(&start, &AUDIT_PAGE_SIZE);
+            for user in users.iter() {
+                total += 1;
+                match pi_coin.get_compliance(&user) {
+                    Some(record) if record.risk_score <= 100 && (!record.ai_override || overridden.contains_key(user.clone())) => well_formed += 1,
+                    _ => log!(env, "Malformed compliance record for {}", user),
+                }
+            }
+            cursor = next;
+        }
+        (well_formed, total)
     }
 
-    // AI calculate compliance score
-    fn ai_calculate_compliance(env: &Env) -> i128 {
-        // Simulate overall score based on audits
-        rand::thread_rng().gen_range(90..100)
+    // Every minted coin id has a coin record, and every live coin's owner is in the holder index
+    fn check_coin_records(env: &Env, pi_coin: &PiCoinContractClient) -> bool {
+        let mut holders = Map::new(env);
+        let mut cursor = Some(0);
+        while let Some(start) = cursor {
+            let (page, next) = pi_coin.enumerate_holders(&start, &AUDIT_PAGE_SIZE);
+            for holder in page.iter() {
+                holders.set(holder, true);
+            }
+            cursor = next;
+        }
+
+        let mut cursor = Some(0);
+        while let Some(start) = cursor {
+            let (coin_ids, next) = pi_coin.enumerate_coins(&start, &AUDIT_PAGE_SIZE);
+            for coin_id in coin_ids.iter() {
+                match pi_coin.get_coin(&coin_id) {
+                    Some(coin) if coin.amount == 0 || holders.contains_key(coin.owner.clone()) => {}
+                    _ => {
+                        log!(env, "Orphaned coin record {:?}", coin_id);
+                        return false;
+                    }
+                }
+            }
+            cursor = next;
+        }
+        true
     }
 
     // Enforce audit fixes (autonomous remediation)
@@ -89,7 +160,7 @@ fn main() {
     let oracle = Address::from_str(&env, "your-oracle-address");
     let governance = Address::from_str(&env, "your-governance-address");
     
-    PiCoinFinalAudit::initialize(env.clone()).unwrap();
+    PiCoinFinalAudit::initialize(env.clone(), 314_159).unwrap();
     loop {
         if let Err(_) = PiCoinFinalAudit::perform_final_audit(env.clone(), pi_coin_contract, oracle, governance) {
             println!("Audit error - Autonomous recovery initiated");
@@ -97,3 +168,55 @@ fn main() {
         std::thread::sleep(std::time::Duration::from_secs(604800)); // Run weekly for ultimate audit
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use pi_coin_contract::{DataKey, PiCoinContract};
+
+    fn setup(env: &Env) -> (Address, PiCoinFinalAuditClient<'_>) {
+        env.mock_all_auths();
+        let pi_coin_id = env.register_contract(None, PiCoinContract);
+        let pi_coin = PiCoinContractClient::new(env, &pi_coin_id);
+        let admin = Address::generate(env);
+        let user = Address::generate(env);
+        pi_coin.init(&Vec::from_array(env, [admin.clone()]), &1u32);
+        pi_coin.register_compliance(&user, &true, &Symbol::new(env, "US"), &10u32);
+        pi_coin.mint(&user, &100, &Symbol::new(env, "mining"));
+
+        let audit = PiCoinFinalAuditClient::new(env, &env.register_contract(None, PiCoinFinalAudit));
+        audit.initialize(&314_159);
+        (pi_coin_id, audit)
+    }
+
+    fn passed(env: &Env, report: &AuditReport, name: &str) -> bool {
+        report.results.iter().find(|result| result.name == Symbol::new(env, name)).unwrap().passed
+    }
+
+    #[test]
+    fn test_healthy_contract_passes_audit() {
+        let env = Env::default();
+        let (pi_coin_id, audit) = setup(&env);
+        let report = audit.perform_final_audit(&pi_coin_id, &Address::generate(&env), &Address::generate(&env));
+        assert!(report.all_passed);
+        assert_eq!(report.results.len(), 4);
+    }
+
+    #[test]
+    fn test_supply_over_cap_is_flagged() {
+        let env = Env::default();
+        let (pi_coin_id, audit) = setup(&env);
+        env.as_contract(&pi_coin_id, || {
+            env.storage().persistent().set(&DataKey::CurrentSupply, &200_000_000_000u64);
+        });
+
+        let report = audit.perform_final_audit(&pi_coin_id, &Address::generate(&env), &Address::generate(&env));
+        assert!(!report.all_passed);
+        assert!(!passed(&env, &report, "supply_within_cap"));
+        assert!(passed(&env, &report, "peg_at_target"));
+        assert!(passed(&env, &report, "compliance_well_formed"));
+        assert!(passed(&env, &report, "no_orphaned_coins"));
+    }
+}
+
@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
 use stellar_sdk::Server; // For deployment
-use rand::Rng; // For AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -56,7 +56,7 @@ impl PiCoinFinalDeployer {
 
     // AI decide deployment
     fn ai_decide_deployment(env: &Env) -> Result<i128, ()> {
-        let network_health = rand::thread_rng().gen_range(75..100);
+        let network_health = NexusPrng::from_ledger(env).gen_range(75, 100);
         Ok(network_health)
     }
 
@@ -77,7 +77,7 @@ impl PiCoinFinalDeployer {
 
     // Verify deployment
     fn verify_deployment(env: &Env) -> Result<bool, ()> {
-        Ok(rand::thread_rng().gen_bool(0.95)) // 95% success simulation
+        Ok(NexusPrng::from_ledger(env).gen_percent(95)) // 95% success simulation
     }
 
     // Recover deployment
@@ -89,7 +89,7 @@ impl PiCoinFinalDeployer {
 
     // AI deployment success
     fn ai_deployment_success(env: &Env) -> i128 {
-        rand::thread_rng().gen_range(90..100)
+        NexusPrng::from_ledger(env).gen_range(90, 100)
     }
 }
 
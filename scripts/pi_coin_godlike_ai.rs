@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rand::Rng; // For godlike AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -59,7 +59,7 @@ impl PiCoinGodlikeAI {
     // Create godlike universes (divine creation)
     fn create_godlike_universes(env: &Env) -> Result<u64, ()> {
         // Simulate creation of new universes for Pi Coin expansion
-        let universes = rand::thread_rng().gen_range(10000..100000); // Godlike scale
+        let universes = NexusPrng::from_ledger(env).gen_range(10000, 100000) as u64; // Godlike scale
         log!(&env, "Godlike universes created: {} - Pi Coin expands infinitely", universes);
         Ok(universes)
     }
@@ -1,12 +1,19 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rand::Rng; // For hyper AI simulation
+
+// Peg the oracle median is measured against ($314,159 in micro-units)
+const PEG_TARGET: u64 = 314_159;
+// Threat caps per signal; resilience_score is 1000 minus their sum
+const MAX_PEG_THREAT: i128 = 500; // 1 point per basis point of deviation
+const CIRCUIT_BREAKER_THREAT: i128 = 300;
+const MAX_SUPPLY_THREAT: i128 = 200; // Utilization above SUPPLY_PRESSURE_BPS
+const SUPPLY_PRESSURE_BPS: i128 = 9_000;
 
 #[contracttype]
 #[derive(Clone)]
 pub struct NexusData {
     pub evolution_level: u32, // Self-evolution counter
-    pub threat_predictions: Map<Symbol, i128>, // Dimension -> Threat score
+    pub threat_predictions: Map<Symbol, i128>, // Signal -> Threat score
     pub resilience_score: i128, // Absolute resilience metric
     pub nexus_timestamp: u64,
     pub subsystems_status: Map<Symbol, bool>, // Subsystem -> Active status
@@ -44,8 +51,8 @@ impl PiCoinNexusAI {
     pub fn orchestrate_nexus_supremacy(env: Env, pi_coin_contract: Address, oracle: Address, governance: Address, admin_address: Address) -> Result<(), ()> {
         let mut data: NexusData = env.storage().instance().get(&Symbol::new(&env, "nexus_data")).unwrap();
         
-        // Multi-dimensional threat prediction (ultimate AI)
-        Self::predict_multi_dimensional_threats(&env, &mut data)?;
+        // Threat signals from live contract state
+        Self::predict_multi_dimensional_threats(&env, &mut data, &pi_coin_contract, &oracle)?;
         
         // Self-evolution based on threats
         Self::self_evolve_nexus(&env, &mut data)?;
@@ -65,18 +72,36 @@ impl PiCoinNexusAI {
         Ok(())
     }
 
-    // Predict multi-dimensional threats (hyper-nexus AI)
-    fn predict_multi_dimensional_threats(env: &Env, data: &mut NexusData) -> Result<(), ()> {
-        // Predict from technology, human, institution, organization dimensions
-        data.threat_predictions.set(Symbol::new(env, "technology"), rand::thread_rng().gen_range(0..10)); // Low tech threats
-        data.threat_predictions.set(Symbol::new(env, "human"), rand::thread_rng().gen_range(0..5)); // Minimal human interference
-        data.threat_predictions.set(Symbol::new(env, "institution"), rand::thread_rng().gen_range(0..1)); // Near-zero institutional threats
-        data.threat_predictions.set(Symbol::new(env, "organization"), rand::thread_rng().gen_range(0..1)); // Absolute organizational immunity
-        log!(&env, "Multi-dimensional threats predicted: Tech {}, Human {}, Institution {}, Organization {} - All countered", 
-             data.threat_predictions.get(Symbol::new(env, "technology")).unwrap_or(0),
-             data.threat_predictions.get(Symbol::new(env, "human")).unwrap_or(0),
-             data.threat_predictions.get(Symbol::new(env, "institution")).unwrap_or(0),
-             data.threat_predictions.get(Symbol::new(env, "organization")).unwrap_or(0));
+    // Derive threat scores deterministically from contract state: oracle peg deviation,
+    // the oracle circuit breaker, and supply utilization. Unreadable signals count as maximal.
+    fn predict_multi_dimensional_threats(env: &Env, data: &mut NexusData, pi_coin_contract: &Address, oracle: &Address) -> Result<(), ()> {
+        let no_args = Vec::new(env);
+        let median = env.try_invoke_contract::<u64, soroban_sdk::Error>(oracle, &Symbol::new(env, "get_current_median"), no_args.clone());
+        let peg_threat = match median {
+            Ok(Ok(price)) => ((price.abs_diff(PEG_TARGET) as i128) * 10_000 / PEG_TARGET as i128).min(MAX_PEG_THREAT),
+            _ => MAX_PEG_THREAT,
+        };
+
+        let tripped = env.try_invoke_contract::<bool, soroban_sdk::Error>(oracle, &Symbol::new(env, "is_circuit_breaker_tripped"), no_args.clone());
+        let breaker_threat = match tripped {
+            Ok(Ok(false)) => 0,
+            _ => CIRCUIT_BREAKER_THREAT,
+        };
+
+        let current = env.try_invoke_contract::<u64, soroban_sdk::Error>(pi_coin_contract, &Symbol::new(env, "get_current_supply"), no_args.clone());
+        let cap = env.try_invoke_contract::<u64, soroban_sdk::Error>(pi_coin_contract, &Symbol::new(env, "get_total_supply"), no_args);
+        let supply_threat = match (current, cap) {
+            (Ok(Ok(current)), Ok(Ok(cap))) if cap > 0 => {
+                let utilization_bps = (current as i128) * 10_000 / cap as i128;
+                ((utilization_bps - SUPPLY_PRESSURE_BPS).max(0) / 5).min(MAX_SUPPLY_THREAT)
+            }
+            _ => MAX_SUPPLY_THREAT,
+        };
+
+        data.threat_predictions.set(Symbol::new(env, "peg_deviation"), peg_threat);
+        data.threat_predictions.set(Symbol::new(env, "circuit_breaker"), breaker_threat);
+        data.threat_predictions.set(Symbol::new(env, "supply_pressure"), supply_threat);
+        log!(&env, "Threat signals: Peg {}, Circuit breaker {}, Supply {}", peg_threat, breaker_threat, supply_threat);
         Ok(())
     }
 
@@ -92,7 +117,7 @@ impl PiCoinNexusAI {
     fn enforce_absolute_resilience(env: &Env, data: &mut NexusData, pi_coin_contract: Address, oracle: Address, governance: Address, admin_address: Address) -> Result<(), ()> {
         // Quantum-secure defenses against all threats
         let total_threat = data.threat_predictions.values().iter().sum::<i128>();
-        data.resilience_score = 1000 - total_threat; // Absolute resilience
+        data.resilience_score = (1000 - total_threat).max(0);
         if data.resilience_score < 950 {
             Self::activate_ultimate_defenses(env, pi_coin_contract, oracle, governance, admin_address)?;
         }
@@ -148,3 +173,49 @@ fn main() {
         std::thread::sleep(std::time::Duration::from_secs(3600)); // Run hourly for eternal supremacy
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use pi_coin_contract::{PiCoinContract, PiCoinContractClient};
+    use ::tests::mocks::{MockOracle, MockOracleClient}; // Leading :: avoids this module's own name
+
+    fn resilience(env: &Env, nexus: &Address, pi_coin: &Address, oracle: &Address) -> i128 {
+        let admin = Address::generate(env);
+        env.as_contract(nexus, || {
+            PiCoinNexusAI::orchestrate_nexus_supremacy(env.clone(), pi_coin.clone(), oracle.clone(), Address::generate(env), admin).unwrap();
+            let data: NexusData = env.storage().instance().get(&Symbol::new(env, "nexus_data")).unwrap();
+            data.resilience_score
+        })
+    }
+
+    #[test]
+    fn test_depeg_lowers_resilience_deterministically() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let nexus = env.register_contract(None, PiCoinNexusAI);
+        let pi_coin = env.register_contract(None, PiCoinContract);
+        PiCoinContractClient::new(&env, &pi_coin).init(&Vec::from_array(&env, [Address::generate(&env)]), &1u32);
+        let oracle_id = env.register_contract(None, MockOracle);
+        let oracle = MockOracleClient::new(&env, &oracle_id);
+        env.as_contract(&nexus, || PiCoinNexusAI::initialize_nexus(env.clone()).unwrap());
+
+        // At peg with nothing minted there is no threat
+        assert_eq!(resilience(&env, &nexus, &pi_coin, &oracle_id), 1000);
+
+        // 1% above peg: 99 basis points of deviation, the same on every run
+        oracle.set_median(&317_300);
+        assert_eq!(resilience(&env, &nexus, &pi_coin, &oracle_id), 901);
+        assert_eq!(resilience(&env, &nexus, &pi_coin, &oracle_id), 901);
+
+        // Deviation threat is capped
+        oracle.set_median(&600_000);
+        assert_eq!(resilience(&env, &nexus, &pi_coin, &oracle_id), 1000 - MAX_PEG_THREAT);
+
+        // A tripped breaker adds its own threat on top
+        oracle.set_circuit_breaker(&true);
+        assert_eq!(resilience(&env, &nexus, &pi_coin, &oracle_id), 1000 - MAX_PEG_THREAT - CIRCUIT_BREAKER_THREAT);
+    }
+}
+
@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
 use stellar_sdk::Server; // For deployment queries
-use rand::Rng; // For AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -59,7 +59,7 @@ impl PiCoinSelfReplicator {
     // AI decide replication (hyper intelligence)
     fn ai_decide_replication(env: &Env) -> Result<i128, ()> {
         // Simulate ML analysis of demand
-        let demand_score = rand::thread_rng().gen_range(70..100);
+        let demand_score = NexusPrng::from_ledger(env).gen_range(70, 100);
         Ok(demand_score)
     }
 
@@ -81,7 +81,7 @@ impl PiCoinSelfReplicator {
 
     // Detect failure
     fn detect_failure(env: &Env) -> Result<bool, ()> {
-        Ok(rand::thread_rng().gen_bool(0.1)) // 10% chance simulation
+        Ok(NexusPrng::from_ledger(env).gen_percent(10)) // 10% chance simulation
     }
 
     // Self-heal
@@ -93,7 +93,7 @@ impl PiCoinSelfReplicator {
 
     // AI global coverage
     fn ai_global_coverage(env: &Env) -> i128 {
-        rand::thread_rng().gen_range(80..100)
+        NexusPrng::from_ledger(env).gen_range(80, 100)
     }
 }
 
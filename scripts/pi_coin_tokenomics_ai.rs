@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
-use rand::Rng; // For AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -13,8 +13,12 @@ pub struct TokenomicsData {
     pub allocated_liquidity: i128,
     pub allocated_reserve: i128,
     pub vesting_schedule: Map<u32, i128>, // Year -> Unlock amount for admin
+    pub genesis_timestamp: u64, // Ledger timestamp vesting years are counted from
+    pub released_tranches: Map<u32, bool>, // Years whose tranche has already been released
 }
 
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
 #[contract]
 pub struct PiCoinTokenomicsAI;
 
@@ -22,7 +26,7 @@ pub struct PiCoinTokenomicsAI;
 impl PiCoinTokenomicsAI {
     // Initialize tokenomics with hyper intelligence
     pub fn initialize(env: Env, admin_address: Address) -> Result<(), ()> {
-        let data = TokenomicsData {
+        let mut data = TokenomicsData {
             total_supply: 100_000_000_000,
             allocated_mining: 0,
             allocated_rewards: 0,
@@ -31,6 +35,8 @@ impl PiCoinTokenomicsAI {
             allocated_liquidity: 0,
             allocated_reserve: 0,
             vesting_schedule: Map::new(&env),
+            genesis_timestamp: env.ledger().timestamp(),
+            released_tranches: Map::new(&env),
         };
         // Set vesting for admin (10% over 4 years)
         data.vesting_schedule.set(1, 2_500_000_000);
@@ -65,10 +71,30 @@ impl PiCoinTokenomicsAI {
         Ok(())
     }
 
+    // Supply not yet allocated to any bucket
+    pub fn remaining_unallocated(env: Env) -> i128 {
+        let data: TokenomicsData = env.storage().instance().get(&Symbol::new(&env, "tokenomics_data")).unwrap();
+        data.total_supply - Self::total_allocated(&data)
+    }
+
+    // Combined allocation across every bucket
+    fn total_allocated(data: &TokenomicsData) -> i128 {
+        data.allocated_mining + data.allocated_rewards + data.allocated_p2p + data.allocated_admin + data.allocated_liquidity + data.allocated_reserve
+    }
+
+    // Global invariant: no allocation may push the combined total over total_supply
+    fn ensure_within_supply(env: &Env, data: &TokenomicsData, incremental: i128) -> Result<(), ()> {
+        if Self::total_allocated(data) + incremental > data.total_supply {
+            log!(&env, "Allocation of {} rejected: would exceed total supply {}", incremental, data.total_supply);
+            return Err(());
+        }
+        Ok(())
+    }
+
     // AI analyze global demand (hyper-tech prediction)
     fn ai_analyze_global_demand(env: &Env) -> Result<i128, ()> {
         // Simulate AI ML analysis of market trends, transactions, and adoption
-        let score = rand::thread_rng().gen_range(60..100); // Dynamic simulation
+        let score = NexusPrng::from_ledger(env).gen_range(60, 100); // Dynamic simulation
         Ok(score)
     }
 
@@ -77,6 +103,7 @@ impl PiCoinTokenomicsAI {
         let max_allocation = 40_000_000_000;
         let incremental = 1_000_000_000; // AI-decided amount
         if data.allocated_mining + incremental <= max_allocation {
+            Self::ensure_within_supply(env, data, incremental)?;
             data.allocated_mining += incremental;
             log!(&env, "Mining rewards allocated autonomously: {} - Total {}", incremental, data.allocated_mining);
         }
@@ -88,6 +115,7 @@ impl PiCoinTokenomicsAI {
         let max_allocation = 20_000_000_000;
         let incremental = 500_000_000;
         if data.allocated_rewards + incremental <= max_allocation {
+            Self::ensure_within_supply(env, data, incremental)?;
             data.allocated_rewards += incremental;
             log!(&env, "Community rewards allocated autonomously: {} - Total {}", incremental, data.allocated_rewards);
         }
@@ -95,17 +123,30 @@ impl PiCoinTokenomicsAI {
     }
 
     // Allocate admin vested (10% with quantum-secure vesting)
+    // Tranche for year N unlocks once N full years have elapsed since genesis, and is released once
     fn allocate_admin_vested(env: &Env, data: &mut TokenomicsData, admin_address: Address) -> Result<(), ()> {
-        let current_year = 1; // Simulate based on ledger timestamp
-        if let Some(unlock_amount) = data.vesting_schedule.get(current_year) {
+        let elapsed_years = Self::elapsed_vesting_years(env, data);
+        for (year, unlock_amount) in data.vesting_schedule.iter() {
+            if year > elapsed_years || data.released_tranches.contains_key(year) {
+                continue;
+            }
+            Self::ensure_within_supply(env, data, unlock_amount)?;
             data.allocated_admin += unlock_amount;
-            log!(&env, "Admin allocation vested autonomously: {} to {} - Total {}", unlock_amount, admin_address, data.allocated_admin);
+            data.released_tranches.set(year, true);
+            log!(&env, "Admin allocation vested autonomously: year {} {} to {} - Total {}", year, unlock_amount, admin_address, data.allocated_admin);
         }
         Ok(())
     }
 
+    // Full vesting years elapsed since genesis
+    fn elapsed_vesting_years(env: &Env, data: &TokenomicsData) -> u32 {
+        let elapsed = env.ledger().timestamp().saturating_sub(data.genesis_timestamp);
+        (elapsed / SECONDS_PER_YEAR).min(u32::MAX as u64) as u32
+    }
+
     // Adjust P2P and liquidity (20% + 5%)
     fn adjust_p2p_and_liquidity(env: &Env, data: &mut TokenomicsData) -> Result<(), ()> {
+        Self::ensure_within_supply(env, data, 250_000_000)?;
         data.allocated_p2p += 200_000_000;
         data.allocated_liquidity += 50_000_000;
         log!(&env, "P2P and liquidity adjusted: P2P {}, Liquidity {}", data.allocated_p2p, data.allocated_liquidity);
@@ -114,6 +155,7 @@ impl PiCoinTokenomicsAI {
 
     // Enforce reserve (5%)
     fn enforce_reserve(env: &Env, data: &mut TokenomicsData) -> Result<(), ()> {
+        Self::ensure_within_supply(env, data, 100_000_000)?;
         data.allocated_reserve += 100_000_000;
         log!(&env, "Reserve enforced for emergencies: +100M - Total {}", data.allocated_reserve);
         Ok(())
@@ -132,4 +174,69 @@ fn main() {
         }
         std::thread::sleep(std::time::Duration::from_secs(86400)); // Run daily for ultimate management
     }
-                }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[test]
+    fn test_allocation_rejected_at_supply_cap() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiCoinTokenomicsAI);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            PiCoinTokenomicsAI::initialize(env.clone(), admin.clone()).unwrap();
+            assert_eq!(PiCoinTokenomicsAI::remaining_unallocated(env.clone()), 100_000_000_000);
+
+            // Fill every bucket up to the cap
+            let key = Symbol::new(&env, "tokenomics_data");
+            let mut data: TokenomicsData = env.storage().instance().get(&key).unwrap();
+            data.allocated_mining = 40_000_000_000;
+            data.allocated_rewards = 20_000_000_000;
+            data.allocated_p2p = 20_000_000_000;
+            data.allocated_admin = 10_000_000_000;
+            data.allocated_liquidity = 5_000_000_000;
+            data.allocated_reserve = 5_000_000_000;
+            env.storage().instance().set(&key, &data);
+            assert_eq!(PiCoinTokenomicsAI::remaining_unallocated(env.clone()), 0);
+
+            // Next round is rejected and leaves allocations untouched
+            assert_eq!(PiCoinTokenomicsAI::manage_tokenomics(env.clone(), admin.clone()), Err(()));
+            assert_eq!(PiCoinTokenomicsAI::remaining_unallocated(env.clone()), 0);
+        });
+    }
+
+    #[test]
+    fn test_vesting_tranches_unlock_once_per_year() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let contract_id = env.register_contract(None, PiCoinTokenomicsAI);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            PiCoinTokenomicsAI::initialize(env.clone(), admin.clone()).unwrap();
+            let mut data: TokenomicsData = env.storage().instance().get(&Symbol::new(&env, "tokenomics_data")).unwrap();
+
+            // Nothing vests before the first year has elapsed
+            PiCoinTokenomicsAI::allocate_admin_vested(&env, &mut data, admin.clone()).unwrap();
+            assert_eq!(data.allocated_admin, 0);
+
+            for year in 1..=4u64 {
+                env.ledger().with_mut(|li| li.timestamp = 1_000 + year * SECONDS_PER_YEAR);
+                PiCoinTokenomicsAI::allocate_admin_vested(&env, &mut data, admin.clone()).unwrap();
+                assert_eq!(data.allocated_admin, 2_500_000_000 * year as i128);
+
+                // Repeat claims within the same year release nothing
+                PiCoinTokenomicsAI::allocate_admin_vested(&env, &mut data, admin.clone()).unwrap();
+                assert_eq!(data.allocated_admin, 2_500_000_000 * year as i128);
+            }
+
+            // Schedule exhausted
+            env.ledger().with_mut(|li| li.timestamp = 1_000 + 10 * SECONDS_PER_YEAR);
+            PiCoinTokenomicsAI::allocate_admin_vested(&env, &mut data, admin.clone()).unwrap();
+            assert_eq!(data.allocated_admin, 10_000_000_000);
+            assert_eq!(data.released_tranches.len(), 4);
+        });
+    }
+}
@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, log, crypto, Bytes, BytesN};
 use stellar_sdk::Server; // For global queries
-use rand::Rng; // For AI simulation
+use pi_coin_contract::prng::NexusPrng; // Ledger-seeded, so every validator draws the same values
 
 #[contracttype]
 #[derive(Clone)]
@@ -98,7 +98,7 @@ impl PiCoinUltimateEnforcer {
 
     // Detect system failure
     fn detect_system_failure(env: &Env) -> Result<bool, ()> {
-        Ok(rand::thread_rng().gen_bool(0.05)) // 5% chance simulation
+        Ok(NexusPrng::from_ledger(env).gen_percent(5)) // 5% chance simulation
     }
 
     // Recover failures
@@ -110,7 +110,7 @@ impl PiCoinUltimateEnforcer {
 
     // AI ultimate success score
     fn ai_ultimate_success(env: &Env) -> i128 {
-        rand::thread_rng().gen_range(95..100)
+        NexusPrng::from_ledger(env).gen_range(95, 100)
     }
 }
 
@@ -0,0 +1,418 @@
+// scripts/pi_coin_web.rs - Pi Coin HTTP API and dashboard service
+// std web service in front of the deployed contracts. Handlers never construct a host Env:
+// every read is a Soroban RPC simulateTransaction against on-chain state, and transfers are
+// simulated and handed back unsigned for the caller's wallet to sign and submit.
+
+use rocket::http::Status;
+use rocket::response::{self, Responder};
+use rocket::serde::json::Json;
+use rocket::{get, launch, post, routes, Build, Request, Rocket, State};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use stellar_xdr::curr::{
+    AccountId, ContractId, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount,
+    Operation, OperationBody, Preconditions, PublicKey, ReadXdr, ScAddress, ScBytes, ScSymbol, ScVal, SequenceNumber,
+    Transaction, TransactionEnvelope, TransactionExt, TransactionV1Envelope, Uint256, VecM, WriteXdr,
+};
+
+pub const PEG_TARGET: u64 = 314_159; // $314,159 in micro-units, as in the oracle
+pub const PEG_BAND: u64 = 1_000; // Deviation the oracle treats as stable
+
+#[derive(thiserror::Error, Debug)]
+pub enum ApiError {
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+    #[error("rpc error: {0}")]
+    Rpc(String),
+    #[error("simulation failed: {0}")]
+    Simulation(String),
+    #[error("unexpected contract result: {0}")]
+    Decode(String),
+}
+
+impl<'r> Responder<'r, 'static> for ApiError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        let status = match self {
+            ApiError::InvalidInput(_) => Status::BadRequest,
+            _ => Status::BadGateway,
+        };
+        (status, Json(ErrorResponse { error: self.to_string() })).respond_to(request)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct PegResponse {
+    pub median_price: u64,
+    pub peg_target: u64,
+    pub deviation: u64,
+    pub within_band: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TransferRequest {
+    pub from: String,
+    pub to: String,
+    pub amount: u64,
+    pub coin_id: String, // Hex-encoded 32-byte coin id
+}
+
+// Simulated transfer; the wallet sets its sequence number, attaches the
+// transaction data and resource fee, signs and submits
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct TransferResponse {
+    pub transaction_xdr: String,
+    pub transaction_data: String,
+    pub min_resource_fee: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct DashboardResponse {
+    pub peg_visual: String,
+    pub provenance_stats: BTreeMap<String, u64>,
+    pub global_alerts: Vec<String>,
+}
+
+// Deployed contracts and the RPC endpoint the service reads them through
+pub struct ServiceConfig {
+    pub rpc_url: String,
+    pub source_account: String, // Any funded account; only used as the simulation source
+    pub pi_coin_contract: String,
+    pub oracle_contract: String,
+    pub dashboard_contract: String,
+}
+
+pub struct Simulation {
+    pub result: ScVal,
+    pub transaction_xdr: String,
+    pub transaction_data: String,
+    pub min_resource_fee: u64,
+}
+
+// Minimal Soroban RPC client for read-only contract calls
+pub struct RpcClient {
+    url: String,
+    http: reqwest::Client,
+}
+
+impl RpcClient {
+    pub fn new(url: &str) -> Self {
+        RpcClient { url: url.to_string(), http: reqwest::Client::new() }
+    }
+
+    // Simulate `function` on `contract` with `source` as the invoking account
+    pub async fn simulate(&self, source: &str, contract: &str, function: &str, args: Vec<ScVal>) -> Result<Simulation, ApiError> {
+        let envelope = invocation(source, contract, function, args)?;
+        let transaction_xdr = envelope.to_xdr_base64(Limits::none()).map_err(|e| ApiError::Decode(e.to_string()))?;
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "simulateTransaction",
+            "params": { "transaction": transaction_xdr },
+        });
+        let body: serde_json::Value = self
+            .http
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| ApiError::Rpc(e.to_string()))?
+            .error_for_status()
+            .map_err(|e| ApiError::Rpc(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| ApiError::Rpc(e.to_string()))?;
+
+        if let Some(error) = body.get("error") {
+            return Err(ApiError::Rpc(error.to_string()));
+        }
+        let result = &body["result"];
+        if let Some(error) = result["error"].as_str() {
+            return Err(ApiError::Simulation(error.to_string()));
+        }
+        let value = result["results"][0]["xdr"]
+            .as_str()
+            .ok_or_else(|| ApiError::Decode("missing simulation result".to_string()))?;
+        Ok(Simulation {
+            result: ScVal::from_xdr_base64(value, Limits::none()).map_err(|e| ApiError::Decode(e.to_string()))?,
+            transaction_xdr,
+            transaction_data: result["transactionData"].as_str().unwrap_or_default().to_string(),
+            min_resource_fee: result["minResourceFee"].as_str().and_then(|fee| fee.parse().ok()).unwrap_or_default(),
+        })
+    }
+}
+
+fn account_id(account: &str) -> Result<AccountId, ApiError> {
+    let key = stellar_strkey::ed25519::PublicKey::from_string(account).map_err(|_| ApiError::InvalidInput(format!("account {account}")))?;
+    Ok(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key.0))))
+}
+
+fn contract_address(contract: &str) -> Result<ScAddress, ApiError> {
+    let id = stellar_strkey::Contract::from_string(contract).map_err(|_| ApiError::InvalidInput(format!("contract {contract}")))?;
+    Ok(ScAddress::Contract(ContractId(Hash(id.0))))
+}
+
+// Unsigned single-operation invocation envelope, as simulateTransaction expects
+fn invocation(source: &str, contract: &str, function: &str, args: Vec<ScVal>) -> Result<TransactionEnvelope, ApiError> {
+    let AccountId(PublicKey::PublicKeyTypeEd25519(source_key)) = account_id(source)?;
+    let operation = Operation {
+        source_account: None,
+        body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+            host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                contract_address: contract_address(contract)?,
+                function_name: ScSymbol(function.try_into().map_err(|_| ApiError::InvalidInput(function.to_string()))?),
+                args: args.try_into().map_err(|_| ApiError::InvalidInput("too many arguments".to_string()))?,
+            }),
+            auth: VecM::default(),
+        }),
+    };
+    Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx: Transaction {
+            source_account: MuxedAccount::Ed25519(source_key),
+            fee: 100,
+            seq_num: SequenceNumber(0),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: vec![operation].try_into().unwrap(),
+            ext: TransactionExt::V0,
+        },
+        signatures: VecM::default(),
+    }))
+}
+
+// Field of a #[contracttype] struct, which encodes as a symbol-keyed map
+fn struct_field<'a>(value: &'a ScVal, field: &str) -> Result<&'a ScVal, ApiError> {
+    let ScVal::Map(Some(map)) = value else {
+        return Err(ApiError::Decode("expected a struct".to_string()));
+    };
+    map.iter()
+        .find(|entry| matches!(&entry.key, ScVal::Symbol(name) if name.0.as_slice() == field.as_bytes()))
+        .map(|entry| &entry.val)
+        .ok_or_else(|| ApiError::Decode(format!("missing field {field}")))
+}
+
+fn bytes_to_string(value: &ScVal) -> Result<String, ApiError> {
+    match value {
+        ScVal::Bytes(bytes) => Ok(String::from_utf8_lossy(bytes.0.as_slice()).into_owned()),
+        other => Err(ApiError::Decode(format!("expected bytes, got {other:?}"))),
+    }
+}
+
+#[get("/peg")]
+async fn get_peg(config: &State<ServiceConfig>, rpc: &State<RpcClient>) -> Result<Json<PegResponse>, ApiError> {
+    let simulation = rpc.simulate(&config.source_account, &config.oracle_contract, "get_current_median", vec![]).await?;
+    let ScVal::U64(median_price) = simulation.result else {
+        return Err(ApiError::Decode(format!("expected u64 median, got {:?}", simulation.result)));
+    };
+    let deviation = median_price.abs_diff(PEG_TARGET);
+    Ok(Json(PegResponse { median_price, peg_target: PEG_TARGET, deviation, within_band: deviation < PEG_BAND }))
+}
+
+#[post("/transfer", data = "<request>")]
+async fn post_transfer(
+    config: &State<ServiceConfig>,
+    rpc: &State<RpcClient>,
+    request: Json<TransferRequest>,
+) -> Result<Json<TransferResponse>, ApiError> {
+    let coin_id: [u8; 32] = hex::decode(&request.coin_id)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ApiError::InvalidInput("coin_id must be 32 hex-encoded bytes".to_string()))?;
+    let args = vec![
+        ScVal::Address(ScAddress::Account(account_id(&request.from)?)),
+        ScVal::Address(ScAddress::Account(account_id(&request.to)?)),
+        ScVal::U64(request.amount),
+        ScVal::Bytes(ScBytes(coin_id.to_vec().try_into().unwrap())),
+    ];
+    let simulation = rpc.simulate(&request.from, &config.pi_coin_contract, "transfer", args).await?;
+    Ok(Json(TransferResponse {
+        transaction_xdr: simulation.transaction_xdr,
+        transaction_data: simulation.transaction_data,
+        min_resource_fee: simulation.min_resource_fee,
+    }))
+}
+
+#[get("/dashboard")]
+async fn get_dashboard(config: &State<ServiceConfig>, rpc: &State<RpcClient>) -> Result<Json<DashboardResponse>, ApiError> {
+    let simulation = rpc.simulate(&config.source_account, &config.dashboard_contract, "get_dashboard", vec![]).await?;
+    let data = simulation.result;
+
+    let mut provenance_stats = BTreeMap::new();
+    if let ScVal::Map(Some(stats)) = struct_field(&data, "provenance_stats")? {
+        for entry in stats.iter() {
+            let (ScVal::Symbol(source), ScVal::U64(count)) = (&entry.key, &entry.val) else {
+                return Err(ApiError::Decode("expected Symbol -> u64 provenance stats".to_string()));
+            };
+            provenance_stats.insert(source.0.to_utf8_string_lossy(), *count);
+        }
+    }
+    let global_alerts = match struct_field(&data, "global_alerts")? {
+        ScVal::Vec(Some(alerts)) => alerts.iter().map(bytes_to_string).collect::<Result<_, _>>()?,
+        _ => Vec::new(),
+    };
+    Ok(Json(DashboardResponse {
+        peg_visual: bytes_to_string(struct_field(&data, "peg_visual")?)?,
+        provenance_stats,
+        global_alerts,
+    }))
+}
+
+pub fn build(config: ServiceConfig) -> Rocket<Build> {
+    let rpc = RpcClient::new(&config.rpc_url);
+    rocket::build()
+        .manage(config)
+        .manage(rpc)
+        .mount("/", routes![get_peg, post_transfer, get_dashboard])
+}
+
+#[launch]
+fn rocket() -> _ {
+    let var = |name: &str| std::env::var(name).unwrap_or_else(|_| panic!("{name} must be set"));
+    build(ServiceConfig {
+        rpc_url: var("PI_COIN_RPC_URL"),
+        source_account: var("PI_COIN_SOURCE_ACCOUNT"),
+        pi_coin_contract: var("PI_COIN_CONTRACT"),
+        oracle_contract: var("PI_COIN_ORACLE_CONTRACT"),
+        dashboard_contract: var("PI_COIN_DASHBOARD_CONTRACT"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use rocket::local::blocking::Client;
+    use serde_json::json;
+    use stellar_xdr::curr::{ScMap, ScMapEntry};
+
+    fn account(seed: u8) -> String {
+        stellar_strkey::ed25519::PublicKey([seed; 32]).to_string()
+    }
+
+    fn contract(seed: u8) -> String {
+        stellar_strkey::Contract([seed; 32]).to_string()
+    }
+
+    fn client(server: &MockServer) -> Client {
+        Client::tracked(build(ServiceConfig {
+            rpc_url: server.base_url(),
+            source_account: account(1),
+            pi_coin_contract: contract(2),
+            oracle_contract: contract(3),
+            dashboard_contract: contract(4),
+        }))
+        .unwrap()
+    }
+
+    fn mock_simulation(server: &MockServer, result: ScVal) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST).path("/").body_contains("simulateTransaction");
+            then.status(200).json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "results": [{ "xdr": result.to_xdr_base64(Limits::none()).unwrap(), "auth": [] }],
+                    "transactionData": "AAAA",
+                    "minResourceFee": "5000",
+                    "latestLedger": 100,
+                },
+            }));
+        })
+    }
+
+    #[test]
+    fn test_peg_reads_oracle_median() {
+        let server = MockServer::start();
+        let rpc = mock_simulation(&server, ScVal::U64(314_500));
+        let client = client(&server);
+
+        let response = client.get("/peg").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.into_json::<PegResponse>().unwrap(),
+            PegResponse { median_price: 314_500, peg_target: PEG_TARGET, deviation: 341, within_band: true }
+        );
+        rpc.assert();
+    }
+
+    #[test]
+    fn test_transfer_returns_simulated_transaction() {
+        let server = MockServer::start();
+        let rpc = mock_simulation(&server, ScVal::Void);
+        let client = client(&server);
+
+        let request = TransferRequest { from: account(5), to: account(6), amount: 10, coin_id: hex::encode([7u8; 32]) };
+        let response = client.post("/transfer").json(&request).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        let body = response.into_json::<TransferResponse>().unwrap();
+        assert_eq!(body.min_resource_fee, 5000);
+        assert_eq!(body.transaction_data, "AAAA");
+
+        // The envelope invokes transfer on the Pi Coin contract from the sender
+        let TransactionEnvelope::Tx(envelope) = TransactionEnvelope::from_xdr_base64(&body.transaction_xdr, Limits::none()).unwrap() else {
+            panic!("expected a v1 envelope");
+        };
+        assert_eq!(envelope.tx.source_account, MuxedAccount::Ed25519(Uint256([5; 32])));
+        let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+            panic!("expected an invocation");
+        };
+        let HostFunction::InvokeContract(call) = &op.host_function else {
+            panic!("expected a contract call");
+        };
+        assert_eq!(call.contract_address, contract_address(&contract(2)).unwrap());
+        assert_eq!(call.function_name.0.to_utf8_string_lossy(), "transfer");
+        assert_eq!(call.args[2], ScVal::U64(10));
+        rpc.assert();
+
+        let bad = TransferRequest { coin_id: "abc".to_string(), ..request };
+        assert_eq!(client.post("/transfer").json(&bad).dispatch().status(), Status::BadRequest);
+    }
+
+    #[test]
+    fn test_dashboard_decodes_contract_state() {
+        let server = MockServer::start();
+        let symbol = |s: &str| ScVal::Symbol(ScSymbol(s.try_into().unwrap()));
+        let bytes = |s: &str| ScVal::Bytes(ScBytes(s.as_bytes().to_vec().try_into().unwrap()));
+        let entry = |key: ScVal, val: ScVal| ScMapEntry { key, val };
+        let data = ScVal::Map(Some(ScMap(
+            vec![
+                entry(symbol("global_alerts"), ScVal::Vec(Some(vec![bytes("Global adoption increasing")].try_into().unwrap()))),
+                entry(symbol("peg_visual"), bytes("{\"peg\": \"$314,159\"}")),
+                entry(symbol("provenance_stats"), ScVal::Map(Some(ScMap(vec![entry(symbol("Mining"), ScVal::U64(3))].try_into().unwrap())))),
+            ]
+            .try_into()
+            .unwrap(),
+        )));
+        mock_simulation(&server, data);
+        let client = client(&server);
+
+        let response = client.get("/dashboard").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.into_json::<DashboardResponse>().unwrap(),
+            DashboardResponse {
+                peg_visual: "{\"peg\": \"$314,159\"}".to_string(),
+                provenance_stats: BTreeMap::from([("Mining".to_string(), 3)]),
+                global_alerts: vec!["Global adoption increasing".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_simulation_failure_is_bad_gateway() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200).json_body(json!({ "jsonrpc": "2.0", "id": 1, "result": { "error": "HostError: contract not found", "latestLedger": 100 } }));
+        });
+        let client = client(&server);
+
+        let response = client.get("/peg").dispatch();
+        assert_eq!(response.status(), Status::BadGateway);
+        assert!(response.into_json::<ErrorResponse>().unwrap().error.contains("contract not found"));
+    }
+}
@@ -4,7 +4,7 @@
 // Unmatched: Intelligence beyond human limits.
 
 use soroban_sdk::{Env, Vec, Symbol, log};
-use rand::Rng; // Assume added for randomness.
+use crate::godhead_nexus::prng::NexusPrng;
 
 pub struct DivineIntelligence {
     env: Env,
@@ -20,8 +20,7 @@ impl DivineIntelligence {
 
     /// Divine prediction.
     pub fn divine_predict(&self) -> Symbol {
-        let mut rng = rand::thread_rng();
-        let rand_val = rng.gen_range(0..100);
+        let rand_val = NexusPrng::from_ledger(&self.env).gen_range(0, 100);
         if rand_val < self.probabilities.get(0).unwrap() {
             Symbol::new(&self.env, "divine_stable")
         } else {
@@ -4,7 +4,7 @@
 // Unmatched: Intelligence rivaling divine foresight.
 
 use soroban_sdk::{Env, Vec, Symbol, log};
-use rand::Rng; // Assume added for randomness.
+use crate::godhead_nexus::prng::NexusPrng;
 
 pub struct GodLikeIntelligence {
     env: Env,
@@ -20,8 +20,7 @@ impl GodLikeIntelligence {
 
     /// God-like prediction.
     pub fn god_like_predict(&self) -> Symbol {
-        let mut rng = rand::thread_rng();
-        let rand_val = rng.gen_range(0..100);
+        let rand_val = NexusPrng::from_ledger(&self.env).gen_range(0, 100);
         let probs = &self.god_matrix.get(0).unwrap();
         if rand_val < probs.get(0).unwrap() {
             Symbol::new(&self.env, "god_stable")
@@ -7,6 +7,8 @@
 pub mod ai_core;
 pub mod autonomous_governance;
 pub mod evolution_engine;
+// Shared with the scripts, which import it from pi_coin_contract directly
+pub use pi_coin_contract::prng;
 
 use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
 use crate::godhead_nexus::ai_core::AICore;
@@ -4,7 +4,7 @@
 // Unmatched: Intelligence at the pinnacle of supremacy.
 
 use soroban_sdk::{Env, Vec, Symbol, log};
-use rand::Rng; // Assume added for randomness.
+use crate::godhead_nexus::prng::NexusPrng;
 
 pub struct SupremeIntelligence {
     env: Env,
@@ -20,8 +20,7 @@ impl SupremeIntelligence {
 
     /// Supreme prediction.
     pub fn supreme_predict(&self) -> Symbol {
-        let mut rng = rand::thread_rng();
-        let rand_val = rng.gen_range(0..100);
+        let rand_val = NexusPrng::from_ledger(&self.env).gen_range(0, 100);
         let probs = &self.supreme_matrix.get(0).unwrap();
         if rand_val < probs.get(0).unwrap() {
             Symbol::new(&self.env, "supreme_stable")
@@ -1,13 +1,13 @@
 use std::time::{Instant, Duration};
 use rand::Rng; // For random load generation
-use soroban_sdk::testutils::EnvTest; // For simulation
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
-// Import contracts for benchmarking (placeholders; real impl link to deployed)
-use stellar_pi_coin_sdk::contracts::pi_coin::PiCoinContract;
-use stellar_pi_coin_sdk::contracts::security::SecurityContract;
+// Contracts under benchmark, registered in the host test environment
+use pi_coin_contract::{PiCoinContract, PiCoinContractClient};
 
 // Benchmark result struct
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct BenchmarkResult {
     operation: String,
     tps: f64,
@@ -31,7 +31,8 @@ fn evolve_load_pattern(history: &Vec<BenchmarkResult>, base_load: u32) -> u32 {
 fn main() {
     println!("GodHead Nexus Benchmarking Started");
 
-    let env = EnvTest::default();
+    let env = Env::default();
+    let (pi_coin, user) = setup_pi_coin(&env);
     let mut history: Vec<BenchmarkResult> = Vec::new();
     let mut base_load = 100; // Starting transactions
 
@@ -44,8 +45,7 @@ fn main() {
         println!("Iteration {}: Simulating {} mint operations", iteration, load);
 
         for _ in 0..load {
-            // Simulate mint call (placeholder; real impl: invoke contract)
-            let result = simulate_mint(&env);
+            let result = bench_mint(&env, &pi_coin, &user);
             if !result {
                 errors += 1;
             }
@@ -83,8 +83,33 @@ fn main() {
     println!("GodHead Nexus Benchmarking Completed");
 }
 
-// Placeholder simulation function
-fn simulate_mint(env: &EnvTest) -> bool {
-    // Simulate success/failure randomly
-    rand::random::<bool>()
+// Register and initialize a Pi Coin contract with one compliant minter
+fn setup_pi_coin(env: &Env) -> (PiCoinContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let pi_coin = PiCoinContractClient::new(env, &env.register_contract(None, PiCoinContract));
+    let admin = Address::generate(env);
+    let user = Address::generate(env);
+    pi_coin.init(&Vec::from_array(env, [admin]), &1u32);
+    pi_coin.register_compliance(&user, &true, &Symbol::new(env, "US"), &10u32);
+    (pi_coin, user)
+}
+
+// One mint invocation through the contract client; false when the contract rejects it
+fn bench_mint(env: &Env, pi_coin: &PiCoinContractClient, user: &Address) -> bool {
+    matches!(pi_coin.try_mint(user, &1, &Symbol::new(env, "mining")), Ok(Ok(_)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_mint_invokes_contract() {
+        let env = Env::default();
+        let (pi_coin, user) = setup_pi_coin(&env);
+        for _ in 0..3 {
+            assert!(bench_mint(&env, &pi_coin, &user));
+        }
+        assert_eq!(pi_coin.get_current_supply(), 3);
+    }
 }